@@ -60,7 +60,7 @@ mod tests {
     use super::*;
     use datamodel::{
         dml, Datamodel, DefaultValue as DMLDefault, Field, FieldArity, FieldType, IndexDefinition, Model,
-        OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
+        OnDeleteStrategy, OnUpdateStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
     };
     use native_types::{NativeType, PostgresType};
     use pretty_assertions::assert_eq;
@@ -101,6 +101,7 @@ mod tests {
                 is_commented_out: true,
                 indices: vec![],
                 id_fields: vec![],
+                soft_delete_field: None,
                 fields: col_types
                     .iter()
                     .map(|col_type| {
@@ -126,12 +127,15 @@ mod tests {
                             documentation,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            generated_as: None,
                             is_commented_out,
                         })
                     })
                     .collect(),
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -148,11 +152,14 @@ mod tests {
                         ),
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     })
                     .collect(),
                 indices: vec![],
                 primary_key: None,
                 foreign_keys: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
@@ -189,6 +196,8 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField::new(
@@ -200,8 +209,10 @@ mod tests {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                soft_delete_field: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -213,18 +224,24 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "required".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: true,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "list".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List),
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                 ],
                 indices: vec![],
@@ -234,6 +251,7 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
@@ -270,6 +288,8 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField {
@@ -283,6 +303,8 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField {
@@ -296,6 +318,8 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField {
@@ -309,6 +333,8 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     }),
                 ],
@@ -319,8 +345,10 @@ mod tests {
                     tpe: dml::IndexType::Unique,
                 }],
                 id_fields: vec![],
+                soft_delete_field: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -332,30 +360,40 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "int_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: Some(DefaultValue::value(PrismaValue::Int(1))),
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "bool_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Boolean, ColumnArity::Nullable),
                         default: Some(DefaultValue::value(PrismaValue::Boolean(true))),
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "float_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Nullable),
                         default: Some(DefaultValue::value(PrismaValue::new_float(1.0))),
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "string_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Nullable),
                         default: Some(DefaultValue::value(PrismaValue::String("default".to_string()))),
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                 ],
                 indices: vec![Index {
@@ -365,6 +403,7 @@ mod tests {
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
@@ -397,11 +436,14 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
                 },
                 // Model with non-auto-incrementing primary key
                 Model {
@@ -421,11 +463,14 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
                 },
                 // Model with primary key seeded by sequence
                 Model {
@@ -445,14 +490,18 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -471,6 +520,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
+                        generated: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -479,6 +530,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    comment: None,
                 },
                 Table {
                     name: "Table2".to_string(),
@@ -494,6 +546,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -502,6 +556,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    comment: None,
                 },
                 Table {
                     name: "Table3".to_string(),
@@ -517,16 +572,17 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
+                        generated: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
                         columns: vec!["primary".to_string()],
-                        sequence: Some(Sequence {
-                            name: "sequence".to_string(),
-                        }),
+                        sequence: Some(Sequence::new("sequence")),
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    comment: None,
                 },
             ],
             enums: vec![],
@@ -564,14 +620,18 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     }),
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                soft_delete_field: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -583,12 +643,16 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "unique".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                 ],
                 indices: vec![Index {
@@ -598,6 +662,7 @@ mod tests {
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
@@ -630,6 +695,8 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            generated_as: None,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField::new(
@@ -646,12 +713,14 @@ mod tests {
                                 references: vec![],
                                 name: "CityToUser".to_string(),
                                 on_delete: OnDeleteStrategy::None,
+                                on_update: OnUpdateStrategy::None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
                 },
                 Model {
                     database_name: None,
@@ -671,6 +740,8 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            generated_as: None,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField {
@@ -684,6 +755,8 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            generated_as: None,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField {
@@ -697,6 +770,8 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            generated_as: None,
                             is_commented_out: false,
                         }),
                         Field::RelationField(RelationField::new(
@@ -708,15 +783,18 @@ mod tests {
                                 fields: vec!["city_id".to_string(), "city_name".to_string()],
                                 references: vec!["id".to_string(), "name".to_string()],
                                 on_delete: OnDeleteStrategy::None,
+                                on_update: OnUpdateStrategy::None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -737,6 +815,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            comment: None,
+                            generated: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -751,6 +831,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            comment: None,
+                            generated: None,
                         },
                     ],
                     indices: vec![],
@@ -760,6 +842,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    comment: None,
                 },
                 Table {
                     name: "User".to_string(),
@@ -777,6 +860,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            comment: None,
+                            generated: None,
                         },
                         Column {
                             name: "city-id".to_string(),
@@ -791,6 +876,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            comment: None,
+                            generated: None,
                         },
                         Column {
                             name: "city-name".to_string(),
@@ -805,6 +892,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            comment: None,
+                            generated: None,
                         },
                     ],
                     indices: vec![],
@@ -822,6 +911,7 @@ mod tests {
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
                     }],
+                    comment: None,
                 },
             ],
             enums: vec![],
@@ -854,6 +944,8 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        generated_as: None,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField::new(
@@ -874,8 +966,10 @@ mod tests {
                     tpe: datamodel::dml::IndexType::Unique,
                 }],
                 id_fields: vec![],
+                soft_delete_field: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -895,6 +989,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -909,6 +1005,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "lastname".to_string(),
@@ -923,6 +1021,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                 ],
                 indices: vec![Index {
@@ -936,6 +1036,7 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
@@ -968,6 +1069,8 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            generated_as: None,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField::new(
@@ -984,12 +1087,14 @@ mod tests {
                                 references: vec![],
                                 name: "CityToUser".to_string(),
                                 on_delete: OnDeleteStrategy::None,
+                                on_update: OnUpdateStrategy::None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
                 },
                 Model {
                     database_name: None,
@@ -1009,6 +1114,8 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            generated_as: None,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField::new(
@@ -1025,15 +1132,18 @@ mod tests {
                                 fields: vec!["city_id".to_string()],
                                 references: vec!["id".to_string()],
                                 on_delete: OnDeleteStrategy::None,
+                                on_update: OnUpdateStrategy::None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -1054,6 +1164,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            comment: None,
+                            generated: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -1068,6 +1180,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            comment: None,
+                            generated: None,
                         },
                     ],
                     indices: vec![],
@@ -1077,6 +1191,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    comment: None,
                 },
                 Table {
                     name: "User".to_string(),
@@ -1094,6 +1209,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            comment: None,
+                            generated: None,
                         },
                         Column {
                             name: "city_id".to_string(),
@@ -1108,6 +1225,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            comment: None,
+                            generated: None,
                         },
                     ],
                     indices: vec![],
@@ -1124,6 +1243,7 @@ mod tests {
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string()],
                     }],
+                    comment: None,
                 },
             ],
             enums: vec![],
@@ -1159,6 +1279,7 @@ mod tests {
                     },
                 ],
             }],
+            composite_types: vec![],
         };
 
         let enum_values = vec!["a".to_string(), "b".to_string()];
@@ -1,3 +1,4 @@
+use crate::introspection_helpers::commented_out_documentation;
 use crate::warnings::{
     warning_enum_values_with_empty_names, warning_fields_with_empty_names, warning_models_without_columns,
     warning_models_without_identifier, warning_unsupported_types, EnumAndValue, Model, ModelAndField,
@@ -25,10 +26,10 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, family: &SqlFamily)
 
         for field in model.scalar_fields_mut() {
             if field.name == *"" {
-                field.documentation = Some(
-                    "This field was commented out because of an invalid name. Please provide a valid one that matches [a-zA-Z][a-zA-Z0-9_]*"
-                        .to_string(),
-                );
+                field.documentation = Some(commented_out_documentation(
+                    field.documentation.as_deref(),
+                    "This field was commented out because of an invalid name. Please provide a valid one that matches [a-zA-Z][a-zA-Z0-9_]*",
+                ));
                 field.name = field.database_name.as_ref().unwrap().to_string();
                 field.is_commented_out = true;
 
@@ -90,7 +91,7 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, family: &SqlFamily)
             };
             //postgres could be valid, or privileges, commenting out because we cannot handle it.
             //others, this is invalid,, commenting out because we cannot handle it.
-            model.documentation = Some(comment);
+            model.documentation = Some(commented_out_documentation(model.documentation.as_deref(), &comment));
             models_without_columns.push(Model {
                 model: model.name.clone(),
             })
@@ -104,10 +105,10 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, family: &SqlFamily)
     {
         if model.strict_unique_criterias().is_empty() {
             model.is_commented_out = true;
-            model.documentation = Some(
-                "The underlying table does not contain a valid unique identifier and can therefore currently not be handled."
-                    .to_string(),
-            );
+            model.documentation = Some(commented_out_documentation(
+                model.documentation.as_deref(),
+                "The underlying table does not contain a valid unique identifier and can therefore currently not be handled.",
+            ));
             models_without_identifiers.push(Model {
                 model: model.name.clone(),
             })
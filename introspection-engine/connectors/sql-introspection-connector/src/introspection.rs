@@ -29,10 +29,11 @@ pub fn introspect(
     {
         debug!("Calculating model: {}", table.name);
         let mut model = Model::new(table.name.clone(), None);
+        model.documentation = table.comment.clone();
 
         for column in &table.columns {
             version_check.check_column_for_type_and_default_value(&column);
-            let field = calculate_scalar_field(&table, &column, &sql_family, native_types);
+            let field = calculate_scalar_field(schema, &table, &column, &sql_family, native_types);
             model.add_field(Field::ScalarField(field));
         }
 
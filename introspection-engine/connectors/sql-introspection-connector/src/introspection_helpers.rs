@@ -1,8 +1,9 @@
 use crate::Dedup;
 use crate::SqlError;
 use datamodel::{
-    common::RelationNames, Datamodel, DefaultValue as DMLDef, FieldArity, FieldType, IndexDefinition, Model,
-    OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
+    common::RelationNames, Datamodel, DefaultValue as DMLDef, FieldArity, FieldType, GeneratedColumn, IndexDefinition,
+    Model, OnDeleteStrategy, OnUpdateStrategy, RelationField, RelationInfo, ScalarField, ScalarType, SequenceOptions,
+    ValueGenerator as VG,
 };
 use datamodel_connector::Connector;
 use quaint::connector::SqlFamily;
@@ -108,6 +109,7 @@ pub fn calculate_many_to_many_field(
         to: opposite_foreign_key.referenced_table.clone(),
         references: opposite_foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        on_update: OnUpdateStrategy::None,
     };
 
     let basename = opposite_foreign_key.referenced_table.clone();
@@ -135,6 +137,7 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
 }
 
 pub(crate) fn calculate_scalar_field(
+    schema: &SqlSchema,
     table: &Table,
     column: &Column,
     family: &SqlFamily,
@@ -155,30 +158,29 @@ pub(crate) fn calculate_scalar_field(
         ColumnArity::List => FieldArity::List,
     };
 
-    let (default_value, dbgenerated_string) = calculate_default(table, &column, &arity);
+    let default_value = calculate_default(schema, table, &column, &arity);
+
+    // Without its expression, a generated column can't be described, and it must not be written to.
+    let unknown_generation_expression = column
+        .generated
+        .as_ref()
+        .map(|generated| generated.expression.is_empty())
+        .unwrap_or(false);
 
-    let (is_commented_out, documentation) = match (&field_type, dbgenerated_string) {
-        (FieldType::Unsupported(_), None) => (true, Some("This type is currently not supported.".to_string())),
-        (FieldType::Unsupported(_), Some(default)) => (
+    let (is_commented_out, reason) = match &field_type {
+        FieldType::Unsupported(_) => (true, Some("This type is currently not supported.")),
+        _ if unknown_generation_expression => (
             true,
-            Some(format!(
-                "This type is currently not supported.\nThe value of this field is generated by the database as: `{}`.",
-                default
-            )),
-        ),
-        (_, Some(default)) => (
-            false,
-            Some(
-                format!(
-                    "The value of this field is generated by the database as: `{}`.",
-                    default
-                )
-                .to_string(),
-            ),
+            Some("The generation expression of this column could not be read."),
         ),
         _ => (false, None),
     };
 
+    let documentation = match reason {
+        Some(reason) => Some(commented_out_documentation(column.comment.as_deref(), reason)),
+        None => column.comment.clone(),
+    };
+
     let is_unique = table.is_column_unique(&column.name) && !is_id;
 
     ScalarField {
@@ -192,10 +194,28 @@ pub(crate) fn calculate_scalar_field(
         documentation,
         is_generated: false,
         is_updated_at: false,
+        is_version: false,
+        generated_as: column
+            .generated
+            .as_ref()
+            .filter(|generated| !generated.expression.is_empty())
+            .map(|generated| GeneratedColumn {
+                expression: generated.expression.clone(),
+                stored: generated.stored,
+            }),
         is_commented_out,
     }
 }
 
+/// The documentation of a commented out model or field: the comment of its table or column,
+/// if any, followed by the reason for commenting it out.
+pub(crate) fn commented_out_documentation(comment: Option<&str>, reason: &str) -> String {
+    match comment {
+        Some(comment) => format!("{}\n{}", comment, reason),
+        None => reason.to_owned(),
+    }
+}
+
 pub(crate) fn calculate_relation_field(
     schema: &SqlSchema,
     table: &Table,
@@ -209,6 +229,7 @@ pub(crate) fn calculate_relation_field(
         to: foreign_key.referenced_table.clone(),
         references: foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        on_update: OnUpdateStrategy::None,
     };
 
     let columns: Vec<&Column> = foreign_key
@@ -243,6 +264,7 @@ pub(crate) fn calculate_backrelation_field(
                 fields: vec![],
                 references: vec![],
                 on_delete: OnDeleteStrategy::None,
+                on_update: OnUpdateStrategy::None,
             };
 
             // unique or id
@@ -271,35 +293,64 @@ pub(crate) fn calculate_backrelation_field(
 }
 
 pub(crate) fn calculate_default(
+    schema: &SqlSchema,
     table: &Table,
     column: &Column,
     arity: &FieldArity,
-) -> (Option<DMLDef>, Option<String>) {
+) -> Option<DMLDef> {
+    let sequence_options = sequence_options(schema, table, column);
+
     match (column.default.as_ref().map(|d| d.kind()), &column.tpe.family) {
-        (_, _) if *arity == FieldArity::List => (None, None),
-        (_, ColumnTypeFamily::Int) if column.auto_increment => {
-            (Some(DMLDef::Expression(VG::new_autoincrement())), None)
-        }
-        (_, ColumnTypeFamily::BigInt) if column.auto_increment => {
-            (Some(DMLDef::Expression(VG::new_autoincrement())), None)
-        }
-        (_, ColumnTypeFamily::Int) if is_sequence(column, table) => {
-            (Some(DMLDef::Expression(VG::new_autoincrement())), None)
+        (_, _) if *arity == FieldArity::List => None,
+        // Generated columns are computed by the database and can't have a default.
+        (_, _) if column.generated.is_some() => None,
+        (_, ColumnTypeFamily::Int) | (_, ColumnTypeFamily::BigInt) if sequence_options.is_some() => {
+            sequence_options.map(|options| DMLDef::Expression(VG::new_sequence(options)))
         }
+        (_, ColumnTypeFamily::Int) if column.auto_increment => Some(DMLDef::Expression(VG::new_autoincrement())),
+        (_, ColumnTypeFamily::BigInt) if column.auto_increment => Some(DMLDef::Expression(VG::new_autoincrement())),
+        (_, ColumnTypeFamily::Int) if is_sequence(column, table) => Some(DMLDef::Expression(VG::new_autoincrement())),
         (_, ColumnTypeFamily::BigInt) if is_sequence(column, table) => {
-            (Some(DMLDef::Expression(VG::new_autoincrement())), None)
+            Some(DMLDef::Expression(VG::new_autoincrement()))
         }
-        (Some(DefaultKind::SEQUENCE(_)), _) => (Some(DMLDef::Expression(VG::new_autoincrement())), None),
-        (Some(DefaultKind::NOW), ColumnTypeFamily::DateTime) => (Some(DMLDef::Expression(VG::new_now())), None),
-        (Some(DefaultKind::DBGENERATED(default_string)), _) => (
-            Some(DMLDef::Expression(VG::new_dbgenerated())),
-            Some(default_string.clone()),
+        (Some(DefaultKind::SEQUENCE(_)), _) => Some(DMLDef::Expression(VG::new_autoincrement())),
+        (Some(DefaultKind::NOW), ColumnTypeFamily::DateTime) => Some(DMLDef::Expression(VG::new_now())),
+        (Some(DefaultKind::DBGENERATED(default_string)), _) if !default_string.trim().is_empty() => Some(
+            DMLDef::Expression(VG::new_dbgenerated_with_expression(default_string.clone())),
         ),
-        (Some(DefaultKind::VALUE(val)), _) => (Some(DMLDef::Single(val.clone())), None),
-        _ => (None, None),
+        (Some(DefaultKind::DBGENERATED(_)), _) => Some(DMLDef::Expression(VG::new_dbgenerated())),
+        (Some(DefaultKind::VALUE(val)), _) => Some(DMLDef::Single(val.clone())),
+        _ => None,
     }
 }
 
+/// The `sequence()` options of identity columns and of columns drawing from a
+/// standalone sequence. Options with the default value of 1 are left out.
+fn sequence_options(schema: &SqlSchema, table: &Table, column: &Column) -> Option<SequenceOptions> {
+    let identity_sequence = schema.sequences.iter().find(|sequence| {
+        sequence
+            .owned_by
+            .as_ref()
+            .map(|owner| owner.identity && owner.table == table.name && owner.column == column.name)
+            .unwrap_or(false)
+    });
+
+    let (name, sequence) = match (identity_sequence, column.default.as_ref().map(|d| d.kind())) {
+        (Some(sequence), _) => (None, sequence),
+        (None, Some(DefaultKind::SEQUENCE(name))) => match schema.get_sequence(name) {
+            Some(sequence) if sequence.is_standalone() => (Some(sequence.name.clone()), sequence),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(SequenceOptions {
+        name,
+        start: Some(sequence.start).filter(|start| *start != 1),
+        increment: Some(sequence.increment).filter(|increment| *increment != 1),
+    })
+}
+
 pub(crate) fn is_id(column: &Column, table: &Table) -> bool {
     table
         .primary_key
@@ -71,20 +71,16 @@ impl RpcImpl {
     ) -> Result<(Configuration, String, Box<dyn IntrospectionConnector>), Error> {
         let config = datamodel::parse_configuration(&schema)?;
 
-        let url = config
+        let source = config
             .subject
             .datasources
             .first()
-            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?
-            .url()
-            .to_owned()
-            .value;
-
-        Ok((
-            config.subject,
-            url.clone(),
-            Box::new(SqlIntrospectionConnector::new(&url).await?),
-        ))
+            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?;
+
+        let url = source.url().value.clone();
+        let connector = SqlIntrospectionConnector::new(&source.connection_url()).await?;
+
+        Ok((config.subject, url, Box::new(connector)))
     }
 
     pub async fn catch<O>(fut: impl std::future::Future<Output = ConnectorResult<O>>) -> RpcResult<O> {
@@ -229,7 +229,7 @@ async fn remapping_field_names_to_empty(api: &TestApi) -> crate::TestResult {
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn db_genererated_values_should_add_comments(api: &TestApi) -> crate::TestResult {
+async fn db_generated_values_should_keep_their_expression(api: &TestApi) -> crate::TestResult {
     api.barrel()
         .execute_with_schema(
             |migration| {
@@ -248,11 +248,9 @@ async fn db_genererated_values_should_add_comments(api: &TestApi) -> crate::Test
         model Blog {
           id            Int    @id @default(autoincrement())
           number        Int?   @default(1)
-          /// The value of this field is generated by the database as: `sqrt((4)::double precision)`.
-          bigger_number Int?   @default(dbgenerated())
+          bigger_number Int?   @default(dbgenerated("sqrt((4)::double precision)"))
           // This type is currently not supported.
-          // The value of this field is generated by the database as: `point((0)::double precision, (0)::double precision)`.
-          // point      point? @default(dbgenerated())
+          // point      point? @default(dbgenerated("point((0)::double precision, (0)::double precision)"))
         }
     "##};
 
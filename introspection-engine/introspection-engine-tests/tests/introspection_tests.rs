@@ -5,6 +5,8 @@ mod errors;
 mod identify_version;
 mod lists;
 mod model_renames;
+mod mssql;
+mod mysql;
 mod native_types;
 mod postgres;
 mod re_introspection;
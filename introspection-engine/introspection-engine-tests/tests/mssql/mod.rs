@@ -0,0 +1,50 @@
+use indoc::indoc;
+use introspection_engine_tests::test_api::*;
+use pretty_assertions::assert_eq;
+use test_macros::test_each_connector;
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn table_and_column_comments_are_introspected_as_documentation(api: &TestApi) -> crate::TestResult {
+    let schema = api.db_name().to_owned();
+
+    api.barrel()
+        .execute_with_schema(
+            move |migration| {
+                migration.create_table("Blog", move |t| {
+                    t.inject_custom("id int primary key");
+                    t.inject_custom("title nvarchar(max) not null");
+                    t.inject_custom("cover image not null");
+                });
+
+                migration.inject_custom(format!(
+                    "EXEC sp_addextendedproperty @name = N'MS_Description', @value = N'The blog posts.', @level0type = N'SCHEMA', @level0name = N'{}', @level1type = N'TABLE', @level1name = N'Blog'",
+                    schema
+                ));
+
+                for (column, comment) in &[("title", "The title of the post."), ("cover", "The cover of the post.")] {
+                    migration.inject_custom(format!(
+                        "EXEC sp_addextendedproperty @name = N'MS_Description', @value = N'{}', @level0type = N'SCHEMA', @level0name = N'{}', @level1type = N'TABLE', @level1name = N'Blog', @level2type = N'COLUMN', @level2name = N'{}'",
+                        comment, schema, column
+                    ));
+                }
+            },
+            api.db_name(),
+        )
+        .await?;
+
+    let dm = indoc! {r#"
+        /// The blog posts.
+        model Blog {
+          id       Int    @id
+          /// The title of the post.
+          title    String
+          // The cover of the post.
+          // This type is currently not supported.
+          // cover image
+        }
+    "#};
+
+    assert_eq!(dm, &api.introspect().await?);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use indoc::indoc;
+use introspection_engine_tests::{assert_eq_datamodels, test_api::*};
+use pretty_assertions::assert_eq;
+use test_macros::test_each_connector;
+
+#[test_each_connector(tags("mysql"))]
+async fn table_and_column_comments_are_introspected_as_documentation(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("title Text Not Null COMMENT 'The title of the post.'");
+            });
+
+            migration.inject_custom("ALTER TABLE `Blog` COMMENT = 'The blog posts.'");
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        /// The blog posts.
+        model Blog {
+          id    Int    @id
+          /// The title of the post.
+          title String
+        }
+    "#};
+
+    let result = api.introspect().await?;
+
+    assert_eq_datamodels!(dm, &result);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn comments_of_tables_without_identifiers_are_kept(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("title Text Not Null");
+            });
+
+            migration.inject_custom("ALTER TABLE `Blog` COMMENT = 'The blog posts.'");
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        // The blog posts.
+        // The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
+        // model Blog {
+          // title String
+        // }
+    "#};
+
+    assert_eq!(dm, &api.introspect().await?);
+
+    Ok(())
+}
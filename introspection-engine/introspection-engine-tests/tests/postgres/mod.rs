@@ -1,5 +1,6 @@
 use indoc::indoc;
 use introspection_engine_tests::{assert_eq_datamodels, test_api::*};
+use pretty_assertions::assert_eq;
 use test_macros::test_each_connector;
 
 #[test_each_connector(tags("postgres"))]
@@ -33,9 +34,9 @@ async fn sequences_should_work(api: &TestApi) -> crate::TestResult {
         model Test {
           id     Int  @id
           serial Int  @default(autoincrement())
-          first  Int  @default(autoincrement())
-          second Int? @default(autoincrement())
-          third  Int  @default(autoincrement())
+          first  Int  @default(sequence(name: "first_Sequence"))
+          second Int? @default(sequence(name: "second_sequence"))
+          third  Int  @default(sequence(name: "third_Sequence"))
         }
     "#}
     .to_string();
@@ -48,3 +49,121 @@ async fn sequences_should_work(api: &TestApi) -> crate::TestResult {
     assert_eq_datamodels!(&result, &dm);
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn shared_sequences_and_their_options_are_introspected(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.inject_custom("CREATE SEQUENCE \"document_numbers\" START WITH 1000 INCREMENT BY 10");
+
+            migration.create_table("Invoice", move |t| {
+                t.inject_custom("id Integer Primary Key Default nextval('\"document_numbers\"'::regclass)");
+            });
+
+            migration.create_table("Receipt", move |t| {
+                t.inject_custom("id Integer Primary Key Default nextval('\"document_numbers\"'::regclass)");
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Invoice {
+          id Int @id @default(sequence(name: "document_numbers", start: 1000, increment: 10))
+        }
+
+        model Receipt {
+          id Int @id @default(sequence(name: "document_numbers", start: 1000, increment: 10))
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn identity_columns_are_introspected_as_sequences(api: &TestApi) -> crate::TestResult {
+    // Identity columns were introduced in Postgres 10.
+    if api.get_database_version().await?.contains("PostgreSQL 9") {
+        return Ok(());
+    }
+
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Invoice", move |t| {
+                t.inject_custom(
+                    "id Integer Primary Key Generated By Default As Identity (Start With 100 Increment By 5)",
+                );
+                t.inject_custom("number Integer Not Null Generated By Default As Identity");
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Invoice {
+          id     Int @id @default(sequence(start: 100, increment: 5))
+          number Int @default(sequence())
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn table_and_column_comments_are_introspected_as_documentation(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("title Text Not Null");
+            });
+
+            migration.inject_custom("COMMENT ON TABLE \"Blog\" IS 'The blog posts.'");
+            migration.inject_custom("COMMENT ON COLUMN \"Blog\".\"title\" IS 'The title of the post.'");
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        /// The blog posts.
+        model Blog {
+          id    Int    @id
+          /// The title of the post.
+          title String
+        }
+    "#};
+
+    let result = api.introspect().await?;
+
+    assert_eq_datamodels!(dm, &result);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn comments_of_unsupported_columns_are_kept(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Device", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("mac macaddr Not Null");
+            });
+
+            migration.inject_custom("COMMENT ON COLUMN \"Device\".\"mac\" IS 'The MAC address of the device.'");
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Device {
+          id     Int     @id
+          // The MAC address of the device.
+          // This type is currently not supported.
+          // mac macaddr
+        }
+    "#};
+
+    assert_eq!(dm, &api.introspect().await?);
+
+    Ok(())
+}
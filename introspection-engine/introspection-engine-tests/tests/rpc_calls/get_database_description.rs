@@ -173,7 +173,14 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                         "id"
                     ],
                     "sequence": {
-                        "name": "Blog_id_seq"
+                        "name": "Blog_id_seq",
+                        "start": 1,
+                        "increment": 1,
+                        "ownedBy": {
+                            "table": "Blog",
+                            "column": "id",
+                            "identity": false
+                        }
                     },
                     "constraintName": "Blog_pkey"
                 },
@@ -183,7 +190,14 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
         "enums": [],
         "sequences": [
             {
-                "name": "Blog_id_seq"
+                "name": "Blog_id_seq",
+                "start": 1,
+                "increment": 1,
+                "ownedBy": {
+                    "table": "Blog",
+                    "column": "id",
+                    "identity": false
+                }
             }
         ]
     });
@@ -398,11 +398,9 @@ async fn pg_default_value_as_dbgenerated(api: &TestApi) -> crate::TestResult {
     let dm = indoc! {r#"
         model Test {
           id              Int       @id @default(autoincrement())
-          /// The value of this field is generated by the database as: `(('  '::text || '>'::text) || ' '::text)`.
-          string_function String?   @default(dbgenerated())
+          string_function String?   @default(dbgenerated("(('  '::text || '>'::text) || ' '::text)"))
           int_serial      Int       @default(autoincrement())
-          /// The value of this field is generated by the database as: `date_part('year'::text, '2001-02-16 20:38:40'::timestamp without time zone)`.
-          int_function    Int?      @default(dbgenerated())
+          int_function    Int?      @default(dbgenerated("date_part('year'::text, '2001-02-16 20:38:40'::timestamp without time zone)"))
           int_sequence    Int?      @default(autoincrement())
           datetime_now    DateTime? @default(now())
           datetime_now_lc DateTime? @default(now())
@@ -658,3 +656,133 @@ async fn negative_default_values_should_work(api: &TestApi) -> crate::TestResult
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn generated_columns_are_introspected_on_sqlite(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Rectangle", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("width", types::integer());
+                t.add_column("height", types::integer());
+                t.inject_custom("area INTEGER GENERATED ALWAYS AS (width * (height + 0)) STORED");
+                t.inject_custom("half INTEGER AS (width / 2)");
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Rectangle {
+          id     Int  @id @default(autoincrement())
+          width  Int
+          height Int
+          area   Int? @generated("width * (height + 0)", stored: true)
+          half   Int? @generated("width / 2")
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn generated_columns_are_introspected_on_postgres(api: &TestApi) -> crate::TestResult {
+    // Generated columns were introduced in PostgreSQL 12.
+    let version = api.get_database_version().await?;
+
+    if ["PostgreSQL 9", "PostgreSQL 10", "PostgreSQL 11"]
+        .iter()
+        .any(|old| version.contains(old))
+    {
+        return Ok(());
+    }
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Rectangle", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("width", types::integer());
+                t.add_column("height", types::integer());
+                t.inject_custom("area INTEGER GENERATED ALWAYS AS (width * height) STORED");
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Rectangle {
+          id     Int  @id @default(autoincrement())
+          width  Int
+          height Int
+          area   Int? @generated("(width * height)", stored: true)
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn generated_columns_are_introspected_on_mysql(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Rectangle", |t| {
+                    t.add_column("id", types::integer().primary(true));
+                    t.add_column("width", types::integer());
+                    t.add_column("height", types::integer());
+                    t.inject_custom("area INTEGER GENERATED ALWAYS AS (width * height) STORED");
+                    t.inject_custom("half INTEGER GENERATED ALWAYS AS (width / 2) VIRTUAL");
+                });
+            },
+            api.schema_name(),
+        )
+        .await?;
+
+    let dm = indoc! {r#"
+        model Rectangle {
+          id     Int  @id
+          width  Int
+          height Int
+          area   Int? @generated("(`width` * `height`)", stored: true)
+          half   Int? @generated("(`width` / 2)")
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn computed_columns_are_introspected_on_sql_server(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Rectangle", |t| {
+                    t.inject_custom("id int primary key");
+                    t.inject_custom("width int not null");
+                    t.inject_custom("height int not null");
+                    t.inject_custom("area AS (width * height) PERSISTED");
+                    t.inject_custom("half AS (width / 2)");
+                });
+            },
+            api.db_name(),
+        )
+        .await?;
+
+    let dm = indoc! {r#"
+        model Rectangle {
+          id     Int  @id
+          width  Int
+          height Int
+          area   Int? @generated("([width]*[height])", stored: true)
+          half   Int? @generated("([width]/(2))")
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
@@ -82,6 +82,18 @@ impl ConnectorError {
         })
     }
 
+    pub fn new_virtual_generated_column_error(connector_name: &str) -> ConnectorError {
+        ConnectorError::from_kind(ErrorKind::VirtualGeneratedColumnNotSupported {
+            connector_name: String::from(connector_name),
+        })
+    }
+
+    pub fn new_required_virtual_generated_column_error(connector_name: &str) -> ConnectorError {
+        ConnectorError::from_kind(ErrorKind::RequiredVirtualGeneratedColumnNotSupported {
+            connector_name: String::from(connector_name),
+        })
+    }
+
     pub fn new_argument_m_out_of_range_error(message: &str, native_type: &str, connector_name: &str) -> ConnectorError {
         ConnectorError::from_kind(ErrorKind::ArgumentOutOfRangeError {
             native_type: String::from(native_type),
@@ -227,4 +239,16 @@ pub enum ErrorKind {
         connector_name: String,
         message: String,
     },
+
+    #[error(
+        "Generated columns must be stored on {}. Add `stored: true` to the @generated attribute.",
+        connector_name
+    )]
+    VirtualGeneratedColumnNotSupported { connector_name: String },
+
+    #[error(
+        "Generated columns that are not stored must be optional on {}. Make the field optional or add `stored: true` to the @generated attribute.",
+        connector_name
+    )]
+    RequiredVirtualGeneratedColumnNotSupported { connector_name: String },
 }
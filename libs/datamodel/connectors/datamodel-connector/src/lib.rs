@@ -71,6 +71,10 @@ pub trait Connector: Send + Sync {
         self.has_capability(ConnectorCapability::AutoIncrementNonIndexedAllowed)
     }
 
+    fn supports_sequences(&self) -> bool {
+        self.has_capability(ConnectorCapability::Sequences)
+    }
+
     fn allows_relation_fields_in_arbitrary_order(&self) -> bool {
         self.has_capability(ConnectorCapability::RelationFieldsInArbitraryOrder)
     }
@@ -108,8 +112,10 @@ pub enum ConnectorCapability {
     AutoIncrementMultipleAllowed,
     AutoIncrementNonIndexedAllowed,
     RelationFieldsInArbitraryOrder,
+    Sequences,
     // start of Query Engine Capabilities
     InsensitiveFilters,
+    CompositeTypeFilters,
 }
 
 /// Contains all capabilities that the connector is able to serve.
@@ -37,7 +37,7 @@ pub struct ValueGenerator {
 
 impl ValueGenerator {
     pub fn new(name: String, args: Vec<PrismaValue>) -> std::result::Result<Self, String> {
-        let generator = ValueGeneratorFn::new(name.as_ref())?;
+        let generator = ValueGeneratorFn::new(name.as_ref(), &args)?;
 
         Ok(ValueGenerator { name, args, generator })
     }
@@ -50,6 +50,12 @@ impl ValueGenerator {
         ValueGenerator::new("dbgenerated".to_owned(), vec![]).unwrap()
     }
 
+    /// A `dbgenerated("expression")` default. The expression is passed to the
+    /// database verbatim.
+    pub fn new_dbgenerated_with_expression(expression: String) -> Self {
+        ValueGenerator::new("dbgenerated".to_owned(), vec![PrismaValue::String(expression)]).unwrap()
+    }
+
     pub fn new_now() -> Self {
         ValueGenerator::new("now".to_owned(), vec![]).unwrap()
     }
@@ -62,10 +68,64 @@ impl ValueGenerator {
         ValueGenerator::new("uuid".to_owned(), vec![]).unwrap()
     }
 
+    pub fn new_nanoid(size: Option<u8>) -> Self {
+        let args = size.map(|size| vec![PrismaValue::Int(size as i64)]).unwrap_or_default();
+
+        ValueGenerator::new("nanoid".to_owned(), args).unwrap()
+    }
+
+    pub fn new_ulid() -> Self {
+        ValueGenerator::new("ulid".to_owned(), vec![]).unwrap()
+    }
+
+    /// A `sequence(name: .., start: .., increment: ..)` default. All
+    /// options are optional, missing ones are stored as nulls.
+    pub fn new_sequence(options: SequenceOptions) -> Self {
+        let args = vec![
+            options.name.map(PrismaValue::String).unwrap_or(PrismaValue::Null),
+            options.start.map(PrismaValue::Int).unwrap_or(PrismaValue::Null),
+            options.increment.map(PrismaValue::Int).unwrap_or(PrismaValue::Null),
+        ];
+
+        ValueGenerator::new("sequence".to_owned(), args).unwrap()
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
 
+    /// The raw SQL expression of a `dbgenerated("expression")` default, if
+    /// one was given.
+    pub fn db_generated_expression(&self) -> Option<&str> {
+        match (self.generator, self.args.first()) {
+            (ValueGeneratorFn::DbGenerated, Some(PrismaValue::String(expression))) => Some(expression),
+            _ => None,
+        }
+    }
+
+    /// The options of a `sequence()` default.
+    pub fn sequence_options(&self) -> Option<SequenceOptions> {
+        if self.generator != ValueGeneratorFn::Sequence {
+            return None;
+        }
+
+        let mut options = SequenceOptions::default();
+
+        if let Some(PrismaValue::String(name)) = self.args.get(0) {
+            options.name = Some(name.clone());
+        }
+
+        if let Some(PrismaValue::Int(start)) = self.args.get(1) {
+            options.start = Some(*start);
+        }
+
+        if let Some(PrismaValue::Int(increment)) = self.args.get(2) {
+            options.increment = Some(*increment);
+        }
+
+        Some(options)
+    }
+
     fn args(&self) -> &[PrismaValue] {
         &self.args
     }
@@ -87,23 +147,82 @@ impl ValueGenerator {
     }
 }
 
+/// The options of a `sequence()` default. A sequence with a name is a
+/// standalone database sequence that can be shared between fields, one
+/// without a name is owned by its column (an identity column on Postgres).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SequenceOptions {
+    /// The name of the sequence.
+    pub name: Option<String>,
+    /// The first value the sequence produces.
+    pub start: Option<i64>,
+    /// The step between two values of the sequence.
+    pub increment: Option<i64>,
+}
+
+/// The default size of ids generated by `nanoid()`.
+const NANOID_DEFAULT_SIZE: u8 = 21;
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum ValueGeneratorFn {
     UUID,
+    UUIDv7,
     CUID,
+    Nanoid(Option<u8>),
+    ULID,
     Now,
     Autoincrement,
+    Sequence,
     DbGenerated,
 }
 
 impl ValueGeneratorFn {
-    fn new(name: &str) -> std::result::Result<Self, String> {
-        match name {
-            "cuid" => Ok(Self::CUID),
-            "uuid" => Ok(Self::UUID),
-            "now" => Ok(Self::Now),
-            "autoincrement" => Ok(Self::Autoincrement),
-            "dbgenerated" => Ok(Self::DbGenerated),
+    fn new(name: &str, args: &[PrismaValue]) -> std::result::Result<Self, String> {
+        match (name, args) {
+            ("cuid", []) => Ok(Self::CUID),
+            ("uuid", []) => Ok(Self::UUID),
+            ("uuid", [PrismaValue::Int(4)]) => Ok(Self::UUID),
+            ("uuid", [PrismaValue::Int(7)]) => Ok(Self::UUIDv7),
+            ("uuid", _) => Err("The function `uuid()` only accepts the versions `4` and `7`.".to_owned()),
+            ("nanoid", []) => Ok(Self::Nanoid(None)),
+            ("nanoid", [PrismaValue::Int(size)]) if *size >= 2 && *size <= 255 => Ok(Self::Nanoid(Some(*size as u8))),
+            ("nanoid", _) => Err("The function `nanoid()` only accepts a size between `2` and `255`.".to_owned()),
+            ("ulid", []) => Ok(Self::ULID),
+            ("now", []) => Ok(Self::Now),
+            ("autoincrement", []) => Ok(Self::Autoincrement),
+            ("sequence", [name, start, increment]) => {
+                match name {
+                    PrismaValue::Null => (),
+                    PrismaValue::String(name) if !name.trim().is_empty() => (),
+                    _ => return Err("The `name` of a sequence must be a non-empty string.".to_owned()),
+                }
+
+                if !matches!(start, PrismaValue::Null | PrismaValue::Int(_)) {
+                    return Err("The `start` of a sequence must be an integer.".to_owned());
+                }
+
+                match increment {
+                    PrismaValue::Null => (),
+                    PrismaValue::Int(increment) if *increment != 0 => (),
+                    _ => return Err("The `increment` of a sequence must be a non-zero integer.".to_owned()),
+                }
+
+                Ok(Self::Sequence)
+            }
+            ("sequence", _) => {
+                Err("The function `sequence()` only accepts the arguments `name`, `start` and `increment`.".to_owned())
+            }
+            ("dbgenerated", []) => Ok(Self::DbGenerated),
+            ("dbgenerated", [PrismaValue::String(expression)]) if !expression.trim().is_empty() => {
+                Ok(Self::DbGenerated)
+            }
+            ("dbgenerated", _) => Err(
+                "The function `dbgenerated()` only accepts a single non-empty string containing an SQL expression."
+                    .to_owned(),
+            ),
+            ("cuid", _) | ("ulid", _) | ("now", _) | ("autoincrement", _) => {
+                Err(format!("The function `{}()` does not take any arguments.", name))
+            }
             _ => Err(format!("The function {} is not a known function.", name)),
         }
     }
@@ -111,9 +230,13 @@ impl ValueGeneratorFn {
     fn invoke(&self) -> Option<PrismaValue> {
         match self {
             Self::UUID => Self::generate_uuid(),
+            Self::UUIDv7 => Self::generate_uuid_v7(),
             Self::CUID => Self::generate_cuid(),
+            Self::Nanoid(size) => Self::generate_nanoid(size.unwrap_or(NANOID_DEFAULT_SIZE)),
+            Self::ULID => Self::generate_ulid(),
             Self::Now => Self::generate_now(),
             Self::Autoincrement => None,
+            Self::Sequence => None,
             Self::DbGenerated => None,
         }
     }
@@ -121,10 +244,15 @@ impl ValueGeneratorFn {
     fn can_handle(&self, scalar_type: ScalarType) -> bool {
         match (self, scalar_type) {
             (Self::UUID, ScalarType::String) => true,
+            (Self::UUIDv7, ScalarType::String) => true,
             (Self::CUID, ScalarType::String) => true,
+            (Self::Nanoid(_), ScalarType::String) => true,
+            (Self::ULID, ScalarType::String) => true,
             (Self::Now, ScalarType::DateTime) => true,
             (Self::Autoincrement, ScalarType::Int) => true,
             (Self::Autoincrement, ScalarType::BigInt) => true,
+            (Self::Sequence, ScalarType::Int) => true,
+            (Self::Sequence, ScalarType::BigInt) => true,
             (Self::DbGenerated, _) => true,
             _ => false,
         }
@@ -138,6 +266,27 @@ impl ValueGeneratorFn {
         Some(PrismaValue::Uuid(Uuid::new_v4()))
     }
 
+    /// Time-ordered UUID: a 48 bit unix timestamp in milliseconds, followed by
+    /// the version and variant bits and random data.
+    fn generate_uuid_v7() -> Option<PrismaValue> {
+        let mut bytes: [u8; 16] = rand::random();
+        let millis = (Utc::now().timestamp_millis() as u64).to_be_bytes();
+
+        bytes[..6].copy_from_slice(&millis[2..]);
+        bytes[6] = (bytes[6] & 0x0f) | 0x70;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        Some(PrismaValue::Uuid(Uuid::from_bytes(bytes)))
+    }
+
+    fn generate_nanoid(size: u8) -> Option<PrismaValue> {
+        Some(PrismaValue::String(nanoid::nanoid!(size as usize)))
+    }
+
+    fn generate_ulid() -> Option<PrismaValue> {
+        Some(PrismaValue::String(ulid::Ulid::new().to_string()))
+    }
+
     fn generate_now() -> Option<PrismaValue> {
         Some(PrismaValue::DateTime(Utc::now().into()))
     }
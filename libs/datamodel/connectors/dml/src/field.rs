@@ -41,6 +41,8 @@ pub enum FieldType {
     Unsupported(String),
     /// The option is Some(x) if the scalar type is based upon a type alias.
     Base(ScalarType, Option<String>),
+    /// This is a field holding a value of the composite type of the given name.
+    CompositeType(String),
 }
 
 impl FieldType {
@@ -138,6 +140,20 @@ impl Field {
         }
     }
 
+    pub fn is_version(&self) -> bool {
+        match &self {
+            Field::ScalarField(sf) => sf.is_version,
+            Field::RelationField(_) => false,
+        }
+    }
+
+    pub fn generated_as(&self) -> Option<&GeneratedColumn> {
+        match &self {
+            Field::ScalarField(sf) => sf.generated_as.as_ref(),
+            Field::RelationField(_) => None,
+        }
+    }
+
     pub fn is_unique(&self) -> bool {
         match &self {
             Field::ScalarField(sf) => sf.is_unique,
@@ -294,6 +310,14 @@ pub struct ScalarField {
     /// automatically.
     pub is_updated_at: bool,
 
+    /// If set, signals that this field holds the version of the record for optimistic
+    /// concurrency control and will be incremented on every update automatically.
+    pub is_version: bool,
+
+    /// If set, the value of this field is computed by the database from the given
+    /// expression, and can not be written to.
+    pub generated_as: Option<GeneratedColumn>,
+
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
 }
@@ -312,6 +336,8 @@ impl ScalarField {
             documentation: None,
             is_generated: false,
             is_updated_at: false,
+            is_version: false,
+            generated_as: None,
             is_commented_out: false,
         }
     }
@@ -375,3 +401,12 @@ impl WithDatabaseName for ScalarField {
         self.database_name = database_name;
     }
 }
+
+/// A column computed by the database, declared with `@generated("expression", stored: true)`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GeneratedColumn {
+    /// The SQL expression the column is computed from.
+    pub expression: String,
+    /// Whether the computed value is stored, as opposed to computed when it is read.
+    pub stored: bool,
+}
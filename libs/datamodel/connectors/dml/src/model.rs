@@ -19,6 +19,8 @@ pub struct Model {
     pub indices: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
+    /// The field marking records as deleted, if the model is in soft delete mode.
+    pub soft_delete_field: Option<String>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
@@ -65,6 +67,7 @@ impl Model {
             fields: vec![],
             indices: vec![],
             id_fields: vec![],
+            soft_delete_field: None,
             documentation: None,
             database_name,
             is_embedded: false,
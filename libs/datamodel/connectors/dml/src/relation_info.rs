@@ -12,6 +12,9 @@ pub struct RelationInfo {
     /// A strategy indicating what happens when
     /// a related node is deleted.
     pub on_delete: OnDeleteStrategy,
+    /// A strategy indicating what happens when
+    /// the referenced fields of a related node are updated.
+    pub on_update: OnUpdateStrategy,
 }
 
 impl PartialEq for RelationInfo {
@@ -21,6 +24,7 @@ impl PartialEq for RelationInfo {
             && self.fields == other.fields
             && self.references == other.references
             && self.on_delete == other.on_delete
+            && self.on_update == other.on_update
     }
 }
 
@@ -34,6 +38,7 @@ impl RelationInfo {
             references: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            on_update: OnUpdateStrategy::None,
         }
     }
 }
@@ -42,6 +47,7 @@ impl RelationInfo {
 #[derive(Debug, Copy, PartialEq, Clone)]
 pub enum OnDeleteStrategy {
     Cascade,
+    SetNull,
     None,
 }
 
@@ -49,7 +55,26 @@ impl ToString for OnDeleteStrategy {
     fn to_string(&self) -> String {
         match self {
             OnDeleteStrategy::Cascade => String::from("CASCADE"),
+            OnDeleteStrategy::SetNull => String::from("SET_NULL"),
             OnDeleteStrategy::None => String::from("NONE"),
         }
     }
 }
+
+/// Describes what happens when the referenced fields of related nodes are updated.
+#[derive(Debug, Copy, PartialEq, Clone)]
+pub enum OnUpdateStrategy {
+    Cascade,
+    SetNull,
+    None,
+}
+
+impl ToString for OnUpdateStrategy {
+    fn to_string(&self) -> String {
+        match self {
+            OnUpdateStrategy::Cascade => String::from("CASCADE"),
+            OnUpdateStrategy::SetNull => String::from("SET_NULL"),
+            OnUpdateStrategy::None => String::from("NONE"),
+        }
+    }
+}
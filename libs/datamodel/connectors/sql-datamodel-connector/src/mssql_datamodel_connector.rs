@@ -97,6 +97,13 @@ impl Connector for MsSqlDatamodelConnector {
     }
 
     fn validate_field(&self, field: &Field) -> Result<(), ConnectorError> {
+        // Only persisted computed columns can be NOT NULL.
+        if matches!(field.generated_as(), Some(generated) if !generated.stored) && field.arity().is_required() {
+            return Err(ConnectorError::new_required_virtual_generated_column_error(
+                "SQL Server",
+            ));
+        }
+
         match field.field_type() {
             FieldType::NativeType(_, native_type) => {
                 let r#type: MsSqlType = native_type.deserialize_native_type();
@@ -52,6 +52,8 @@ impl PostgresDatamodelConnector {
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
             ConnectorCapability::InsensitiveFilters,
             ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::Sequences,
+            ConnectorCapability::CompositeTypeFilters,
         ];
 
         let small_int = NativeTypeConstructor::without_args(SMALL_INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -124,6 +126,10 @@ impl Connector for PostgresDatamodelConnector {
     }
 
     fn validate_field(&self, field: &Field) -> Result<(), ConnectorError> {
+        if matches!(field.generated_as(), Some(generated) if !generated.stored) {
+            return Err(ConnectorError::new_virtual_generated_column_error("Postgres"));
+        }
+
         if let FieldType::NativeType(_scalar_type, native_type_instance) = field.field_type() {
             let native_type_name = native_type_instance.name.as_str();
             let native_type: PostgresType = native_type_instance.deserialize_native_type();
@@ -0,0 +1,36 @@
+use super::*;
+
+/// A composite type declaration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeType {
+    /// The name of the composite type.
+    pub name: Identifier,
+    /// The fields of the composite type.
+    pub fields: Vec<Field>,
+    /// The documentation for this composite type.
+    pub documentation: Option<Comment>,
+    /// The location of this composite type in the text representation.
+    pub span: Span,
+}
+
+impl WithIdentifier for CompositeType {
+    fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+impl WithSpan for CompositeType {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl WithDocumentation for CompositeType {
+    fn documentation(&self) -> &Option<Comment> {
+        &self.documentation
+    }
+
+    fn is_commented_out(&self) -> bool {
+        false
+    }
+}
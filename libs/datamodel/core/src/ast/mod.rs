@@ -6,12 +6,14 @@
 mod argument;
 mod attribute;
 mod comment;
+mod composite_type;
 mod r#enum;
 mod expression;
 mod field;
 mod generator_config;
 mod identifier;
 mod model;
+mod schema_files;
 mod source_config;
 mod span;
 mod top;
@@ -25,14 +27,16 @@ pub mod renderer;
 pub use argument::Argument;
 pub use attribute::Attribute;
 pub use comment::Comment;
+pub use composite_type::CompositeType;
 pub use expression::Expression;
 pub use field::{Field, FieldArity};
 pub use generator_config::GeneratorConfig;
 pub use identifier::Identifier;
 pub use model::Model;
 pub use r#enum::{Enum, EnumValue};
+pub use schema_files::{SchemaFile, SchemaFiles};
 pub use source_config::SourceConfig;
-pub use span::Span;
+pub use span::{FileId, Span};
 pub use top::Top;
 pub use traits::{ArgumentContainer, WithAttributes, WithDocumentation, WithIdentifier, WithName, WithSpan};
 
@@ -48,7 +52,7 @@ pub use traits::{ArgumentContainer, WithAttributes, WithDocumentation, WithIdent
 /// Schema = Datamodel + Generators + Datasources
 #[derive(Debug, Clone, PartialEq)]
 pub struct SchemaAst {
-    /// All models, enums, datasources, generators or type aliases
+    /// All models, composite types, enums, datasources, generators or type aliases
     pub tops: Vec<Top>,
 }
 
@@ -90,6 +94,10 @@ impl SchemaAst {
         })
     }
 
+    pub fn find_composite_type(&self, type_name: &str) -> Option<&CompositeType> {
+        self.composite_types().into_iter().find(|t| t.name.name == type_name)
+    }
+
     pub fn find_enum(&self, enum_name: &str) -> Option<&Enum> {
         self.enums().into_iter().find(|e| e.name.name == enum_name)
     }
@@ -124,6 +132,16 @@ impl SchemaAst {
             .collect()
     }
 
+    pub fn composite_types(&self) -> Vec<&CompositeType> {
+        self.tops
+            .iter()
+            .filter_map(|top| match top {
+                Top::CompositeType(x) => Some(x),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn enums(&self) -> Vec<&Enum> {
         self.tops
             .iter()
@@ -1,5 +1,5 @@
 use super::Rule;
-use crate::ast::{Identifier, Span};
+use crate::ast::{FileId, Identifier, Span};
 
 pub type Token<'a> = pest::iterators::Pair<'a, Rule>;
 
@@ -16,15 +16,15 @@ pub fn parsing_catch_all(token: &Token, kind: &str) {
 }
 
 pub trait ToIdentifier {
-    fn to_id(&self) -> Identifier;
+    fn to_id(&self, file_id: FileId) -> Identifier;
 }
 
 // this is not implemented for Token because auto completion does not work then
 impl ToIdentifier for pest::iterators::Pair<'_, Rule> {
-    fn to_id(&self) -> Identifier {
+    fn to_id(&self, file_id: FileId) -> Identifier {
         Identifier {
             name: String::from(self.as_str()),
-            span: Span::from_pest(self.as_span()),
+            span: Span::from_pest(self.as_span(), file_id),
         }
     }
 }
@@ -1,6 +1,7 @@
 mod helpers;
 mod parse_attribute;
 mod parse_comments;
+mod parse_composite_type;
 mod parse_enum;
 mod parse_expression;
 mod parse_field;
@@ -11,7 +12,7 @@ mod parse_types;
 
 // TODO: why does this need to be public?
 pub use parse_expression::parse_expression;
-pub use parse_schema::parse_schema;
+pub use parse_schema::{parse_imports, parse_schema, parse_schema_file, parse_schema_files};
 
 // The derive is placed here because it generates the `Rule` enum which is used in all parsing functions.
 // It is more convenient if this enum is directly available here.
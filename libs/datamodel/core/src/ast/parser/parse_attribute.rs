@@ -5,15 +5,15 @@ use super::{
 };
 use crate::ast::*;
 
-pub fn parse_attribute(token: &Token) -> Attribute {
+pub fn parse_attribute(token: &Token, file_id: FileId) -> Attribute {
     let mut name: Option<Identifier> = None;
     let mut arguments: Vec<Argument> = vec![];
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::attribute => return parse_attribute(&current),
-            Rule::attribute_name => name = Some(current.to_id()),
-            Rule::attribute_arguments => parse_attribute_args(&current, &mut arguments),
+            Rule::attribute => return parse_attribute(&current, file_id),
+            Rule::attribute_name => name = Some(current.to_id(file_id)),
+            Rule::attribute_arguments => parse_attribute_args(&current, &mut arguments, file_id),
             _ => parsing_catch_all(&current, "attribute"),
         }
     }
@@ -22,36 +22,36 @@ pub fn parse_attribute(token: &Token) -> Attribute {
         Some(name) => Attribute {
             name,
             arguments,
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
         },
         _ => panic!("Encountered impossible type during parsing: {:?}", token.as_str()),
     }
 }
 
-fn parse_attribute_args(token: &Token, arguments: &mut Vec<Argument>) {
+fn parse_attribute_args(token: &Token, arguments: &mut Vec<Argument>, file_id: FileId) {
     for current in token.relevant_children() {
         match current.as_rule() {
             // This is a named arg.
-            Rule::argument => arguments.push(parse_attribute_arg(&current)),
+            Rule::argument => arguments.push(parse_attribute_arg(&current, file_id)),
             // This is an unnamed arg.
             Rule::argument_value => arguments.push(Argument {
                 name: Identifier::new(""),
-                value: parse_arg_value(&current),
-                span: Span::from_pest(current.as_span()),
+                value: parse_arg_value(&current, file_id),
+                span: Span::from_pest(current.as_span(), file_id),
             }),
             _ => parsing_catch_all(&current, "attribute arguments"),
         }
     }
 }
 
-fn parse_attribute_arg(token: &Token) -> Argument {
+pub(crate) fn parse_attribute_arg(token: &Token, file_id: FileId) -> Argument {
     let mut name: Option<Identifier> = None;
     let mut argument: Option<Expression> = None;
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::argument_name => name = Some(current.to_id()),
-            Rule::argument_value => argument = Some(parse_arg_value(&current)),
+            Rule::argument_name => name = Some(current.to_id(file_id)),
+            Rule::argument_value => argument = Some(parse_arg_value(&current, file_id)),
             _ => parsing_catch_all(&current, "attribute argument"),
         }
     }
@@ -60,7 +60,7 @@ fn parse_attribute_arg(token: &Token) -> Argument {
         (Some(name), Some(value)) => Argument {
             name,
             value,
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
         },
         _ => panic!(
             "Encountered impossible attribute arg during parsing: {:?}",
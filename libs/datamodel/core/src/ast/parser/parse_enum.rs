@@ -8,7 +8,7 @@ use crate::ast::parser::helpers::TokenExtensions;
 use crate::ast::*;
 use crate::diagnostics::{DatamodelError, Diagnostics};
 
-pub fn parse_enum(token: &Token) -> Result<Enum, Diagnostics> {
+pub fn parse_enum(token: &Token, file_id: FileId) -> Result<Enum, Diagnostics> {
     let mut errors = Diagnostics::new();
     let mut name: Option<Identifier> = None;
     let mut attributes: Vec<Attribute> = vec![];
@@ -17,16 +17,16 @@ pub fn parse_enum(token: &Token) -> Result<Enum, Diagnostics> {
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::non_empty_identifier => name = Some(current.to_id()),
-            Rule::block_level_attribute => attributes.push(parse_attribute(&current)),
-            Rule::enum_value_declaration => match parse_enum_value(&name.as_ref().unwrap().name, &current) {
+            Rule::non_empty_identifier => name = Some(current.to_id(file_id)),
+            Rule::block_level_attribute => attributes.push(parse_attribute(&current, file_id)),
+            Rule::enum_value_declaration => match parse_enum_value(&name.as_ref().unwrap().name, &current, file_id) {
                 Ok(enum_value) => values.push(enum_value),
                 Err(err) => errors.push_error(err),
             },
             Rule::comment_block => comment = Some(parse_comment_block(&current)),
             Rule::BLOCK_LEVEL_CATCH_ALL => errors.push_error(DatamodelError::new_validation_error(
                 "This line is not a enum value definition.",
-                Span::from_pest(current.as_span()),
+                Span::from_pest(current.as_span(), file_id),
             )),
             _ => parsing_catch_all(&current, "enum"),
         }
@@ -40,7 +40,7 @@ pub fn parse_enum(token: &Token) -> Result<Enum, Diagnostics> {
             values,
             attributes,
             documentation: comment,
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
         }),
         _ => panic!(
             "Encountered impossible enum declaration during parsing, name is missing: {:?}",
@@ -49,7 +49,7 @@ pub fn parse_enum(token: &Token) -> Result<Enum, Diagnostics> {
     }
 }
 
-fn parse_enum_value(enum_name: &str, token: &Token) -> Result<EnumValue, DatamodelError> {
+fn parse_enum_value(enum_name: &str, token: &Token, file_id: FileId) -> Result<EnumValue, DatamodelError> {
     let mut name: Option<Identifier> = None;
     let mut attributes: Vec<Attribute> = vec![];
     let mut comments: Vec<String> = vec![];
@@ -57,9 +57,9 @@ fn parse_enum_value(enum_name: &str, token: &Token) -> Result<EnumValue, Datamod
     // todo validate that the identifier is valid???
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::non_empty_identifier => name = Some(current.to_id()),
-            Rule::maybe_empty_identifier => name = Some(current.to_id()),
-            Rule::attribute => attributes.push(parse_attribute(&current)),
+            Rule::non_empty_identifier => name = Some(current.to_id(file_id)),
+            Rule::maybe_empty_identifier => name = Some(current.to_id(file_id)),
+            Rule::attribute => attributes.push(parse_attribute(&current, file_id)),
             Rule::number => {
                 return Err(DatamodelError::new_enum_validation_error(
                     &format!(
@@ -67,7 +67,7 @@ fn parse_enum_value(enum_name: &str, token: &Token) -> Result<EnumValue, Datamod
                         current.as_str()
                     ),
                     enum_name,
-                    Span::from_pest(token.as_span()),
+                    Span::from_pest(token.as_span(), file_id),
                 ));
             }
             Rule::doc_comment => {
@@ -85,7 +85,7 @@ fn parse_enum_value(enum_name: &str, token: &Token) -> Result<EnumValue, Datamod
             name,
             attributes,
             documentation: doc_comments_to_string(&comments),
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
             commented_out: false,
         }),
         _ => panic!(
@@ -3,19 +3,20 @@ use regex::Regex;
 use std::borrow::Cow;
 
 use super::helpers::{parsing_catch_all, Token, TokenExtensions};
+use super::parse_attribute::parse_attribute_arg;
 use super::Rule;
 use crate::ast::*;
 
-pub fn parse_expression(token: &Token) -> Expression {
+pub fn parse_expression(token: &Token, file_id: FileId) -> Expression {
     let first_child = token.first_relevant_child();
-    let span = Span::from_pest(first_child.as_span());
+    let span = Span::from_pest(first_child.as_span(), file_id);
     match first_child.as_rule() {
         Rule::numeric_literal => Expression::NumericValue(first_child.as_str().to_string(), span),
         Rule::string_literal => Expression::StringValue(parse_string_literal(&first_child), span),
         Rule::boolean_literal => Expression::BooleanValue(first_child.as_str().to_string(), span),
         Rule::constant_literal => Expression::ConstantValue(first_child.as_str().to_string(), span),
-        Rule::function => parse_function(&first_child),
-        Rule::array_expression => parse_array(&first_child),
+        Rule::function => parse_function(&first_child, file_id),
+        Rule::array_expression => parse_array(&first_child, file_id),
         _ => unreachable!(
             "Encountered impossible literal during parsing: {:?}",
             first_child.tokens()
@@ -23,46 +24,52 @@ pub fn parse_expression(token: &Token) -> Expression {
     }
 }
 
-fn parse_function(token: &Token) -> Expression {
+fn parse_function(token: &Token, file_id: FileId) -> Expression {
     let mut name: Option<String> = None;
-    let mut arguments: Vec<Expression> = vec![];
+    let mut arguments: Vec<Argument> = vec![];
 
     for current in token.relevant_children() {
         match current.as_rule() {
             Rule::non_empty_identifier => name = Some(current.as_str().to_string()),
-            Rule::expression => arguments.push(parse_expression(&current)),
+            Rule::argument => arguments.push(parse_attribute_arg(&current, file_id)),
+            // This is an unnamed arg.
+            Rule::argument_value => arguments.push(Argument {
+                name: Identifier::new(""),
+                value: parse_arg_value(&current, file_id),
+                span: Span::from_pest(current.as_span(), file_id),
+            }),
             _ => parsing_catch_all(&current, "function"),
         }
     }
 
     match name {
-        Some(name) => Expression::Function(name, arguments, Span::from_pest(token.as_span())),
+        Some(name) => Expression::Function(name, arguments, Span::from_pest(token.as_span(), file_id)),
         _ => unreachable!("Encountered impossible function during parsing: {:?}", token.as_str()),
     }
 }
 
-fn parse_array(token: &Token) -> Expression {
+fn parse_array(token: &Token, file_id: FileId) -> Expression {
     let mut elements: Vec<Expression> = vec![];
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::expression => elements.push(parse_expression(&current)),
+            Rule::expression => elements.push(parse_expression(&current, file_id)),
             _ => parsing_catch_all(&current, "array"),
         }
     }
 
-    Expression::Array(elements, Span::from_pest(token.as_span()))
+    Expression::Array(elements, Span::from_pest(token.as_span(), file_id))
 }
 
-pub fn parse_arg_value(token: &Token) -> Expression {
+pub fn parse_arg_value(token: &Token, file_id: FileId) -> Expression {
     let current = token.first_relevant_child();
     match current.as_rule() {
-        Rule::expression => parse_expression(&current),
+        Rule::expression => parse_expression(&current, file_id),
         _ => unreachable!("Encountered impossible value during parsing: {:?}", current.tokens()),
     }
 }
 
-fn parse_string_literal(token: &Token) -> String {
+pub(crate) fn parse_string_literal(token: &Token) -> String {
     let current = token.first_relevant_child();
     match current.as_rule() {
         Rule::string_content => unescape_string_literal(current.as_str()).into_owned(),
@@ -8,7 +8,7 @@ use super::{
 use crate::ast::*;
 use crate::diagnostics::DatamodelError;
 
-pub fn parse_field(model_name: &str, token: &Token) -> Result<Field, DatamodelError> {
+pub fn parse_field(model_name: &str, token: &Token, file_id: FileId) -> Result<Field, DatamodelError> {
     let mut name: Option<Identifier> = None;
     let mut attributes: Vec<Attribute> = Vec::new();
     let mut field_type: Option<((FieldArity, String), Span)> = None;
@@ -16,15 +16,20 @@ pub fn parse_field(model_name: &str, token: &Token) -> Result<Field, DatamodelEr
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::non_empty_identifier => name = Some(current.to_id()),
-            Rule::field_type => field_type = Some((parse_field_type(&current)?, Span::from_pest(current.as_span()))),
+            Rule::non_empty_identifier => name = Some(current.to_id(file_id)),
+            Rule::field_type => {
+                field_type = Some((
+                    parse_field_type(&current, file_id)?,
+                    Span::from_pest(current.as_span(), file_id),
+                ))
+            }
             Rule::LEGACY_COLON => {
                 return Err(DatamodelError::new_legacy_parser_error(
                     "Field declarations don't require a `:`.",
-                    Span::from_pest(current.as_span()),
+                    Span::from_pest(current.as_span(), file_id),
                 ))
             }
-            Rule::attribute => attributes.push(parse_attribute(&current)),
+            Rule::attribute => attributes.push(parse_attribute(&current, file_id)),
             Rule::doc_comment_and_new_line => comments.push(parse_doc_comment(&current)),
             Rule::doc_comment => comments.push(parse_doc_comment(&current)),
             _ => parsing_catch_all(&current, "field"),
@@ -41,13 +46,13 @@ pub fn parse_field(model_name: &str, token: &Token) -> Result<Field, DatamodelEr
             arity,
             attributes,
             documentation: doc_comments_to_string(&comments),
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
             is_commented_out: false,
         }),
         _ => Err(DatamodelError::new_model_validation_error(
             &"This field declaration is invalid. It is either missing a name or a type.".to_string(),
             model_name,
-            Span::from_pest(token.as_span()),
+            Span::from_pest(token.as_span(), file_id),
         )),
     }
 }
@@ -8,7 +8,7 @@ use super::{
 use crate::ast::*;
 use crate::diagnostics::{DatamodelError, Diagnostics};
 
-pub fn parse_model(token: &Token) -> Result<Model, Diagnostics> {
+pub fn parse_model(token: &Token, file_id: FileId) -> Result<Model, Diagnostics> {
     let mut errors = Diagnostics::new();
     let mut name: Option<Identifier> = None;
     let mut attributes: Vec<Attribute> = vec![];
@@ -17,20 +17,16 @@ pub fn parse_model(token: &Token) -> Result<Model, Diagnostics> {
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::TYPE_KEYWORD => errors.push_error(DatamodelError::new_legacy_parser_error(
-                "Model declarations have to be indicated with the `model` keyword.",
-                Span::from_pest(current.as_span()),
-            )),
-            Rule::non_empty_identifier => name = Some(current.to_id()),
-            Rule::block_level_attribute => attributes.push(parse_attribute(&current)),
-            Rule::field_declaration => match parse_field(&name.as_ref().unwrap().name, &current) {
+            Rule::non_empty_identifier => name = Some(current.to_id(file_id)),
+            Rule::block_level_attribute => attributes.push(parse_attribute(&current, file_id)),
+            Rule::field_declaration => match parse_field(&name.as_ref().unwrap().name, &current, file_id) {
                 Ok(field) => fields.push(field),
                 Err(err) => errors.push_error(err),
             },
             Rule::comment_block => comment = Some(parse_comment_block(&current)),
             Rule::BLOCK_LEVEL_CATCH_ALL => errors.push_error(DatamodelError::new_validation_error(
                 "This line is not a valid field or attribute definition.",
-                Span::from_pest(current.as_span()),
+                Span::from_pest(current.as_span(), file_id),
             )),
             _ => parsing_catch_all(&current, "model"),
         }
@@ -44,7 +40,7 @@ pub fn parse_model(token: &Token) -> Result<Model, Diagnostics> {
             fields,
             attributes,
             documentation: comment,
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
             commented_out: false,
         }),
         _ => panic!(
@@ -2,7 +2,9 @@ use pest::Parser;
 
 use super::{
     helpers::{parsing_catch_all, TokenExtensions},
+    parse_composite_type::parse_composite_type,
     parse_enum::parse_enum,
+    parse_expression::parse_string_literal,
     parse_model::parse_model,
     parse_source_and_generator::{parse_generator, parse_source},
     parse_types::parse_type_alias,
@@ -13,6 +15,48 @@ use crate::diagnostics::{DatamodelError, Diagnostics};
 
 /// Parses a Prisma V2 datamodel document into an internal AST representation.
 pub fn parse_schema(datamodel_string: &str) -> Result<SchemaAst, Diagnostics> {
+    parse_schema_file(datamodel_string, FileId::default())
+}
+
+/// Parses all files of a schema into one AST. The spans of the AST refer to
+/// the files they were parsed from.
+pub fn parse_schema_files(files: &SchemaFiles) -> Result<SchemaAst, Diagnostics> {
+    let mut errors = Diagnostics::new();
+    let mut tops = Vec::new();
+
+    for (file_id, file) in files.iter() {
+        match parse_schema_file(&file.contents, file_id) {
+            Ok(mut ast) => tops.append(&mut ast.tops),
+            Err(mut err) => errors.append(&mut err),
+        }
+    }
+
+    errors.to_result()?;
+
+    Ok(SchemaAst { tops })
+}
+
+/// Returns the paths of the `import` statements of a schema file, as written.
+/// A file that doesn't parse has no imports: its errors are reported when
+/// the schema is parsed.
+pub fn parse_imports(datamodel_string: &str) -> Vec<String> {
+    let mut datamodel_wrapped = match PrismaDatamodelParser::parse(Rule::schema, datamodel_string) {
+        Ok(datamodel_wrapped) => datamodel_wrapped,
+        Err(_) => return Vec::new(),
+    };
+
+    datamodel_wrapped
+        .next()
+        .unwrap()
+        .relevant_children()
+        .into_iter()
+        .filter(|current| current.as_rule() == Rule::import_statement)
+        .map(|current| parse_string_literal(&current.first_relevant_child()))
+        .collect()
+}
+
+/// Parses one file of a schema, see `parse_schema_files`.
+pub fn parse_schema_file(datamodel_string: &str, file_id: FileId) -> Result<SchemaAst, Diagnostics> {
     let mut errors = Diagnostics::new();
     let datamodel_result = PrismaDatamodelParser::parse(Rule::schema, datamodel_string);
 
@@ -22,32 +66,38 @@ pub fn parse_schema(datamodel_string: &str) -> Result<SchemaAst, Diagnostics> {
             let mut top_level_definitions: Vec<Top> = vec![];
             for current in datamodel.relevant_children() {
                 match current.as_rule() {
-                    Rule::model_declaration => match parse_model(&current) {
+                    Rule::model_declaration => match parse_model(&current, file_id) {
                         Ok(model) => top_level_definitions.push(Top::Model(model)),
                         Err(mut err) => errors.append(&mut err),
                     },
-                    Rule::enum_declaration => match parse_enum(&current) {
+                    Rule::composite_type_declaration => match parse_composite_type(&current, file_id) {
+                        Ok(composite_type) => top_level_definitions.push(Top::CompositeType(composite_type)),
+                        Err(mut err) => errors.append(&mut err),
+                    },
+                    Rule::enum_declaration => match parse_enum(&current, file_id) {
                         Ok(enm) => top_level_definitions.push(Top::Enum(enm)),
                         Err(mut err) => errors.append(&mut err),
                     },
-                    Rule::source_block => match parse_source(&current) {
+                    Rule::source_block => match parse_source(&current, file_id) {
                         Ok(source) => top_level_definitions.push(Top::Source(source)),
                         Err(mut err) => errors.append(&mut err),
                     },
-                    Rule::generator_block => match parse_generator(&current) {
+                    Rule::generator_block => match parse_generator(&current, file_id) {
                         Ok(generator) => top_level_definitions.push(Top::Generator(generator)),
                         Err(mut err) => errors.append(&mut err),
                     },
-                    Rule::type_alias => top_level_definitions.push(Top::Type(parse_type_alias(&current))),
+                    Rule::type_alias => top_level_definitions.push(Top::Type(parse_type_alias(&current, file_id))),
+                    // Imports are resolved when the files of a schema are loaded, see `SchemaFiles::load`.
+                    Rule::import_statement => (),
                     Rule::comment_block => (),
                     Rule::EOI => {}
                     Rule::CATCH_ALL => errors.push_error(DatamodelError::new_validation_error(
                         &"This line is invalid. It does not start with any known Prisma schema keyword.".to_string(),
-                        Span::from_pest(current.as_span()),
+                        Span::from_pest(current.as_span(), file_id),
                     )),
                     Rule::arbitrary_block => errors.push_error(DatamodelError::new_validation_error(
                         &"This block is invalid. It does not start with any known Prisma schema keyword. Valid keywords include \'model\', \'enum\', \'datasource\' and \'generator\'.".to_string(),
-                        Span::from_pest(current.as_span()),
+                        Span::from_pest(current.as_span(), file_id),
                     )),
                     _ => parsing_catch_all(&current, "datamodel"),
                 }
@@ -61,8 +111,16 @@ pub fn parse_schema(datamodel_string: &str) -> Result<SchemaAst, Diagnostics> {
         }
         Err(err) => {
             let location = match err.location {
-                pest::error::InputLocation::Pos(pos) => Span::new(pos, pos),
-                pest::error::InputLocation::Span((from, to)) => Span::new(from, to),
+                pest::error::InputLocation::Pos(pos) => Span {
+                    start: pos,
+                    end: pos,
+                    file_id,
+                },
+                pest::error::InputLocation::Span((from, to)) => Span {
+                    start: from,
+                    end: to,
+                    file_id,
+                },
             };
 
             let expected = match err.variant {
@@ -87,6 +145,7 @@ fn get_expected_from_error(positives: &[Rule]) -> Vec<&'static str> {
 fn rule_to_string(rule: Rule) -> &'static str {
     match rule {
         Rule::model_declaration => "model declaration",
+        Rule::composite_type_declaration => "composite type declaration",
         Rule::enum_declaration => "enum declaration",
         Rule::source_block => "source definition",
         Rule::generator_block => "generator definition",
@@ -115,6 +174,7 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::field_type => "field type",
         Rule::field_declaration => "field declaration",
         Rule::type_alias => "type alias",
+        Rule::import_statement => "import statement",
         Rule::key_value => "configuration property",
         Rule::string_any => "any character",
         Rule::string_escaped_interpolation => "string interpolation",
@@ -133,6 +193,7 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::ENUM_KEYWORD => "\"enum\" keyword",
         Rule::GENERATOR_KEYWORD => "\"generator\" keyword",
         Rule::DATASOURCE_KEYWORD => "\"datasource\" keyword",
+        Rule::IMPORT_KEYWORD => "\"import\" keyword",
         Rule::INTERPOLATION_START => "string interpolation start",
         Rule::INTERPOLATION_END => "string interpolation end",
         Rule::CATCH_ALL => "CATCH ALL",
@@ -7,7 +7,7 @@ use super::{
 use crate::ast::*;
 use crate::diagnostics::{DatamodelError, Diagnostics};
 
-pub fn parse_source(token: &Token) -> Result<SourceConfig, Diagnostics> {
+pub fn parse_source(token: &Token, file_id: FileId) -> Result<SourceConfig, Diagnostics> {
     let mut errors = Diagnostics::new();
     let mut name: Option<Identifier> = None;
     let mut properties: Vec<Argument> = vec![];
@@ -15,12 +15,12 @@ pub fn parse_source(token: &Token) -> Result<SourceConfig, Diagnostics> {
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::non_empty_identifier => name = Some(current.to_id()),
-            Rule::key_value => properties.push(parse_key_value(&current)),
+            Rule::non_empty_identifier => name = Some(current.to_id(file_id)),
+            Rule::key_value => properties.push(parse_key_value(&current, file_id)),
             Rule::comment_block => comment = Some(parse_comment_block(&current)),
             Rule::BLOCK_LEVEL_CATCH_ALL => errors.push_error(DatamodelError::new_validation_error(
                 "This line is not a valid definition within a datasource.",
-                Span::from_pest(current.as_span()),
+                Span::from_pest(current.as_span(), file_id),
             )),
             _ => parsing_catch_all(&current, "source"),
         }
@@ -33,7 +33,7 @@ pub fn parse_source(token: &Token) -> Result<SourceConfig, Diagnostics> {
             name,
             properties,
             documentation: comment,
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
         }),
         _ => panic!(
             "Encountered impossible source declaration during parsing, name is missing: {:?}",
@@ -42,7 +42,7 @@ pub fn parse_source(token: &Token) -> Result<SourceConfig, Diagnostics> {
     }
 }
 
-pub fn parse_generator(token: &Token) -> Result<GeneratorConfig, Diagnostics> {
+pub fn parse_generator(token: &Token, file_id: FileId) -> Result<GeneratorConfig, Diagnostics> {
     let mut errors = Diagnostics::new();
     let mut name: Option<Identifier> = None;
     let mut properties: Vec<Argument> = vec![];
@@ -50,13 +50,13 @@ pub fn parse_generator(token: &Token) -> Result<GeneratorConfig, Diagnostics> {
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::non_empty_identifier => name = Some(current.to_id()),
-            Rule::key_value => properties.push(parse_key_value(&current)),
+            Rule::non_empty_identifier => name = Some(current.to_id(file_id)),
+            Rule::key_value => properties.push(parse_key_value(&current, file_id)),
             Rule::doc_comment => comments.push(parse_doc_comment(&current)),
             Rule::doc_comment_and_new_line => comments.push(parse_doc_comment(&current)),
             Rule::BLOCK_LEVEL_CATCH_ALL => errors.push_error(DatamodelError::new_validation_error(
                 "This line is not a valid definition within a generator.",
-                Span::from_pest(current.as_span()),
+                Span::from_pest(current.as_span(), file_id),
             )),
             _ => parsing_catch_all(&current, "generator"),
         }
@@ -69,7 +69,7 @@ pub fn parse_generator(token: &Token) -> Result<GeneratorConfig, Diagnostics> {
             name,
             properties,
             documentation: doc_comments_to_string(&comments),
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
         }),
         _ => panic!(
             "Encountered impossible generator declaration during parsing, name is missing: {:?}",
@@ -78,14 +78,14 @@ pub fn parse_generator(token: &Token) -> Result<GeneratorConfig, Diagnostics> {
     }
 }
 
-fn parse_key_value(token: &Token) -> Argument {
+fn parse_key_value(token: &Token, file_id: FileId) -> Argument {
     let mut name: Option<Identifier> = None;
     let mut value: Option<Expression> = None;
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::non_empty_identifier => name = Some(current.to_id()),
-            Rule::expression => value = Some(parse_expression(&current)),
+            Rule::non_empty_identifier => name = Some(current.to_id(file_id)),
+            Rule::expression => value = Some(parse_expression(&current, file_id)),
             _ => unreachable!(
                 "Encountered impossible source property declaration during parsing: {:?}",
                 current.tokens()
@@ -97,7 +97,7 @@ fn parse_key_value(token: &Token) -> Argument {
         (Some(name), Some(value)) => Argument {
             name,
             value,
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
         },
         _ => panic!(
             "Encountered impossible source property declaration during parsing: {:?}",
@@ -7,7 +7,7 @@ use super::{
 use crate::ast::*;
 use crate::diagnostics::DatamodelError;
 
-pub fn parse_type_alias(token: &Token) -> Field {
+pub fn parse_type_alias(token: &Token, file_id: FileId) -> Field {
     let mut name: Option<Identifier> = None;
     let mut attributes: Vec<Attribute> = vec![];
     let mut base_type: Option<(String, Span)> = None;
@@ -16,9 +16,11 @@ pub fn parse_type_alias(token: &Token) -> Field {
     for current in token.relevant_children() {
         match current.as_rule() {
             Rule::TYPE_KEYWORD => {}
-            Rule::non_empty_identifier => name = Some(current.to_id()),
-            Rule::base_type => base_type = Some((parse_base_type(&current), Span::from_pest(current.as_span()))),
-            Rule::attribute => attributes.push(parse_attribute(&current)),
+            Rule::non_empty_identifier => name = Some(current.to_id(file_id)),
+            Rule::base_type => {
+                base_type = Some((parse_base_type(&current), Span::from_pest(current.as_span(), file_id)))
+            }
+            Rule::attribute => attributes.push(parse_attribute(&current, file_id)),
             Rule::comment_block => comment = Some(parse_comment_block(&current)),
             _ => parsing_catch_all(&current, "custom type"),
         }
@@ -34,7 +36,7 @@ pub fn parse_type_alias(token: &Token) -> Field {
             arity: FieldArity::Required,
             attributes,
             documentation: comment,
-            span: Span::from_pest(token.as_span()),
+            span: Span::from_pest(token.as_span(), file_id),
             is_commented_out: false,
         },
         _ => panic!(
@@ -44,7 +46,7 @@ pub fn parse_type_alias(token: &Token) -> Field {
     }
 }
 
-pub fn parse_field_type(token: &Token) -> Result<(FieldArity, String), DatamodelError> {
+pub fn parse_field_type(token: &Token, file_id: FileId) -> Result<(FieldArity, String), DatamodelError> {
     let current = token.first_relevant_child();
     match current.as_rule() {
         Rule::optional_type => Ok((FieldArity::Optional, parse_base_type(&current))),
@@ -52,15 +54,15 @@ pub fn parse_field_type(token: &Token) -> Result<(FieldArity, String), Datamodel
         Rule::list_type => Ok((FieldArity::List, parse_base_type(&current))),
         Rule::legacy_required_type => Err(DatamodelError::new_legacy_parser_error(
             "Fields are required by default, `!` is no longer required.",
-            Span::from_pest(current.as_span()),
+            Span::from_pest(current.as_span(), file_id),
         )),
         Rule::legacy_list_type => Err(DatamodelError::new_legacy_parser_error(
             "To specify a list, please use `Type[]` instead of `[Type]`.",
-            Span::from_pest(current.as_span()),
+            Span::from_pest(current.as_span(), file_id),
         )),
         Rule::unsupported_optional_list_type => Err(DatamodelError::new_legacy_parser_error(
             "Optional lists are not supported. Use either `Type[]` or `Type?`.",
-            Span::from_pest(current.as_span()),
+            Span::from_pest(current.as_span(), file_id),
         )),
         _ => unreachable!("Encountered impossible field during parsing: {:?}", current.tokens()),
     }
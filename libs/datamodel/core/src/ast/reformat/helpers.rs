@@ -11,10 +11,12 @@ impl TokenExtensions for Token<'_> {
     fn is_top_level_element(&self) -> bool {
         match self.as_rule() {
             Rule::model_declaration => true,
+            Rule::composite_type_declaration => true,
             Rule::enum_declaration => true,
             Rule::source_block => true,
             Rule::generator_block => true,
             Rule::type_alias => true,
+            Rule::import_statement => true,
             Rule::comment_block => true,
             _ => false,
         }
@@ -5,20 +5,31 @@ use pest::Parser;
 // do multiple mutable borrows inside a match statement.
 use super::helpers::*;
 use crate::ast::helper::get_sort_index_of_attribute;
+use crate::ast::{FileId, SchemaAst, SchemaFiles};
 use crate::common::WritableString;
-use crate::diagnostics::ValidatedMissingFields;
+use crate::diagnostics::{Diagnostics, ValidatedDatamodel, ValidatedMissingFields};
 use pest::iterators::Pair;
 
+type Missing = (
+    Result<ValidatedMissingFields, Diagnostics>,
+    Result<Vec<MissingFieldAttribute>, Diagnostics>,
+);
+
 pub struct Reformatter<'a> {
     input: &'a str,
-    missing_fields: Result<ValidatedMissingFields, crate::diagnostics::Diagnostics>,
-    missing_field_attributes: Result<Vec<MissingFieldAttribute>, crate::diagnostics::Diagnostics>,
+    missing_fields: Result<ValidatedMissingFields, Diagnostics>,
+    missing_field_attributes: Result<Vec<MissingFieldAttribute>, Diagnostics>,
 }
 
 impl<'a> Reformatter<'a> {
     pub fn new(input: &'a str) -> Self {
-        let missing_fields = Self::find_all_missing_fields(&input);
-        let missing_field_attributes = Self::find_all_missing_attributes(&input);
+        let schema = crate::parse_schema_ast(input).and_then(|schema_ast| {
+            let validated_datamodel = crate::parse_datamodel_and_ignore_datasource_urls(input)?;
+            Ok((schema_ast, validated_datamodel))
+        });
+
+        let (missing_fields, missing_field_attributes) = Self::find_missing(schema);
+
         Reformatter {
             input,
             missing_fields,
@@ -26,17 +37,51 @@ impl<'a> Reformatter<'a> {
         }
     }
 
+    /// Creates a reformatter for each file of a schema split over several
+    /// files. The schema is parsed and validated once, and the fields added
+    /// for relations are found on the whole schema, as relations can span
+    /// files.
+    pub fn for_files(files: &'a SchemaFiles) -> Vec<(FileId, Self)> {
+        let schema = crate::parse_schema_ast_files(files).and_then(|schema_ast| {
+            let validated_datamodel = crate::parse_datamodel_files_and_ignore_datasource_urls(files)?;
+            Ok((schema_ast, validated_datamodel))
+        });
+
+        let (missing_fields, missing_field_attributes) = Self::find_missing(schema);
+
+        files
+            .iter()
+            .map(|(file_id, file)| {
+                let reformatter = Reformatter {
+                    input: file.contents.as_str(),
+                    missing_fields: missing_fields.clone(),
+                    missing_field_attributes: missing_field_attributes.clone(),
+                };
+
+                (file_id, reformatter)
+            })
+            .collect()
+    }
+
+    fn find_missing(schema: Result<(SchemaAst, ValidatedDatamodel), Diagnostics>) -> Missing {
+        match schema {
+            Ok((schema_ast, validated_datamodel)) => (
+                Ok(Self::find_all_missing_fields(&schema_ast, &validated_datamodel)),
+                Ok(Self::find_all_missing_attributes(&schema_ast, &validated_datamodel)),
+            ),
+            Err(err) => (Err(err.clone()), Err(err)),
+        }
+    }
+
     // this finds all auto generated fields, that are added during auto generation AND are missing from the original input.
-    fn find_all_missing_fields(schema_string: &str) -> Result<ValidatedMissingFields, crate::diagnostics::Diagnostics> {
-        let mut diagnostics = crate::diagnostics::Diagnostics::new();
-        let schema_ast = crate::parse_schema_ast(&schema_string)?;
-        let validated_datamodel = crate::parse_datamodel_and_ignore_datasource_urls(&schema_string)?;
+    pub(crate) fn find_all_missing_fields(
+        schema_ast: &SchemaAst,
+        validated_datamodel: &ValidatedDatamodel,
+    ) -> ValidatedMissingFields {
         let generators = &vec![];
         let lowerer = crate::transform::dml_to_ast::LowerDmlToAst::new(None, generators);
         let mut result = Vec::new();
 
-        diagnostics.append_warning_vec(validated_datamodel.warnings);
-
         for model in validated_datamodel.subject.models() {
             let ast_model = schema_ast.find_model(&model.name).unwrap();
 
@@ -52,19 +97,16 @@ impl<'a> Reformatter<'a> {
             }
         }
 
-        Ok(ValidatedMissingFields {
+        ValidatedMissingFields {
             subject: result,
-            warnings: diagnostics.warnings,
-        })
+            warnings: validated_datamodel.warnings.clone(),
+        }
     }
 
     fn find_all_missing_attributes(
-        schema_string: &str,
-    ) -> Result<Vec<MissingFieldAttribute>, crate::diagnostics::Diagnostics> {
-        let mut diagnostics = crate::diagnostics::Diagnostics::new();
-        let schema_ast = crate::parse_schema_ast(&schema_string)?;
-        let validated_datamodel = crate::parse_datamodel_and_ignore_datasource_urls(&schema_string)?;
-        diagnostics.append_warning_vec(validated_datamodel.warnings);
+        schema_ast: &SchemaAst,
+        validated_datamodel: &ValidatedDatamodel,
+    ) -> Vec<MissingFieldAttribute> {
         let generators = &vec![];
         let lowerer = crate::transform::dml_to_ast::LowerDmlToAst::new(None, generators);
         let mut missing_field_attributes = Vec::new();
@@ -93,7 +135,7 @@ impl<'a> Reformatter<'a> {
                 }
             }
         }
-        Ok(missing_field_attributes)
+        missing_field_attributes
     }
 
     pub fn reformat_to(&self, output: &mut dyn std::io::Write, ident_width: usize) {
@@ -123,6 +165,7 @@ impl<'a> Reformatter<'a> {
         let mut types_table = TableFormat::new();
         let mut types_mode = false;
         let mut seen_at_least_one_top_level_element = false;
+        let mut previous_top_level_element_was_import = false;
 
         for current in token.clone().into_inner() {
             match current.as_rule() {
@@ -146,12 +189,15 @@ impl<'a> Reformatter<'a> {
             // * fold multiple new lines between blocks into one
             // * all new lines before the first block get removed
             if current.is_top_level_element() {
-                // separate top level elements with new lines
-                if seen_at_least_one_top_level_element {
+                let is_import = current.as_rule() == Rule::import_statement;
+
+                // separate top level elements with new lines, consecutive imports form one element
+                if seen_at_least_one_top_level_element && !(is_import && previous_top_level_element_was_import) {
                     //                    println!("rendering newline");
                     target.write("\n");
                 }
                 seen_at_least_one_top_level_element = true;
+                previous_top_level_element_was_import = is_import;
             }
 
             //            println!("top level: {:?} |{:?}|", current.as_rule(), current.as_str());
@@ -165,9 +211,11 @@ impl<'a> Reformatter<'a> {
                     }
                 }
                 Rule::model_declaration => self.reformat_model(target, &current),
+                Rule::composite_type_declaration => self.reformat_composite_type(target, &current),
                 Rule::enum_declaration => self.reformat_enum(target, &current),
                 Rule::source_block => self.reformat_datasource(target, &current),
                 Rule::generator_block => self.reformat_generator(target, &current),
+                Rule::import_statement => Self::reformat_import(target, &current),
                 Rule::type_alias => {
                     if !types_mode {
                         panic!("Renderer not in type mode.");
@@ -189,6 +237,16 @@ impl<'a> Reformatter<'a> {
         target.write("");
     }
 
+    fn reformat_import(target: &mut Renderer, token: &Token) {
+        for current in token.clone().into_inner() {
+            if current.as_rule() == Rule::string_literal {
+                target.write("import ");
+                target.write(current.as_str());
+                target.end_line();
+            }
+        }
+    }
+
     fn reformat_datasource(&self, target: &mut Renderer, token: &Token) {
         self.reformat_block_element(
             "datasource",
@@ -263,6 +321,23 @@ impl<'a> Reformatter<'a> {
         );
     }
 
+    fn reformat_composite_type(&self, target: &mut Renderer, token: &Token) {
+        self.reformat_block_element(
+            "type",
+            target,
+            &token,
+            Box::new(|table, renderer, token, type_name| match token.as_rule() {
+                Rule::TYPE_KEYWORD => {}
+                Rule::block_level_attribute => {
+                    table.render(renderer);
+                    Self::reformat_attribute(renderer, &token, "@@");
+                }
+                Rule::field_declaration => self.reformat_field(table, &token, type_name),
+                _ => Self::reformat_generic_token(table, &token),
+            }),
+        );
+    }
+
     fn reformat_block_element(
         &self,
         block_type: &'static str,
@@ -673,11 +748,20 @@ impl<'a> Reformatter<'a> {
                     target.write(current.as_str());
                     target.write("(");
                 }
-                Rule::expression => {
+                // This is a named arg.
+                Rule::argument => {
                     if has_seen_one_argument {
                         target.write(", ");
                     }
-                    Self::reformat_expression(target, &current);
+                    Self::reformat_attribute_arg(target, &current);
+                    has_seen_one_argument = true;
+                }
+                // This is an unnamed arg.
+                Rule::argument_value => {
+                    if has_seen_one_argument {
+                        target.write(", ");
+                    }
+                    Self::reformat_arg_value(target, &current);
                     has_seen_one_argument = true;
                 }
                 Rule::doc_comment | Rule::doc_comment_and_new_line => {
@@ -714,13 +798,13 @@ impl<'a> Reformatter<'a> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MissingField {
     pub model: String,
     pub field: crate::ast::Field,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MissingFieldAttribute {
     pub model: String,
     pub field: String,
@@ -0,0 +1,104 @@
+use super::{parser::parse_imports, FileId};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+/// One file of a schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaFile {
+    /// The name the file is reported with in diagnostics, usually its path.
+    pub name: String,
+    pub contents: String,
+}
+
+/// The files of a schema split over several files, e.g. the `.prisma` files
+/// of a `schema/` directory. The files form one schema: models can refer to
+/// models and enums of other files.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaFiles {
+    files: Vec<SchemaFile>,
+}
+
+impl SchemaFiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a schema file and the files it imports. Imports are resolved
+    /// relative to the importing file, and a file imported several times is
+    /// loaded once.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Self::load_paths(vec![path.as_ref().to_path_buf()])
+    }
+
+    /// Loads all `.prisma` files of a directory, ordered by file name, and
+    /// the files they import.
+    pub fn load_dir(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let mut paths = Vec::new();
+
+        for entry in std::fs::read_dir(path)? {
+            let path = entry?.path();
+
+            if path.is_file() && path.extension().map(|ext| ext == "prisma").unwrap_or(false) {
+                paths.push(path);
+            }
+        }
+
+        paths.sort();
+
+        Self::load_paths(paths)
+    }
+
+    fn load_paths(paths: Vec<PathBuf>) -> std::io::Result<Self> {
+        let mut files = Self::new();
+        let mut loaded = HashSet::new();
+        // A stack, so that the imports of a file follow it in order.
+        let mut pending: Vec<PathBuf> = paths.into_iter().rev().collect();
+
+        while let Some(path) = pending.pop() {
+            if !loaded.insert(path.canonicalize()?) {
+                continue;
+            }
+
+            let contents = std::fs::read_to_string(&path)?;
+            let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+            pending.extend(
+                parse_imports(&contents)
+                    .into_iter()
+                    .rev()
+                    .map(|import| dir.join(import)),
+            );
+            files.add(path.display().to_string(), contents);
+        }
+
+        Ok(files)
+    }
+
+    /// Adds a file, returning the id its spans are parsed with.
+    pub fn add(&mut self, name: impl Into<String>, contents: impl Into<String>) -> FileId {
+        self.files.push(SchemaFile {
+            name: name.into(),
+            contents: contents.into(),
+        });
+
+        FileId(self.files.len() - 1)
+    }
+
+    pub fn get(&self, file_id: FileId) -> Option<&SchemaFile> {
+        self.files.get(file_id.0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (FileId, &SchemaFile)> {
+        self.files.iter().enumerate().map(|(idx, file)| (FileId(idx), file))
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
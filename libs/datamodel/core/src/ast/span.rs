@@ -1,24 +1,36 @@
+/// Identifies a file of a schema split over several files, see `SchemaFiles`.
+/// Schemas parsed from a single string only have the default file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FileId(pub usize);
+
+impl FileId {
+    /// The file of spans that don't point into any file, e.g. of generated AST nodes.
+    pub const NONE: FileId = FileId(usize::MAX);
+}
+
 /// Represents a location in a datamodel's text representation.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
+    pub file_id: FileId,
 }
 
 impl Span {
-    pub fn new(start: usize, end: usize) -> Span {
-        Span { start, end }
+    pub fn new(start: usize, end: usize, file_id: FileId) -> Span {
+        Span { start, end, file_id }
     }
 
-    // Creates a new empty span.
+    /// Creates a new empty span, which doesn't point into any file.
     pub fn empty() -> Span {
-        Span { start: 0, end: 0 }
+        Span::new(0, 0, FileId::NONE)
     }
-    /// Creates a new ast::Span from a pest::Span.
-    pub fn from_pest(s: pest::Span) -> Span {
+    /// Creates a new ast::Span from a pest::Span in the given file.
+    pub fn from_pest(s: pest::Span, file_id: FileId) -> Span {
         Span {
             start: s.start(),
             end: s.end(),
+            file_id,
         }
     }
 
@@ -27,6 +39,7 @@ impl Span {
         Span {
             start: offset + self.start,
             end: offset + self.end,
+            file_id: self.file_id,
         }
     }
 }
@@ -0,0 +1,400 @@
+//! Code actions resolve diagnostics of a schema with concrete text edits, e.g. for editor
+//! quick-fixes. They are computed from the AST, so that they are also available for
+//! schemas that do not validate.
+use crate::{
+    ast::{
+        self,
+        reformat::Reformatter,
+        renderer::{LineWriteable, Renderer, StringBuilder},
+        Span,
+    },
+    diagnostics::{DatamodelError, Diagnostics, ValidatedDatamodel},
+    Field,
+};
+
+/// A named set of edits resolving the diagnostic at `span`.
+#[derive(Debug, Clone)]
+pub struct CodeAction {
+    pub title: String,
+    pub span: Span,
+    pub edits: Vec<TextEdit>,
+}
+
+/// Replaces the text between `start` and `end` with `text`. Insertions have `start == end`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct TextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+impl TextEdit {
+    pub fn insert(position: usize, text: impl Into<String>) -> Self {
+        TextEdit {
+            start: position,
+            end: position,
+            text: text.into(),
+        }
+    }
+
+    pub fn replace(span: Span, text: impl Into<String>) -> Self {
+        TextEdit {
+            start: span.start,
+            end: span.end,
+            text: text.into(),
+        }
+    }
+}
+
+/// The position new attributes of a field are inserted at: after its existing attributes.
+pub fn attribute_insert_position(field: &ast::Field) -> usize {
+    field
+        .attributes
+        .iter()
+        .map(|attribute| attribute.span.end)
+        .chain(std::iter::once(field.field_type.span.end))
+        .max()
+        .unwrap()
+}
+
+/// Value generators that produce strings and can't be the default of an integer field.
+const STRING_GENERATORS: &[&str] = &["uuid", "cuid"];
+
+/// Computes the code actions for the given schema.
+pub fn code_actions(schema: &str) -> Vec<CodeAction> {
+    let ast = match crate::parse_schema_ast(schema) {
+        Ok(ast) => ast,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut actions = Vec::new();
+
+    match crate::parse_datamodel_and_ignore_datasource_urls(schema) {
+        Ok(validated) => missing_back_relation_fields(&ast, &validated, &[], &mut actions),
+        Err(diagnostics) => {
+            let ambiguous = ambiguous_relations(&diagnostics);
+
+            for relation in &ambiguous {
+                name_ambiguous_relation(schema, &ast, relation, &mut actions);
+            }
+
+            if let Ok(datamodel) = crate::lift_ast_with_back_relations(&ast) {
+                let validated = ValidatedDatamodel {
+                    subject: datamodel,
+                    warnings: Vec::new(),
+                };
+
+                missing_back_relation_fields(&ast, &validated, &ambiguous, &mut actions);
+            }
+        }
+    }
+
+    string_defaults_on_int_fields(&ast, &mut actions);
+
+    actions
+}
+
+/// The relation fields of a model that validation reported as ambiguous.
+struct AmbiguousRelation<'a> {
+    model: &'a str,
+    fields: &'a [String],
+    span: Span,
+}
+
+impl AmbiguousRelation<'_> {
+    fn contains(&self, model: &str, field: &str) -> bool {
+        self.model == model && self.fields.iter().any(|name| name == field)
+    }
+}
+
+fn ambiguous_relations(diagnostics: &Diagnostics) -> Vec<AmbiguousRelation<'_>> {
+    diagnostics
+        .errors
+        .iter()
+        .filter_map(|error| match error {
+            DatamodelError::AmbiguousRelationError {
+                model_name,
+                fields,
+                span,
+                ..
+            } => Some(AmbiguousRelation {
+                model: model_name,
+                fields,
+                span: *span,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Back relation fields missing in the schema are generated during validation.
+/// The action adds them explicitly, like the reformatter does. Fields of ambiguous
+/// relations are skipped, as their opposite side is unknown.
+fn missing_back_relation_fields(
+    ast: &ast::SchemaAst,
+    validated: &ValidatedDatamodel,
+    ambiguous: &[AmbiguousRelation<'_>],
+    actions: &mut Vec<CodeAction>,
+) {
+    let datamodel = &validated.subject;
+
+    for missing_field in Reformatter::find_all_missing_fields(ast, validated).subject {
+        let relation_field = match datamodel
+            .find_model(&missing_field.model)
+            .and_then(|model| model.find_field(&missing_field.field.name.name))
+        {
+            Some(Field::RelationField(relation_field)) => relation_field,
+            _ => continue,
+        };
+
+        let related_field = match datamodel.find_related_field(relation_field) {
+            Some(related_field) => related_field,
+            None => continue,
+        };
+
+        let related_model = &relation_field.relation_info.to;
+
+        if ambiguous
+            .iter()
+            .any(|relation| relation.contains(related_model, &related_field.name))
+        {
+            continue;
+        }
+
+        let span = match ast.find_field(related_model, &related_field.name) {
+            Some(ast_field) => ast_field.span,
+            None => continue,
+        };
+
+        let ast_model = ast.find_model(&missing_field.model).unwrap();
+
+        actions.push(CodeAction {
+            title: format!(
+                "Add the opposite relation field `{}` to model `{}`",
+                missing_field.field.name.name, missing_field.model
+            ),
+            span,
+            edits: vec![TextEdit::insert(
+                ast_model.span.end - 1,
+                format!("  {}\n", render_field(&missing_field.field)),
+            )],
+        });
+    }
+}
+
+/// Names the relation of the field an ambiguity diagnostic points at. The opposite field
+/// gets the same name when it can be told apart: the other field of an unnamed self
+/// relation between two fields. For relations between two models, the field is only
+/// named when the related model has no unnamed opposite fields that it could be paired
+/// with. No action is offered when the pairing is ambiguous or the relations are already
+/// named.
+fn name_ambiguous_relation(
+    schema: &str,
+    ast: &ast::SchemaAst,
+    relation: &AmbiguousRelation<'_>,
+    actions: &mut Vec<CodeAction>,
+) {
+    let model = match ast.find_model(relation.model) {
+        Some(model) => model,
+        None => return,
+    };
+
+    let fields: Vec<&ast::Field> = relation
+        .fields
+        .iter()
+        .filter_map(|name| model.fields.iter().find(|field| &field.name.name == name))
+        .collect();
+
+    let (reported, others): (Vec<&ast::Field>, Vec<&ast::Field>) =
+        fields.iter().copied().partition(|field| field.span == relation.span);
+
+    let field = match reported.first() {
+        Some(field) => *field,
+        None => return,
+    };
+
+    if fields.iter().any(|field| relation_name(field).is_some()) {
+        return;
+    }
+
+    if field.field_type.name == model.name.name {
+        match others.as_slice() {
+            // Two forward fields are two relations.
+            [other] if defines_fields(field) && defines_fields(other) => {
+                push_relation_name_action(schema, model, field, None, true, relation.span, actions)
+            }
+            // The relation is named after the side defining `fields`.
+            [other] if defines_fields(other) => {
+                push_relation_name_action(schema, model, other, Some(field), true, relation.span, actions)
+            }
+            [other] => push_relation_name_action(schema, model, field, Some(*other), true, relation.span, actions),
+            _ => (),
+        }
+
+        return;
+    }
+
+    let opposite_fields = ast
+        .find_model(&field.field_type.name)
+        .map(|related_model| unnamed_relation_fields(related_model, &model.name.name))
+        .unwrap_or_default();
+
+    if opposite_fields.is_empty() {
+        push_relation_name_action(schema, model, field, None, false, relation.span, actions);
+    }
+}
+
+/// The relation fields of `model` pointing to `related_model` without a relation name.
+fn unnamed_relation_fields<'a>(model: &'a ast::Model, related_model: &str) -> Vec<&'a ast::Field> {
+    model
+        .fields
+        .iter()
+        .filter(|field| field.field_type.name == related_model && relation_name(field).is_none())
+        .collect()
+}
+
+fn push_relation_name_action(
+    schema: &str,
+    model: &ast::Model,
+    field: &ast::Field,
+    other: Option<&ast::Field>,
+    is_self_relation: bool,
+    span: Span,
+    actions: &mut Vec<CodeAction>,
+) {
+    let name = format!("{}{}", model.name.name, capitalize(&field.name.name));
+
+    let title = match other {
+        Some(other) if is_self_relation => format!(
+            "Name the self relation of `{}` and `{}` \"{}\"",
+            field.name.name, other.name.name, name
+        ),
+        Some(other) => format!(
+            "Name the relation of `{}` and `{}` \"{}\"",
+            field.name.name, other.name.name, name
+        ),
+        None => format!("Add the relation name \"{}\" to `{}`", name, field.name.name),
+    };
+
+    let edits = std::iter::once(field)
+        .chain(other)
+        .map(|field| add_relation_name(schema, field, &name))
+        .collect();
+
+    actions.push(CodeAction { title, span, edits });
+}
+
+/// `@default(uuid())` and `@default(cuid())` produce strings.
+fn string_defaults_on_int_fields(ast: &ast::SchemaAst, actions: &mut Vec<CodeAction>) {
+    for model in ast.models() {
+        for field in &model.fields {
+            if field.field_type.name != "Int" && field.field_type.name != "BigInt" {
+                continue;
+            }
+
+            let default = field
+                .attributes
+                .iter()
+                .filter(|attribute| attribute.name.name == "default")
+                .flat_map(|attribute| attribute.arguments.iter())
+                .find_map(|argument| match &argument.value {
+                    ast::Expression::Function(name, _, span) if STRING_GENERATORS.contains(&name.as_str()) => {
+                        Some((name, *span))
+                    }
+                    _ => None,
+                });
+
+            let (generator, generator_span) = match default {
+                Some(default) => default,
+                None => continue,
+            };
+
+            let mut string_type = StringBuilder::new();
+            string_type.write("String");
+            Renderer::render_field_arity(&mut string_type, &field.arity);
+
+            actions.push(CodeAction {
+                title: format!("Change the type of `{}` to String", field.name.name),
+                span: field.span,
+                edits: vec![TextEdit::replace(field.field_type.span, string_type.to_string())],
+            });
+
+            actions.push(CodeAction {
+                title: format!("Replace `{}()` with `autoincrement()`", generator),
+                span: field.span,
+                edits: vec![TextEdit::replace(generator_span, "autoincrement()")],
+            });
+        }
+    }
+}
+
+fn relation_name(field: &ast::Field) -> Option<&str> {
+    field
+        .attributes
+        .iter()
+        .find(|attribute| attribute.name.name == "relation")?
+        .arguments
+        .iter()
+        .find(|argument| argument.name.name.is_empty() || argument.name.name == "name")
+        .and_then(|argument| match &argument.value {
+            ast::Expression::StringValue(name, _) => Some(name.as_str()),
+            _ => None,
+        })
+}
+
+/// Whether the field is the side of the relation with `@relation(fields: [...])`.
+fn defines_fields(field: &ast::Field) -> bool {
+    field
+        .attributes
+        .iter()
+        .filter(|attribute| attribute.name.name == "relation")
+        .flat_map(|attribute| attribute.arguments.iter())
+        .any(|argument| argument.name.name == "fields")
+}
+
+/// Adds the name to an existing `@relation` attribute, or adds the attribute.
+fn add_relation_name(schema: &str, field: &ast::Field, name: &str) -> TextEdit {
+    match field
+        .attributes
+        .iter()
+        .find(|attribute| attribute.name.name == "relation")
+    {
+        Some(attribute) => {
+            let after_name = attribute.name.span.end;
+
+            if !schema[after_name..].starts_with('(') {
+                TextEdit::insert(after_name, format!("(\"{}\")", name))
+            } else if attribute.arguments.is_empty() {
+                TextEdit::insert(after_name + 1, format!("\"{}\"", name))
+            } else {
+                TextEdit::insert(after_name + 1, format!("\"{}\", ", name))
+            }
+        }
+        None => TextEdit::insert(attribute_insert_position(field), format!(" @relation(\"{}\")", name)),
+    }
+}
+
+fn render_field(field: &ast::Field) -> String {
+    let mut builder = StringBuilder::new();
+
+    builder.write(&field.name.name);
+    builder.write(" ");
+    builder.write(&field.field_type.name);
+    Renderer::render_field_arity(&mut builder, &field.arity);
+
+    for attribute in &field.attributes {
+        builder.write(" ");
+        Renderer::render_field_attribute(&mut builder, attribute);
+    }
+
+    builder.to_string()
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
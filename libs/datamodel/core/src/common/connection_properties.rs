@@ -0,0 +1,5 @@
+pub const CONNECTION_LIMIT_KEY: &str = "connectionLimit";
+pub const POOL_TIMEOUT_KEY: &str = "poolTimeout";
+pub const CONNECT_TIMEOUT_KEY: &str = "connectTimeout";
+pub const SSL_MODE_KEY: &str = "sslMode";
+pub const STATEMENT_CACHE_SIZE_KEY: &str = "statementCacheSize";
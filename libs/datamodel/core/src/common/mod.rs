@@ -4,6 +4,7 @@ mod default_names;
 mod name_normalizer;
 mod string_helper;
 
+pub mod connection_properties;
 pub mod preview_features;
 pub mod provider_names;
 
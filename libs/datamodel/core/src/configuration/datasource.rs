@@ -1,6 +1,17 @@
+use crate::common::{
+    connection_properties::{CONNECTION_LIMIT_KEY, CONNECT_TIMEOUT_KEY, POOL_TIMEOUT_KEY},
+    provider_names::{MSSQL_SOURCE_NAME, SQLITE_SOURCE_NAME},
+};
 use crate::configuration::preview_features::PreviewFeatures;
 use datamodel_connector::{Connector, ConnectorCapabilities};
 use serde::Serialize;
+use url::{form_urlencoded, Url};
+
+const CONNECTION_LIMIT_PARAM: &str = "connection_limit";
+const POOL_TIMEOUT_PARAM: &str = "pool_timeout";
+const CONNECT_TIMEOUT_PARAM: &str = "connect_timeout";
+const SSL_MODE_PARAM: &str = "sslmode";
+const STATEMENT_CACHE_SIZE_PARAM: &str = "statement_cache_size";
 
 /// a `datasource` from the prisma schema.
 pub struct Datasource {
@@ -16,6 +27,10 @@ pub struct Datasource {
     /// the connector of the active provider
     pub active_connector: Box<dyn Connector>,
     pub preview_features: Vec<String>,
+    /// where the referential integrity of relations is enforced
+    pub referential_integrity: ReferentialIntegrity,
+    /// the connection properties of the datasource block. Parameters in the URL take precedence.
+    pub connection: ConnectionConfig,
 }
 
 impl std::fmt::Debug for Datasource {
@@ -26,6 +41,8 @@ impl std::fmt::Debug for Datasource {
             .field("active_provider", &self.active_provider)
             .field("url", &self.url)
             .field("documentation", &self.documentation)
+            .field("referential_integrity", &self.referential_integrity)
+            .field("connection", &self.connection)
             .field("active_connector", &&"...")
             .finish()
     }
@@ -36,12 +53,186 @@ impl Datasource {
         &self.url
     }
 
+    /// The URL to connect with: the URL of the datasource, with its connection properties added
+    /// as parameters. Parameters already in the URL take precedence over the properties.
+    pub fn connection_url(&self) -> String {
+        let connection = &self.connection;
+        let url = self.url.value.as_str();
+
+        if self.active_provider == MSSQL_SOURCE_NAME {
+            // SQL Server uses JDBC strings, `sqlserver://host:port;key=value`, with the same
+            // parameter names as the datasource properties.
+            let params = vec![
+                (CONNECTION_LIMIT_KEY, connection.connection_limit),
+                (POOL_TIMEOUT_KEY, connection.pool_timeout),
+                (CONNECT_TIMEOUT_KEY, connection.connect_timeout),
+            ];
+
+            return append_jdbc_params(url, params);
+        }
+
+        let params: Vec<(&str, String)> = vec![
+            (
+                CONNECTION_LIMIT_PARAM,
+                connection.connection_limit.map(|v| v.to_string()),
+            ),
+            (POOL_TIMEOUT_PARAM, connection.pool_timeout.map(|v| v.to_string())),
+            (CONNECT_TIMEOUT_PARAM, connection.connect_timeout.map(|v| v.to_string())),
+            (SSL_MODE_PARAM, connection.ssl_mode.map(|mode| mode.to_string())),
+            (
+                STATEMENT_CACHE_SIZE_PARAM,
+                connection.statement_cache_size.map(|v| v.to_string()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name, value)))
+        .collect();
+
+        if params.is_empty() {
+            return url.to_owned();
+        }
+
+        // SQLite URLs are file paths like `file:dev.db`, which `Url` would turn into absolute
+        // `file:///` URLs. Only their query is rewritten.
+        if self.active_provider == SQLITE_SOURCE_NAME {
+            let (path, query) = match url.find('?') {
+                Some(idx) => (&url[..idx], &url[idx + 1..]),
+                None => (url, ""),
+            };
+
+            return format!("{}?{}", path, append_query_params(query, &params));
+        }
+
+        // Invalid URLs are left as they are, for the connector to report.
+        match Url::parse(url) {
+            Ok(mut parsed) => {
+                let query = append_query_params(parsed.query().unwrap_or_default(), &params);
+                parsed.set_query(Some(&query));
+
+                parsed.to_string()
+            }
+            Err(_) => url.to_owned(),
+        }
+    }
+
     pub fn capabilities(&self) -> ConnectorCapabilities {
         let capabilities = self.active_connector.capabilities().clone();
         ConnectorCapabilities::new(capabilities)
     }
 }
 
+/// Appends the parameters missing in the query. Parameter names are case insensitive.
+fn append_query_params(query: &str, params: &[(&str, String)]) -> String {
+    let existing: Vec<String> = form_urlencoded::parse(query.as_bytes())
+        .map(|(name, _)| name.to_lowercase())
+        .collect();
+
+    let mut serializer = form_urlencoded::Serializer::new(query.to_owned());
+
+    for (name, value) in params {
+        if !existing.contains(&name.to_lowercase()) {
+            serializer.append_pair(name, value);
+        }
+    }
+
+    serializer.finish()
+}
+
+/// Appends the `;key=value` parameters missing in a JDBC string. Keys are case insensitive.
+fn append_jdbc_params(jdbc_string: &str, params: Vec<(&str, Option<u32>)>) -> String {
+    let existing: Vec<String> = jdbc_string
+        .split(';')
+        .skip(1)
+        .filter_map(|param| param.split('=').next())
+        .map(|name| name.trim().to_lowercase())
+        .collect();
+
+    let mut jdbc_string = jdbc_string.trim_end_matches(';').to_owned();
+
+    for (name, value) in params {
+        if let Some(value) = value.filter(|_| !existing.contains(&name.to_lowercase())) {
+            jdbc_string.push_str(&format!(";{}={}", name, value));
+        }
+    }
+
+    jdbc_string
+}
+
+/// Where the referential integrity of relations is enforced, configured with the
+/// `referentialIntegrity` property of a datasource.
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Copy, Debug, Serialize, PartialEq)]
+pub enum ReferentialIntegrity {
+    /// The database enforces it with foreign keys. This is the default.
+    ForeignKeys,
+    /// The database has no foreign keys: Prisma emulates the referential
+    /// actions of the relations when writing.
+    Prisma,
+}
+
+impl Default for ReferentialIntegrity {
+    fn default() -> Self {
+        ReferentialIntegrity::ForeignKeys
+    }
+}
+
+impl ToString for ReferentialIntegrity {
+    fn to_string(&self) -> String {
+        match self {
+            ReferentialIntegrity::ForeignKeys => String::from("foreignKeys"),
+            ReferentialIntegrity::Prisma => String::from("prisma"),
+        }
+    }
+}
+
+/// The connection properties of a datasource, e.g. `connectionLimit = 10`. The
+/// same settings given as URL parameters override these.
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Default, Serialize, PartialEq)]
+pub struct ConnectionConfig {
+    /// The maximum number of connections in the pool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_limit: Option<u32>,
+    /// Seconds to wait for a connection from the pool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_timeout: Option<u32>,
+    /// Seconds to wait for a new connection to the database.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_timeout: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssl_mode: Option<SslMode>,
+    /// The number of prepared statements cached per connection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_cache_size: Option<u32>,
+}
+
+impl ConnectionConfig {
+    pub fn is_empty(&self) -> bool {
+        *self == ConnectionConfig::default()
+    }
+}
+
+/// Whether TLS is used for the connection, configured with the `sslMode`
+/// property of a datasource.
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Copy, Debug, Serialize, PartialEq)]
+pub enum SslMode {
+    Disable,
+    /// TLS is used if the server supports it.
+    Prefer,
+    Require,
+}
+
+impl ToString for SslMode {
+    fn to_string(&self) -> String {
+        match self {
+            SslMode::Disable => String::from("disable"),
+            SslMode::Prefer => String::from("prefer"),
+            SslMode::Require => String::from("require"),
+        }
+    }
+}
+
 #[serde(rename_all = "camelCase")]
 #[derive(Clone, Debug, Serialize, PartialEq)]
 pub struct StringFromEnvVar {
@@ -1,4 +1,6 @@
 use super::DatamodelError;
+use crate::ast::SchemaFiles;
+use crate::diagnostics::helper::pretty_print_without_source;
 use crate::diagnostics::warning::DatamodelWarning;
 
 /// Represents a list of validation or parser errors and warnings.
@@ -90,6 +92,26 @@ impl Diagnostics {
 
         String::from_utf8_lossy(&message).into_owned()
     }
+
+    /// Like `to_pretty_string`, for the diagnostics of a schema split over several files.
+    pub fn to_pretty_string_for_files(&self, files: &SchemaFiles) -> String {
+        let mut message: Vec<u8> = Vec::new();
+
+        for err in self.to_error_iter() {
+            let span = err.span();
+
+            // Synthetic spans may not point into any file. Their errors are printed without an excerpt.
+            match files.get(span.file_id) {
+                Some(file) if span.end <= file.contents.len() => {
+                    err.pretty_print(&mut message, &file.name, &file.contents)
+                }
+                _ => pretty_print_without_source(&mut message, err.description().as_str()),
+            }
+            .expect("printing datamodel error");
+        }
+
+        String::from_utf8_lossy(&message).into_owned()
+    }
 }
 
 impl std::fmt::Display for Diagnostics {
@@ -107,6 +107,9 @@ pub enum DatamodelError {
   #[error("Error validating model \"{}\": {}", model_name, message)]
   ModelValidationError { message: String, model_name: String, span: Span  },
 
+  #[error("Error validating model \"{}\": {}", model_name, message)]
+  AmbiguousRelationError { message: String, model_name: String, fields: Vec<String>, span: Span },
+
   #[error("Error validating field `{}` in model `{}`: {}", field, model, message)]
   FieldValidationError { message: String, model: String, field: String, span: Span },
 
@@ -267,6 +270,15 @@ impl DatamodelError {
     }
   }
 
+  pub fn new_ambiguous_relation_error(message: &str, model_name: &str, fields: &[&str], span: Span) -> DatamodelError {
+    DatamodelError::AmbiguousRelationError {
+      message: String::from(message),
+      model_name: String::from(model_name),
+      fields: fields.iter().map(|field| String::from(*field)).collect(),
+      span,
+    }
+  }
+
   pub fn new_enum_validation_error(message: &str, enum_name: &str, span: Span) -> DatamodelError {
     DatamodelError::EnumValidationError {
       message: String::from(message),
@@ -384,6 +396,7 @@ impl DatamodelError {
       DatamodelError::ValidationError { span, .. } => *span,
       DatamodelError::LegacyParserError { span, .. } => *span,
       DatamodelError::ModelValidationError { span, .. } => *span,
+      DatamodelError::AmbiguousRelationError { span, .. } => *span,
       DatamodelError::DuplicateAttributeError { span, .. } => *span,
       DatamodelError::DuplicateConfigKeyError { span, .. } => *span,
       DatamodelError::DuplicateTopError { span, .. } => *span,
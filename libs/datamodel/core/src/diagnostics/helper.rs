@@ -43,6 +43,11 @@ pub(crate) fn pretty_print(f: &mut dyn std::io::Write, file_name: &str, text: &s
   writeln!(f, "{}", format_line_number(0))
 }
 
+/// Pretty prints an error or warning whose span does not point into a known source text.
+pub(crate) fn pretty_print_without_source(f: &mut dyn std::io::Write, description: &str) -> std::io::Result<()> {
+    writeln!(f, "{}: {}", "error".bright_red().bold(), description.bold())
+}
+
 fn format_line_number_with_line(line_number: usize, lines: &[&str]) -> colored::ColoredString {
     if line_number > 0 && line_number <= lines.len() {
         colored::ColoredString::from(format!("{}{}", format_line_number(line_number), lines[line_number - 1]).as_str())
@@ -1,11 +1,12 @@
 mod to_dmmf;
 pub use to_dmmf::render_to_dmmf;
 pub use to_dmmf::render_to_dmmf_value;
+pub use to_dmmf::schema_to_dmmf;
 
 // This is a simple JSON serialization using Serde.
 // The JSON format follows the DMMF spec.
 #[serde(rename_all = "camelCase")]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct Field {
     pub name: String,
     pub kind: String,
@@ -18,7 +19,7 @@ pub struct Field {
     pub field_type: String,
     pub has_default_value: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default: Option<serde_json::Value>,
+    pub default: Option<DefaultValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relation_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -32,18 +33,40 @@ pub struct Field {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_updated_at: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_version: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
 }
 
+/// The default value of a field: a value, or a function generating it.
+#[serde(untagged)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub enum DefaultValue {
+    Function(Function),
+    Value(Value),
+}
+
 #[serde(rename_all = "camelCase")]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct Function {
     pub name: String,
-    pub args: Vec<serde_json::Value>,
+    pub args: Vec<Value>,
+}
+
+/// A value in the DMMF. Values without a JSON counterpart, like dates, bytes or
+/// big integers, are rendered as strings.
+#[serde(untagged)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub enum Value {
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    List(Vec<Value>),
+    Null,
 }
 
 #[serde(rename_all = "camelCase")]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
@@ -56,17 +79,19 @@ pub struct Model {
     pub id_fields: Vec<String>,
     pub unique_fields: Vec<Vec<String>>,
     pub unique_indexes: Vec<UniqueIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soft_delete_field: Option<String>,
 }
 
 #[serde(rename_all = "camelCase")]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct UniqueIndex {
     pub name: Option<String>,
     pub fields: Vec<String>,
 }
 
 #[serde(rename_all = "camelCase")]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct Enum {
     pub name: String,
     pub values: Vec<EnumValue>,
@@ -76,14 +101,25 @@ pub struct Enum {
 }
 
 #[serde(rename_all = "camelCase")]
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct EnumValue {
     pub name: String,
     pub db_name: Option<String>,
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct CompositeType {
+    pub name: String,
+    pub fields: Vec<Field>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct Datamodel {
     pub enums: Vec<Enum>,
     pub models: Vec<Model>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<CompositeType>,
 }
@@ -1,4 +1,4 @@
-use super::{Datamodel, Enum, EnumValue, Field, Function, Model, UniqueIndex};
+use super::{CompositeType, Datamodel, DefaultValue, Enum, EnumValue, Field, Function, Model, UniqueIndex, Value};
 use crate::{dml, IndexType, ScalarType};
 use bigdecimal::ToPrimitive;
 use prisma_value::PrismaValue;
@@ -13,10 +13,12 @@ pub fn render_to_dmmf_value(schema: &dml::Datamodel) -> serde_json::Value {
     serde_json::to_value(&dmmf).expect("Failed to render JSON")
 }
 
-fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
+/// Convert a datamodel to its typed DMMF representation.
+pub fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
     let mut datamodel = Datamodel {
         models: vec![],
         enums: vec![],
+        types: vec![],
     };
 
     for enum_model in schema.enums() {
@@ -27,6 +29,10 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
         datamodel.models.push(model_to_dmmf(&model));
     }
 
+    for composite_type in schema.composite_types() {
+        datamodel.types.push(composite_type_to_dmmf(&composite_type));
+    }
+
     datamodel
 }
 
@@ -52,6 +58,36 @@ fn enum_value_to_dmmf(en: &dml::EnumValue) -> EnumValue {
     }
 }
 
+fn composite_type_to_dmmf(composite_type: &dml::CompositeType) -> CompositeType {
+    CompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type
+            .fields()
+            .map(|field| Field {
+                name: field.name.clone(),
+                kind: get_field_type_kind(&field.field_type),
+                is_required: field.arity == dml::FieldArity::Required,
+                is_list: field.arity == dml::FieldArity::List,
+                is_id: false,
+                is_read_only: false,
+                has_default_value: false,
+                default: None,
+                is_unique: false,
+                relation_name: None,
+                relation_from_fields: None,
+                relation_to_fields: None,
+                relation_on_delete: None,
+                field_type: get_type_name(&field.field_type),
+                is_generated: None,
+                is_updated_at: None,
+                is_version: None,
+                documentation: field.documentation.clone(),
+            })
+            .collect(),
+        documentation: composite_type.documentation.clone(),
+    }
+}
+
 fn model_to_dmmf(model: &dml::Model) -> Model {
     Model {
         name: model.name.clone(),
@@ -86,6 +122,7 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
                 }
             })
             .collect(),
+        soft_delete_field: model.soft_delete_field.clone(),
     }
 }
 
@@ -102,7 +139,7 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         is_id: field.is_id(),
         is_read_only: a_relation_field_is_based_on_this_field,
         has_default_value: field.default_value().is_some(),
-        default: default_value_to_serde(&field.default_value().cloned()),
+        default: default_value_to_dmmf(&field.default_value().cloned()),
         is_unique: field.is_unique(),
         relation_name: get_relation_name(field),
         relation_from_fields: get_relation_from_fields(field),
@@ -111,13 +148,19 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated()),
         is_updated_at: Some(field.is_updated_at()),
+        is_version: Some(field.is_version()),
         documentation: field.documentation().map(|v| v.to_owned()),
     }
 }
 
 fn get_field_kind(field: &dml::Field) -> String {
-    match field.field_type() {
+    get_field_type_kind(&field.field_type())
+}
+
+fn get_field_type_kind(field_type: &dml::FieldType) -> String {
+    match field_type {
         dml::FieldType::Relation(_) => String::from("object"),
+        dml::FieldType::CompositeType(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_, _) => String::from("scalar"),
         dml::FieldType::NativeType(_, _) => String::from("scalar"),
@@ -125,48 +168,47 @@ fn get_field_kind(field: &dml::Field) -> String {
     }
 }
 
-fn default_value_to_serde(dv_opt: &Option<dml::DefaultValue>) -> Option<serde_json::Value> {
+fn default_value_to_dmmf(dv_opt: &Option<dml::DefaultValue>) -> Option<DefaultValue> {
     dv_opt.as_ref().map(|dv| match dv {
-        dml::DefaultValue::Single(value) => prisma_value_to_serde(&value.clone()),
-        dml::DefaultValue::Expression(vg) => function_to_serde(&vg.name, &vg.args),
+        dml::DefaultValue::Single(value) => DefaultValue::Value(prisma_value_to_dmmf(value)),
+        dml::DefaultValue::Expression(vg) => DefaultValue::Function(function_to_dmmf(&vg.name, &vg.args)),
     })
 }
 
-fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
+fn prisma_value_to_dmmf(value: &PrismaValue) -> Value {
     match value {
-        PrismaValue::Boolean(val) => serde_json::Value::Bool(*val),
-        PrismaValue::String(val) => serde_json::Value::String(val.clone()),
-        PrismaValue::Enum(val) => serde_json::Value::String(val.clone()),
-        PrismaValue::Float(val) => {
-            serde_json::Value::Number(serde_json::Number::from_f64(val.to_f64().unwrap()).unwrap())
-        }
-        PrismaValue::Int(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
-        PrismaValue::BigInt(val) => serde_json::Value::String(val.to_string()),
-        PrismaValue::DateTime(val) => serde_json::Value::String(val.to_rfc3339()),
-        PrismaValue::Null => serde_json::Value::Null,
-        PrismaValue::Uuid(val) => serde_json::Value::String(val.to_string()),
-        PrismaValue::Json(val) => serde_json::Value::String(val.to_string()),
-        PrismaValue::Xml(val) => serde_json::Value::String(val.to_string()),
-        PrismaValue::List(value_vec) => {
-            serde_json::Value::Array(value_vec.iter().map(|pv| prisma_value_to_serde(pv)).collect())
-        }
-        PrismaValue::Bytes(b) => serde_json::Value::String(prisma_value::encode_bytes(b)),
-    }
-}
-
-fn function_to_serde(name: &str, args: &[PrismaValue]) -> serde_json::Value {
-    let func = Function {
-        name: String::from(name),
-        args: args.iter().map(|arg| prisma_value_to_serde(arg)).collect(),
-    };
+        PrismaValue::Boolean(val) => Value::Boolean(*val),
+        PrismaValue::String(val) => Value::String(val.clone()),
+        PrismaValue::Enum(val) => Value::String(val.clone()),
+        PrismaValue::Float(val) => Value::Number(val.to_f64().unwrap()),
+        PrismaValue::Int(val) => Value::Number(*val as f64),
+        PrismaValue::BigInt(val) => Value::String(val.to_string()),
+        PrismaValue::DateTime(val) => Value::String(val.to_rfc3339()),
+        PrismaValue::Null => Value::Null,
+        PrismaValue::Uuid(val) => Value::String(val.to_string()),
+        PrismaValue::Json(val) => Value::String(val.to_string()),
+        PrismaValue::Xml(val) => Value::String(val.to_string()),
+        PrismaValue::List(value_vec) => Value::List(value_vec.iter().map(prisma_value_to_dmmf).collect()),
+        PrismaValue::Bytes(b) => Value::String(prisma_value::encode_bytes(b)),
+    }
+}
 
-    serde_json::to_value(&func).expect("Failed to render function JSON")
+fn function_to_dmmf(name: &str, args: &[PrismaValue]) -> Function {
+    Function {
+        name: String::from(name),
+        args: args.iter().map(prisma_value_to_dmmf).collect(),
+    }
 }
 
 fn get_field_type(field: &dml::Field) -> String {
-    match &field.field_type() {
+    get_type_name(&field.field_type())
+}
+
+fn get_type_name(field_type: &dml::FieldType) -> String {
+    match field_type {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
         dml::FieldType::Enum(t) => t.clone(),
+        dml::FieldType::CompositeType(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
         dml::FieldType::NativeType(t, _) => type_to_string(t),
@@ -1,4 +1,4 @@
-use crate::{configuration, StringFromEnvVar};
+use crate::{configuration, ConnectionConfig, ReferentialIntegrity, StringFromEnvVar};
 
 #[serde(rename_all = "camelCase")]
 #[derive(Debug, serde::Serialize)]
@@ -9,6 +9,10 @@ pub struct SourceConfig {
     pub url: StringFromEnvVar,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referential_integrity: Option<ReferentialIntegrity>,
+    #[serde(flatten)]
+    pub connection: ConnectionConfig,
 }
 
 pub fn render_sources_to_json_value(sources: &[configuration::Datasource]) -> serde_json::Value {
@@ -38,5 +42,8 @@ fn source_to_json_struct(source: &configuration::Datasource) -> SourceConfig {
         active_provider: source.active_provider.to_string(),
         url: source.url().clone(),
         documentation: source.documentation.clone(),
+        referential_integrity: Some(source.referential_integrity)
+            .filter(|referential_integrity| *referential_integrity != ReferentialIntegrity::default()),
+        connection: source.connection.clone(),
     }
 }
@@ -77,18 +77,20 @@ extern crate pest_derive;
 extern crate tracing;
 
 pub mod ast;
+pub mod code_actions;
 pub mod common;
 pub mod configuration;
 pub mod diagnostics;
 pub mod dml;
 pub mod json;
+pub mod rename;
 pub mod transform;
 pub mod walkers;
 
 pub use crate::dml::*;
 pub use configuration::*;
 
-use crate::ast::SchemaAst;
+use crate::ast::{SchemaAst, SchemaFiles};
 use crate::diagnostics::{ValidatedConfiguration, ValidatedDatamodel, ValidatedDatasources};
 use std::io::Write;
 use transform::{
@@ -107,6 +109,20 @@ pub fn parse_datamodel_and_ignore_datasource_urls(
     parse_datamodel_internal(datamodel_string, true)
 }
 
+/// Parses and validates a schema split over several files, using core attributes only.
+/// The spans of the diagnostics refer to the files they were found in.
+pub fn parse_datamodel_files(files: &SchemaFiles) -> Result<ValidatedDatamodel, diagnostics::Diagnostics> {
+    let ast = ast::parser::parse_schema_files(files)?;
+    validate_schema_ast(&ast, false)
+}
+
+pub fn parse_datamodel_files_and_ignore_datasource_urls(
+    files: &SchemaFiles,
+) -> Result<ValidatedDatamodel, diagnostics::Diagnostics> {
+    let ast = ast::parser::parse_schema_files(files)?;
+    validate_schema_ast(&ast, true)
+}
+
 pub fn parse_datamodel_without_validation(datamodel_string: &str) -> Result<Datamodel, diagnostics::Diagnostics> {
     let ast = ast::parser::parse_schema(datamodel_string)?;
     let sources = load_sources(&ast, true, vec![])?;
@@ -116,6 +132,15 @@ pub fn parse_datamodel_without_validation(datamodel_string: &str) -> Result<Data
     validator.lift_without_validation(&ast)
 }
 
+/// Lifts the AST without validating it, and adds the missing back relation fields.
+pub(crate) fn lift_ast_with_back_relations(ast: &ast::SchemaAst) -> Result<Datamodel, diagnostics::Diagnostics> {
+    let sources = load_sources(ast, true, vec![])?;
+    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+    let validator = ValidationPipeline::new(&sources.subject, &generators.subject);
+
+    validator.lift_with_back_relations(ast)
+}
+
 /// Parses and validates a datamodel string, using core attributes only.
 /// In case of an error, a pretty, colorful string is returned.
 pub fn parse_datamodel_or_pretty_error(datamodel_string: &str, file_name: &str) -> Result<ValidatedDatamodel, String> {
@@ -140,8 +165,15 @@ fn parse_datamodel_internal(
     datamodel_string: &str,
     ignore_datasource_urls: bool,
 ) -> Result<ValidatedDatamodel, diagnostics::Diagnostics> {
-    let mut diagnostics = diagnostics::Diagnostics::new();
     let ast = ast::parser::parse_schema(datamodel_string)?;
+    validate_schema_ast(&ast, ignore_datasource_urls)
+}
+
+fn validate_schema_ast(
+    ast: &SchemaAst,
+    ignore_datasource_urls: bool,
+) -> Result<ValidatedDatamodel, diagnostics::Diagnostics> {
+    let mut diagnostics = diagnostics::Diagnostics::new();
     let sources = load_sources(&ast, ignore_datasource_urls, vec![])?;
     let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
     let validator = ValidationPipeline::new(&sources.subject, &generators.subject);
@@ -189,23 +221,21 @@ pub fn parse_schema_ast(datamodel_string: &str) -> Result<SchemaAst, diagnostics
     ast::parser::parse_schema(datamodel_string)
 }
 
+/// Parses the files of a schema into one AST, see `parse_datamodel_files`.
+pub fn parse_schema_ast_files(files: &SchemaFiles) -> Result<SchemaAst, diagnostics::Diagnostics> {
+    ast::parser::parse_schema_files(files)
+}
+
 /// Loads all configuration blocks from a datamodel using the built-in source definitions.
 pub fn parse_configuration(datamodel_string: &str) -> Result<ValidatedConfiguration, diagnostics::Diagnostics> {
-    let mut warnings = Vec::new();
     let ast = ast::parser::parse_schema(datamodel_string)?;
-    let mut validated_sources = load_sources(&ast, false, vec![])?;
-    let mut validated_generators = GeneratorLoader::load_generators_from_ast(&ast)?;
-
-    warnings.append(&mut validated_generators.warnings);
-    warnings.append(&mut validated_sources.warnings);
+    load_configuration(&ast, false, vec![])
+}
 
-    Ok(ValidatedConfiguration {
-        subject: Configuration {
-            datasources: validated_sources.subject,
-            generators: validated_generators.subject,
-        },
-        warnings,
-    })
+/// Loads all configuration blocks from a schema split over several files, see `parse_datamodel_files`.
+pub fn parse_configuration_files(files: &SchemaFiles) -> Result<ValidatedConfiguration, diagnostics::Diagnostics> {
+    let ast = ast::parser::parse_schema_files(files)?;
+    load_configuration(&ast, false, vec![])
 }
 
 /// - `datasource_url_overrides`: the tuples consist of datasource name and url
@@ -213,30 +243,25 @@ pub fn parse_configuration_with_url_overrides(
     schema: &str,
     datasource_url_overrides: Vec<(String, String)>,
 ) -> Result<ValidatedConfiguration, diagnostics::Diagnostics> {
-    let mut warnings = Vec::new();
     let ast = ast::parser::parse_schema(schema)?;
-    let mut validated_sources = load_sources(&ast, false, datasource_url_overrides)?;
-    let mut validated_generators = GeneratorLoader::load_generators_from_ast(&ast)?;
-
-    warnings.append(&mut validated_generators.warnings);
-    warnings.append(&mut validated_sources.warnings);
-
-    Ok(ValidatedConfiguration {
-        subject: Configuration {
-            datasources: validated_sources.subject,
-            generators: validated_generators.subject,
-        },
-        warnings,
-    })
+    load_configuration(&ast, false, datasource_url_overrides)
 }
 
 pub fn parse_configuration_and_ignore_datasource_urls(
     datamodel_string: &str,
 ) -> Result<ValidatedConfiguration, diagnostics::Diagnostics> {
-    let mut warnings = Vec::new();
     let ast = ast::parser::parse_schema(datamodel_string)?;
-    let mut validated_sources = load_sources(&ast, true, vec![])?;
-    let mut validated_generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+    load_configuration(&ast, true, vec![])
+}
+
+fn load_configuration(
+    schema_ast: &SchemaAst,
+    ignore_datasource_urls: bool,
+    datasource_url_overrides: Vec<(String, String)>,
+) -> Result<ValidatedConfiguration, diagnostics::Diagnostics> {
+    let mut warnings = Vec::new();
+    let mut validated_sources = load_sources(schema_ast, ignore_datasource_urls, datasource_url_overrides)?;
+    let mut validated_generators = GeneratorLoader::load_generators_from_ast(schema_ast)?;
 
     warnings.append(&mut validated_generators.warnings);
     warnings.append(&mut validated_sources.warnings);
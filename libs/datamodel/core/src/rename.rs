@@ -0,0 +1,338 @@
+//! Renames models, fields and enums, rewriting all references to them in the schema.
+//! Optionally, the database name is kept with `@@map` / `@map`, so that renaming
+//! does not change the database schema.
+use crate::{
+    ast::{self, FileId, Span, WithIdentifier},
+    code_actions::{attribute_insert_position, TextEdit},
+    diagnostics::{DatamodelError, Diagnostics},
+    dml,
+    transform::ast_to_dml::reserved_model_names::TypeNameValidator,
+    walkers::{walk_models, walk_scalar_fields},
+};
+use std::str::FromStr;
+
+/// What to rename.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenameTarget {
+    Model(String),
+    Field { model: String, field: String },
+    Enum(String),
+}
+
+/// Computes the edits renaming the target to `new_name`. If `preserve_database_name`
+/// is set, a `@@map` / `@map` attribute with the previous name is added, unless the
+/// target is mapped already.
+pub fn rename(
+    schema: &str,
+    target: &RenameTarget,
+    new_name: &str,
+    preserve_database_name: bool,
+) -> Result<Vec<TextEdit>, Diagnostics> {
+    let validated = crate::parse_datamodel_and_ignore_datasource_urls(schema)?;
+    let ast = crate::parse_schema_ast(schema)?;
+    let datamodel = &validated.subject;
+
+    let mut edits = Vec::new();
+
+    match target {
+        RenameTarget::Model(name) => {
+            let ast_model = find_model(schema, &ast, name)?;
+            check_identifier(new_name, ast_model.name.span)?;
+            check_type_name(new_name, "model", ast_model.name.span)?;
+            check_top_is_free(&ast, new_name, "model")?;
+
+            edits.push(TextEdit::replace(ast_model.name.span, new_name));
+
+            for model in walk_models(datamodel) {
+                for relation_field in model.relation_fields() {
+                    if relation_field.referenced_model().name() != name.as_str() {
+                        continue;
+                    }
+
+                    // Generated back relation fields are not in the schema.
+                    if let Some(ast_field) = ast.find_field(model.name(), relation_field.name()) {
+                        edits.push(rename_field_type(ast_field, new_name));
+                    }
+                }
+            }
+
+            if preserve_database_name && datamodel.find_model(name).unwrap().database_name.is_none() {
+                edits.push(add_block_map(ast_model.span, name));
+
+                // Implicit relation names are derived from the model names, and
+                // they name the join tables of many-to-many relations.
+                for model in walk_models(datamodel) {
+                    for relation_field in model.relation_fields() {
+                        if model.name() != name.as_str() && relation_field.referenced_model().name() != name.as_str() {
+                            continue;
+                        }
+
+                        if let Some(ast_field) = ast.find_field(model.name(), relation_field.name()) {
+                            edits.extend(add_relation_name(ast_field, relation_field.relation_name()));
+                        }
+                    }
+                }
+            }
+        }
+        RenameTarget::Field { model, field } => {
+            let ast_model = find_model(schema, &ast, model)?;
+            let ast_field = ast_model
+                .fields
+                .iter()
+                .find(|ast_field| &ast_field.name.name == field)
+                .ok_or_else(|| {
+                    DatamodelError::new_validation_error(
+                        &format!("Field `{}` not found on model `{}`.", field, model),
+                        ast_model.name.span,
+                    )
+                })?;
+
+            check_identifier(new_name, ast_field.name.span)?;
+
+            if let Some(existing) = ast_model.fields.iter().find(|f| f.name.name == new_name) {
+                return Err(DatamodelError::new_duplicate_field_error(model, new_name, existing.name.span).into());
+            }
+
+            // Generated back relation fields are not in the schema, but take the name as well.
+            if datamodel.find_model(model).unwrap().find_field(new_name).is_some() {
+                return Err(DatamodelError::new_duplicate_field_error(model, new_name, ast_field.name.span).into());
+            }
+
+            edits.push(TextEdit::replace(ast_field.name.span, new_name));
+
+            // Relation fields have no column and are not referenced by name.
+            if let Some(scalar_field) = datamodel.find_model(model).unwrap().find_scalar_field(field) {
+                for walker in walk_models(datamodel) {
+                    for relation_field in walker.relation_fields() {
+                        let ast_relation_field = match ast.find_field(walker.name(), relation_field.name()) {
+                            Some(ast_relation_field) => ast_relation_field,
+                            None => continue,
+                        };
+
+                        if walker.name() == model.as_str()
+                            && relation_field.referencing_fields().any(|f| f.name() == field.as_str())
+                        {
+                            edits.extend(rename_in_attribute_arrays(
+                                &ast_relation_field.attributes,
+                                "relation",
+                                Some("fields"),
+                                field,
+                                new_name,
+                            ));
+                        }
+
+                        if relation_field.referenced_model().name() == model.as_str()
+                            && relation_field.referenced_fields().any(|f| f.name() == field.as_str())
+                        {
+                            edits.extend(rename_in_attribute_arrays(
+                                &ast_relation_field.attributes,
+                                "relation",
+                                Some("references"),
+                                field,
+                                new_name,
+                            ));
+                        }
+                    }
+                }
+
+                for attribute_name in &["id", "unique", "index"] {
+                    edits.extend(rename_in_attribute_arrays(
+                        &ast_model.attributes,
+                        attribute_name,
+                        None,
+                        field,
+                        new_name,
+                    ));
+                }
+
+                if preserve_database_name && scalar_field.database_name.is_none() {
+                    edits.push(TextEdit::insert(
+                        attribute_insert_position(ast_field),
+                        format!(" @map(\"{}\")", field),
+                    ));
+                }
+            }
+        }
+        RenameTarget::Enum(name) => {
+            let ast_enum = ast
+                .find_enum(name)
+                .ok_or_else(|| not_found_error(schema, &format!("Enum `{}` not found.", name)))?;
+            check_identifier(new_name, ast_enum.name.span)?;
+            check_type_name(new_name, "enum", ast_enum.name.span)?;
+            check_top_is_free(&ast, new_name, "enum")?;
+
+            edits.push(TextEdit::replace(ast_enum.name.span, new_name));
+
+            for field in walk_scalar_fields(datamodel) {
+                let uses_enum = field
+                    .field_type()
+                    .as_enum()
+                    .map(|r#enum| r#enum.name() == name.as_str())
+                    .unwrap_or(false);
+
+                if let (true, Some(ast_field)) = (uses_enum, ast.find_field(field.model().name(), field.name())) {
+                    edits.push(rename_field_type(ast_field, new_name));
+                }
+            }
+
+            let composite_type_fields = ast
+                .composite_types()
+                .into_iter()
+                .flat_map(|composite_type| composite_type.fields.iter())
+                .filter(|ast_field| &ast_field.field_type.name == name);
+
+            edits.extend(composite_type_fields.map(|ast_field| rename_field_type(ast_field, new_name)));
+
+            if preserve_database_name && datamodel.find_enum(name).unwrap().database_name.is_none() {
+                edits.push(add_block_map(ast_enum.span, name));
+            }
+        }
+    }
+
+    Ok(edits)
+}
+
+fn find_model<'a>(schema: &str, ast: &'a ast::SchemaAst, name: &str) -> Result<&'a ast::Model, Diagnostics> {
+    ast.find_model(name)
+        .ok_or_else(|| not_found_error(schema, &format!("Model `{}` not found.", name)))
+}
+
+/// A missing target has no position of its own, so the error spans the whole schema.
+fn not_found_error(schema: &str, message: &str) -> Diagnostics {
+    DatamodelError::new_validation_error(message, Span::new(0, schema.len(), FileId::default())).into()
+}
+
+/// The new name must be a valid identifier of the schema language. `span` is the name being replaced.
+fn check_identifier(new_name: &str, span: Span) -> Result<(), Diagnostics> {
+    let mut chars = new_name.chars();
+    let is_valid = chars.next().map(|c| c.is_ascii_alphanumeric()).unwrap_or(false)
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(DatamodelError::new_validation_error(
+            &format!(
+                "`{}` is not a valid name. Names must start with a letter or a digit, followed by letters, digits, `_` or `-`.",
+                new_name
+            ),
+            span,
+        )
+        .into())
+    }
+}
+
+/// Models and enums can't take the names of scalar types or reserved names of the client.
+fn check_type_name(new_name: &str, top_type: &str, span: Span) -> Result<(), Diagnostics> {
+    if dml::ScalarType::from_str(new_name).is_ok() {
+        return Err(DatamodelError::new_reserved_scalar_type_error(new_name, span).into());
+    }
+
+    if TypeNameValidator::new().is_reserved(new_name) {
+        return Err(DatamodelError::new_validation_error(
+            &format!(
+                "The {} name `{}` is invalid. It is a reserved name. Please change it.",
+                top_type, new_name
+            ),
+            span,
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn check_top_is_free(ast: &ast::SchemaAst, new_name: &str, top_type: &str) -> Result<(), Diagnostics> {
+    match ast.tops.iter().find(|top| top.name() == new_name) {
+        Some(existing) => Err(DatamodelError::new_duplicate_top_error(
+            new_name,
+            top_type,
+            existing.get_type(),
+            existing.identifier().span,
+        )
+        .into()),
+        None => Ok(()),
+    }
+}
+
+/// Replaces the type name of a field, keeping its arity.
+fn rename_field_type(field: &ast::Field, new_name: &str) -> TextEdit {
+    let span = field.field_type.span;
+
+    TextEdit {
+        start: span.start,
+        end: span.start + field.field_type.name.len(),
+        text: new_name.to_owned(),
+    }
+}
+
+/// Renames the field in the array arguments of the given attributes, e.g. `@@index([a, b])`.
+/// If `argument_name` is not set, the unnamed argument and the `fields` argument are used.
+fn rename_in_attribute_arrays(
+    attributes: &[ast::Attribute],
+    attribute_name: &str,
+    argument_name: Option<&str>,
+    old_name: &str,
+    new_name: &str,
+) -> Vec<TextEdit> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.name.name == attribute_name)
+        .flat_map(|attribute| attribute.arguments.iter())
+        .filter(|argument| match argument_name {
+            Some(argument_name) => argument.name.name == argument_name,
+            None => argument.name.name.is_empty() || argument.name.name == "fields",
+        })
+        .flat_map(|argument| match &argument.value {
+            ast::Expression::Array(values, _) => values.iter().collect(),
+            value => vec![value],
+        })
+        .filter_map(|value| match value {
+            ast::Expression::ConstantValue(name, span) if name == old_name => Some(TextEdit::replace(*span, new_name)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Makes the relation name of the field explicit, unless it has a name already.
+fn add_relation_name(field: &ast::Field, relation_name: &str) -> Option<TextEdit> {
+    let relation_attribute = field
+        .attributes
+        .iter()
+        .find(|attribute| attribute.name.name == "relation");
+
+    let relation_attribute = match relation_attribute {
+        Some(attribute) => attribute,
+        None => {
+            return Some(TextEdit::insert(
+                attribute_insert_position(field),
+                format!(" @relation(\"{}\")", relation_name),
+            ))
+        }
+    };
+
+    let has_name = relation_attribute.arguments.iter().any(|argument| {
+        (argument.name.name.is_empty() || argument.name.name == "name")
+            && matches!(argument.value, ast::Expression::StringValue(_, _))
+    });
+
+    if has_name {
+        return None;
+    }
+
+    match relation_attribute.arguments.first() {
+        Some(argument) => Some(TextEdit::insert(
+            argument.span.start,
+            format!("\"{}\", ", relation_name),
+        )),
+        None => Some(TextEdit::insert(
+            relation_attribute.span.end - 1,
+            format!("\"{}\"", relation_name),
+        )),
+    }
+}
+
+/// Adds `@@map("<name>")` before the closing brace of a model or enum.
+fn add_block_map(block_span: Span, name: &str) -> TextEdit {
+    TextEdit::insert(block_span.end - 1, format!("  @@map(\"{}\")\n", name))
+}
@@ -5,12 +5,18 @@ use super::{
     datasource_provider::DatasourceProvider,
 };
 use crate::ast::Span;
-use crate::configuration::StringFromEnvVar;
+use crate::common::connection_properties::{
+    CONNECTION_LIMIT_KEY, CONNECT_TIMEOUT_KEY, POOL_TIMEOUT_KEY, SSL_MODE_KEY, STATEMENT_CACHE_SIZE_KEY,
+};
+use crate::common::provider_names::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME};
+use crate::configuration::{ConnectionConfig, ReferentialIntegrity, SslMode, StringFromEnvVar};
 use crate::diagnostics::{DatamodelError, DatamodelWarning, Diagnostics, ValidatedDatasource, ValidatedDatasources};
 use crate::{ast, Datasource};
 use datamodel_connector::{CombinedConnector, Connector};
+use std::convert::TryFrom;
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const REFERENTIAL_INTEGRITY_KEY: &str = "referentialIntegrity";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -171,6 +177,32 @@ impl DatasourceLoader {
             return Err(diagnostics.merge_error(DatamodelError::new_connector_error("Preview features are only supported in the generator block. Please move this field to the generator block.", span)));
         }
 
+        let referential_integrity = match args.arg(REFERENTIAL_INTEGRITY_KEY).ok() {
+            Some(arg) => match arg.as_str()?.as_str() {
+                "foreignKeys" => ReferentialIntegrity::ForeignKeys,
+                "prisma" => ReferentialIntegrity::Prisma,
+                value => {
+                    return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                        &format!(
+                            "Invalid referential integrity setting `{}`. Valid settings are `foreignKeys` and `prisma`.",
+                            value
+                        ),
+                        source_name,
+                        arg.span(),
+                    )));
+                }
+            },
+            None => ReferentialIntegrity::default(),
+        };
+
+        let (connection, connection_properties) = match lift_connection_config(&mut args, source_name) {
+            Ok(result) => result,
+            Err(mut err) => {
+                diagnostics.append(&mut err);
+                return Err(diagnostics);
+            }
+        };
+
         let documentation = ast_source.documentation.clone().map(|comment| comment.text);
         let url = StringFromEnvVar {
             from_env_var: env_var_for_url,
@@ -211,16 +243,38 @@ impl DatasourceLoader {
         let (successes, errors): (Vec<_>, Vec<_>) = validated_providers.into_iter().partition(|result| result.is_ok());
         if !successes.is_empty() {
             let first_successful_provider = successes.into_iter().next().unwrap()?;
+            let active_provider = first_successful_provider.canonical_name();
+
+            for (property, span) in connection_properties
+                .iter()
+                .filter(|(property, _)| !supports_connection_property(active_provider, property))
+            {
+                diagnostics.push_error(DatamodelError::new_source_validation_error(
+                    &format!(
+                        "The `{}` property is not supported by the `{}` provider.",
+                        property, active_provider
+                    ),
+                    source_name,
+                    *span,
+                ));
+            }
+
+            if diagnostics.has_errors() {
+                return Err(diagnostics);
+            }
+
             Ok(ValidatedDatasource {
                 subject: Datasource {
                     name: source_name.to_string(),
                     provider: providers,
-                    active_provider: first_successful_provider.canonical_name().to_string(),
+                    active_provider: active_provider.to_string(),
                     url,
                     documentation,
                     combined_connector,
                     active_connector: first_successful_provider.connector(),
                     preview_features,
+                    referential_integrity,
+                    connection,
                 },
                 warnings: diagnostics.warnings,
             })
@@ -234,6 +288,90 @@ impl DatasourceLoader {
     }
 }
 
+/// Parses the connection properties of a datasource. Their spans are returned as well, for
+/// checking them against the provider. All invalid properties are reported.
+fn lift_connection_config(
+    args: &mut Arguments<'_>,
+    source_name: &str,
+) -> Result<(ConnectionConfig, Vec<(&'static str, Span)>), Diagnostics> {
+    let mut errors = Diagnostics::new();
+    let mut config = ConnectionConfig::default();
+    let mut properties = Vec::new();
+
+    let numeric_properties = vec![
+        (CONNECTION_LIMIT_KEY, &mut config.connection_limit),
+        (POOL_TIMEOUT_KEY, &mut config.pool_timeout),
+        (CONNECT_TIMEOUT_KEY, &mut config.connect_timeout),
+        (STATEMENT_CACHE_SIZE_KEY, &mut config.statement_cache_size),
+    ];
+
+    for (property, target) in numeric_properties {
+        let arg = match args.optional_arg(property) {
+            Some(arg) => arg,
+            None => continue,
+        };
+
+        // A pool without connections can never hand one out.
+        let minimum = if property == CONNECTION_LIMIT_KEY { 1 } else { 0 };
+        properties.push((property, arg.span()));
+
+        let value = match arg.as_int() {
+            Ok(value) => value,
+            Err(err) => {
+                errors.push_error(err);
+                continue;
+            }
+        };
+
+        match u32::try_from(value) {
+            Ok(value) if value >= minimum => *target = Some(value),
+            _ => errors.push_error(DatamodelError::new_source_validation_error(
+                &format!(
+                    "Invalid value `{}` for `{}`. Expected an integer between {} and {}.",
+                    value,
+                    property,
+                    minimum,
+                    u32::MAX
+                ),
+                source_name,
+                arg.span(),
+            )),
+        }
+    }
+
+    if let Some(arg) = args.optional_arg(SSL_MODE_KEY) {
+        properties.push((SSL_MODE_KEY, arg.span()));
+
+        match arg.as_str() {
+            Ok(value) => match value.as_str() {
+                "disable" => config.ssl_mode = Some(SslMode::Disable),
+                "prefer" => config.ssl_mode = Some(SslMode::Prefer),
+                "require" => config.ssl_mode = Some(SslMode::Require),
+                value => errors.push_error(DatamodelError::new_source_validation_error(
+                    &format!(
+                        "Invalid ssl mode `{}`. Valid modes are `disable`, `prefer` and `require`.",
+                        value
+                    ),
+                    source_name,
+                    arg.span(),
+                )),
+            },
+            Err(err) => errors.push_error(err),
+        }
+    }
+
+    errors.to_result().map(|_| (config, properties))
+}
+
+fn supports_connection_property(provider: &str, property: &str) -> bool {
+    match property {
+        SSL_MODE_KEY => provider == POSTGRES_SOURCE_NAME,
+        STATEMENT_CACHE_SIZE_KEY => provider == POSTGRES_SOURCE_NAME || provider == MYSQL_SOURCE_NAME,
+        POOL_TIMEOUT_KEY | CONNECT_TIMEOUT_KEY => provider != SQLITE_SOURCE_NAME,
+        _ => true,
+    }
+}
+
 fn get_builtin_datasource_providers() -> Vec<Box<dyn DatasourceProvider>> {
     vec![
         Box::new(MySqlDatasourceProvider::new()),
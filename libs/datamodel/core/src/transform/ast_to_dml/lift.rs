@@ -50,6 +50,10 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::CompositeType(ct) => match self.lift_composite_type(&ct, ast_schema) {
+                    Ok(ct) => schema.add_composite_type(ct),
+                    Err(mut err) => errors.append(&mut err),
+                },
                 ast::Top::Source(_) => { /* Source blocks are explicitly ignored by the validator */ }
                 ast::Top::Generator(_) => { /* Generator blocks are explicitly ignored by the validator */ }
                 // TODO: For now, type blocks are never checked on their own.
@@ -89,6 +93,103 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
         Ok(model)
     }
 
+    /// Internal: Validates a composite type AST node and lifts it to a DML composite type.
+    fn lift_composite_type(
+        &self,
+        ast_composite_type: &ast::CompositeType,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeType, Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        // Composite types are stored as Json values in the table of the enclosing model.
+        let supports_json = match self.source {
+            Some(source) => source.combined_connector.supports_json(),
+            None => true,
+        };
+        if !supports_json {
+            errors.push_error(DatamodelError::new_validation_error(
+                &format!(
+                    "You defined the composite type `{}`. But the current connector does not support the Json type composite types are stored in.",
+                    &ast_composite_type.name.name
+                ),
+                ast_composite_type.span,
+            ));
+            return Err(errors);
+        }
+
+        let mut composite_type = dml::CompositeType::new(&ast_composite_type.name.name);
+        composite_type.documentation = ast_composite_type.documentation.clone().map(|comment| comment.text);
+
+        for ast_field in &ast_composite_type.fields {
+            match self.lift_composite_type_field(ast_composite_type, ast_field, ast_schema) {
+                Ok(field) => composite_type.add_field(field),
+                Err(err) => errors.push_error(err),
+            }
+        }
+
+        if ast_composite_type.fields.is_empty() {
+            errors.push_error(DatamodelError::new_validation_error(
+                "A composite type must have at least one field.",
+                ast_composite_type.span,
+            ))
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(composite_type)
+        }
+    }
+
+    /// Internal: Lift a field of a composite type. Composite type fields are plain values,
+    /// they can neither point to models nor carry attributes.
+    fn lift_composite_type_field(
+        &self,
+        ast_composite_type: &ast::CompositeType,
+        ast_field: &ast::Field,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeTypeField, DatamodelError> {
+        if let Some(attribute) = ast_field.attributes.first() {
+            return Err(DatamodelError::new_validation_error(
+                "Fields of composite types do not support attributes.",
+                attribute.span,
+            ));
+        }
+
+        let (field_type, extra_attributes) = self.lift_field_type(&ast_field, None, ast_schema, &mut Vec::new())?;
+
+        if let FieldType::Relation(_) = field_type {
+            return Err(DatamodelError::new_validation_error(
+                "Fields of composite types cannot refer to models.",
+                ast_field.field_type.span,
+            ));
+        }
+
+        if !extra_attributes.is_empty() {
+            return Err(DatamodelError::new_validation_error(
+                "Fields of composite types do not support attributes. The type alias used here defines attributes.",
+                ast_field.field_type.span,
+            ));
+        }
+
+        let arity = self.lift_field_arity(&ast_field.arity);
+
+        if let (FieldType::CompositeType(type_name), dml::FieldArity::List) = (&field_type, arity) {
+            return Err(DatamodelError::new_validation_error(
+                &format!(
+                    "Field `{}` in composite type `{}` can't be a list of the composite type `{}`. Lists of composite types are not supported.",
+                    &ast_field.name.name, &ast_composite_type.name.name, type_name
+                ),
+                ast_field.field_type.span,
+            ));
+        }
+
+        let mut field = dml::CompositeTypeField::new(&ast_field.name.name, arity, field_type);
+        field.documentation = ast_field.documentation.clone().map(|comment| comment.text);
+
+        Ok(field)
+    }
+
     /// Internal: Validates an enum AST node.
     fn lift_enum(&self, ast_enum: &ast::Enum) -> Result<dml::Enum, Diagnostics> {
         let mut errors = Diagnostics::new();
@@ -362,6 +463,8 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
+        } else if ast_schema.find_composite_type(type_name).is_some() {
+            Ok((dml::FieldType::CompositeType(type_name.clone()), vec![]))
         } else {
             self.resolve_custom_type(ast_field, ast_schema, checked_types)
         }
@@ -392,7 +495,7 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
             let (field_type, mut attrs) =
                 self.lift_field_type(custom_type, Some(type_name.to_owned()), ast_schema, checked_types)?;
 
-            if let dml::FieldType::Relation(_) = field_type {
+            if let dml::FieldType::Relation(_) | dml::FieldType::CompositeType(_) = field_type {
                 return Err(DatamodelError::new_validation_error(
                     "Only scalar types can be used for defining custom types.",
                     custom_type.field_type.span,
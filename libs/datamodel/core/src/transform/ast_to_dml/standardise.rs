@@ -4,7 +4,7 @@ use crate::{
     ast,
     common::{NameNormalizer, RelationNames},
     diagnostics::Diagnostics,
-    dml, Field, OnDeleteStrategy, ScalarField, UniqueCriteria,
+    dml, Field, OnDeleteStrategy, OnUpdateStrategy, ScalarField, UniqueCriteria,
 };
 use itertools::Itertools;
 use std::collections::HashMap;
@@ -34,6 +34,18 @@ impl Standardiser {
         Ok(())
     }
 
+    /// Names the relations and adds the missing back relation fields, skipping the other
+    /// consistency fixes. Used for datamodels that did not pass validation.
+    pub fn add_back_relations(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        schema: &mut dml::Datamodel,
+    ) -> Result<(), Diagnostics> {
+        self.name_unnamed_relations(schema);
+
+        self.add_missing_back_relations(ast_schema, schema)
+    }
+
     /// For any relations which are missing references, sets them to the @id fields
     /// of the foreign model.
     /// Also adds missing underlying scalar fields.
@@ -227,6 +239,7 @@ impl Standardiser {
                         references: vec![],
                         name: rel_info.name.clone(),
                         on_delete: OnDeleteStrategy::None,
+                        on_update: OnUpdateStrategy::None,
                     };
                     let mut back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
                     back_relation_field.arity = dml::FieldArity::List;
@@ -239,7 +252,11 @@ impl Standardiser {
                         underlying_fields: vec![],
                     });
                 } else {
-                    let unique_criteria = self.unique_criteria(&model);
+                    // Models without unique criteria only get here when validation failed.
+                    let unique_criteria = match model.loose_unique_criterias().into_iter().next() {
+                        Some(unique_criteria) => unique_criteria,
+                        None => continue,
+                    };
                     let unique_criteria_field_names =
                         unique_criteria.fields.iter().map(|f| f.name.to_owned()).collect();
 
@@ -298,6 +315,7 @@ impl Standardiser {
                         references: unique_criteria_field_names,
                         name: rel_info.name.clone(),
                         on_delete: OnDeleteStrategy::None,
+                        on_update: OnUpdateStrategy::None,
                     };
 
                     let back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
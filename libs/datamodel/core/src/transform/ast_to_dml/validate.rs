@@ -96,6 +96,23 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_sequences(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(err) = self.validate_version_field(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_generated_fields(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_base_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -137,6 +154,10 @@ impl<'a> Validator<'a> {
             all_errors.append(&mut errors_for_enum);
         }
 
+        if let Err(ref mut the_errors) = self.validate_shared_sequences(ast_schema, schema) {
+            all_errors.append(the_errors);
+        }
+
         if all_errors.has_errors() {
             Err(all_errors)
         } else {
@@ -188,6 +209,14 @@ impl<'a> Validator<'a> {
             }
         }
 
+        for composite_type in ast_schema.composite_types() {
+            errors.push_opt_error(composite_type.name.validate("Composite type").err());
+
+            for field in composite_type.fields.iter() {
+                errors.push_opt_error(field.name.validate("Field").err());
+            }
+        }
+
         for enum_decl in ast_schema.enums() {
             errors.push_opt_error(enum_decl.name.validate("Enum").err());
             errors.append(&mut enum_decl.validate_attributes());
@@ -283,6 +312,32 @@ impl<'a> Validator<'a> {
                     ));
                 }
             }
+
+            if let FieldType::CompositeType(type_name) = &field.field_type {
+                let ast_field = ast_model.find_field(&field.name);
+                let is_indexed = field.is_unique
+                    || field.is_id
+                    || model.id_fields.contains(&field.name)
+                    || model.indices.iter().any(|index| index.fields.contains(&field.name));
+
+                if field.is_list() {
+                    errors.push_error(DatamodelError::new_field_validation_error(
+                        &format!("Field `{}` in model `{}` can't be a list of the composite type `{}`. Lists of composite types are not supported.", &field.name, &model.name, type_name),
+                        &model.name,
+                        &field.name,
+                        ast_field.span,
+                    ));
+                }
+
+                if is_indexed {
+                    errors.push_error(DatamodelError::new_field_validation_error(
+                        &format!("Field `{}` in model `{}` is of the composite type `{}` and can't be part of an id, unique constraint or index.", &field.name, &model.name, type_name),
+                        &model.name,
+                        &field.name,
+                        ast_field.span,
+                    ));
+                }
+            }
         }
 
         if errors.has_errors() {
@@ -373,6 +428,118 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_sequences(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        let data_source = match self.source {
+            Some(data_source) if !data_source.combined_connector.supports_sequences() => data_source,
+            _ => return Ok(()),
+        };
+
+        for field in model.scalar_fields() {
+            if sequence_options(field).is_some() {
+                errors.push_error(DatamodelError::new_attribute_validation_error(
+                    &format!(
+                        "The `sequence()` default value is not supported by the provider `{}`.",
+                        data_source.active_provider
+                    ),
+                    "default",
+                    ast_model.find_field(&field.name).span,
+                ));
+            }
+        }
+
+        errors.to_result()
+    }
+
+    /// Fields sharing a named sequence must agree on its options.
+    fn validate_shared_sequences(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        schema: &dml::Datamodel,
+    ) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+        let mut seen: Vec<(String, i64, i64)> = Vec::new();
+
+        for model in schema.models() {
+            for field in model.scalar_fields() {
+                let (name, start, increment) = match sequence_options(field) {
+                    Some(dml::SequenceOptions {
+                        name: Some(name),
+                        start,
+                        increment,
+                    }) => (name, start.unwrap_or(1), increment.unwrap_or(1)),
+                    _ => continue,
+                };
+
+                match seen.iter().find(|(seen_name, _, _)| *seen_name == name) {
+                    Some((_, seen_start, seen_increment)) if (*seen_start, *seen_increment) != (start, increment) => {
+                        let ast_model = ast_schema.find_model(&model.name).expect(STATE_ERROR);
+
+                        errors.push_error(DatamodelError::new_attribute_validation_error(
+                            &format!(
+                                "The sequence `{}` is used with different `start` or `increment` options on other fields.",
+                                name
+                            ),
+                            "default",
+                            ast_model.find_field(&field.name).span,
+                        ));
+                    }
+                    Some(_) => (),
+                    None => seen.push((name, start, increment)),
+                }
+            }
+        }
+
+        errors.to_result()
+    }
+
+    fn validate_version_field(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let version_fields: Vec<_> = model.scalar_fields().filter(|field| field.is_version).collect();
+
+        if version_fields.len() > 1 {
+            return Err(DatamodelError::new_attribute_validation_error(
+                "The `@version` attribute can only be used once per model.",
+                "version",
+                ast_model.span,
+            ));
+        }
+
+        if let Some(field) = version_fields.into_iter().find(|field| field.is_id || field.is_unique) {
+            return Err(DatamodelError::new_attribute_validation_error(
+                "Fields that are marked with @version can not be unique.",
+                "version",
+                ast_model.find_field(&field.name).span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn validate_generated_fields(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        for field in model.scalar_fields().filter(|field| field.generated_as.is_some()) {
+            let message = if field.default_value.is_some() {
+                "Fields that are marked with @generated can not have a default value."
+            } else if field.is_id || model.id_fields.contains(&field.name) {
+                "Fields that are marked with @generated can not be part of the id."
+            } else if field.is_updated_at || field.is_version {
+                "Fields that are marked with @generated can not be marked with @updatedAt or @version."
+            } else {
+                continue;
+            };
+
+            errors.push_error(DatamodelError::new_attribute_validation_error(
+                message,
+                "generated",
+                ast_model.find_field(&field.name).span,
+            ));
+        }
+
+        errors.to_result()
+    }
+
     fn validate_model_has_strict_unique_criteria(
         &self,
         ast_model: &ast::Model,
@@ -993,7 +1160,7 @@ impl<'a> Validator<'a> {
                         if rel_a.to == rel_b.to && rel_a.name == rel_b.name {
                             if rel_a.name == "" {
                                 // unnamed relation
-                                return Err(DatamodelError::new_model_validation_error(
+                                return Err(DatamodelError::new_ambiguous_relation_error(
                                             &format!(
                                                 "Ambiguous relation detected. The fields `{}` and `{}` in model `{}` both refer to `{}`. Please provide different relation names for them by adding `@relation(<name>).",
                                                 &field_a.name,
@@ -1002,6 +1169,7 @@ impl<'a> Validator<'a> {
                                                 &rel_a.to
                                             ),
                                             &model.name,
+                                            &[&field_a.name, &field_b.name],
                                             ast_schema
                                                 .find_field(&model.name, &field_a.name)
                                                 .expect(STATE_ERROR)
@@ -1009,7 +1177,7 @@ impl<'a> Validator<'a> {
                                         ));
                             } else {
                                 // explicitly named relation
-                                return Err(DatamodelError::new_model_validation_error(
+                                return Err(DatamodelError::new_ambiguous_relation_error(
                                             &format!(
                                                 "Wrongly named relation detected. The fields `{}` and `{}` in model `{}` both use the same relation name. Please provide different relation names for them through `@relation(<name>).",
                                                 &field_a.name,
@@ -1017,6 +1185,7 @@ impl<'a> Validator<'a> {
                                                 &model.name,
                                             ),
                                             &model.name,
+                                            &[&field_a.name, &field_b.name],
                                             ast_schema
                                                 .find_field(&model.name, &field_a.name)
                                                 .expect(STATE_ERROR)
@@ -1034,7 +1203,7 @@ impl<'a> Validator<'a> {
                                 if rel_c.to == model.name && rel_a.name == rel_b.name && rel_a.name == rel_c.name {
                                     if rel_a.name == "" {
                                         // unnamed relation
-                                        return Err(DatamodelError::new_model_validation_error(
+                                        return Err(DatamodelError::new_ambiguous_relation_error(
                                                         &format!(
                                                             "Unnamed self relation detected. The fields `{}`, `{}` and `{}` in model `{}` have no relation name. Please provide a relation name for one of them by adding `@relation(<name>).",
                                                             &field_a.name,
@@ -1043,13 +1212,14 @@ impl<'a> Validator<'a> {
                                                             &model.name
                                                         ),
                                                         &model.name,
+                                                        &[&field_a.name, &field_b.name, &field_c.name],
                                                         ast_schema
                                                             .find_field(&model.name, &field_a.name)
                                                             .expect(STATE_ERROR)
                                                             .span,
                                                     ));
                                     } else {
-                                        return Err(DatamodelError::new_model_validation_error(
+                                        return Err(DatamodelError::new_ambiguous_relation_error(
                                                         &format!(
                                                         "Wrongly named self relation detected. The fields `{}`, `{}` and `{}` in model `{}` have the same relation name. At most two relation fields can belong to the same relation and therefore have the same name. Please assign a different relation name to one of them.",
                                                             &field_a.name,
@@ -1058,6 +1228,7 @@ impl<'a> Validator<'a> {
                                                             &model.name
                                                         ),
                                                         &model.name,
+                                                        &[&field_a.name, &field_b.name, &field_c.name],
                                                         ast_schema
                                                             .find_field(&model.name, &field_a.name)
                                                             .expect(STATE_ERROR)
@@ -1071,7 +1242,7 @@ impl<'a> Validator<'a> {
                         // Ambiguous unnamed self relation: two fields are enough.
                         if rel_a.name.is_empty() && rel_b.name.is_empty() {
                             // A self relation, but there are at least two fields without a name.
-                            return Err(DatamodelError::new_model_validation_error(
+                            return Err(DatamodelError::new_ambiguous_relation_error(
                                         &format!(
                                             "Ambiguous self relation detected. The fields `{}` and `{}` in model `{}` both refer to `{}`. If they are part of the same relation add the same relation name for them with `@relation(<name>)`.",
                                             &field_a.name,
@@ -1080,6 +1251,7 @@ impl<'a> Validator<'a> {
                                             &rel_a.to
                                         ),
                                         &model.name,
+                                        &[&field_a.name, &field_b.name],
                                         ast_schema
                                             .find_field(&model.name, &field_a.name)
                                             .expect(STATE_ERROR)
@@ -1094,3 +1266,10 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 }
+
+fn sequence_options(field: &dml::ScalarField) -> Option<dml::SequenceOptions> {
+    match &field.default_value {
+        Some(dml::DefaultValue::Expression(generator)) => generator.sequence_options(),
+        _ => None,
+    }
+}
@@ -98,4 +98,15 @@ impl<'a, 'b> ValidationPipeline<'a, 'b> {
         // Phase 3: Lift AST to DML.
         self.lifter.lift(ast_schema)
     }
+
+    /// Lifts the AST and adds the back relation fields the standardiser generates, without
+    /// validating it. Back relation fields that would conflict with existing fields are left out.
+    pub fn lift_with_back_relations(&self, ast_schema: &ast::SchemaAst) -> Result<Datamodel, Diagnostics> {
+        let mut schema = self.lift_without_validation(ast_schema)?;
+
+        // The conflicts are reported by `validate`.
+        self.standardiser.add_back_relations(ast_schema, &mut schema).ok();
+
+        Ok(schema)
+    }
 }
@@ -1,6 +1,6 @@
 use super::{super::helpers::*, AttributeValidator};
 use crate::diagnostics::DatamodelError;
-use crate::{ast, dml, ValueGenerator};
+use crate::{ast, dml, ValueGeneratorFn};
 use prisma_value::PrismaValue;
 
 /// Prismas builtin `@default` attribute.
@@ -20,6 +20,11 @@ impl AttributeValidator<dml::Field> for DefaultAttributeValidator {
                 return self.new_attribute_validation_error("Cannot set a default value on list field.", args.span());
             }
 
+            if let dml::FieldType::CompositeType(_) = sf.field_type {
+                return self
+                    .new_attribute_validation_error("Cannot set a default value on a composite field.", args.span());
+            }
+
             if let dml::FieldType::Base(scalar_type, _) = sf.field_type {
                 let dv = args
                     .default_arg("value")?
@@ -41,7 +46,7 @@ impl AttributeValidator<dml::Field> for DefaultAttributeValidator {
                     Ok(value) => sf.default_value = Some(dml::DefaultValue::Single(PrismaValue::Enum(value))),
                     Err(err) => {
                         let generator = default_arg.as_value_generator()?;
-                        if generator == ValueGenerator::new_dbgenerated() {
+                        if generator.generator == ValueGeneratorFn::DbGenerated {
                             sf.default_value = Some(dml::DefaultValue::Expression(generator));
                         } else {
                             return Err(self.wrap_in_attribute_validation_error(&err));
@@ -68,9 +73,25 @@ impl AttributeValidator<dml::Field> for DefaultAttributeValidator {
 pub fn lower_default_value(dv: dml::DefaultValue) -> ast::Expression {
     match dv {
         dml::DefaultValue::Single(v) => lower_prisma_value(&v),
+        dml::DefaultValue::Expression(e) if e.generator == ValueGeneratorFn::Sequence => {
+            // Sequence options are always rendered as named arguments, leaving out the unset ones.
+            let args = ["name", "start", "increment"]
+                .iter()
+                .zip(e.args.iter())
+                .filter(|(_, value)| **value != PrismaValue::Null)
+                .map(|(name, value)| ast::Argument::new(name, lower_prisma_value(value)))
+                .collect();
+
+            ast::Expression::Function(e.name, args, ast::Span::empty())
+        }
         dml::DefaultValue::Expression(e) => {
-            let exprs = e.args.iter().map(lower_prisma_value).collect();
-            ast::Expression::Function(e.name, exprs, ast::Span::empty())
+            let args = e
+                .args
+                .iter()
+                .map(|value| ast::Argument::new_unnamed(lower_prisma_value(value)))
+                .collect();
+
+            ast::Expression::Function(e.name, args, ast::Span::empty())
         }
     }
 }
@@ -0,0 +1,57 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@generated` attribute.
+pub struct GeneratedAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for GeneratedAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"generated"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let expression = args.default_arg("expression")?.as_str()?;
+        let stored = match args.optional_arg("stored") {
+            Some(stored) => stored.as_bool()?,
+            None => false,
+        };
+
+        match obj {
+            dml::Field::ScalarField(sf) if sf.arity == dml::FieldArity::List => self.new_attribute_validation_error(
+                "Fields that are marked with @generated can not be lists.",
+                args.span(),
+            ),
+            dml::Field::ScalarField(sf) => {
+                sf.generated_as = Some(dml::GeneratedColumn { expression, stored });
+
+                Ok(())
+            }
+            dml::Field::RelationField(_) => self.new_attribute_validation_error(
+                "Fields that are marked with @generated can not be relation fields.",
+                args.span(),
+            ),
+        }
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        let generated = match field.generated_as() {
+            Some(generated) => generated,
+            None => return vec![],
+        };
+
+        let mut args = vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+            generated.expression.clone(),
+            ast::Span::empty(),
+        ))];
+
+        if generated.stored {
+            args.push(ast::Argument::new(
+                "stored",
+                ast::Expression::BooleanValue(String::from("true"), ast::Span::empty()),
+            ));
+        }
+
+        vec![ast::Attribute::new(self.attribute_name(), args)]
+    }
+}
@@ -32,10 +32,59 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 rf.relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
-            // TODO: bring `onDelete` back once `prisma migrate` is a thing
-            //            if let Ok(on_delete) = args.arg("onDelete") {
-            //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
-            //            }
+            if let Ok(on_delete) = args.arg("onDelete") {
+                rf.relation_info.on_delete = match on_delete.as_constant_literal()?.as_str() {
+                    "CASCADE" => dml::OnDeleteStrategy::Cascade,
+                    "SET_NULL" => dml::OnDeleteStrategy::SetNull,
+                    "NONE" => dml::OnDeleteStrategy::None,
+                    action => {
+                        return self.new_attribute_validation_error(
+                            &format!(
+                                "Invalid referential action `{}`. Valid actions are `CASCADE`, `SET_NULL` and `NONE`.",
+                                action
+                            ),
+                            on_delete.span(),
+                        )
+                    }
+                };
+            }
+
+            if let Ok(on_update) = args.arg("onUpdate") {
+                rf.relation_info.on_update = match on_update.as_constant_literal()?.as_str() {
+                    "CASCADE" => dml::OnUpdateStrategy::Cascade,
+                    "SET_NULL" => dml::OnUpdateStrategy::SetNull,
+                    "NONE" => dml::OnUpdateStrategy::None,
+                    action => {
+                        return self.new_attribute_validation_error(
+                            &format!(
+                                "Invalid referential action `{}`. Valid actions are `CASCADE`, `SET_NULL` and `NONE`.",
+                                action
+                            ),
+                            on_update.span(),
+                        )
+                    }
+                };
+            }
+
+            let has_actions = rf.relation_info.on_delete != dml::OnDeleteStrategy::None
+                || rf.relation_info.on_update != dml::OnUpdateStrategy::None;
+
+            if has_actions && rf.relation_info.fields.is_empty() {
+                return self.new_attribute_validation_error(
+                    "Referential actions can only be defined on the side of the relation that defines `fields`.",
+                    args.span(),
+                );
+            }
+
+            let sets_null = rf.relation_info.on_delete == dml::OnDeleteStrategy::SetNull
+                || rf.relation_info.on_update == dml::OnUpdateStrategy::SetNull;
+
+            if sets_null && rf.arity.is_required() {
+                return self.new_attribute_validation_error(
+                    "The referential action `SET_NULL` can't be used on a required relation field.",
+                    args.span(),
+                );
+            }
 
             Ok(())
         } else {
@@ -107,6 +156,13 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 ));
             }
 
+            if relation_info.on_update != dml::OnUpdateStrategy::None {
+                args.push(ast::Argument::new_constant(
+                    "onUpdate",
+                    &relation_info.on_update.to_string(),
+                ));
+            }
+
             if !args.is_empty() {
                 return vec![ast::Attribute::new(self.attribute_name(), args)];
             }
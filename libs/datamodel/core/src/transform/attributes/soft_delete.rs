@@ -0,0 +1,57 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@@softDelete` attribute.
+pub struct SoftDeleteAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for SoftDeleteAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"softDelete"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let field_name = args.arg("field")?.as_constant_literal()?;
+
+        let field = match obj.find_scalar_field(&field_name) {
+            Some(field) => field,
+            None => {
+                return self.new_attribute_validation_error(
+                    &format!(
+                        "The soft delete field `{}` is not a scalar field of the model.",
+                        field_name
+                    ),
+                    args.span(),
+                )
+            }
+        };
+
+        if field.field_type.scalar_type() != Some(dml::ScalarType::DateTime) || field.arity != dml::FieldArity::Optional
+        {
+            return self.new_attribute_validation_error(
+                &format!(
+                    "The soft delete field `{}` must be an optional field of type DateTime.",
+                    field_name
+                ),
+                args.span(),
+            );
+        }
+
+        obj.soft_delete_field = Some(field_name);
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        match &model.soft_delete_field {
+            Some(field_name) => vec![ast::Attribute::new(
+                self.attribute_name(),
+                vec![ast::Argument::new(
+                    "field",
+                    ast::Expression::ConstantValue(field_name.clone(), ast::Span::empty()),
+                )],
+            )],
+            None => vec![],
+        }
+    }
+}
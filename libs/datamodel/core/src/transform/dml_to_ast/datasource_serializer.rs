@@ -1,5 +1,8 @@
 use crate::ast;
-use crate::configuration::Datasource;
+use crate::common::connection_properties::{
+    CONNECTION_LIMIT_KEY, CONNECT_TIMEOUT_KEY, POOL_TIMEOUT_KEY, SSL_MODE_KEY, STATEMENT_CACHE_SIZE_KEY,
+};
+use crate::configuration::{Datasource, ReferentialIntegrity};
 
 pub struct DatasourceSerializer {}
 
@@ -41,6 +44,32 @@ impl DatasourceSerializer {
             arguments.push(ast::Argument::new_array("previewFeatures", features));
         }
 
+        if source.referential_integrity != ReferentialIntegrity::default() {
+            arguments.push(ast::Argument::new_string(
+                "referentialIntegrity",
+                &source.referential_integrity.to_string(),
+            ));
+        }
+
+        let connection = &source.connection;
+        let numeric_properties = vec![
+            (CONNECTION_LIMIT_KEY, connection.connection_limit),
+            (POOL_TIMEOUT_KEY, connection.pool_timeout),
+            (CONNECT_TIMEOUT_KEY, connection.connect_timeout),
+            (STATEMENT_CACHE_SIZE_KEY, connection.statement_cache_size),
+        ];
+
+        for (name, value) in numeric_properties {
+            if let Some(value) = value {
+                let value = ast::Expression::NumericValue(value.to_string(), ast::Span::empty());
+                arguments.push(ast::Argument::new(name, value));
+            }
+        }
+
+        if let Some(ssl_mode) = connection.ssl_mode {
+            arguments.push(ast::Argument::new_string(SSL_MODE_KEY, &ssl_mode.to_string()));
+        }
+
         ast::SourceConfig {
             name: ast::Identifier::new(&source.name),
             properties: arguments,
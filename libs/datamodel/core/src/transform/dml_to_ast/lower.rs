@@ -29,6 +29,10 @@ impl<'a> LowerDmlToAst<'a> {
             }
         }
 
+        for composite_type in datamodel.composite_types() {
+            tops.push(ast::Top::CompositeType(self.lower_composite_type(composite_type)))
+        }
+
         for enm in datamodel.enums() {
             tops.push(ast::Top::Enum(self.lower_enum(enm, datamodel)))
         }
@@ -53,6 +57,26 @@ impl<'a> LowerDmlToAst<'a> {
         }
     }
 
+    fn lower_composite_type(&self, composite_type: &dml::CompositeType) -> ast::CompositeType {
+        ast::CompositeType {
+            name: ast::Identifier::new(&composite_type.name),
+            fields: composite_type
+                .fields()
+                .map(|field| ast::Field {
+                    name: ast::Identifier::new(&field.name),
+                    arity: self.lower_field_arity(&field.arity),
+                    attributes: vec![],
+                    field_type: self.lower_type(&field.field_type),
+                    documentation: field.documentation.clone().map(|text| ast::Comment { text }),
+                    span: ast::Span::empty(),
+                    is_commented_out: false,
+                })
+                .collect(),
+            documentation: composite_type.documentation.clone().map(|text| ast::Comment { text }),
+            span: ast::Span::empty(),
+        }
+    }
+
     fn lower_enum(&self, enm: &dml::Enum, datamodel: &dml::Datamodel) -> ast::Enum {
         ast::Enum {
             name: ast::Identifier::new(&enm.name),
@@ -120,6 +144,7 @@ impl<'a> LowerDmlToAst<'a> {
                 ast::Identifier::new(&custom_type_name.as_ref().unwrap_or(&tpe.to_string()))
             }
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::CompositeType(tpe) => ast::Identifier::new(&tpe),
             dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Relation(rel) => ast::Identifier::new(&rel.to),
             dml::FieldType::NativeType(prisma_tpe, _native_tpe) => ast::Identifier::new(&prisma_tpe.to_string()),
@@ -25,14 +25,14 @@ impl EnvFunction {
             ));
         };
 
-        if args.len() != 1 {
+        if args.len() != 1 || !args[0].is_unnamed() {
             return Err(DatamodelError::new_functional_evaluation_error(
                 "Exactly one string parameter must be passed to the env function.",
                 expr.span(),
             ));
         }
 
-        let var_wrapped = &args[0];
+        let var_wrapped = &args[0].value;
         let var_name = ValueValidator::new(var_wrapped).as_str()?;
         Ok(Self {
             var_name,
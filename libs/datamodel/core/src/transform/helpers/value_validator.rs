@@ -191,8 +191,8 @@ impl ValueValidator {
 
     pub fn as_default_value_for_scalar_type(&self, scalar_type: ScalarType) -> Result<DefaultValue, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => {
-                let generator = self.get_value_generator(&name)?;
+            ast::Expression::Function(name, args, _) => {
+                let generator = self.get_value_generator(&name, &args)?;
                 generator
                     .check_compatibility_with_scalar_type(scalar_type)
                     .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))?;
@@ -207,15 +207,75 @@ impl ValueValidator {
 
     pub fn as_value_generator(&self) -> Result<ValueGenerator, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => self.get_value_generator(&name),
+            ast::Expression::Function(name, args, _) => self.get_value_generator(&name, &args),
             _ => Err(self.construct_type_mismatch_error("function")),
         }
     }
 
-    fn get_value_generator(&self, name: &str) -> Result<ValueGenerator, DatamodelError> {
-        ValueGenerator::new(name.to_string(), vec![])
+    fn get_value_generator(&self, name: &str, args: &[ast::Argument]) -> Result<ValueGenerator, DatamodelError> {
+        let args = if name == "sequence" {
+            Self::sequence_arguments(args)?
+        } else {
+            if let Some(arg) = args.iter().find(|arg| !arg.is_unnamed()) {
+                return Err(DatamodelError::new_functional_evaluation_error(
+                    &format!("The function `{}()` does not take named arguments.", name),
+                    arg.span,
+                ));
+            }
+
+            args.iter()
+                .map(|arg| Self::function_argument_value(name, &arg.value))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        ValueGenerator::new(name.to_string(), args)
             .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))
     }
+
+    /// Resolves the arguments of `sequence()` into the positional form
+    /// `[name, start, increment]`, with nulls for the ones not given.
+    fn sequence_arguments(args: &[ast::Argument]) -> Result<Vec<PrismaValue>, DatamodelError> {
+        const PARAMETERS: &[&str] = &["name", "start", "increment"];
+
+        let mut values = vec![PrismaValue::Null; PARAMETERS.len()];
+
+        for arg in args {
+            let position =
+                match PARAMETERS.iter().position(|param| *param == arg.name.name) {
+                    Some(position) => position,
+                    None => return Err(DatamodelError::new_functional_evaluation_error(
+                        "The function `sequence()` only accepts the named arguments `name`, `start` and `increment`.",
+                        arg.span,
+                    )),
+                };
+
+            if values[position] != PrismaValue::Null {
+                return Err(DatamodelError::new_functional_evaluation_error(
+                    &format!("The argument `{}` is specified more than once.", arg.name.name),
+                    arg.span,
+                ));
+            }
+
+            values[position] = Self::function_argument_value("sequence", &arg.value)?;
+        }
+
+        Ok(values)
+    }
+
+    fn function_argument_value(name: &str, arg: &ast::Expression) -> Result<PrismaValue, DatamodelError> {
+        match arg {
+            ast::Expression::StringValue(value, _) => Ok(PrismaValue::String(value.clone())),
+            ast::Expression::NumericValue(_, _) => ValueValidator::new(arg).as_int().map(PrismaValue::Int),
+            _ => Err(DatamodelError::new_functional_evaluation_error(
+                &format!(
+                    "The function `{}()` only accepts string and integer arguments, but received {}.",
+                    name,
+                    arg.describe_value_type()
+                ),
+                arg.span(),
+            )),
+        }
+    }
 }
 
 pub trait ValueListValidator {
@@ -2,7 +2,8 @@
 //! The most prominent functionality is the pain free navigation of relations.
 use crate::{
     dml::{
-        Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model, ScalarField, WithDatabaseName,
+        Datamodel, DefaultValue, Enum, FieldArity, FieldType, GeneratedColumn, IndexDefinition, Model,
+        OnDeleteStrategy, OnUpdateStrategy, ScalarField, WithDatabaseName,
     },
     NativeTypeInstance, RelationField,
 };
@@ -25,6 +26,11 @@ pub fn walk_scalar_fields<'a>(datamodel: &'a Datamodel) -> impl Iterator<Item =
     })
 }
 
+/// Iterator over all the enums in the schema.
+pub fn walk_enums<'a>(datamodel: &'a Datamodel) -> impl Iterator<Item = EnumWalker<'a>> + 'a {
+    datamodel.enums().map(move |r#enum| EnumWalker { datamodel, r#enum })
+}
+
 /// Iterator over all the relations in the schema. Each relation will only occur
 /// once.
 pub fn walk_relations(datamodel: &Datamodel) -> impl Iterator<Item = RelationWalker<'_>> {
@@ -68,6 +74,10 @@ impl<'a> ModelWalker<'a> {
         self.model.final_database_name()
     }
 
+    pub fn documentation(&self) -> Option<&'a str> {
+        self.model.documentation.as_deref()
+    }
+
     pub fn into_relation_fields(self) -> impl Iterator<Item = RelationFieldWalker<'a>> + 'a {
         self.model.relation_fields().map(move |field| RelationFieldWalker {
             datamodel: self.datamodel,
@@ -160,6 +170,14 @@ impl<'a> ScalarFieldWalker<'a> {
         self.field.default_value.as_ref()
     }
 
+    pub fn documentation(&self) -> Option<&'a str> {
+        self.field.documentation.as_deref()
+    }
+
+    pub fn generated_as(&self) -> Option<&'a GeneratedColumn> {
+        self.field.generated_as.as_ref()
+    }
+
     pub fn field_type(&self) -> TypeWalker<'a> {
         match &self.field.field_type {
             FieldType::Enum(name) => TypeWalker::Enum(EnumWalker {
@@ -168,6 +186,8 @@ impl<'a> ScalarFieldWalker<'a> {
             }),
             FieldType::Base(scalar_type, _) => TypeWalker::Base(*scalar_type),
             FieldType::NativeType(scalar_type, native_type) => TypeWalker::NativeType(*scalar_type, native_type),
+            // Composite types are stored as Json values.
+            FieldType::CompositeType(_) => TypeWalker::Base(ScalarType::Json),
             _ => TypeWalker::Other,
         }
     }
@@ -184,6 +204,10 @@ impl<'a> ScalarFieldWalker<'a> {
         self.field.is_unique
     }
 
+    pub fn is_updated_at(&self) -> bool {
+        self.field.is_updated_at
+    }
+
     pub fn model(&self) -> ModelWalker<'a> {
         ModelWalker {
             model: self.model,
@@ -212,6 +236,13 @@ impl<'a> TypeWalker<'a> {
         }
     }
 
+    pub fn as_scalar_type(&self) -> Option<ScalarType> {
+        match self {
+            TypeWalker::Base(scalar_type) | TypeWalker::NativeType(scalar_type, _) => Some(*scalar_type),
+            _ => None,
+        }
+    }
+
     pub fn is_int(&self) -> bool {
         matches!(self, TypeWalker::Base(ScalarType::Int))
     }
@@ -248,6 +279,18 @@ impl<'a> RelationFieldWalker<'a> {
         }
     }
 
+    pub fn name(&self) -> &'a str {
+        &self.field.name
+    }
+
+    pub fn on_delete(&self) -> OnDeleteStrategy {
+        self.field.relation_info.on_delete
+    }
+
+    pub fn on_update(&self) -> OnUpdateStrategy {
+        self.field.relation_info.on_update
+    }
+
     pub fn opposite_side(&self) -> RelationFieldWalker<'a> {
         RelationFieldWalker {
             datamodel: self.datamodel,
@@ -256,6 +299,30 @@ impl<'a> RelationFieldWalker<'a> {
         }
     }
 
+    /// The scalar fields of the enclosing model holding the foreign key of the relation.
+    pub fn referencing_fields<'b>(&'b self) -> impl Iterator<Item = ScalarFieldWalker<'a>> + 'b {
+        self.field.relation_info.fields.iter().filter_map(move |field| {
+            self.model
+                .find_scalar_field(field.as_str())
+                .map(|field| ScalarFieldWalker {
+                    datamodel: self.datamodel,
+                    model: self.model,
+                    field,
+                })
+        })
+    }
+
+    /// The scalar fields of the referenced model the relation points to.
+    pub fn referenced_fields<'b>(&'b self) -> impl Iterator<Item = ScalarFieldWalker<'a>> + 'b {
+        let model = self.referenced_model();
+
+        self.field
+            .relation_info
+            .references
+            .iter()
+            .filter_map(move |field| model.find_scalar_field(field.as_str()))
+    }
+
     pub fn referencing_columns<'b>(&'b self) -> impl Iterator<Item = &'a str> + 'b {
         self
             .field
@@ -315,6 +382,10 @@ impl<'a> EnumWalker<'a> {
     pub fn db_name(&self) -> &'a str {
         self.r#enum.final_database_name()
     }
+
+    pub fn name(&self) -> &'a str {
+        &self.r#enum.name
+    }
 }
 
 #[derive(Debug)]
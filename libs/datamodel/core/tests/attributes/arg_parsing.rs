@@ -1,5 +1,8 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+};
 
 #[test]
 fn fail_on_duplicate_attribute() {
@@ -12,7 +15,10 @@ fn fail_on_duplicate_attribute() {
 
     let errors = parse_error(dml);
 
-    errors.assert_is(DatamodelError::new_duplicate_argument_error("name", Span::new(87, 104)));
+    errors.assert_is(DatamodelError::new_duplicate_argument_error(
+        "name",
+        Span::new(87, 104, FileId(0)),
+    ));
 }
 
 #[test]
@@ -28,7 +34,7 @@ fn fail_on_duplicate_unnamed_attribute() {
 
     errors.assert_is(DatamodelError::new_duplicate_default_argument_error(
         "name",
-        Span::new(81, 98),
+        Span::new(81, 98, FileId(0)),
     ));
 }
 
@@ -43,5 +49,8 @@ fn fail_on_extra_argument() {
 
     let errors = parse_error(dml);
 
-    errors.assert_is(DatamodelError::new_unused_argument_error("unused", Span::new(81, 98)));
+    errors.assert_is(DatamodelError::new_unused_argument_error(
+        "unused",
+        Span::new(81, 98, FileId(0)),
+    ));
 }
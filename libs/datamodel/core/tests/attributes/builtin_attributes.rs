@@ -37,10 +37,10 @@ fn duplicate_attributes_should_error() {
 
     error.assert_is_at(
         0,
-        DatamodelError::new_duplicate_attribute_error("unique", ast::Span::new(75, 81)),
+        DatamodelError::new_duplicate_attribute_error("unique", ast::Span::new(75, 81, ast::FileId(0))),
     );
     error.assert_is_at(
         1,
-        DatamodelError::new_duplicate_attribute_error("unique", ast::Span::new(83, 89)),
+        DatamodelError::new_duplicate_attribute_error("unique", ast::Span::new(83, 89, ast::FileId(0))),
     );
 }
@@ -1,5 +1,8 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+};
 
 #[test]
 fn must_error_if_default_value_for_relation_field() {
@@ -19,7 +22,7 @@ fn must_error_if_default_value_for_relation_field() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "Cannot set a default value on a relation field.",
         "default",
-        Span::new(53, 64),
+        Span::new(53, 64, FileId(0)),
     ));
 }
 
@@ -37,7 +40,7 @@ fn must_error_if_default_value_for_list() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "Cannot set a default value on list field.",
         "default",
-        Span::new(60, 78),
+        Span::new(60, 78, FileId(0)),
     ));
 }
 
@@ -55,7 +58,7 @@ fn must_error_if_default_value_type_missmatch() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "Expected a String value, but received numeric value \"3\".",
         "default",
-        Span::new(66, 67),
+        Span::new(66, 67, FileId(0)),
     ));
 }
 
@@ -73,7 +76,7 @@ fn must_error_if_default_value_parser_error() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "Expected a datetime value, but failed while parsing \"Hugo\": input contains invalid characters.",
         "default",
-        Span::new(68, 74),
+        Span::new(68, 74, FileId(0)),
     ));
 }
 
@@ -91,10 +94,47 @@ fn must_error_if_unknown_function_is_used() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The function unknown_function is not a known function.",
         "default",
-        Span::new(68, 86),
+        Span::new(68, 86, FileId(0)),
     ));
 }
 
+#[test]
+fn must_error_if_generator_function_arguments_are_invalid() {
+    let dml = r#"
+    model Model {
+        id  String @id @default(nanoid(1))
+        a   String @default(uuid(5))
+        b   String @default(cuid(8))
+        c   String @default(dbgenerated(""))
+        d   String @default(dbgenerated(true))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(5);
+    errors.assert_is_message_at(
+        0,
+        "Error parsing attribute \"@default\": The function `nanoid()` only accepts a size between `2` and `255`.",
+    );
+    errors.assert_is_message_at(
+        1,
+        "Error parsing attribute \"@default\": The function `uuid()` only accepts the versions `4` and `7`.",
+    );
+    errors.assert_is_message_at(
+        2,
+        "Error parsing attribute \"@default\": The function `cuid()` does not take any arguments.",
+    );
+    errors.assert_is_message_at(
+        3,
+        "Error parsing attribute \"@default\": The function `dbgenerated()` only accepts a single non-empty string containing an SQL expression.",
+    );
+    errors.assert_is_message_at(
+        4,
+        "Error parsing attribute \"@default\": The function `dbgenerated()` only accepts string and integer arguments, but received boolean.",
+    );
+}
+
 #[test]
 fn must_error_if_now_function_is_used_for_fields_that_are_not_datetime() {
     let dml = r#"
@@ -109,7 +149,7 @@ fn must_error_if_now_function_is_used_for_fields_that_are_not_datetime() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The function `now()` can not be used on fields of type `String`.",
         "default",
-        Span::new(70, 75),
+        Span::new(70, 75, FileId(0)),
     ));
 }
 
@@ -127,7 +167,7 @@ fn must_error_if_autoincrement_function_is_used_for_fields_that_are_not_int() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The function `autoincrement()` can not be used on fields of type `String`.",
         "default",
-        Span::new(70, 85),
+        Span::new(70, 85, FileId(0)),
     ));
 }
 
@@ -149,7 +189,7 @@ fn must_error_if_default_value_for_enum_is_not_valid() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The defined default value is not a valid value of the enum specified for the field.",
         "default",
-        Span::new(46, 65),
+        Span::new(46, 65, FileId(0)),
     ));
 }
 
@@ -172,7 +212,7 @@ fn must_error_if_using_non_id_auto_increment_on_sqlite() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The `autoincrement()` default value is used on a non-id field even though the datasource does not support this.",
         "default",
-        Span::new(142, 188),
+        Span::new(142, 188, FileId(0)),
     ));
 }
 
@@ -196,7 +236,7 @@ fn must_error_if_using_multiple_auto_increment_on_mysql() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The `autoincrement()` default value is used multiple times on this model even though the underlying datasource only supports one instance per table.",
         "default",
-        Span::new(89, 241),
+        Span::new(89, 241, FileId(0)),
     ));
 }
 
@@ -219,6 +259,6 @@ fn must_error_if_using_non_indexed_auto_increment_on_mysql() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The `autoincrement()` default value is used on a non-indexed field even though the datasource does not support this.",
         "default",
-        Span::new(135, 173),
+        Span::new(135, 173, FileId(0)),
     ));
 }
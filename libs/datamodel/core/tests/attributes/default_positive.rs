@@ -117,3 +117,75 @@ fn db_generated_function_must_work_for_enum_fields() {
         .assert_enum_type("Role")
         .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated()));
 }
+
+#[test]
+fn db_generated_function_must_keep_its_expression() {
+    let dml = r#"
+    model Model {
+        id        String   @id @default(dbgenerated("gen_random_uuid()"))
+        expiresAt DateTime @default(dbgenerated("now() + interval '1 day'"))
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("id")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new_dbgenerated_with_expression("gen_random_uuid()".to_owned()),
+        ));
+    user_model
+        .assert_has_scalar_field("expiresAt")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new_dbgenerated_with_expression("now() + interval '1 day'".to_owned()),
+        ));
+}
+
+#[test]
+fn should_set_default_for_id_generator_functions() {
+    let dml = r#"
+    model Model {
+        id         String @id @default(nanoid())
+        shortId    String @default(nanoid(10))
+        sortableId String @default(ulid())
+        uuidV4     String @default(uuid(4))
+        uuidV7     String @default(uuid(7))
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("id")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_nanoid(None)));
+    user_model
+        .assert_has_scalar_field("shortId")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_nanoid(Some(10))));
+    user_model
+        .assert_has_scalar_field("sortableId")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_ulid()));
+    user_model
+        .assert_has_scalar_field("uuidV4")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(4)]).unwrap(),
+        ));
+    user_model
+        .assert_has_scalar_field("uuidV7")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(7)]).unwrap(),
+        ));
+}
+
+#[test]
+fn id_generator_functions_must_generate_values() {
+    let nanoid = ValueGenerator::new_nanoid(Some(10)).generate();
+    assert!(matches!(nanoid, Some(PrismaValue::String(ref id)) if id.len() == 10));
+
+    let ulid = ValueGenerator::new_ulid().generate();
+    assert!(matches!(ulid, Some(PrismaValue::String(ref id)) if id.len() == 26));
+
+    let uuid_v7 = ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(7)])
+        .unwrap()
+        .generate();
+    assert!(matches!(uuid_v7, Some(PrismaValue::Uuid(ref id)) if id.get_version_num() == 7));
+}
@@ -0,0 +1,111 @@
+use crate::common::*;
+
+#[test]
+fn should_fail_on_list_fields() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgres://"
+    }
+
+    model Post {
+        id Int @id
+        tags String[] @generated("ARRAY['a']", stored: true)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@generated\": Fields that are marked with @generated can not be lists.",
+    );
+}
+
+#[test]
+fn should_fail_if_the_expression_is_missing() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        length Int @generated(stored: true)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Argument \"expression\" is missing in attribute \"@generated\".");
+}
+
+#[test]
+fn should_fail_on_fields_with_a_default_value() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        length Int @default(1) @generated("1 + 1")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@generated\": Fields that are marked with @generated can not have a default value.",
+    );
+}
+
+#[test]
+fn should_fail_on_id_fields() {
+    let dml = r#"
+    model Post {
+        id Int @id @generated("1 + 1")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@generated\": Fields that are marked with @generated can not be part of the id.",
+    );
+}
+
+#[test]
+fn should_fail_on_updated_at_fields() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        updatedAt DateTime @updatedAt @generated("now()", stored: true)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@generated\": Fields that are marked with @generated can not be marked with @updatedAt or @version.",
+    );
+}
+
+#[test]
+fn should_fail_on_virtual_generated_columns_on_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgres://"
+    }
+
+    model Post {
+        id Int @id
+        length Int @generated("id * 2")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Generated columns must be stored on Postgres. Add `stored: true` to the @generated attribute.",
+    );
+}
+
+#[test]
+fn should_fail_on_required_virtual_generated_columns_on_sql_server() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlserver"
+        url = "sqlserver://localhost:1433"
+    }
+
+    model Post {
+        id Int @id
+        length Int @generated("id * 2")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Generated columns that are not stored must be optional on SQL Server. Make the field optional or add `stored: true` to the @generated attribute.",
+    );
+}
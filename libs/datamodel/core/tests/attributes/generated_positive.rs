@@ -0,0 +1,59 @@
+use crate::common::*;
+use datamodel::{GeneratedColumn, ScalarType};
+use indoc::indoc;
+
+#[test]
+fn should_apply_generated_attribute() {
+    let dml = r#"
+    model Rectangle {
+        id     Int @id
+        width  Int
+        height Int
+        area   Int @generated("width * height", stored: true)
+        double Int @generated("width * 2")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let model = schema.assert_has_model("Rectangle");
+
+    let area = model.assert_has_scalar_field("area").assert_base_type(&ScalarType::Int);
+    assert_eq!(
+        area.generated_as,
+        Some(GeneratedColumn {
+            expression: "width * height".to_owned(),
+            stored: true,
+        })
+    );
+
+    let double = model.assert_has_scalar_field("double");
+    assert_eq!(
+        double.generated_as,
+        Some(GeneratedColumn {
+            expression: "width * 2".to_owned(),
+            stored: false,
+        })
+    );
+
+    assert_eq!(model.assert_has_scalar_field("width").generated_as, None);
+}
+
+#[test]
+fn should_render_generated_attribute() {
+    let input = indoc!(
+        r#"
+        model Rectangle {
+          id     Int @id
+          width  Int
+          height Int
+          area   Int @generated("width * height", stored: true)
+          double Int @generated("width * 2")
+        }
+        "#
+    );
+
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml);
+
+    assert_eq!(input, rendered);
+}
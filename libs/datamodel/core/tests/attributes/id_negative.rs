@@ -1,5 +1,8 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+};
 
 #[test]
 fn id_should_error_if_the_field_is_not_required() {
@@ -14,7 +17,7 @@ fn id_should_error_if_the_field_is_not_required() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "Fields that are marked as id must be required.",
         "id",
-        Span::new(36, 38),
+        Span::new(36, 38, FileId(0)),
     ));
 }
 
@@ -31,7 +34,7 @@ fn id_should_error_if_unique_and_id_are_specified() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "Fields that are marked as id should not have an additional @unique.",
         "unique",
-        Span::new(39, 45),
+        Span::new(39, 45, FileId(0)),
     ));
 }
 
@@ -49,7 +52,7 @@ fn id_should_error_multiple_ids_are_provided() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "At most one field must be marked as the id field with the `@id` attribute.",
         "Model",
-        Span::new(5, 105),
+        Span::new(5, 105, FileId(0)),
     ));
 }
 
@@ -69,7 +72,7 @@ fn id_must_error_when_single_and_multi_field_id_is_used() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Each model must have at most one id criteria. You can\'t have `@id` and `@@id` at the same time.",
         "Model",
-        Span::new(5, 104),
+        Span::new(5, 104, FileId(0)),
     ));
 }
 
@@ -88,7 +91,7 @@ fn id_must_error_when_multi_field_is_referring_to_undefined_fields() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "The multi field id declaration refers to the unknown fields c.",
         "Model",
-        Span::new(58, 67),
+        Span::new(58, 67, FileId(0)),
     ));
 }
 
@@ -108,6 +111,6 @@ fn must_error_when_multi_field_is_referring_fields_that_are_not_required() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "The id definition refers to the optional fields b, c. Id definitions must reference only required fields.",
         "Model",
-        Span::new(75, 86),
+        Span::new(75, 86, FileId(0)),
     ));
 }
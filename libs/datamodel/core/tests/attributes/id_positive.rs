@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::ast::Span;
+use datamodel::ast::{FileId, Span};
 use datamodel::diagnostics::DatamodelError;
 use datamodel::dml::*;
 use prisma_value::PrismaValue;
@@ -161,7 +161,7 @@ fn relation_field_as_id_must_error() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The field `identification` is a relation field and cannot be marked with `@id`. Only scalar fields can be declared as id.",
         "id",
-        Span::new(84, 86),
+        Span::new(84, 86, FileId(0)),
     ));
 }
 
@@ -184,6 +184,6 @@ fn relation_fields_as_part_of_compound_id_must_error() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "The id definition refers to the relation fields identification. Id definitions must reference only scalar fields.",
         "User",
-        Span::new(136, 162),
+        Span::new(136, 162, FileId(0)),
     ));
 }
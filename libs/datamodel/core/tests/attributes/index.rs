@@ -1,4 +1,8 @@
-use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string, IndexDefinition, IndexType};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::*,
+    render_datamodel_to_string, IndexDefinition, IndexType,
+};
 
 use crate::common::*;
 
@@ -69,7 +73,7 @@ fn indexes_on_relation_fields_must_error() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "The index definition refers to the relation fields identification. Index definitions must reference only scalar fields. Did you mean `@@index([identificationId])`?",
         "User",
-        Span::new(195,218),
+        Span::new(195,218, FileId(0)),
     ));
 }
 
@@ -163,7 +167,10 @@ fn multiple_indexes_with_same_name_are_not_supported_by_sqlite() {
     errors.assert_length(1);
     errors.assert_is_at(
         0,
-        DatamodelError::new_multiple_indexes_with_same_name_are_not_supported("MyIndexName", Span::new(279, 311)),
+        DatamodelError::new_multiple_indexes_with_same_name_are_not_supported(
+            "MyIndexName",
+            Span::new(279, 311, FileId(0)),
+        ),
     );
 }
 
@@ -198,7 +205,10 @@ fn multiple_indexes_with_same_name_are_not_supported_by_postgres() {
     errors.assert_length(1);
     errors.assert_is_at(
         0,
-        DatamodelError::new_multiple_indexes_with_same_name_are_not_supported("MyIndexName", Span::new(285, 317)),
+        DatamodelError::new_multiple_indexes_with_same_name_are_not_supported(
+            "MyIndexName",
+            Span::new(285, 317, FileId(0)),
+        ),
     );
 }
 
@@ -246,7 +256,7 @@ fn must_error_when_unknown_fields_are_used() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "The index definition refers to the unknown fields foo, bar.",
         "User",
-        Span::new(48, 64),
+        Span::new(48, 64, FileId(0)),
     ));
 }
 
@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::ast::Span;
+use datamodel::ast::{FileId, Span};
 use datamodel::diagnostics::DatamodelError;
 
 #[test]
@@ -50,6 +50,6 @@ fn map_must_error_for_relation_fields() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The attribute `@map` can not be used on relation fields.",
         "map",
-        Span::new(128, 146),
+        Span::new(128, 146, FileId(0)),
     ));
 }
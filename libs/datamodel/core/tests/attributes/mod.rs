@@ -1,6 +1,8 @@
 pub mod builtin_attributes;
 pub mod default_negative;
 pub mod default_positive;
+pub mod generated_negative;
+pub mod generated_positive;
 pub mod id_negative;
 pub mod id_positive;
 pub mod index;
@@ -11,9 +13,16 @@ pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
+pub mod relations_referential_actions;
+pub mod sequence_negative;
+pub mod sequence_positive;
+pub mod soft_delete_negative;
+pub mod soft_delete_positive;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
+pub mod version_negative;
+pub mod version_positive;
 
 pub mod arg_parsing;
@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::ast::Span;
+use datamodel::ast::{FileId, Span};
 use datamodel::diagnostics::DatamodelError;
 use datamodel::dml::ScalarType;
 use datamodel::{render_datamodel_to_string, FieldArity, FieldType, ScalarField};
@@ -680,6 +680,6 @@ fn must_handle_conflicts_with_existing_fields_if_types_are_incompatible_and_name
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Automatic underlying field generation tried to add the field `blogId_BlogToPost` in model `Post` for the back relation field of `posts` in `Blog`. A field with that name exists already and has an incompatible type for the relation. Please add the back relation manually.",
         "Post",
-        Span::new(75,281),
+        Span::new(75,281, FileId(0)),
     ));
 }
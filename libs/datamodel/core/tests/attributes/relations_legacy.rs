@@ -1,6 +1,9 @@
 use crate::common::*;
 use datamodel::dml;
-use datamodel::{ast::Span, diagnostics::DatamodelError};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+};
 
 // Ported from
 // https://github.com/prisma/prisma/blob/master/server/servers/deploy/src/test/scala/com/prisma/deploy/migration/validation/RelationAttributeSpec.scala
@@ -60,7 +63,7 @@ fn fail_if_back_relation_for_embedded_type() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Embedded models cannot have back relation fields.",
         "Comment",
-        Span::new(151, 161),
+        Span::new(151, 161, FileId(0)),
     ));
 }
 
@@ -107,6 +110,6 @@ fn fail_if_ambigous_relation_fields_do_not_specify_a_name() {
 
     errors.assert_is_at(
         0,
-        DatamodelError::new_model_validation_error("Ambiguous relation detected. The fields `comments` and `comments2` in model `Todo` both refer to `Comment`. Please provide different relation names for them by adding `@relation(<name>).", "Todo", Span::new(41, 60)),
+        DatamodelError::new_model_validation_error("Ambiguous relation detected. The fields `comments` and `comments2` in model `Todo` both refer to `Comment`. Please provide different relation names for them by adding `@relation(<name>).", "Todo", Span::new(41, 60, FileId(0))),
     );
 }
@@ -1,5 +1,8 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+};
 
 #[test]
 fn should_fail_on_ambiguous_relations_with_automatic_names_1() {
@@ -18,7 +21,7 @@ fn should_fail_on_ambiguous_relations_with_automatic_names_1() {
     let errors = parse_error(dml);
 
     errors.assert_is(
-        DatamodelError::new_model_validation_error("Ambiguous relation detected. The fields `posts` and `more_posts` in model `User` both refer to `Post`. Please provide different relation names for them by adding `@relation(<name>).", "User", Span::new(45, 58)),
+        DatamodelError::new_model_validation_error("Ambiguous relation detected. The fields `posts` and `more_posts` in model `User` both refer to `Post`. Please provide different relation names for them by adding `@relation(<name>).", "User", Span::new(45, 58, FileId(0))),
     );
 }
 
@@ -41,7 +44,7 @@ fn should_fail_on_colliding_implicit_self_relations() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Colliding implicit relations. Please add scalar types husbandId, and teacherId.",
         "User",
-        Span::new(5, 342),
+        Span::new(5, 342, FileId(0)),
     ));
 }
 
@@ -64,7 +67,7 @@ fn should_fail_on_ambiguous_relations_with_automatic_names_2() {
     let errors = parse_error(dml);
 
     errors.assert_is(
-        DatamodelError::new_model_validation_error("Ambiguous relation detected. The fields `author1` and `author2` in model `Post` both refer to `User`. Please provide different relation names for them by adding `@relation(<name>).", "Post", Span::new(114, 127)),
+        DatamodelError::new_model_validation_error("Ambiguous relation detected. The fields `author1` and `author2` in model `Post` both refer to `User`. Please provide different relation names for them by adding `@relation(<name>).", "Post", Span::new(114, 127, FileId(0))),
     );
 }
 
@@ -88,7 +91,7 @@ fn should_fail_on_ambiguous_relations_with_manual_names_1() {
         DatamodelError::new_model_validation_error(
             "Wrongly named relation detected. The fields `posts` and `more_posts` in model `User` both use the same relation name. Please provide different relation names for them through `@relation(<name>).", 
             "User", 
-            Span::new(45, 82)
+            Span::new(45, 82, FileId(0))
         ),
     );
 }
@@ -114,7 +117,7 @@ fn should_fail_on_ambiguous_relations_with_manual_names_2() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Wrongly named relation detected. The fields `posts` and `even_more_posts` in model `User` both use the same relation name. Please provide different relation names for them through `@relation(<name>).",
         "User",
-        Span::new(45, 79),
+        Span::new(45, 79, FileId(0)),
     ));
 }
 
@@ -134,7 +137,7 @@ fn should_fail_on_ambiguous_self_relation() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Unnamed self relation detected. The fields `father`, `son` and `mother` in model `User` have no relation name. Please provide a relation name for one of them by adding `@relation(<name>).",
         "User",
-        Span::new(45, 57),
+        Span::new(45, 57, FileId(0)),
     ));
 }
 
@@ -153,7 +156,7 @@ fn should_fail_on_ambiguous_self_relation_with_two_fields() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Ambiguous self relation detected. The fields `child` and `mother` in model `User` both refer to `User`. If they are part of the same relation add the same relation name for them with `@relation(<name>)`.",
         "User",
-        Span::new(57, 68),
+        Span::new(57, 68, FileId(0)),
     ));
 }
 
@@ -173,7 +176,7 @@ fn should_fail_on_ambiguous_named_self_relation() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Wrongly named self relation detected. The fields `father`, `son` and `mother` in model `User` have the same relation name. At most two relation fields can belong to the same relation and therefore have the same name. Please assign a different relation name to one of them.",
         "User",
-        Span::new(45, 83),
+        Span::new(45, 83, FileId(0)),
     ));
 }
 
@@ -197,7 +200,7 @@ fn should_fail_on_conflicting_back_relation_field_name() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Automatic related field generation would cause a naming conflict. Please add an explicit opposite relation field.",
         "User",
-        Span::new(90, 108),
+        Span::new(90, 108, FileId(0)),
     ));
 }
 
@@ -225,6 +228,6 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
     errors.assert_is_at(0, DatamodelError::new_model_validation_error(
         "Automatic opposite related field generation would cause a naming conflict. Please add an explicit opposite relation field.",
         "Todo",
-        Span::new(98, 152),
+        Span::new(98, 152, FileId(0)),
     ));
 }
@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::ast::Span;
+use datamodel::ast::{FileId, Span};
 use datamodel::diagnostics::DatamodelError;
 use datamodel::dml;
 
@@ -55,7 +55,7 @@ fn relation_must_error_when_base_field_does_not_exist() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The argument fields must refer only to existing fields. The following fields do not exist in this model: userId", Span::new(162, 218)));
+    errors.assert_is(DatamodelError::new_validation_error("The argument fields must refer only to existing fields. The following fields do not exist in this model: userId", Span::new(162, 218, FileId(0))));
 }
 
 #[test]
@@ -84,8 +84,8 @@ fn relation_must_error_when_base_field_is_not_scalar() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is_at(0,DatamodelError::new_validation_error("The argument fields must refer only to scalar fields. But it is referencing the following relation fields: other", Span::new(210, 265)));
-    errors.assert_is_at(1,DatamodelError::new_attribute_validation_error("The type of the field `other` in the model `Post` is not matching the type of the referenced field `id` in model `User`.", "relation", Span::new(210, 265)));
+    errors.assert_is_at(0,DatamodelError::new_validation_error("The argument fields must refer only to scalar fields. But it is referencing the following relation fields: other", Span::new(210, 265, FileId(0))));
+    errors.assert_is_at(1,DatamodelError::new_attribute_validation_error("The type of the field `other` in the model `Post` is not matching the type of the referenced field `id` in model `User`.", "relation", Span::new(210, 265, FileId(0))));
 }
 
 #[test]
@@ -137,7 +137,7 @@ fn optional_relation_field_must_error_when_one_underlying_field_is_required() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The relation field `user` uses the scalar fields userFirstName, userLastName. At least one of those fields is required. Hence the relation field must be required as well.", Span::new(338, 444)));
+    errors.assert_is(DatamodelError::new_validation_error("The relation field `user` uses the scalar fields userFirstName, userLastName. At least one of those fields is required. Hence the relation field must be required as well.", Span::new(338, 444, FileId(0))));
 }
 
 #[test]
@@ -189,7 +189,7 @@ fn required_relation_field_must_error_when_all_underlying_fields_are_optional()
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The relation field `user` uses the scalar fields userFirstName, userLastName. All those fields are optional. Hence the relation field must be optional as well.", Span::new(340, 446)));
+    errors.assert_is(DatamodelError::new_validation_error("The relation field `user` uses the scalar fields userFirstName, userLastName. All those fields are optional. Hence the relation field must be optional as well.", Span::new(340, 446, FileId(0))));
 }
 
 #[test]
@@ -211,7 +211,7 @@ fn required_relation_field_must_error_if_it_is_virtual() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The relation field `address` on Model `User` is required. This is no longer valid because it\'s not possible to enforce this constraint on the database level. Please change the field type from `Address` to `Address?` to fix this.",
         "relation",
-        Span::new(54, 77),
+        Span::new(54, 77, FileId(0)),
     ));
 }
 
@@ -233,7 +233,7 @@ fn relation_must_error_when_referenced_field_does_not_exist() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer only to existing fields in the related model `User`. The following fields do not exist in the related model: fooBar", Span::new(181, 241)));
+    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer only to existing fields in the related model `User`. The following fields do not exist in the related model: fooBar", Span::new(181, 241, FileId(0))));
 }
 
 #[test]
@@ -254,7 +254,7 @@ fn relation_must_error_when_referenced_field_is_not_scalar() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer only to scalar fields in the related model `User`. But it is referencing the following relation fields: posts", Span::new(181, 240)));
+    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer only to scalar fields in the related model `User`. But it is referencing the following relation fields: posts", Span::new(181, 240, FileId(0))));
 }
 
 #[test]
@@ -275,7 +275,7 @@ fn relation_must_error_when_referenced_fields_are_not_a_unique_criteria() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer to a unique criteria in the related model `User`. But it is referencing the following fields that are not a unique criteria: firstName", Span::new(213, 284)));
+    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer to a unique criteria in the related model `User`. But it is referencing the following fields that are not a unique criteria: firstName", Span::new(213, 284, FileId(0))));
 }
 
 #[allow(non_snake_case)]
@@ -326,7 +326,7 @@ fn relation_must_error_when_referenced_fields_are_multiple_uniques() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer to a unique criteria in the related model `User`. But it is referencing the following fields that are not a unique criteria: id, firstName", Span::new(298, 375)));
+    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer to a unique criteria in the related model `User`. But it is referencing the following fields that are not a unique criteria: id, firstName", Span::new(298, 375, FileId(0))));
 }
 
 #[test]
@@ -346,7 +346,7 @@ fn relation_must_error_when_types_of_base_field_and_referenced_field_do_not_matc
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_attribute_validation_error("The type of the field `userId` in the model `Post` is not matching the type of the referenced field `id` in model `User`.", "relation", Span::new(204, 265)));
+    errors.assert_is(DatamodelError::new_attribute_validation_error("The type of the field `userId` in the model `Post` is not matching the type of the referenced field `id` in model `User`.", "relation", Span::new(204, 265, FileId(0))));
 }
 
 #[test]
@@ -370,7 +370,7 @@ fn relation_must_error_when_number_of_fields_and_references_is_not_equal() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "You must specify the same number of fields in `fields` and `references`.",
         "relation",
-        Span::new(200, 273),
+        Span::new(200, 273, FileId(0)),
     ));
 }
 
@@ -440,7 +440,7 @@ fn must_error_when_references_argument_is_missing_for_one_to_many() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "The relation field `user` on Model `Post` must specify the `references` argument in the @relation attribute.",
         "relation",
-        Span::new(172, 214),
+        Span::new(172, 214, FileId(0)),
     ));
 }
 
@@ -469,7 +469,7 @@ fn must_error_fields_or_references_argument_is_placed_on_wrong_side_for_one_to_m
     errors.assert_is(
         DatamodelError::new_attribute_validation_error(
             "The relation field `posts` on Model `User` must not specify the `fields` or `references` argument in the @relation attribute. You must only specify it on the opposite field `user` on model `Post`.",
-            "relation", Span::new(208, 268)
+            "relation", Span::new(208, 268, FileId(0))
         ),
     );
 }
@@ -497,13 +497,13 @@ fn must_error_when_both_arguments_are_missing_for_one_to_many() {
         DatamodelError::new_attribute_validation_error(
             "The relation field `user` on Model `Post` must specify the `fields` argument in the @relation attribute.",
             "relation",
-            Span::new(172, 183),
+            Span::new(172, 183, FileId(0)),
         ),
     );
     errors.assert_is_at(1, DatamodelError::new_attribute_validation_error(
         "The relation field `user` on Model `Post` must specify the `references` argument in the @relation attribute.",
         "relation",
-        Span::new(172, 183),
+        Span::new(172, 183, FileId(0)),
     ));
 }
 
@@ -556,14 +556,14 @@ fn must_error_when_references_argument_is_missing_for_one_to_one() {
         0,
         DatamodelError::new_attribute_validation_error(
             "The relation fields `post` on Model `User` and `user` on Model `Post` do not provide the `references` argument in the @relation attribute. You have to provide it on one of the two fields.",
-            "relation", Span::new(77, 91)
+            "relation", Span::new(77, 91, FileId(0))
         ),
     );
     errors.assert_is_at(
         1,
         DatamodelError::new_attribute_validation_error(
             "The relation fields `user` on Model `Post` and `post` on Model `User` do not provide the `references` argument in the @relation attribute. You have to provide it on one of the two fields.",
-            "relation", Span::new(170, 212)
+            "relation", Span::new(170, 212, FileId(0))
         ),
     );
 }
@@ -590,14 +590,14 @@ fn must_error_when_fields_and_references_argument_are_placed_on_different_sides_
         0,
         DatamodelError::new_attribute_validation_error(
             "The relation field `post` on Model `User` provides the `references` argument in the @relation attribute. And the related field `user` on Model `Post` provides the `fields` argument. You must provide both arguments on the same side.",
-            "relation", Span::new(99, 142)
+            "relation", Span::new(99, 142, FileId(0))
         ),
     );
     errors.assert_is_at(
         1,
         DatamodelError::new_attribute_validation_error(
             "The relation field `user` on Model `Post` provides the `fields` argument in the @relation attribute. And the related field `post` on Model `User` provides the `references` argument. You must provide both arguments on the same side.",
-            "relation", Span::new(220, 263)
+            "relation", Span::new(220, 263, FileId(0))
         ),
     );
 }
@@ -624,14 +624,14 @@ fn must_error_when_fields_or_references_argument_is_placed_on_both_sides_for_one
             0,
             DatamodelError::new_attribute_validation_error(
                 "The relation fields `post` on Model `User` and `user` on Model `Post` both provide the `references` argument in the @relation attribute. You have to provide it only on one of the two fields.",
-                "relation", Span::new(99, 160)
+                "relation", Span::new(99, 160, FileId(0))
             ),
         );
     errors.assert_is_at(
             1,
             DatamodelError::new_attribute_validation_error(
                 "The relation fields `post` on Model `User` and `user` on Model `Post` both provide the `fields` argument in the @relation attribute. You have to provide it only on one of the two fields.",
-                "relation", Span::new(99, 160)
+                "relation", Span::new(99, 160, FileId(0))
             ),
         );
 
@@ -639,7 +639,7 @@ fn must_error_when_fields_or_references_argument_is_placed_on_both_sides_for_one
         2,
         DatamodelError::new_attribute_validation_error(
             "The relation fields `user` on Model `Post` and `post` on Model `User` both provide the `references` argument in the @relation attribute. You have to provide it only on one of the two fields.",
-            "relation", Span::new(238, 299)
+            "relation", Span::new(238, 299, FileId(0))
         ),
     );
 
@@ -647,7 +647,7 @@ fn must_error_when_fields_or_references_argument_is_placed_on_both_sides_for_one
         3,
         DatamodelError::new_attribute_validation_error(
             "The relation fields `user` on Model `Post` and `post` on Model `User` both provide the `fields` argument in the @relation attribute. You have to provide it only on one of the two fields.",
-            "relation", Span::new(238,299)
+            "relation", Span::new(238,299, FileId(0))
         ),
     );
 }
@@ -665,11 +665,11 @@ fn must_error_for_required_one_to_one_self_relations() {
     let errors = parse_error(dml);
     errors.assert_is_at(
         0,
-        DatamodelError::new_field_validation_error("The relation fields `friend` and `friendOf` on Model `User` are both required. This is not allowed for a self relation because it would not be possible to create a record.", "User", "friend", Span::new(83, 152)),
+        DatamodelError::new_field_validation_error("The relation fields `friend` and `friendOf` on Model `User` are both required. This is not allowed for a self relation because it would not be possible to create a record.", "User", "friend", Span::new(83, 152, FileId(0))),
     );
     errors.assert_is_at(
         1,
-        DatamodelError::new_field_validation_error("The relation fields `friendOf` and `friend` on Model `User` are both required. This is not allowed for a self relation because it would not be possible to create a record.", "User", "friendOf", Span::new(162, 197)),
+        DatamodelError::new_field_validation_error("The relation fields `friendOf` and `friend` on Model `User` are both required. This is not allowed for a self relation because it would not be possible to create a record.", "User", "friendOf", Span::new(162, 197, FileId(0))),
     );
 }
 
@@ -692,7 +692,7 @@ fn must_error_when_non_id_field_is_referenced_in_a_many_to_many() {
         0,
         DatamodelError::new_validation_error(
             "Many to many relations must always reference the id field of the related model. Change the argument `references` to use the id field of the related model `Post`. But it is referencing the following fields that are not the id: slug",  
-            Span::new(225, 268)
+            Span::new(225, 268, FileId(0))
         ),
     );
 }
@@ -719,7 +719,7 @@ fn must_error_nicely_when_a_many_to_many_is_not_possible() {
         "The relation field `posts` on Model `Category` references `Post` which does not have an `@id` field. Models without `@id` can not be part of a many to many relation. Use an explicit intermediate Model to represent this relationship.",
         "Category",
         "posts",
-        Span::new(252, 295)
+        Span::new(252, 295, FileId(0))
     ));
 }
 
@@ -744,7 +744,7 @@ fn must_error_when_many_to_many_is_not_possible_due_to_missing_id() {
             "The relation field `posts` on Model `Category` references `Post` which does not have an `@id` field. Models without `@id` can not be part of a many to many relation. Use an explicit intermediate Model to represent this relationship.", 
             "Category",
             "posts", 
-            Span::new(193, 206)
+            Span::new(193, 206, FileId(0))
         ),
     );
 }
@@ -0,0 +1,111 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn referential_actions_are_parsed() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int   @id
+        authorId Int?
+        author   User? @relation(fields: [authorId], references: [id], onDelete: CASCADE, onUpdate: SET_NULL)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("author")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::Cascade)
+        .assert_relation_update_strategy(dml::OnUpdateStrategy::SetNull);
+
+    schema
+        .assert_has_model("User")
+        .assert_has_relation_field("posts")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::None)
+        .assert_relation_update_strategy(dml::OnUpdateStrategy::None);
+}
+
+#[test]
+fn referential_actions_are_rendered() {
+    let dml = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int   @id
+  authorId Int?
+  author   User? @relation(fields: [authorId], references: [id], onDelete: SET_NULL, onUpdate: CASCADE)
+}
+"#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml));
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn invalid_referential_actions_must_error() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], onDelete: RESTRICT)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": Invalid referential action `RESTRICT`. Valid actions are `CASCADE`, `SET_NULL` and `NONE`.",
+    );
+}
+
+#[test]
+fn referential_actions_must_be_defined_on_the_side_with_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(onDelete: CASCADE)
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": Referential actions can only be defined on the side of the relation that defines `fields`.",
+    );
+}
+
+#[test]
+fn set_null_must_not_be_used_on_required_relations() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], onDelete: SET_NULL)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The referential action `SET_NULL` can't be used on a required relation field.",
+    );
+}
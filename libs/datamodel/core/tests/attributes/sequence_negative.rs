@@ -0,0 +1,124 @@
+use crate::common::*;
+
+#[test]
+fn should_fail_on_unknown_sequence_arguments() {
+    let dml = r#"
+    model Invoice {
+        id Int @id @default(sequence(name: "invoices", cache: 10))
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@default\": The function `sequence()` only accepts the named arguments `name`, `start` and `increment`.",
+    );
+}
+
+#[test]
+fn should_fail_on_unnamed_sequence_arguments() {
+    let dml = r#"
+    model Invoice {
+        id Int @id @default(sequence("invoices"))
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@default\": The function `sequence()` only accepts the named arguments `name`, `start` and `increment`.",
+    );
+}
+
+#[test]
+fn should_fail_on_invalid_sequence_options() {
+    let dml = r#"
+    model Invoice {
+        id Int @id @default(sequence(name: ""))
+        a  Int @default(sequence(increment: 0))
+        b  Int @default(sequence(start: "one"))
+        c  Int @default(sequence(start: 1, start: 2))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message_at(
+        0,
+        "Error parsing attribute \"@default\": The `name` of a sequence must be a non-empty string.",
+    );
+    errors.assert_is_message_at(
+        1,
+        "Error parsing attribute \"@default\": The `increment` of a sequence must be a non-zero integer.",
+    );
+    errors.assert_is_message_at(
+        2,
+        "Error parsing attribute \"@default\": The `start` of a sequence must be an integer.",
+    );
+    errors.assert_is_message_at(
+        3,
+        "Error parsing attribute \"@default\": The argument `start` is specified more than once.",
+    );
+}
+
+#[test]
+fn should_fail_on_named_arguments_for_other_functions() {
+    let dml = r#"
+    model Invoice {
+        id String @id @default(nanoid(size: 10))
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@default\": The function `nanoid()` does not take named arguments.",
+    );
+}
+
+#[test]
+fn should_fail_on_non_integer_fields() {
+    let dml = r#"
+    model Invoice {
+        id String @id @default(sequence())
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@default\": The function `sequence()` can not be used on fields of type `String`.",
+    );
+}
+
+#[test]
+fn should_fail_on_connectors_without_sequences() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://"
+    }
+
+    model Invoice {
+        id Int @id @default(sequence(start: 10))
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@default\": The `sequence()` default value is not supported by the provider `mysql`.",
+    );
+}
+
+#[test]
+fn should_fail_on_shared_sequences_with_different_options() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgres://"
+    }
+
+    model Invoice {
+        id Int @id @default(sequence(name: "document_numbers", start: 1000))
+    }
+
+    model Receipt {
+        id Int @id @default(sequence(name: "document_numbers", increment: 2))
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@default\": The sequence `document_numbers` is used with different `start` or `increment` options on other fields.",
+    );
+}
@@ -0,0 +1,97 @@
+use crate::common::*;
+use datamodel::{DefaultValue, SequenceOptions, ValueGenerator};
+use indoc::indoc;
+
+#[test]
+fn should_apply_sequence_defaults() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgres://"
+    }
+
+    model Invoice {
+        id     Int @id @default(sequence(name: "document_numbers", start: 1000))
+        number Int @default(sequence(start: 10, increment: 5))
+    }
+
+    model Receipt {
+        id Int @id @default(sequence(name: "document_numbers", start: 1000))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let invoice = schema.assert_has_model("Invoice");
+
+    invoice
+        .assert_has_scalar_field("id")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_sequence(
+            SequenceOptions {
+                name: Some("document_numbers".to_owned()),
+                start: Some(1000),
+                increment: None,
+            },
+        )));
+
+    invoice
+        .assert_has_scalar_field("number")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_sequence(
+            SequenceOptions {
+                name: None,
+                start: Some(10),
+                increment: Some(5),
+            },
+        )));
+
+    schema
+        .assert_has_model("Receipt")
+        .assert_has_scalar_field("id")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_sequence(
+            SequenceOptions {
+                name: Some("document_numbers".to_owned()),
+                start: Some(1000),
+                increment: None,
+            },
+        )));
+}
+
+#[test]
+fn should_allow_a_sequence_without_options() {
+    let dml = r#"
+    model Invoice {
+        id Int @id @default(sequence())
+    }
+    "#;
+
+    let schema = parse(dml);
+    let default = schema
+        .assert_has_model("Invoice")
+        .assert_has_scalar_field("id")
+        .default_value
+        .clone();
+
+    match default {
+        Some(DefaultValue::Expression(generator)) => {
+            assert_eq!(generator.sequence_options(), Some(SequenceOptions::default()))
+        }
+        other => panic!("Expected a sequence default, got {:?}", other),
+    }
+}
+
+#[test]
+fn should_render_sequence_defaults_with_named_arguments() {
+    let input = indoc!(
+        r#"
+        model Invoice {
+          id     BigInt @id @default(sequence(name: "document_numbers", start: 1000, increment: 10))
+          number Int    @default(sequence(increment: 2))
+          serial Int    @default(sequence())
+        }
+        "#
+    );
+
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml);
+
+    assert_eq!(input, rendered);
+}
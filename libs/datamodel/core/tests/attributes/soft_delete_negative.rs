@@ -0,0 +1,54 @@
+use crate::common::*;
+
+#[test]
+fn should_fail_if_soft_delete_field_does_not_exist() {
+    let dml = r#"
+    model Post {
+        id Int @id
+
+        @@softDelete(field: deletedAt)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@softDelete\": The soft delete field `deletedAt` is not a scalar field of the model.",
+    );
+}
+
+#[test]
+fn should_fail_if_soft_delete_field_is_required() {
+    let dml = r#"
+    model Post {
+        id        Int      @id
+        deletedAt DateTime
+
+        @@softDelete(field: deletedAt)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@softDelete\": The soft delete field `deletedAt` must be an optional field of type DateTime.",
+    );
+}
+
+#[test]
+fn should_fail_if_soft_delete_field_is_not_a_date_time() {
+    let dml = r#"
+    model Post {
+        id      Int      @id
+        deleted Boolean?
+
+        @@softDelete(field: deleted)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@softDelete\": The soft delete field `deleted` must be an optional field of type DateTime.",
+    );
+}
@@ -0,0 +1,45 @@
+use crate::common::*;
+use indoc::indoc;
+
+#[test]
+fn should_apply_soft_delete_attribute() {
+    let dml = r#"
+    model Post {
+        id        Int       @id
+        deletedAt DateTime?
+
+        @@softDelete(field: deletedAt)
+    }
+
+    model Comment {
+        id Int @id
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert_eq!(
+        schema.assert_has_model("Post").soft_delete_field,
+        Some("deletedAt".to_owned())
+    );
+    assert_eq!(schema.assert_has_model("Comment").soft_delete_field, None);
+}
+
+#[test]
+fn should_render_soft_delete_attribute() {
+    let input = indoc!(
+        r#"
+        model Post {
+          id        Int       @id
+          deletedAt DateTime?
+
+          @@softDelete(field: deletedAt)
+        }
+        "#
+    );
+
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml);
+
+    assert_eq!(input, rendered);
+}
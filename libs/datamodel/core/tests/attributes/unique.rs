@@ -1,6 +1,10 @@
 #![allow(non_snake_case)]
 
-use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string, IndexDefinition, IndexType};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::*,
+    render_datamodel_to_string, IndexDefinition, IndexType,
+};
 
 use crate::common::*;
 
@@ -38,7 +42,7 @@ fn multiple_unnamed_arguments_must_error() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_attribute_validation_error("You provided multiple unnamed arguments. This is not possible. Did you forget the brackets? Did you mean `[firstName, lastName]`?", "unique", Span::new(108, 134)));
+    errors.assert_is(DatamodelError::new_attribute_validation_error("You provided multiple unnamed arguments. This is not possible. Did you forget the brackets? Did you mean `[firstName, lastName]`?", "unique", Span::new(108, 134, FileId(0))));
 }
 
 #[test]
@@ -59,7 +63,7 @@ fn multi_field_unique_indexes_on_relation_fields_must_error_and_give_nice_error_
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_model_validation_error("The unique index definition refers to the relation fields identification. Index definitions must reference only scalar fields. Did you mean `@@unique([identificationId])`?", "User",Span::new(193, 217)));
+    errors.assert_is(DatamodelError::new_model_validation_error("The unique index definition refers to the relation fields identification. Index definitions must reference only scalar fields. Did you mean `@@unique([identificationId])`?", "User",Span::new(193, 217, FileId(0))));
 }
 
 #[test]
@@ -80,7 +84,7 @@ fn multi_field_unique_indexes_on_relation_fields_must_error_and_give_nice_error_
 
     let errors = parse_error(dml);
     // in this case the error can't give a suggestion
-    errors.assert_is(DatamodelError::new_model_validation_error("The unique index definition refers to the relation fields user. Index definitions must reference only scalar fields.", "Identification",Span::new(270, 284)));
+    errors.assert_is(DatamodelError::new_model_validation_error("The unique index definition refers to the relation fields user. Index definitions must reference only scalar fields.", "Identification",Span::new(270, 284, FileId(0))));
 }
 
 #[test]
@@ -99,7 +103,7 @@ fn single_field_unique_on_relation_fields_must_error_nicely_with_ONE_underlying_
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_attribute_validation_error("The field `identification` is a relation field and cannot be marked with `unique`. Only scalar fields can be made unique. Did you mean to put it on `identificationId`?", "unique", Span::new(183, 189)));
+    errors.assert_is(DatamodelError::new_attribute_validation_error("The field `identification` is a relation field and cannot be marked with `unique`. Only scalar fields can be made unique. Did you mean to put it on `identificationId`?", "unique", Span::new(183, 189, FileId(0))));
 }
 
 #[test]
@@ -121,7 +125,7 @@ fn single_field_unique_on_relation_fields_must_error_nicely_with_MANY_underlying
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_attribute_validation_error("The field `identification` is a relation field and cannot be marked with `unique`. Only scalar fields can be made unique. Did you mean to provide `@@unique([identificationId1, identificationId2])`?", "unique", Span::new(235, 241)));
+    errors.assert_is(DatamodelError::new_attribute_validation_error("The field `identification` is a relation field and cannot be marked with `unique`. Only scalar fields can be made unique. Did you mean to provide `@@unique([identificationId1, identificationId2])`?", "unique", Span::new(235, 241, FileId(0))));
 }
 
 #[test]
@@ -235,7 +239,7 @@ fn must_error_when_unknown_fields_are_used() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "The unique index definition refers to the unknown fields foo, bar.",
         "User",
-        Span::new(48, 65),
+        Span::new(48, 65, FileId(0)),
     ));
 }
 
@@ -255,7 +259,7 @@ fn must_error_when_using_the_same_field_multiple_times() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "The unique index definition refers to the fields email multiple times.",
         "User",
-        Span::new(83, 105),
+        Span::new(83, 105, FileId(0)),
     ));
 }
 
@@ -1,5 +1,8 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+};
 
 #[test]
 fn must_error_on_model_without_unique_criteria() {
@@ -14,7 +17,7 @@ fn must_error_on_model_without_unique_criteria() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Each model must have at least one unique criteria that has only required fields. Either mark a single field with `@id`, `@unique` or add a multi field criterion with `@@id([])` or `@@unique([])` to the model.",
         "Model",
-        Span::new(5, 42),
+        Span::new(5, 42, FileId(0)),
     ));
 }
 
@@ -35,7 +38,7 @@ fn must_error_if_only_loose_unique_criterias_are_present() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "Each model must have at least one unique criteria that has only required fields. Either mark a single field with `@id`, `@unique` or add a multi field criterion with `@@id([])` or `@@unique([])` to the model. The following unique criterias were not considered as they contain fields that are not required:\n- name\n- a, b",
         "Model",
-        Span::new(5, 139),
+        Span::new(5, 139, FileId(0)),
     ));
 }
 
@@ -1,5 +1,8 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+};
 
 #[test]
 fn should_fail_if_field_type_is_string() {
@@ -15,7 +18,7 @@ fn should_fail_if_field_type_is_string() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "Fields that are marked with @updatedAt must be of type DateTime.",
         "updatedAt",
-        Span::new(62, 71),
+        Span::new(62, 71, FileId(0)),
     ));
 }
 
@@ -33,6 +36,6 @@ fn should_fail_if_field_arity_is_list() {
     errors.assert_is(DatamodelError::new_attribute_validation_error(
         "Fields that are marked with @updatedAt can not be lists.",
         "updatedAt",
-        Span::new(66, 75),
+        Span::new(66, 75, FileId(0)),
     ));
 }
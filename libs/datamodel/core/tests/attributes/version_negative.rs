@@ -0,0 +1,76 @@
+use crate::common::*;
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+};
+
+#[test]
+fn should_fail_if_field_type_is_string() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        version String @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @version must be of type Int.",
+        "version",
+        Span::new(61, 68, FileId(0)),
+    ));
+}
+
+#[test]
+fn should_fail_if_field_is_optional() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        version Int? @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @version must be required.",
+        "version",
+        Span::new(59, 66, FileId(0)),
+    ));
+}
+
+#[test]
+fn should_fail_if_used_multiple_times_on_a_model() {
+    let dml = r#"
+    model Post {
+        id       Int @id
+        version  Int @version
+        revision Int @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The `@version` attribute can only be used once per model.",
+        "version",
+        Span::new(5, 108, FileId(0)),
+    ));
+}
+
+#[test]
+fn should_fail_if_field_is_unique() {
+    let dml = r#"
+    model Post {
+        id      Int @id
+        version Int @unique @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@version\": Fields that are marked with @version can not be unique.",
+    );
+}
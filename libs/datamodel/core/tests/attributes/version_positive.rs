@@ -0,0 +1,38 @@
+use crate::common::*;
+use datamodel::ScalarType;
+use indoc::indoc;
+
+#[test]
+fn should_apply_version_attribute() {
+    let dml = r#"
+    model Post {
+        id      Int @id
+        version Int @default(1) @version
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_scalar_field("version")
+        .assert_base_type(&ScalarType::Int)
+        .assert_is_version(true);
+    post_model.assert_has_scalar_field("id").assert_is_version(false);
+}
+
+#[test]
+fn should_render_version_attribute() {
+    let input = indoc!(
+        r#"
+        model Post {
+          id      Int @id
+          version Int @default(1) @version
+        }
+        "#
+    );
+
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml);
+
+    assert_eq!(input, rendered);
+}
@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::ast::Span;
+use datamodel::ast::{FileId, Span};
 use datamodel::diagnostics::DatamodelError;
 use datamodel::{dml, ScalarType};
 
@@ -105,12 +105,12 @@ fn scalar_list_types_are_not_supported_by_default() {
 
     errors.assert_is_at(
         0,
-        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "comments", Span::new(106, 126)),
+        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "comments", Span::new(106, 126, FileId(0))),
     );
 
     errors.assert_is_at(
         1,
-        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "enums", Span::new(134, 152)),
+        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "enums", Span::new(134, 152, FileId(0))),
     );
 }
 
@@ -143,12 +143,12 @@ fn scalar_list_types_are_not_supported_by_mysql() {
 
     errors.assert_is_at(
         0,
-        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "comments", Span::new(174, 192)),
+        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "comments", Span::new(174, 192, FileId(0))),
     );
 
     errors.assert_is_at(
         1,
-        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "enums", Span::new(200, 216)),
+        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "enums", Span::new(200, 216, FileId(0))),
     );
 }
 
@@ -166,7 +166,7 @@ fn json_type_must_work_for_some_connectors() {
         "Field `json` in model `User` can\'t be of type Json. The current connector does not support the Json type.",
         "User",
         "json",
-        Span::new(50, 60),
+        Span::new(50, 60, FileId(0)),
     ));
 
     // SQLite does not support it
@@ -174,7 +174,7 @@ fn json_type_must_work_for_some_connectors() {
         "Field `json` in model `User` can\'t be of type Json. The current connector does not support the Json type.",
         "User",
         "json",
-        Span::new(139, 149),
+        Span::new(139, 149, FileId(0)),
     ));
 
     // Postgres does support it
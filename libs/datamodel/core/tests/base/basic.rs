@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::ast::Span;
+use datamodel::ast::{FileId, Span};
 use datamodel::diagnostics::DatamodelError;
 use datamodel::ScalarType;
 
@@ -82,7 +82,7 @@ fn must_error_for_invalid_model_names() {
     errors.assert_is(DatamodelError::new_model_validation_error(
         "The model name `PrismaClient` is invalid. It is a reserved name. Please change it. Read more at https://pris.ly/d/naming-models",
         "PrismaClient",
-        Span::new(5, 50),
+        Span::new(5, 50, FileId(0)),
     ));
 }
 
@@ -98,7 +98,7 @@ fn must_error_for_invalid_enum_names() {
     errors.assert_is(DatamodelError::new_enum_validation_error(
         "The enum name `PrismaClient` is invalid. It is a reserved name. Please change it. Read more at https://www.prisma.io/docs/reference/tools-and-interfaces/prisma-schema/data-model#naming-enums",
         "PrismaClient",
-        Span::new(5, 40),
+        Span::new(5, 40, FileId(0)),
     ));
 }
 
@@ -115,7 +115,7 @@ fn must_return_good_error_messages_for_numbers_in_enums() {
     let errors = parse_error(dml);
     errors.assert_is(DatamodelError::new_validation_error(
         "The name of a Enum Value must not start with a number.",
-        Span::new(27, 28),
+        Span::new(27, 28, FileId(0)),
     ));
 }
 
@@ -131,7 +131,7 @@ fn must_return_good_error_message_for_empty_enum() {
     errors.assert_is(DatamodelError::new_validation_error(
         // todo error for empty enum
         "An enum must have at least one value.",
-        Span::new(5, 25),
+        Span::new(5, 25, FileId(0)),
     ));
 }
 
@@ -146,7 +146,7 @@ fn must_return_good_error_message_for_enum_with_all_variants_commented_out() {
     let errors = parse_error(dml);
     errors.assert_is(DatamodelError::new_validation_error(
         "An enum must have at least one value.",
-        Span::new(5, 37),
+        Span::new(5, 37, FileId(0)),
     ));
 }
 
@@ -163,6 +163,6 @@ fn invalid_line_must_not_break() {
     let errors = parse_error(dml);
     errors.assert_is(DatamodelError::new_validation_error(
         "This line is invalid. It does not start with any known Prisma schema keyword.",
-        Span::new(5, 16),
+        Span::new(5, 16, FileId(0)),
     ));
 }
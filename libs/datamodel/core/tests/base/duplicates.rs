@@ -1,5 +1,8 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+};
 
 #[test]
 fn fail_on_duplicate_models() {
@@ -18,7 +21,7 @@ fn fail_on_duplicate_models() {
         "User",
         "model",
         "model",
-        Span::new(53, 57),
+        Span::new(53, 57, FileId(0)),
     ));
 }
 
@@ -45,7 +48,7 @@ fn fail_on_duplicate_models_with_relations() {
 
     errors.assert_is_at(
         0,
-        DatamodelError::new_duplicate_top_error("Post", "model", "model", Span::new(52, 56)),
+        DatamodelError::new_duplicate_top_error("Post", "model", "model", Span::new(52, 56, FileId(0))),
     );
 }
 
@@ -67,7 +70,7 @@ fn fail_on_model_enum_conflict() {
         "User",
         "model",
         "enum",
-        Span::new(65, 69),
+        Span::new(65, 69, FileId(0)),
     ));
 }
 #[test]
@@ -85,7 +88,7 @@ fn fail_on_model_type_conflict() {
         "User",
         "model",
         "type",
-        Span::new(34, 38),
+        Span::new(34, 38, FileId(0)),
     ));
 }
 
@@ -105,7 +108,7 @@ fn fail_on_enum_type_conflict() {
         "User",
         "enum",
         "type",
-        Span::new(33, 37),
+        Span::new(33, 37, FileId(0)),
     ));
 }
 
@@ -124,7 +127,7 @@ fn fail_on_duplicate_field() {
     errors.assert_is(DatamodelError::new_duplicate_field_error(
         "User",
         "firstName",
-        Span::new(70, 79),
+        Span::new(70, 79, FileId(0)),
     ));
 }
 
@@ -143,7 +146,7 @@ fn fail_on_duplicate_enum_value() {
     errors.assert_is(DatamodelError::new_duplicate_enum_value_error(
         "Role",
         "Moderator",
-        Span::new(57, 67),
+        Span::new(57, 67, FileId(0)),
     ));
 }
 
@@ -160,7 +163,7 @@ fn fail_on_reserved_name_for_enum() {
 
     errors.assert_is(DatamodelError::new_reserved_scalar_type_error(
         "String",
-        Span::new(10, 16),
+        Span::new(10, 16, FileId(0)),
     ));
 }
 
@@ -176,7 +179,7 @@ fn fail_on_reserved_name_for_model() {
 
     errors.assert_is(DatamodelError::new_reserved_scalar_type_error(
         "DateTime",
-        Span::new(11, 19),
+        Span::new(11, 19, FileId(0)),
     ));
 }
 
@@ -188,5 +191,8 @@ fn fail_on_reserved_name_fo_custom_type() {
 
     let errors = parse_error(dml);
 
-    errors.assert_is(DatamodelError::new_reserved_scalar_type_error("Int", Span::new(10, 13)));
+    errors.assert_is(DatamodelError::new_reserved_scalar_type_error(
+        "Int",
+        Span::new(10, 13, FileId(0)),
+    ));
 }
@@ -0,0 +1,185 @@
+use datamodel::code_actions::{code_actions, CodeAction, TextEdit};
+use indoc::indoc;
+
+fn apply(schema: &str, action: &CodeAction) -> String {
+    let mut edits: Vec<&TextEdit> = action.edits.iter().collect();
+    edits.sort_by(|a, b| b.start.cmp(&a.start));
+
+    let mut result = schema.to_owned();
+    for edit in edits {
+        result.replace_range(edit.start..edit.end, &edit.text);
+    }
+
+    result
+}
+
+fn find_action<'a>(actions: &'a [CodeAction], title: &str) -> &'a CodeAction {
+    actions
+        .iter()
+        .find(|action| action.title == title)
+        .unwrap_or_else(|| panic!("No code action `{}` in {:?}", title, actions))
+}
+
+#[test]
+fn add_missing_back_relation_field() {
+    let schema = indoc! {r#"
+        model User {
+          id Int @id
+        }
+
+        model Post {
+          id     Int  @id
+          userId Int
+          user   User @relation(fields: [userId], references: [id])
+        }
+    "#};
+
+    let actions = code_actions(schema);
+    let action = find_action(&actions, "Add the opposite relation field `Post` to model `User`");
+
+    let expected = indoc! {r#"
+        model User {
+          id Int @id
+          Post Post[]
+        }
+
+        model Post {
+          id     Int  @id
+          userId Int
+          user   User @relation(fields: [userId], references: [id])
+        }
+    "#};
+
+    assert_eq!(apply(schema, action), expected);
+}
+
+#[test]
+fn add_missing_back_relation_field_to_a_schema_that_does_not_validate() {
+    let schema = indoc! {r#"
+        model User {
+          id Int @id
+        }
+
+        model Post {
+          id     Int  @id
+          userId Int
+          user   User @relation(fields: [userId], references: [id])
+        }
+
+        model Log {
+          message String
+        }
+    "#};
+
+    let actions = code_actions(schema);
+    let action = find_action(&actions, "Add the opposite relation field `Post` to model `User`");
+
+    assert!(apply(schema, action).contains("  id Int @id\n  Post Post[]\n}"));
+}
+
+#[test]
+fn name_ambiguous_relations_without_opposite_fields() {
+    let schema = indoc! {r#"
+        model User {
+          id Int @id
+        }
+
+        model Post {
+          id       Int  @id
+          authorId Int
+          editorId Int
+          author   User @relation(fields: [authorId], references: [id])
+          editor   User @relation(fields: [editorId], references: [id])
+        }
+    "#};
+
+    let actions = code_actions(schema);
+    let action = find_action(&actions, "Add the relation name \"PostAuthor\" to `author`");
+    let result = apply(schema, action);
+
+    assert!(result.contains("author   User @relation(\"PostAuthor\", fields: [authorId], references: [id])"));
+    assert!(result.contains("editor   User @relation(fields: [editorId], references: [id])"));
+    assert!(!actions
+        .iter()
+        .any(|action| action.title.starts_with("Add the opposite relation field")));
+}
+
+#[test]
+fn ambiguous_pairings_of_relation_fields_have_no_action() {
+    let schema = indoc! {r#"
+        model User {
+          id      Int    @id
+          written Post[]
+          edited  Post[]
+        }
+
+        model Post {
+          id       Int  @id
+          authorId Int
+          editorId Int
+          author   User @relation(fields: [authorId], references: [id])
+          editor   User @relation(fields: [editorId], references: [id])
+        }
+    "#};
+
+    let actions = code_actions(schema);
+
+    assert!(actions.is_empty(), "{:?}", actions);
+}
+
+#[test]
+fn ambiguous_self_relations_with_more_than_two_fields_have_no_action() {
+    let schema = indoc! {r#"
+        model User {
+          id        Int    @id
+          managerId Int?
+          manager   User?  @relation(fields: [managerId], references: [id])
+          reports   User[]
+          followers User[]
+        }
+    "#};
+
+    let actions = code_actions(schema);
+
+    assert!(actions.is_empty(), "{:?}", actions);
+}
+
+#[test]
+fn name_ambiguous_self_relations() {
+    let schema = indoc! {r#"
+        model User {
+          id        Int   @id
+          managerId Int?
+          manager   User? @relation(fields: [managerId], references: [id])
+          reports   User[]
+        }
+    "#};
+
+    let actions = code_actions(schema);
+    let action = find_action(
+        &actions,
+        "Name the self relation of `manager` and `reports` \"UserManager\"",
+    );
+    let result = apply(schema, action);
+
+    assert!(result.contains("manager   User? @relation(\"UserManager\", fields: [managerId], references: [id])"));
+    assert!(result.contains("reports   User[] @relation(\"UserManager\")"));
+    assert!(datamodel::parse_datamodel(&result).is_ok());
+}
+
+#[test]
+fn fix_string_generator_defaults_on_int_fields() {
+    let schema = indoc! {r#"
+        model User {
+          id Int @id @default(uuid())
+        }
+    "#};
+
+    let actions = code_actions(schema);
+
+    let action = find_action(&actions, "Change the type of `id` to String");
+    assert!(apply(schema, action).contains("id String @id @default(uuid())"));
+
+    let action = find_action(&actions, "Replace `uuid()` with `autoincrement()`");
+    assert!(apply(schema, action).contains("id Int @id @default(autoincrement())"));
+}
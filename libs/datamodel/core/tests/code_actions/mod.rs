@@ -0,0 +1,2 @@
+pub mod code_actions;
+pub mod rename;
@@ -0,0 +1,344 @@
+use datamodel::code_actions::TextEdit;
+use datamodel::rename::{rename, RenameTarget};
+use indoc::indoc;
+
+fn apply(schema: &str, edits: &[TextEdit]) -> String {
+    let mut edits: Vec<&TextEdit> = edits.iter().collect();
+    edits.sort_by(|a, b| b.start.cmp(&a.start));
+
+    let mut result = schema.to_owned();
+    for edit in edits {
+        result.replace_range(edit.start..edit.end, &edit.text);
+    }
+
+    result
+}
+
+fn field(model: &str, field: &str) -> RenameTarget {
+    RenameTarget::Field {
+        model: model.to_owned(),
+        field: field.to_owned(),
+    }
+}
+
+#[test]
+fn rename_model_rewrites_relation_field_types() {
+    let schema = indoc! {r#"
+        model User {
+          id    Int    @id
+          posts Post[]
+        }
+
+        model Post {
+          id       Int   @id
+          authorId Int?
+          author   User? @relation(fields: [authorId], references: [id])
+        }
+    "#};
+
+    let edits = rename(schema, &RenameTarget::Model("User".to_owned()), "Author", false).unwrap();
+
+    let expected = indoc! {r#"
+        model Author {
+          id    Int    @id
+          posts Post[]
+        }
+
+        model Post {
+          id       Int   @id
+          authorId Int?
+          author   Author? @relation(fields: [authorId], references: [id])
+        }
+    "#};
+
+    assert_eq!(apply(schema, &edits), expected);
+}
+
+#[test]
+fn rename_model_can_preserve_the_table_name() {
+    let schema = indoc! {r#"
+        model User {
+          id Int @id
+        }
+    "#};
+
+    let edits = rename(schema, &RenameTarget::Model("User".to_owned()), "Account", true).unwrap();
+
+    let expected = indoc! {r#"
+        model Account {
+          id Int @id
+          @@map("User")
+        }
+    "#};
+
+    assert_eq!(apply(schema, &edits), expected);
+}
+
+#[test]
+fn rename_model_preserving_the_table_name_keeps_implicit_relation_names() {
+    let schema = indoc! {r#"
+        model User {
+          id         Int        @id
+          categories Category[]
+          posts      Post[]     @relation("authored")
+        }
+
+        model Category {
+          id    Int    @id
+          users User[]
+        }
+
+        model Post {
+          id       Int  @id
+          authorId Int
+          author   User @relation("authored", fields: [authorId], references: [id])
+        }
+    "#};
+
+    let edits = rename(schema, &RenameTarget::Model("User".to_owned()), "Member", true).unwrap();
+
+    let expected = indoc! {r#"
+        model Member {
+          id         Int        @id
+          categories Category[] @relation("CategoryToUser")
+          posts      Post[]     @relation("authored")
+          @@map("User")
+        }
+
+        model Category {
+          id    Int    @id
+          users Member[] @relation("CategoryToUser")
+        }
+
+        model Post {
+          id       Int  @id
+          authorId Int
+          author   Member @relation("authored", fields: [authorId], references: [id])
+        }
+    "#};
+
+    let result = apply(schema, &edits);
+
+    assert_eq!(result, expected);
+
+    let renamed = datamodel::parse_datamodel(&result).unwrap().subject;
+    let original = datamodel::parse_datamodel(schema).unwrap().subject;
+
+    let relation_name = |datamodel: &datamodel::Datamodel, model: &str, field: &str| {
+        datamodel
+            .find_model(model)
+            .unwrap()
+            .find_relation_field(field)
+            .unwrap()
+            .relation_info
+            .name
+            .clone()
+    };
+
+    assert_eq!(
+        relation_name(&original, "Category", "users"),
+        relation_name(&renamed, "Category", "users")
+    );
+}
+
+#[test]
+fn rename_mapped_model_does_not_add_another_map() {
+    let schema = indoc! {r#"
+        model User {
+          id Int @id
+
+          @@map("users")
+        }
+    "#};
+
+    let edits = rename(schema, &RenameTarget::Model("User".to_owned()), "Account", true).unwrap();
+
+    let expected = indoc! {r#"
+        model Account {
+          id Int @id
+
+          @@map("users")
+        }
+    "#};
+
+    assert_eq!(apply(schema, &edits), expected);
+}
+
+#[test]
+fn rename_scalar_field_rewrites_relations_and_indexes() {
+    let schema = indoc! {r#"
+        model User {
+          id    Int    @id
+          email String
+          posts Post[]
+
+          @@unique([email])
+        }
+
+        model Post {
+          id          Int    @id
+          authorEmail String
+          author      User   @relation(fields: [authorEmail], references: [email])
+
+          @@index([authorEmail])
+        }
+    "#};
+
+    let edits = rename(schema, &field("User", "email"), "mail", false).unwrap();
+
+    let expected = indoc! {r#"
+        model User {
+          id    Int    @id
+          mail String
+          posts Post[]
+
+          @@unique([mail])
+        }
+
+        model Post {
+          id          Int    @id
+          authorEmail String
+          author      User   @relation(fields: [authorEmail], references: [mail])
+
+          @@index([authorEmail])
+        }
+    "#};
+
+    assert_eq!(apply(schema, &edits), expected);
+
+    let edits = rename(schema, &field("Post", "authorEmail"), "email", true).unwrap();
+
+    let expected = indoc! {r#"
+        model User {
+          id    Int    @id
+          email String
+          posts Post[]
+
+          @@unique([email])
+        }
+
+        model Post {
+          id          Int    @id
+          email String @map("authorEmail")
+          author      User   @relation(fields: [email], references: [email])
+
+          @@index([email])
+        }
+    "#};
+
+    assert_eq!(apply(schema, &edits), expected);
+}
+
+#[test]
+fn rename_enum_rewrites_field_types() {
+    let schema = indoc! {r#"
+        model User {
+          id    Int    @id
+          roles Role[]
+          role  Role?
+        }
+
+        enum Role {
+          ADMIN
+          USER
+        }
+    "#};
+
+    let edits = rename(schema, &RenameTarget::Enum("Role".to_owned()), "UserRole", true).unwrap();
+
+    let expected = indoc! {r#"
+        model User {
+          id    Int    @id
+          roles UserRole[]
+          role  UserRole?
+        }
+
+        enum UserRole {
+          ADMIN
+          USER
+          @@map("Role")
+        }
+    "#};
+
+    assert_eq!(apply(schema, &edits), expected);
+}
+
+#[test]
+fn rename_to_an_existing_name_is_an_error() {
+    let schema = indoc! {r#"
+        model User {
+          id   Int    @id
+          name String
+        }
+
+        model Post {
+          id Int @id
+        }
+    "#};
+
+    let err = rename(schema, &RenameTarget::Model("User".to_owned()), "Post", false).unwrap_err();
+    assert_eq!(
+        err.errors[0].description(),
+        "The model \"Post\" cannot be defined because a model with that name already exists."
+    );
+
+    let err = rename(schema, &field("User", "name"), "id", false).unwrap_err();
+    assert_eq!(
+        err.errors[0].description(),
+        "Field \"id\" is already defined on model \"User\"."
+    );
+
+    let err = rename(schema, &field("User", "email"), "mail", false).unwrap_err();
+    assert_eq!(
+        err.errors[0].description(),
+        "Error validating: Field `email` not found on model `User`."
+    );
+
+    let err = rename(schema, &RenameTarget::Model("Comment".to_owned()), "Note", false).unwrap_err();
+    assert_eq!(err.errors[0].span().start, 0);
+    assert_eq!(err.errors[0].span().end, schema.len());
+}
+
+#[test]
+fn rename_to_an_invalid_name_is_an_error() {
+    let schema = indoc! {r#"
+        model User {
+          id   Int    @id
+          name String
+        }
+
+        model Post {
+          id     Int  @id
+          userId Int
+          user   User @relation(fields: [userId], references: [id])
+        }
+    "#};
+
+    let user = RenameTarget::Model("User".to_owned());
+
+    let err = rename(schema, &user, "My User", false).unwrap_err();
+    assert_eq!(
+        err.errors[0].description(),
+        "Error validating: `My User` is not a valid name. Names must start with a letter or a digit, followed by letters, digits, `_` or `-`."
+    );
+    assert_eq!(&schema[err.errors[0].span().start..err.errors[0].span().end], "User");
+
+    let err = rename(schema, &user, "String", false).unwrap_err();
+    assert_eq!(
+        err.errors[0].description(),
+        "\"String\" is a reserved scalar type name and can not be used."
+    );
+
+    let err = rename(schema, &user, "PrismaClient", false).unwrap_err();
+    assert_eq!(
+        err.errors[0].description(),
+        "Error validating: The model name `PrismaClient` is invalid. It is a reserved name. Please change it."
+    );
+
+    // `Post` is the generated back relation field of `User`.
+    let err = rename(schema, &field("User", "name"), "Post", false).unwrap_err();
+    assert_eq!(
+        err.errors[0].description(),
+        "Field \"Post\" is already defined on model \"User\"."
+    );
+}
@@ -24,12 +24,14 @@ pub trait ScalarFieldAsserts {
     fn assert_is_id(&self) -> &Self;
     fn assert_is_unique(&self, b: bool) -> &Self;
     fn assert_is_updated_at(&self, b: bool) -> &Self;
+    fn assert_is_version(&self, b: bool) -> &Self;
 }
 
 pub trait RelationFieldAsserts {
     fn assert_relation_name(&self, t: &str) -> &Self;
     fn assert_relation_to(&self, t: &str) -> &Self;
     fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self;
+    fn assert_relation_update_strategy(&self, t: dml::OnUpdateStrategy) -> &Self;
     fn assert_relation_referenced_fields(&self, t: &[&str]) -> &Self;
     fn assert_relation_base_fields(&self, t: &[&str]) -> &Self;
 }
@@ -151,6 +153,11 @@ impl ScalarFieldAsserts for dml::ScalarField {
         assert_eq!(self.is_updated_at, b);
         self
     }
+
+    fn assert_is_version(&self, b: bool) -> &Self {
+        assert_eq!(self.is_version, b);
+        self
+    }
 }
 
 impl FieldAsserts for dml::RelationField {
@@ -186,6 +193,11 @@ impl RelationFieldAsserts for dml::RelationField {
         self
     }
 
+    fn assert_relation_update_strategy(&self, t: dml::OnUpdateStrategy) -> &Self {
+        assert_eq!(self.relation_info.on_update, t);
+        self
+    }
+
     fn assert_relation_referenced_fields(&self, t: &[&str]) -> &Self {
         assert_eq!(self.relation_info.references, t);
         self
@@ -135,7 +135,7 @@ generator js1 {
         diagnostics.assert_is(DatamodelError::GeneratorArgumentNotFound {
             argument_name: String::from("provider"),
             generator_name: String::from("js1"),
-            span: datamodel::ast::Span::new(1, 73),
+            span: datamodel::ast::Span::new(1, 73, datamodel::ast::FileId(0)),
         });
     } else {
         panic!("Expected error.")
@@ -157,7 +157,7 @@ fn nice_error_for_unknown_generator_preview_feature() {
         diagnostics.assert_is(DatamodelError::new_preview_feature_not_known_error(
             "foo",
             Vec::from(GENERATOR_PREVIEW_FEATURES),
-            datamodel::ast::Span::new(84, 91),
+            datamodel::ast::Span::new(84, 91, datamodel::ast::FileId(0)),
         ));
     } else {
         panic!("Expected error.")
@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::ast::Span;
+use datamodel::ast::{FileId, Span};
 use datamodel::diagnostics::DatamodelWarning;
 
 #[test]
@@ -16,7 +16,7 @@ fn nice_warning_for_deprecated_generator_preview_feature() {
     res.warnings
         .assert_is(DatamodelWarning::new_deprecated_preview_feature_warning(
             "middlewares",
-            Span::new(88, 103),
+            Span::new(88, 103, FileId(0)),
         ));
 }
 
@@ -32,7 +32,9 @@ fn nice_warning_for_provider_array_deprecation() {
 
     res.warnings
         .assert_is(DatamodelWarning::new_deprecated_provider_array_warning(Span::new(
-            29, 51,
+            29,
+            51,
+            FileId(0),
         )));
 }
 
@@ -48,6 +50,8 @@ fn nice_warning_for_provider_array_deprecation_on_single_element_in_array() {
 
     res.warnings
         .assert_is(DatamodelWarning::new_deprecated_provider_array_warning(Span::new(
-            29, 41,
+            29,
+            41,
+            FileId(0),
         )));
 }
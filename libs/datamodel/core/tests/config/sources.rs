@@ -1,5 +1,9 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError, StringFromEnvVar};
+use datamodel::{
+    ast::{FileId, Span},
+    diagnostics::DatamodelError,
+    ConnectionConfig, ReferentialIntegrity, SslMode, StringFromEnvVar,
+};
 use pretty_assertions::assert_eq;
 use serial_test::serial;
 
@@ -22,11 +26,11 @@ datasource db2 {
     diagnostics.assert_length(2);
     diagnostics.assert_is_at(
         0,
-        DatamodelError::new_source_validation_error("You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.", "db1", Span::new(1, 82)),
+        DatamodelError::new_source_validation_error("You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.", "db1", Span::new(1, 82, FileId(0))),
     );
     diagnostics.assert_is_at(
         1,
-        DatamodelError::new_source_validation_error("You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.", "db2", Span::new(84, 155)),
+        DatamodelError::new_source_validation_error("You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.", "db2", Span::new(84, 155, FileId(0))),
     );
 }
 
@@ -46,7 +50,7 @@ fn must_forbid_env_functions_in_provider_field() {
     let diagnostics = config.err().expect("This must error");
     diagnostics.assert_is(DatamodelError::new_functional_evaluation_error(
         "A datasource must not use the env() function in the provider argument.",
-        Span::new(9, 108),
+        Span::new(9, 108, FileId(0)),
     ));
 }
 
@@ -65,7 +69,7 @@ fn must_forbid_env_functions_in_provider_field_even_if_missing() {
     let diagnostics = config.err().expect("This must error");
     diagnostics.assert_is(DatamodelError::new_functional_evaluation_error(
         "A datasource must not use the env() function in the provider argument.",
-        Span::new(9, 108),
+        Span::new(9, 108, FileId(0)),
     ));
 }
 
@@ -83,7 +87,7 @@ fn must_error_for_empty_urls() {
     diagnostics.assert_is(DatamodelError::new_source_validation_error(
         "You must provide a nonempty URL for the datasource `myds`.",
         "myds",
-        Span::new(77, 79),
+        Span::new(77, 79, FileId(0)),
     ));
 }
 
@@ -101,7 +105,7 @@ fn must_error_for_empty_provider_arrays() {
     let diagnostics = config.err().expect("This must error");
     diagnostics.assert_is(DatamodelError::new_validation_error(
         "This line is not a valid definition within a datasource.",
-        Span::new(39, 53),
+        Span::new(39, 53, FileId(0)),
     ));
 }
 
@@ -121,7 +125,7 @@ fn must_error_for_empty_urls_derived_from_env_vars() {
     diagnostics.assert_is(DatamodelError::new_source_validation_error(
         "You must provide a nonempty URL for the datasource `myds`. The environment variable `DB_URL` resolved to an empty string.",
         "myds",
-        Span::new(77, 90),
+        Span::new(77, 90, FileId(0)),
     ));
 }
 
@@ -139,7 +143,7 @@ fn must_error_if_wrong_protocol_is_used_for_mysql() {
     diagnostics.assert_is(DatamodelError::new_source_validation_error(
         "The URL for datasource `myds` must start with the protocol `mysql://`.",
         "myds",
-        Span::new(76, 91),
+        Span::new(76, 91, FileId(0)),
     ));
 }
 
@@ -157,7 +161,7 @@ fn must_error_if_wrong_protocol_is_used_for_postgresql() {
     diagnostics.assert_is(DatamodelError::new_source_validation_error(
         "The URL for datasource `myds` must start with the protocol `postgresql://`.",
         "myds",
-        Span::new(81, 91),
+        Span::new(81, 91, FileId(0)),
     ));
 }
 
@@ -175,7 +179,7 @@ fn must_error_if_wrong_protocol_is_used_for_sqlite() {
     diagnostics.assert_is(DatamodelError::new_source_validation_error(
         "The URL for datasource `myds` must start with the protocol `file:`.",
         "myds",
-        Span::new(77, 87),
+        Span::new(77, 87, FileId(0)),
     ));
 }
 
@@ -224,7 +228,7 @@ fn must_error_if_env_var_is_missing() {
     let diagnostics = result.err().unwrap();
     diagnostics.assert_is(DatamodelError::new_environment_functional_evaluation_error(
         "DATABASE_URL",
-        Span::new(75, 94),
+        Span::new(75, 94, FileId(0)),
     ));
 }
 
@@ -312,7 +316,7 @@ fn fail_to_load_sources_for_invalid_source() {
     if let Err(diagnostics) = res {
         diagnostics.assert_is(DatamodelError::DatasourceProviderNotKnownError {
             source_name: String::from("AStrangeHalfMongoDatabase"),
-            span: datamodel::ast::Span::new(49, 76),
+            span: datamodel::ast::Span::new(49, 76, datamodel::ast::FileId(0)),
         });
     } else {
         panic!("Expected error.")
@@ -332,7 +336,7 @@ fn fail_when_preview_features_are_declared() {
     let errors = parse_error(dml);
     errors.assert_is(DatamodelError::new_connector_error(
         "Preview features are only supported in the generator block. Please move this field to the generator block.",
-        Span::new(99, 106),
+        Span::new(99, 106, FileId(0)),
     ));
 }
 
@@ -345,7 +349,7 @@ fn fail_when_no_source_is_declared() {
     if let Err(diagnostics) = res.validate_that_one_datasource_is_provided() {
         diagnostics.assert_is(DatamodelError::ValidationError {
             message: "You defined no datasource. You must define exactly one datasource.".to_string(),
-            span: datamodel::ast::Span::new(0, 0),
+            span: datamodel::ast::Span::new(0, 0, datamodel::ast::FileId(0)),
         });
     } else {
         panic!("Expected error.")
@@ -373,6 +377,369 @@ fn microsoft_sql_server_preview_feature_must_work() {
     assert!(generator.preview_features.contains(&String::from("microsoftSqlServer")));
 }
 
+#[test]
+fn referential_integrity_must_be_parsed() {
+    let schema = r#"
+        datasource db {
+            provider = "mysql"
+            url = "mysql://localhost"
+            referentialIntegrity = "prisma"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let source = config.datasources.first().unwrap();
+
+    assert_eq!(source.referential_integrity, ReferentialIntegrity::Prisma);
+
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+    let expected = r#"[
+      {
+        "name": "db",
+        "provider": ["mysql"],
+        "activeProvider": "mysql",
+        "url": {
+          "fromEnvVar": null,
+          "value": "mysql://localhost"
+        },
+        "referentialIntegrity": "prisma"
+      }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn referential_integrity_defaults_to_foreign_keys() {
+    let schema = r#"
+        datasource db {
+            provider = "mysql"
+            url = "mysql://localhost"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let source = config.datasources.first().unwrap();
+
+    assert_eq!(source.referential_integrity, ReferentialIntegrity::ForeignKeys);
+}
+
+#[test]
+fn invalid_referential_integrity_must_error() {
+    let schema = r#"
+        datasource db {
+            provider = "mysql"
+            url = "mysql://localhost"
+            referentialIntegrity = "triggers"
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema);
+    let diagnostics = config.err().expect("This must error");
+
+    diagnostics.assert_is_message(
+        "Error validating datasource `db`: Invalid referential integrity setting `triggers`. Valid settings are `foreignKeys` and `prisma`.",
+    );
+}
+
+#[test]
+fn connection_properties_must_be_parsed() {
+    let schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            connectionLimit = 10
+            poolTimeout = 20
+            connectTimeout = 5
+            sslMode = "require"
+            statementCacheSize = 0
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let source = config.datasources.first().unwrap();
+
+    let expected = ConnectionConfig {
+        connection_limit: Some(10),
+        pool_timeout: Some(20),
+        connect_timeout: Some(5),
+        ssl_mode: Some(SslMode::Require),
+        statement_cache_size: Some(0),
+    };
+
+    assert_eq!(source.connection, expected);
+
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+    let expected = r#"[
+      {
+        "name": "db",
+        "provider": ["postgresql"],
+        "activeProvider": "postgresql",
+        "url": {
+          "fromEnvVar": null,
+          "value": "postgresql://localhost"
+        },
+        "connectionLimit": 10,
+        "poolTimeout": 20,
+        "connectTimeout": 5,
+        "sslMode": "require",
+        "statementCacheSize": 0
+      }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn connection_properties_default_to_none() {
+    let schema = r#"
+        datasource db {
+            provider = "mysql"
+            url = "mysql://localhost"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let source = config.datasources.first().unwrap();
+
+    assert!(source.connection.is_empty());
+}
+
+#[test]
+fn invalid_connection_limit_must_error() {
+    let schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            connectionLimit = 0
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema);
+    let diagnostics = config.err().expect("This must error");
+    let start = schema.find("0\n").unwrap();
+
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "Invalid value `0` for `connectionLimit`. Expected an integer between 1 and 4294967295.",
+        "db",
+        Span::new(start, start + 1, FileId(0)),
+    ));
+}
+
+#[test]
+fn invalid_ssl_mode_must_error() {
+    let schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            sslMode = "always"
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema);
+    let diagnostics = config.err().expect("This must error");
+    let start = schema.find("\"always\"").unwrap();
+
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "Invalid ssl mode `always`. Valid modes are `disable`, `prefer` and `require`.",
+        "db",
+        Span::new(start, start + "\"always\"".len(), FileId(0)),
+    ));
+}
+
+#[test]
+fn all_invalid_connection_properties_must_error() {
+    let schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            connectionLimit = 0
+            poolTimeout = -1
+            sslMode = "always"
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema);
+    let diagnostics = config.err().expect("This must error");
+    let limit_start = schema.find("0\n").unwrap();
+    let timeout_start = schema.find("-1\n").unwrap();
+    let ssl_mode_start = schema.find("\"always\"").unwrap();
+
+    diagnostics.assert_length(3);
+    diagnostics.assert_is_at(
+        0,
+        DatamodelError::new_source_validation_error(
+            "Invalid value `0` for `connectionLimit`. Expected an integer between 1 and 4294967295.",
+            "db",
+            Span::new(limit_start, limit_start + 1, FileId(0)),
+        ),
+    );
+    diagnostics.assert_is_at(
+        1,
+        DatamodelError::new_source_validation_error(
+            "Invalid value `-1` for `poolTimeout`. Expected an integer between 0 and 4294967295.",
+            "db",
+            Span::new(timeout_start, timeout_start + 2, FileId(0)),
+        ),
+    );
+    diagnostics.assert_is_at(
+        2,
+        DatamodelError::new_source_validation_error(
+            "Invalid ssl mode `always`. Valid modes are `disable`, `prefer` and `require`.",
+            "db",
+            Span::new(ssl_mode_start, ssl_mode_start + "\"always\"".len(), FileId(0)),
+        ),
+    );
+}
+
+#[test]
+fn unsupported_connection_properties_must_error() {
+    let schema = r#"
+        datasource db {
+            provider = "sqlite"
+            url = "file:dev.db"
+            poolTimeout = 10
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema);
+    let diagnostics = config.err().expect("This must error");
+    let start = schema.find("10\n").unwrap();
+
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `poolTimeout` property is not supported by the `sqlite` provider.",
+        "db",
+        Span::new(start, start + 2, FileId(0)),
+    ));
+
+    let schema = r#"
+        datasource db {
+            provider = "mysql"
+            url = "mysql://localhost"
+            sslMode = "require"
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema);
+    let diagnostics = config.err().expect("This must error");
+    let start = schema.find("\"require\"").unwrap();
+
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `sslMode` property is not supported by the `mysql` provider.",
+        "db",
+        Span::new(start, start + "\"require\"".len(), FileId(0)),
+    ));
+}
+
+fn connection_url_for(provider: &str, url: &str, properties: &str) -> String {
+    let schema = format!(
+        "datasource db {{\n  provider = \"{}\"\n  url = \"{}\"\n{}\n}}",
+        provider, url, properties
+    );
+    let config = datamodel::parse_configuration(&schema).unwrap();
+
+    config.subject.datasources.first().unwrap().connection_url()
+}
+
+#[test]
+fn properties_are_added_as_query_parameters() {
+    let url = connection_url_for(
+        "postgresql",
+        "postgresql://localhost/db",
+        "connectionLimit = 10\nsslMode = \"require\"\nstatementCacheSize = 0",
+    );
+
+    assert_eq!(
+        url,
+        "postgresql://localhost/db?connection_limit=10&sslmode=require&statement_cache_size=0"
+    );
+}
+
+#[test]
+fn properties_are_appended_to_existing_query_parameters() {
+    let url = connection_url_for("mysql", "mysql://localhost/db?socket_timeout=5", "poolTimeout = 20");
+
+    assert_eq!(url, "mysql://localhost/db?socket_timeout=5&pool_timeout=20");
+}
+
+#[test]
+fn query_parameters_override_properties() {
+    let url = connection_url_for(
+        "postgresql",
+        "postgresql://localhost/db?connection_limit=1&sslmode=disable",
+        "connectionLimit = 10\nsslMode = \"require\"\nconnectTimeout = 5",
+    );
+
+    assert_eq!(
+        url,
+        "postgresql://localhost/db?connection_limit=1&sslmode=disable&connect_timeout=5"
+    );
+}
+
+#[test]
+fn query_parameters_are_matched_case_insensitively() {
+    let url = connection_url_for(
+        "postgresql",
+        "postgresql://localhost/db?SSLMODE=disable&Connection_Limit=1",
+        "connectionLimit = 10\nsslMode = \"require\"",
+    );
+
+    assert_eq!(url, "postgresql://localhost/db?SSLMODE=disable&Connection_Limit=1");
+}
+
+#[test]
+fn duplicate_query_parameters_are_kept_as_they_are() {
+    let url = connection_url_for(
+        "mysql",
+        "mysql://localhost/db?connection_limit=1&connection_limit=2",
+        "connectionLimit = 10",
+    );
+
+    assert_eq!(url, "mysql://localhost/db?connection_limit=1&connection_limit=2");
+}
+
+#[test]
+fn properties_are_added_as_jdbc_parameters() {
+    let url = connection_url_for(
+        "sqlserver",
+        "sqlserver://localhost:1433;database=master;",
+        "connectionLimit = 10\npoolTimeout = 20\nconnectTimeout = 5",
+    );
+
+    assert_eq!(
+        url,
+        "sqlserver://localhost:1433;database=master;connectionLimit=10;poolTimeout=20;connectTimeout=5"
+    );
+}
+
+#[test]
+fn jdbc_parameters_override_properties() {
+    let url = connection_url_for(
+        "sqlserver",
+        "sqlserver://localhost:1433;CONNECTIONLIMIT=1;connectionLimit=2",
+        "connectionLimit = 10\npoolTimeout = 20",
+    );
+
+    assert_eq!(
+        url,
+        "sqlserver://localhost:1433;CONNECTIONLIMIT=1;connectionLimit=2;poolTimeout=20"
+    );
+}
+
+#[test]
+fn sqlite_paths_are_kept_as_they_are() {
+    let url = connection_url_for("sqlite", "file:dev.db", "connectionLimit = 1");
+
+    assert_eq!(url, "file:dev.db?connection_limit=1");
+}
+
+#[test]
+fn urls_without_properties_are_unchanged() {
+    let url = connection_url_for("postgresql", "postgresql://localhost/db?sslmode=prefer", "");
+
+    assert_eq!(url, "postgresql://localhost/db?sslmode=prefer");
+}
+
 fn assert_eq_json(a: &str, b: &str) {
     let json_a: serde_json::Value = serde_json::from_str(a).expect("The String a was not valid JSON.");
     let json_b: serde_json::Value = serde_json::from_str(b).expect("The String b was not valid JSON.");
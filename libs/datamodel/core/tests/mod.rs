@@ -1,6 +1,7 @@
 pub mod attributes;
 pub mod base;
 pub mod capabilities;
+pub mod code_actions;
 pub mod common;
 pub mod config;
 pub mod functions;
@@ -1,2 +1,3 @@
 pub mod literals;
+pub mod multi_file;
 pub mod nice_errors;
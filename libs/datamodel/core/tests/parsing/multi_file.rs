@@ -0,0 +1,211 @@
+use crate::common::*;
+use datamodel::ast::{FileId, SchemaFiles, Span};
+use datamodel::diagnostics::{DatamodelError, Diagnostics};
+
+fn blog_files() -> SchemaFiles {
+    let mut files = SchemaFiles::new();
+
+    files.add(
+        "schema/user.prisma",
+        r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+        "#,
+    );
+
+    files.add(
+        "schema/post.prisma",
+        r#"
+        model Post {
+            id       Int    @id
+            authorId Int
+            author   User   @relation(fields: [authorId], references: [id])
+            status   Status
+        }
+
+        enum Status {
+            DRAFT
+            PUBLISHED
+        }
+        "#,
+    );
+
+    files
+}
+
+#[test]
+fn relations_and_enums_resolve_across_files() {
+    let datamodel = datamodel::parse_datamodel_files(&blog_files()).unwrap().subject;
+
+    datamodel
+        .assert_has_model("User")
+        .assert_has_relation_field("posts")
+        .assert_relation_to("Post");
+
+    datamodel
+        .assert_has_model("Post")
+        .assert_has_relation_field("author")
+        .assert_relation_to("User");
+
+    datamodel.assert_has_enum("Status");
+}
+
+#[test]
+fn spans_refer_to_the_file_they_were_parsed_from() {
+    let ast = datamodel::parse_schema_ast_files(&blog_files()).unwrap();
+
+    assert_eq!(ast.find_model("User").unwrap().span.file_id, FileId(0));
+    assert_eq!(ast.find_model("Post").unwrap().span.file_id, FileId(1));
+    assert_eq!(
+        ast.find_field("Post", "author").unwrap().attributes[0].span.file_id,
+        FileId(1)
+    );
+}
+
+#[test]
+fn validation_errors_carry_the_file_of_their_span() {
+    let mut files = blog_files();
+    let file_id = files.add(
+        "schema/comment.prisma",
+        r#"
+        model Comment {
+            id     Int  @id
+            post   Post @relation(fields: [postId], references: [id])
+        }
+        "#,
+    );
+
+    let errors = datamodel::parse_datamodel_files(&files).unwrap_err();
+
+    assert!(errors.has_errors());
+    assert!(errors.to_error_iter().all(|err| err.span().file_id == file_id));
+    assert!(errors
+        .to_pretty_string_for_files(&files)
+        .contains("schema/comment.prisma"));
+}
+
+#[test]
+fn errors_outside_of_the_files_are_printed_without_an_excerpt() {
+    let files = blog_files();
+    let mut errors = Diagnostics::new();
+    errors.push_error(DatamodelError::new_validation_error(
+        "Something went wrong.",
+        Span::empty(),
+    ));
+
+    let message = errors.to_pretty_string_for_files(&files);
+
+    assert!(message.contains("Something went wrong."));
+    assert!(!message.contains("schema/"));
+}
+
+#[test]
+fn models_defined_in_two_files_are_duplicates() {
+    let mut files = blog_files();
+    files.add(
+        "schema/other_user.prisma",
+        r#"
+        model User {
+            id Int @id
+        }
+        "#,
+    );
+
+    let errors = datamodel::parse_datamodel_files(&files).unwrap_err();
+
+    errors.assert_is_message("The model \"User\" cannot be defined because a model with that name already exists.");
+}
+
+#[test]
+fn parser_errors_carry_the_file_of_their_span() {
+    let mut files = blog_files();
+    let file_id = files.add(
+        "schema/broken.prisma",
+        "model Broken {\n  id Int @id\n  name:String\n}\n",
+    );
+
+    let errors = datamodel::parse_schema_ast_files(&files).unwrap_err();
+
+    assert_eq!(errors.errors[0].span().file_id, file_id);
+}
+
+#[test]
+fn the_configuration_is_found_in_any_file() {
+    let mut files = blog_files();
+    files.add(
+        "schema/config.prisma",
+        r#"
+        datasource db {
+            provider = "postgresql"
+            url      = "postgresql://localhost/blog"
+        }
+        "#,
+    );
+
+    let config = datamodel::parse_configuration_files(&files).unwrap().subject;
+
+    assert_eq!(config.datasources.len(), 1);
+    assert_eq!(config.datasources[0].name, "db");
+}
+
+#[test]
+fn imports_are_not_part_of_the_schema() {
+    let schema = r#"
+        import "./post.prisma"
+        import "./user.prisma"
+
+        model Comment {
+            id Int @id
+        }
+    "#;
+
+    assert_eq!(
+        datamodel::ast::parser::parse_imports(schema),
+        vec!["./post.prisma".to_owned(), "./user.prisma".to_owned()]
+    );
+
+    let datamodel = datamodel::parse_datamodel(schema).unwrap().subject;
+
+    assert_eq!(datamodel.models().count(), 1);
+}
+
+#[test]
+fn imported_files_are_loaded_once_relative_to_the_importing_file() {
+    let dir = std::env::temp_dir().join(format!("multi_file_imports_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("models")).unwrap();
+
+    std::fs::write(
+        dir.join("schema.prisma"),
+        "import \"models/post.prisma\"\nimport \"models/user.prisma\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("models/post.prisma"),
+        "import \"user.prisma\"\n\nmodel Post {\n  id       Int  @id\n  authorId Int\n  author   User @relation(fields: [authorId], references: [id])\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("models/user.prisma"),
+        "model User {\n  id    Int    @id\n  posts Post[]\n}\n",
+    )
+    .unwrap();
+
+    let files = SchemaFiles::load(dir.join("schema.prisma")).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let names: Vec<&str> = files.iter().map(|(_, file)| file.name.as_str()).collect();
+
+    assert_eq!(names.len(), 3);
+    assert!(names[0].ends_with("schema.prisma"));
+    assert!(names[1].ends_with("post.prisma"));
+    assert!(names[2].ends_with("user.prisma"));
+
+    let datamodel = datamodel::parse_datamodel_files(&files).unwrap().subject;
+
+    datamodel
+        .assert_has_model("Post")
+        .assert_has_relation_field("author")
+        .assert_relation_to("User");
+}
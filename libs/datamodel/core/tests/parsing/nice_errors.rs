@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::ast::Span;
+use datamodel::ast::{FileId, Span};
 use datamodel::diagnostics::DatamodelError;
 
 #[test]
@@ -14,7 +14,7 @@ fn nice_error_for_missing_model_keyword() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "This block is invalid. It does not start with any known Prisma schema keyword. Valid keywords include 'model', 'enum', 'datasource' and 'generator'.",
-        Span::new(5, 36),
+        Span::new(5, 36, FileId(0)),
     ));
 }
 
@@ -33,7 +33,7 @@ fn nice_error_for_missing_model_keyword_2() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "This block is invalid. It does not start with any known Prisma schema keyword. Valid keywords include 'model', 'enum', 'datasource' and 'generator'.",
-        Span::new(47, 70),
+        Span::new(47, 70, FileId(0)),
     ));
 }
 
@@ -50,7 +50,7 @@ fn nice_error_on_incorrect_enum_field() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "The character `-` is not allowed in Enum Value names.",
-        Span::new(25, 31),
+        Span::new(25, 31, FileId(0)),
     ));
 }
 
@@ -68,7 +68,7 @@ fn nice_error_missing_type() {
     error.assert_is(DatamodelError::new_model_validation_error(
         "This field declaration is invalid. It is either missing a name or a type.",
         "User",
-        Span::new(45, 50),
+        Span::new(45, 50, FileId(0)),
     ));
 }
 
@@ -84,7 +84,7 @@ fn nice_error_missing_attribute_name() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "The name of a Attribute must not be empty.",
-        Span::new(38, 38),
+        Span::new(38, 38, FileId(0)),
     ));
 }
 
@@ -102,14 +102,14 @@ fn nice_error_missing_braces() {
         0,
         DatamodelError::new_validation_error(
             "This line is invalid. It does not start with any known Prisma schema keyword.",
-            Span::new(5, 16),
+            Span::new(5, 16, FileId(0)),
         ),
     );
     error.assert_is_at(
         1,
         DatamodelError::new_validation_error(
             "This line is invalid. It does not start with any known Prisma schema keyword.",
-            Span::new(24, 35),
+            Span::new(24, 35, FileId(0)),
         ),
     );
 }
@@ -125,7 +125,7 @@ fn nice_error_broken_field_type_legacy_list() {
 
     error.assert_is(DatamodelError::new_legacy_parser_error(
         "To specify a list, please use `Type[]` instead of `[Type]`.",
-        Span::new(29, 34),
+        Span::new(29, 34, FileId(0)),
     ));
 }
 
@@ -140,7 +140,7 @@ fn nice_error_broken_field_type_legacy_colon() {
 
     error.assert_is(DatamodelError::new_legacy_parser_error(
         "Field declarations don't require a `:`.",
-        Span::new(28, 29),
+        Span::new(28, 29, FileId(0)),
     ));
 }
 
@@ -155,12 +155,13 @@ fn nice_error_broken_field_type_legacy_required() {
 
     error.assert_is(DatamodelError::new_legacy_parser_error(
         "Fields are required by default, `!` is no longer required.",
-        Span::new(29, 33),
+        Span::new(29, 33, FileId(0)),
     ));
 }
 
 #[test]
-fn nice_error_legacy_model_decl() {
+fn nice_error_attributes_in_composite_type() {
+    // `type` declares a composite type, not a model, so its fields can't have attributes.
     let dml = r#"
     type User {
         id Int @id
@@ -168,10 +169,7 @@ fn nice_error_legacy_model_decl() {
 
     let error = parse_error(dml);
 
-    error.assert_is(DatamodelError::new_legacy_parser_error(
-        "Model declarations have to be indicated with the `model` keyword.",
-        Span::new(5, 9),
-    ));
+    error.assert_is_message("Error validating: Fields of composite types do not support attributes.");
 }
 
 #[test]
@@ -189,7 +187,7 @@ fn nice_error_in_case_of_literal_type_in_env_var() {
         "String",
         "literal",
         "DATABASE_URL",
-        Span::new(67, 79),
+        Span::new(67, 79, FileId(0)),
     ));
 }
 
@@ -208,7 +206,7 @@ fn nice_error_in_case_of_bool_type_in_env_var() {
         "String",
         "boolean",
         "true",
-        Span::new(67, 71),
+        Span::new(67, 71, FileId(0)),
     ));
 }
 
@@ -227,7 +225,7 @@ fn nice_error_in_case_of_numeric_type_in_env_var() {
         "String",
         "numeric",
         "4",
-        Span::new(67, 68),
+        Span::new(67, 68, FileId(0)),
     ));
 }
 
@@ -246,7 +244,7 @@ fn nice_error_in_case_of_array_type_in_env_var() {
         "String",
         "array",
         "(array)",
-        Span::new(67, 81),
+        Span::new(67, 81, FileId(0)),
     ));
 }
 
@@ -262,7 +260,7 @@ fn optional_list_fields_must_error() {
 
     error.assert_is(DatamodelError::new_legacy_parser_error(
         "Optional lists are not supported. Use either `Type[]` or `Type?`.",
-        Span::new(51, 60),
+        Span::new(51, 60, FileId(0)),
     ));
 }
 
@@ -282,7 +280,7 @@ fn invalid_lines_at_the_top_level_must_render_nicely() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "This line is invalid. It does not start with any known Prisma schema keyword.",
-        Span::new(64, 72),
+        Span::new(64, 72, FileId(0)),
     ));
 }
 
@@ -300,7 +298,7 @@ fn invalid_lines_in_datasources_must_render_nicely() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "This line is not a valid definition within a datasource.",
-        Span::new(100, 124),
+        Span::new(100, 124, FileId(0)),
     ));
 }
 
@@ -317,7 +315,7 @@ fn invalid_lines_in_generators_must_render_nicely() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "This line is not a valid definition within a generator.",
-        Span::new(52, 76),
+        Span::new(52, 76, FileId(0)),
     ));
 }
 
@@ -335,6 +333,6 @@ fn invalid_field_line_must_error_nicely() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "This line is not a valid field or attribute definition.",
-        Span::new(43, 57),
+        Span::new(43, 57, FileId(0)),
     ));
 }
@@ -822,6 +822,47 @@ model Bl
     assert_reformat(input, expected);
 }
 
+#[test]
+fn named_function_arguments_are_kept() {
+    let input = r#"
+        model Invoice {
+            id     Int @id @default(sequence(name:"invoice_numbers",start:  1000))
+            number Int @default(sequence( increment: 2 ))
+        }
+    "#;
+
+    let expected = r#"model Invoice {
+  id     Int @id @default(sequence(name: "invoice_numbers", start: 1000))
+  number Int @default(sequence(increment: 2))
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
+#[test]
+fn imports_are_grouped_at_their_position() {
+    let input = r#"
+        import   "./enums.prisma"
+
+
+        import "./models/user.prisma"
+        model Post {
+            id Int @id
+        }
+    "#;
+
+    let expected = r#"import "./enums.prisma"
+import "./models/user.prisma"
+
+model Post {
+  id Int @id
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
 fn assert_reformat(schema: &str, expected_result: &str) {
     println!("schema: {:?}", schema);
     let result = datamodel::ast::reformat::Reformatter::new(&schema).reformat_to_string();
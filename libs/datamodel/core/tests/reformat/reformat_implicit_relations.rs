@@ -1,4 +1,5 @@
 extern crate datamodel;
+use datamodel::ast::{reformat::Reformatter, SchemaFiles};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -396,6 +397,38 @@ model Post {
     assert_reformat(input, expected);
 }
 
+#[test]
+fn back_relation_fields_are_added_in_the_file_of_their_model() {
+    let mut files = SchemaFiles::new();
+    let blog = files.add("blog.prisma", "model Blog {\n  id Int @id\n}\n");
+    let post = files.add(
+        "post.prisma",
+        r#"model Post {
+  id     Int  @id
+  blogId Int
+  blog   Blog @relation(fields: [blogId], references: [id])
+}
+"#,
+    );
+
+    let expected_blog = r#"model Blog {
+  id   Int    @id
+  Post Post[]
+}
+"#;
+
+    let reformatters = Reformatter::for_files(&files);
+
+    assert_eq!(reformatters.len(), 2);
+    assert_eq!(reformatters[0].0, blog);
+    assert_eq!(reformatters[0].1.reformat_to_string(), expected_blog);
+    assert_eq!(reformatters[1].0, post);
+    assert_eq!(
+        reformatters[1].1.reformat_to_string(),
+        files.get(post).unwrap().contents
+    );
+}
+
 fn assert_reformat(schema: &str, expected_result: &str) {
     println!("schema: {:?}", schema);
     let result = datamodel::ast::reformat::Reformatter::new(&schema).reformat_to_string();
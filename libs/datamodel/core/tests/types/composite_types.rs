@@ -0,0 +1,187 @@
+use crate::common::*;
+use datamodel::{dml, render_datamodel_to_string, FieldType};
+
+#[test]
+fn parse_composite_types() {
+    let dml = r#"
+    model User {
+        id      Int      @id
+        address Address
+        work    Address?
+    }
+
+    /// A postal address.
+    type Address {
+        street String
+        city   String
+        zip    String?
+        tags   String[]
+        geo    Geo?
+    }
+
+    type Geo {
+        lat Float
+        lng Float
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("User");
+
+    user_model
+        .assert_has_scalar_field("address")
+        .assert_arity(&dml::FieldArity::Required);
+    user_model
+        .assert_has_scalar_field("work")
+        .assert_arity(&dml::FieldArity::Optional);
+    assert_eq!(
+        user_model.find_scalar_field("address").unwrap().field_type,
+        FieldType::CompositeType(String::from("Address"))
+    );
+
+    let address = datamodel.find_composite_type("Address").unwrap();
+    assert_eq!(address.documentation, Some(String::from("A postal address.")));
+    assert_eq!(address.fields.len(), 5);
+    assert_eq!(address.find_field("tags").unwrap().arity, dml::FieldArity::List);
+    assert_eq!(
+        address.find_field("geo").unwrap().field_type,
+        FieldType::CompositeType(String::from("Geo"))
+    );
+    assert!(datamodel.find_composite_type("Geo").is_some());
+}
+
+#[test]
+fn composite_types_must_render_correctly() {
+    let input = r#"model User {
+  id      Int      @id
+  address Address?
+}
+
+type Address {
+  street String
+  zip    String?
+}
+"#;
+
+    let datamodel = parse(input);
+    let rendered = render_datamodel_to_string(&datamodel);
+
+    assert_eq!(rendered, input);
+}
+
+#[test]
+fn composite_type_fields_must_not_refer_to_models() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    type Address {
+        owner User
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating: Fields of composite types cannot refer to models.");
+}
+
+#[test]
+fn composite_type_fields_must_not_have_attributes() {
+    let dml = r#"
+    type Address {
+        street String @unique
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating: Fields of composite types do not support attributes.");
+}
+
+#[test]
+fn composite_types_must_have_fields() {
+    let dml = r#"
+    type Address {
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating: A composite type must have at least one field.");
+}
+
+#[test]
+fn lists_of_composite_types_are_not_supported() {
+    let dml = r#"
+    model User {
+        id        Int       @id
+        addresses Address[]
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating field `addresses` in model `User`: Field `addresses` in model `User` can't be a list of the composite type `Address`. Lists of composite types are not supported.");
+}
+
+#[test]
+fn lists_of_composite_types_are_not_supported_in_composite_types() {
+    let dml = r#"
+    type Address {
+        street String
+        geo    Geo[]
+    }
+
+    type Geo {
+        lat Float
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating: Field `geo` in composite type `Address` can't be a list of the composite type `Geo`. Lists of composite types are not supported.");
+}
+
+#[test]
+fn composite_fields_must_not_be_unique() {
+    let dml = r#"
+    model User {
+        id      Int     @id
+        address Address @unique
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating field `address` in model `User`: Field `address` in model `User` is of the composite type `Address` and can't be part of an id, unique constraint or index.");
+}
+
+#[test]
+fn composite_fields_must_not_have_defaults() {
+    let dml = r#"
+    model User {
+        id      Int     @id
+        address Address @default("{}")
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    parse_error(dml)
+        .assert_is_message("Error parsing attribute \"@default\": Cannot set a default value on a composite field.");
+}
+
+#[test]
+fn composite_types_require_json_support() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url      = "file:dev.db"
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating: You defined the composite type `Address`. But the current connector does not support the Json type composite types are stored in.");
+}
@@ -1,3 +1,4 @@
+pub mod composite_types;
 mod helper;
 mod mssql_native_types;
 pub mod mysql_native_types;
@@ -117,7 +117,7 @@ fn should_fail_on_native_type_decimal_when_scale_is_bigger_than_precision() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "The scale must not be larger than the precision for the Decimal(2,4) native type in SQL Server.",
-        ast::Span::new(203, 232),
+        ast::Span::new(203, 232, ast::FileId(0)),
     ));
 }
 
@@ -146,7 +146,7 @@ fn should_fail_on_native_type_numeric_when_scale_is_bigger_than_precision() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "The scale must not be larger than the precision for the Numeric(2,4) native type in SQL Server.",
-        ast::Span::new(203, 233),
+        ast::Span::new(203, 233, ast::FileId(0)),
     ));
 }
 
@@ -218,7 +218,7 @@ fn should_fail_on_incompatible_scalar_type_with_tiny_int() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "Native type Bit is not compatible with declared field type DateTime, expected field type Boolean or Int.",
-        ast::Span::new(302, 308),
+        ast::Span::new(302, 308, ast::FileId(0)),
     ));
 }
 
@@ -158,7 +158,7 @@ fn should_fail_on_native_type_decimal_when_scale_is_bigger_than_precision() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "The scale must not be larger than the precision for the Decimal native type in MySQL.",
-        ast::Span::new(281, 311),
+        ast::Span::new(281, 311, ast::FileId(0)),
     ));
 }
 
@@ -185,7 +185,7 @@ fn should_fail_on_native_type_numeric_when_scale_is_bigger_than_precision() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "The scale must not be larger than the precision for the Numeric native type in MySQL.",
-        ast::Span::new(281, 311),
+        ast::Span::new(281, 311, ast::FileId(0)),
     ));
 }
 
@@ -212,6 +212,6 @@ fn should_fail_on_incompatible_scalar_type_with_tiny_int() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "Native type TinyInt is not compatible with declared field type DateTime, expected field type Boolean or Int.",
-        ast::Span::new(294, 304),
+        ast::Span::new(294, 304, ast::FileId(0)),
     ));
 }
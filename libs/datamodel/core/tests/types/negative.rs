@@ -15,11 +15,11 @@ fn shound_fail_on_attribute_duplication() {
 
     error.assert_is_at(
         0,
-        DatamodelError::new_duplicate_attribute_error("id", ast::Span::new(23, 25)),
+        DatamodelError::new_duplicate_attribute_error("id", ast::Span::new(23, 25, ast::FileId(0))),
     );
     error.assert_is_at(
         1,
-        DatamodelError::new_duplicate_attribute_error("id", ast::Span::new(77, 79)),
+        DatamodelError::new_duplicate_attribute_error("id", ast::Span::new(77, 79, ast::FileId(0))),
     );
 }
 
@@ -38,11 +38,11 @@ fn shound_fail_on_attribute_duplication_recursive() {
 
     error.assert_is_at(
         0,
-        DatamodelError::new_duplicate_attribute_error("default", ast::Span::new(40, 47)),
+        DatamodelError::new_duplicate_attribute_error("default", ast::Span::new(40, 47, ast::FileId(0))),
     );
     error.assert_is_at(
         1,
-        DatamodelError::new_duplicate_attribute_error("default", ast::Span::new(128, 135)),
+        DatamodelError::new_duplicate_attribute_error("default", ast::Span::new(128, 135, ast::FileId(0))),
     );
 }
 
@@ -62,7 +62,7 @@ fn should_fail_on_endless_recursive_type_def() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "Recursive type definitions are not allowed. Recursive path was: ID -> MyStringWithDefault -> MyString -> ID.",
-        ast::Span::new(21, 23),
+        ast::Span::new(21, 23, ast::FileId(0)),
     ));
 }
 
@@ -80,7 +80,10 @@ fn shound_fail_on_unresolvable_type() {
 
     let error = parse_error(dml);
 
-    error.assert_is(DatamodelError::new_type_not_found_error("Hugo", ast::Span::new(21, 25)));
+    error.assert_is(DatamodelError::new_type_not_found_error(
+        "Hugo",
+        ast::Span::new(21, 25, ast::FileId(0)),
+    ));
 }
 
 #[test]
@@ -105,7 +108,7 @@ fn should_fail_on_custom_related_types() {
 
     error.assert_is(DatamodelError::new_validation_error(
         "Only scalar types can be used for defining custom types.",
-        ast::Span::new(25, 29),
+        ast::Span::new(25, 29, ast::FileId(0)),
     ));
 }
 
@@ -132,7 +135,7 @@ fn should_fail_on_native_type_with_invalid_datasource_name() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "The prefix pg is invalid. It must be equal to the name of an existing datasource e.g. db. Did you mean to use db.Integer?",
-        ast::Span::new(300, 310),
+        ast::Span::new(300, 310, ast::FileId(0)),
     ));
 }
 
@@ -160,7 +163,7 @@ fn should_fail_on_native_type_with_invalid_number_of_arguments() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "Native type VarChar takes 1 optional arguments, but received 3.",
-        ast::Span::new(338, 357),
+        ast::Span::new(338, 357, ast::FileId(0)),
     ));
 }
 
@@ -177,7 +180,7 @@ fn should_fail_on_decimal_scalar_type_used_without_preview_feature() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "Native types can only be used if the corresponding feature flag is enabled. Please add this field in your generator block: `previewFeatures = [\"nativeTypes\"]`",
-        ast::Span::new(64, 76),
+        ast::Span::new(64, 76, ast::FileId(0)),
     ));
 }
 
@@ -194,7 +197,7 @@ fn should_fail_on_bytes_scalar_type_used_without_preview_feature() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "Native types can only be used if the corresponding feature flag is enabled. Please add this field in your generator block: `previewFeatures = [\"nativeTypes\"]`",
-        ast::Span::new(64, 74),
+        ast::Span::new(64, 74, ast::FileId(0)),
     ));
 }
 #[test]
@@ -210,7 +213,7 @@ fn should_fail_on_big_int_scalar_type_used_without_preview_feature() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "Native types can only be used if the corresponding feature flag is enabled. Please add this field in your generator block: `previewFeatures = [\"nativeTypes\"]`",
-        ast::Span::new(64, 75),
+        ast::Span::new(64, 75, ast::FileId(0)),
     ));
 }
 
@@ -238,7 +241,7 @@ fn should_fail_on_native_type_with_unknown_type() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "Native type Numerical is not supported for postgresql connector.",
-        ast::Span::new(300, 318),
+        ast::Span::new(300, 318, ast::FileId(0)),
     ));
 }
 
@@ -260,7 +263,7 @@ fn should_fail_on_missing_native_types_feature_flag() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "Native types can only be used if the corresponding feature flag is enabled. Please add this field in your generator block: `previewFeatures = [\"nativeTypes\"]`",
-        ast::Span::new(178, 196),
+        ast::Span::new(178, 196, ast::FileId(0)),
     ));
 }
 
@@ -292,7 +295,7 @@ fn should_fail_on_native_type_with_incompatible_type() {
         0,
         DatamodelError::new_connector_error(
             "Native type VarChar is not compatible with declared field type Boolean, expected field type String.",
-            ast::Span::new(301, 314),
+            ast::Span::new(301, 314, ast::FileId(0)),
         ),
     );
 
@@ -300,7 +303,7 @@ fn should_fail_on_native_type_with_incompatible_type() {
         1,
         DatamodelError::new_connector_error(
             "Native type BigInt is not compatible with declared field type Int, expected field type BigInt.",
-            ast::Span::new(336, 345),
+            ast::Span::new(336, 345, ast::FileId(0)),
         ),
     );
 }
@@ -328,6 +331,6 @@ fn should_fail_on_native_type_with_invalid_arguments() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "Expected a numeric value, but failed while parsing \"a\": invalid digit found in string.",
-        ast::Span::new(300, 313),
+        ast::Span::new(300, 313, ast::FileId(0)),
     ));
 }
@@ -88,7 +88,7 @@ fn should_fail_on_native_type_decimal_when_scale_is_bigger_than_precision() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "The scale must not be larger than the precision for the Decimal native type in Postgres.",
-        ast::Span::new(289, 319),
+        ast::Span::new(289, 319, ast::FileId(0)),
     ));
 }
 
@@ -115,7 +115,7 @@ fn should_fail_on_native_type_numeric_when_scale_is_bigger_than_precision() {
 
     error.assert_is(DatamodelError::new_connector_error(
         "The scale must not be larger than the precision for the Numeric native type in Postgres.",
-        ast::Span::new(289, 319),
+        ast::Span::new(289, 319, ast::FileId(0)),
     ));
 }
 
@@ -29,7 +29,9 @@ impl<'a> DatamodelConverter<'a> {
             models: self.convert_models(),
             relations: self.convert_relations(),
             enums: self.convert_enums(),
+            composite_types: self.convert_composite_types(),
             version: Some("v2".to_string()),
+            referential_integrity: Default::default(),
         }
     }
 
@@ -52,6 +54,26 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    fn convert_composite_types(&self) -> Vec<InternalCompositeType> {
+        self.datamodel
+            .composite_types()
+            .map(|ct| InternalCompositeType {
+                name: ct.name.clone(),
+                fields: ct
+                    .fields()
+                    .map(|field| InternalCompositeTypeField {
+                        name: field.name.clone(),
+                        type_identifier: field.field_type.type_identifier(),
+                        is_required: field.is_required(),
+                        is_list: field.is_list(),
+                        internal_enum: field.field_type.internal_enum(self.datamodel),
+                        composite_type: field.field_type.composite_type(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
     fn convert_models(&self) -> Vec<ModelTemplate> {
         self.datamodel
             .models()
@@ -100,6 +122,7 @@ impl<'a> DatamodelConverter<'a> {
                     is_autoincrement: sf.is_auto_increment(),
                     behaviour: sf.behaviour(),
                     internal_enum: sf.internal_enum(self.datamodel),
+                    composite_type: sf.field_type.composite_type(),
                     db_name: sf.database_name.clone(),
                     arity: sf.arity,
                     default_value: sf.default_value.clone(),
@@ -334,28 +357,11 @@ trait DatamodelFieldExtensions {
     fn is_auto_generated_int_id(&self) -> bool;
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
-    fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
 }
 
 impl DatamodelFieldExtensions for dml::ScalarField {
     fn type_identifier(&self) -> TypeIdentifier {
-        match &self.field_type {
-            dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
-            dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
-            dml::FieldType::Base(scalar, _) => match scalar {
-                dml::ScalarType::Boolean => TypeIdentifier::Boolean,
-                dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Float => TypeIdentifier::Float,
-                dml::ScalarType::Decimal => TypeIdentifier::Decimal,
-                dml::ScalarType::Int => TypeIdentifier::Int,
-                dml::ScalarType::String => TypeIdentifier::String,
-                dml::ScalarType::Json => TypeIdentifier::Json,
-                dml::ScalarType::Bytes => TypeIdentifier::Bytes,
-                dml::ScalarType::BigInt => TypeIdentifier::BigInt,
-            },
-            dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
-            dml::FieldType::NativeType(scalar_type, _) => (*scalar_type).into(),
-        }
+        self.field_type.type_identifier()
     }
 
     fn is_unique(&self, model: &dml::Model) -> bool {
@@ -384,30 +390,69 @@ impl DatamodelFieldExtensions for dml::ScalarField {
     fn behaviour(&self) -> Option<FieldBehaviour> {
         if self.is_updated_at {
             Some(FieldBehaviour::UpdatedAt)
+        } else if self.is_version {
+            Some(FieldBehaviour::Version)
+        } else if self.generated_as.is_some() {
+            Some(FieldBehaviour::Generated)
         } else {
             None
         }
     }
 
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum> {
-        match self.field_type {
-            dml::FieldType::Enum(ref name) => {
-                datamodel
-                    .enums()
-                    .find(|e| e.name == name.clone())
-                    .map(|e| InternalEnum {
-                        name: e.name.clone(),
-                        values: e.values().map(|v| self.internal_enum_value(v)).collect(),
+        self.field_type.internal_enum(datamodel)
+    }
+}
+
+trait DatamodelFieldTypeExtensions {
+    fn type_identifier(&self) -> TypeIdentifier;
+    fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
+    fn composite_type(&self) -> Option<String>;
+}
+
+impl DatamodelFieldTypeExtensions for dml::FieldType {
+    fn type_identifier(&self) -> TypeIdentifier {
+        match self {
+            dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
+            dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
+            dml::FieldType::Base(scalar, _) => match scalar {
+                dml::ScalarType::Boolean => TypeIdentifier::Boolean,
+                dml::ScalarType::DateTime => TypeIdentifier::DateTime,
+                dml::ScalarType::Float => TypeIdentifier::Float,
+                dml::ScalarType::Decimal => TypeIdentifier::Decimal,
+                dml::ScalarType::Int => TypeIdentifier::Int,
+                dml::ScalarType::String => TypeIdentifier::String,
+                dml::ScalarType::Json => TypeIdentifier::Json,
+                dml::ScalarType::Bytes => TypeIdentifier::Bytes,
+                dml::ScalarType::BigInt => TypeIdentifier::BigInt,
+            },
+            dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
+            dml::FieldType::NativeType(scalar_type, _) => (*scalar_type).into(),
+            // Composite values are stored as Json documents.
+            dml::FieldType::CompositeType(_) => TypeIdentifier::Json,
+        }
+    }
+
+    fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum> {
+        match self {
+            dml::FieldType::Enum(ref name) => datamodel.find_enum(name).map(|e| InternalEnum {
+                name: e.name.clone(),
+                values: e
+                    .values()
+                    .map(|v| InternalEnumValue {
+                        name: v.name.clone(),
+                        database_name: v.database_name.clone(),
                     })
-            }
+                    .collect(),
+            }),
             _ => None,
         }
     }
 
-    fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue {
-        InternalEnumValue {
-            name: enum_value.name.clone(),
-            database_name: enum_value.database_name.clone(),
+    fn composite_type(&self) -> Option<String> {
+        match self {
+            dml::FieldType::CompositeType(name) => Some(name.clone()),
+            _ => None,
         }
     }
 }
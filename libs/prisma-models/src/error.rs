@@ -12,6 +12,9 @@ pub enum DomainError {
     #[error("Relation `{}` not found", name)]
     RelationNotFound { name: String },
 
+    #[error("Composite type `{}` not found", name)]
+    CompositeTypeNotFound { name: String },
+
     #[error("ScalarField `{}` on model `{}` not found", name, model)]
     ScalarFieldNotFound { name: String, model: String },
 
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use datamodel::ReferentialIntegrity;
 use once_cell::sync::OnceCell;
 use std::sync::{Arc, Weak};
 
@@ -10,13 +11,17 @@ pub struct InternalDataModelTemplate {
     pub models: Vec<ModelTemplate>,
     pub relations: Vec<RelationTemplate>,
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<InternalCompositeType>,
     pub version: Option<String>,
+    pub referential_integrity: ReferentialIntegrity,
 }
 
 #[derive(Debug)]
 pub struct InternalDataModel {
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<InternalCompositeType>,
     version: Option<String>,
+    referential_integrity: ReferentialIntegrity,
 
     /// Todo clarify / rename.
     /// The db name influences how data is queried from the database.
@@ -74,13 +79,38 @@ impl InternalEnumValue {
     }
 }
 
+/// A composite type. Values of composite types are stored as Json documents.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeType {
+    pub name: String,
+    pub fields: Vec<InternalCompositeTypeField>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeTypeField {
+    pub name: String,
+    pub type_identifier: TypeIdentifier,
+    pub is_required: bool,
+    pub is_list: bool,
+    pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<String>,
+}
+
+impl InternalCompositeType {
+    pub fn find_field(&self, name: &str) -> Option<&InternalCompositeTypeField> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+}
+
 impl InternalDataModelTemplate {
     pub fn build(self, db_name: String) -> InternalDataModelRef {
         let internal_data_model = Arc::new(InternalDataModel {
             models: OnceCell::new(),
             relations: OnceCell::new(),
             enums: self.enums,
+            composite_types: self.composite_types,
             version: self.version,
+            referential_integrity: self.referential_integrity,
             db_name,
             relation_fields: OnceCell::new(),
         });
@@ -126,6 +156,14 @@ impl InternalDataModel {
             .ok_or_else(|| DomainError::ModelNotFound { name: name.to_string() })
     }
 
+    pub fn find_composite_type(&self, name: &str) -> crate::Result<InternalCompositeType> {
+        self.composite_types
+            .iter()
+            .find(|composite_type| composite_type.name == name)
+            .cloned()
+            .ok_or_else(|| DomainError::CompositeTypeNotFound { name: name.to_string() })
+    }
+
     pub fn find_relation(&self, name: &str) -> crate::Result<RelationWeakRef> {
         self.relations
             .get()
@@ -138,6 +176,12 @@ impl InternalDataModel {
         self.version.is_none()
     }
 
+    /// Whether the referential actions of the relations have to be emulated
+    /// by the writes, as the database has no foreign keys enforcing them.
+    pub fn emulates_referential_actions(&self) -> bool {
+        self.referential_integrity == ReferentialIntegrity::Prisma
+    }
+
     pub fn fields_requiring_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
         self.relation_fields()
             .iter()
@@ -139,6 +139,14 @@ impl Model {
             .collect()
     }
 
+    /// The field marking records of this model as deleted, if the model is in soft delete mode.
+    pub fn soft_delete_field(&self) -> Option<ScalarFieldRef> {
+        self.dml_model
+            .soft_delete_field
+            .as_ref()
+            .map(|name| self.fields().find_from_scalar(name).unwrap())
+    }
+
     pub fn is_legacy(&self) -> bool {
         self.internal_data_model().is_legacy()
     }
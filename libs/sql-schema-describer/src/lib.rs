@@ -131,6 +131,9 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's comment, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
 }
 
 impl Table {
@@ -250,6 +253,12 @@ pub struct Column {
     pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// The column's comment, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// The generation expression, if the column is computed by the database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated: Option<GeneratedColumn>,
 }
 
 impl Column {
@@ -258,6 +267,17 @@ impl Column {
     }
 }
 
+/// A column computed by the database (`GENERATED ALWAYS AS (...)`).
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedColumn {
+    /// The generation expression, as returned by the database. Empty if it could not be read,
+    /// on SQLite it is parsed from the table definition.
+    pub expression: String,
+    /// Whether the value is stored on disk, or computed on read.
+    pub stored: bool,
+}
+
 /// The type of a column.
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -461,6 +481,54 @@ pub struct Enum {
 pub struct Sequence {
     /// Sequence name.
     pub name: String,
+    /// The first value of the sequence.
+    #[serde(default = "default_sequence_option")]
+    pub start: i64,
+    /// The step between two values of the sequence.
+    #[serde(default = "default_sequence_option")]
+    pub increment: i64,
+    /// The column the sequence belongs to, for serial and identity columns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owned_by: Option<SequenceOwner>,
+}
+
+impl Sequence {
+    /// A standalone sequence with default options.
+    pub fn new(name: impl Into<String>) -> Self {
+        Sequence {
+            name: name.into(),
+            start: 1,
+            increment: 1,
+            owned_by: None,
+        }
+    }
+
+    /// Is the sequence independent of any column? Standalone sequences can be
+    /// shared between columns.
+    pub fn is_standalone(&self) -> bool {
+        self.owned_by.is_none()
+    }
+
+    /// Does the sequence back an identity column?
+    pub fn is_identity(&self) -> bool {
+        self.owned_by.as_ref().map(|owner| owner.identity).unwrap_or(false)
+    }
+}
+
+fn default_sequence_option() -> i64 {
+    1
+}
+
+/// The column owning a sequence.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SequenceOwner {
+    /// The table of the owning column.
+    pub table: String,
+    /// The owning column.
+    pub column: String,
+    /// Whether the column is an identity column, as opposed to a serial.
+    pub identity: bool,
 }
 
 #[derive(PartialEq, Debug, Clone)]
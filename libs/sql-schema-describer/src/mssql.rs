@@ -75,12 +75,19 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_all_columns(schema).await?;
         let mut indexes = self.get_all_indices(schema).await?;
         let mut foreign_keys = self.get_foreign_keys(schema).await?;
+        let mut comments = self.get_table_comments(schema).await?;
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in table_names {
-            let table = self.get_table(&table_name, &mut columns, &mut indexes, &mut foreign_keys);
+            let table = self.get_table(
+                &table_name,
+                &mut columns,
+                &mut indexes,
+                &mut foreign_keys,
+                &mut comments,
+            );
             tables.push(table);
         }
 
@@ -139,6 +146,31 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    /// Returns a map from table name to the table's comment, stored in the
+    /// `MS_Description` extended property.
+    #[tracing::instrument]
+    async fn get_table_comments(&self, schema: &str) -> DescriberResult<HashMap<String, String>> {
+        let select = r#"
+            SELECT t.name AS table_name, CONVERT(nvarchar(max), ep.value) AS comment
+            FROM sys.tables t
+            INNER JOIN sys.extended_properties ep
+                ON ep.major_id = t.object_id AND ep.minor_id = 0 AND ep.class = 1 AND ep.name = 'MS_Description'
+            WHERE SCHEMA_NAME(t.schema_id) = @P1
+            AND t.is_ms_shipped = 0;
+        "#;
+
+        let rows = self.conn.query_raw(select, &[schema.into()]).await?;
+
+        let comments = rows
+            .into_iter()
+            .map(|row| (row.get_expect_string("table_name"), row.get_expect_string("comment")))
+            .collect();
+
+        trace!("Found table comments: {:?}", comments);
+
+        Ok(comments)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = indoc! {r#"
@@ -176,6 +208,7 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, Vec<Column>>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        comments: &mut HashMap<String, String>,
     ) -> Table {
         let columns = columns.remove(name).unwrap_or_default();
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
@@ -188,6 +221,7 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            comment: comments.remove(name),
         }
     }
 
@@ -206,9 +240,16 @@ impl SqlSchemaDescriber {
                     END)                                           AS numeric_precision,
                 convert(int, CASE
                     WHEN c.system_type_id IN (40, 41, 42, 43, 58, 61) THEN NULL
-                    ELSE ODBCSCALE(c.system_type_id, c.scale) END) AS numeric_scale
+                    ELSE ODBCSCALE(c.system_type_id, c.scale) END) AS numeric_scale,
+                CONVERT(nvarchar(max), ep.value)                   AS comment,
+                cc.definition                                      AS generation_expression,
+                CONVERT(bit, COALESCE(cc.is_persisted, 0))         AS is_persisted
             FROM sys.columns c
                     INNER JOIN sys.tables t ON c.object_id = t.object_id
+                    LEFT JOIN sys.extended_properties ep ON ep.major_id = c.object_id
+                        AND ep.minor_id = c.column_id AND ep.class = 1 AND ep.name = 'MS_Description'
+                    LEFT JOIN sys.computed_columns cc ON cc.object_id = c.object_id
+                        AND cc.column_id = c.column_id
             WHERE OBJECT_SCHEMA_NAME(c.object_id) = @P1
             AND t.is_ms_shipped = 0
 
@@ -312,6 +353,13 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                comment: col.get_string("comment"),
+                generated: col
+                    .get_string("generation_expression")
+                    .map(|expression| GeneratedColumn {
+                        expression,
+                        stored: col.get_expect_bool("is_persisted"),
+                    }),
             });
         }
 
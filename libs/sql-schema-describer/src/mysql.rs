@@ -17,6 +17,8 @@ fn is_mariadb(version: &str) -> bool {
 
 enum Flavour {
     Mysql,
+    /// MySQL 5.6 has no generated columns, and no `generation_expression` in the information schema.
+    Mysql56,
     MariaDb,
 }
 
@@ -24,6 +26,8 @@ impl Flavour {
     fn from_version(version_string: &str) -> Self {
         if is_mariadb(version_string) {
             Self::MariaDb
+        } else if version_string.starts_with("5.6") {
+            Self::Mysql56
         } else {
             Self::Mysql
         }
@@ -63,10 +67,11 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = Self::get_all_columns(&self.conn, schema, &flavour).await?;
         let mut indexes = Self::get_all_indexes(&self.conn, schema).await?;
         let mut fks = Self::get_foreign_keys(&self.conn, schema).await?;
+        let mut comments = self.get_table_comments(schema).await?;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks, &mut comments);
             tables.push(table);
             enums.extend(enms.iter().cloned());
         }
@@ -124,6 +129,30 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    /// Returns a map from table name to the table's comment.
+    #[tracing::instrument(skip(self))]
+    async fn get_table_comments(&self, schema: &str) -> DescriberResult<HashMap<String, String>> {
+        let sql = "SELECT table_name as table_name, table_comment as table_comment
+            FROM information_schema.tables
+            WHERE table_schema = ?
+            AND table_type = 'BASE TABLE'
+            AND table_comment <> ''";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let comments = rows
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get_expect_string("table_name"),
+                    row.get_expect_string("table_comment"),
+                )
+            })
+            .collect();
+
+        trace!("Found table comments: {:?}", comments);
+
+        Ok(comments)
+    }
+
     #[tracing::instrument(skip(self))]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = r#"
@@ -148,13 +177,14 @@ impl SqlSchemaDescriber {
         Ok(size as usize)
     }
 
-    #[tracing::instrument(skip(self, columns, indexes, foreign_keys))]
+    #[tracing::instrument(skip(self, columns, indexes, foreign_keys, comments))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        comments: &mut HashMap<String, String>,
     ) -> (Table, Vec<Enum>) {
         let (columns, enums) = columns.remove(name).unwrap_or((vec![], vec![]));
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
@@ -168,6 +198,7 @@ impl SqlSchemaDescriber {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                comment: comments.remove(name),
             },
             enums,
         )
@@ -181,7 +212,13 @@ impl SqlSchemaDescriber {
         // We alias all the columns because MySQL column names are case-insensitive in queries, but the
         // information schema column names became upper-case in MySQL 8, causing the code fetching
         // the result values by column name below to fail.
-        let sql = "
+        let generation_expression = match flavour {
+            Flavour::Mysql56 => "NULL generation_expression",
+            Flavour::Mysql | Flavour::MariaDb => "generation_expression generation_expression",
+        };
+
+        let sql = format!(
+            "
             SELECT
                 column_name column_name,
                 data_type data_type,
@@ -193,15 +230,19 @@ impl SqlSchemaDescriber {
                 column_default column_default,
                 is_nullable is_nullable,
                 extra extra,
+                column_comment column_comment,
+                {generation_expression},
                 table_name table_name
             FROM information_schema.columns
             WHERE table_schema = ?
             ORDER BY ordinal_position
-        ";
+        ",
+            generation_expression = generation_expression
+        );
 
         let mut map = HashMap::new();
 
-        let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+        let rows = conn.query_raw(&sql, &[schema_name.into()]).await?;
 
         for col in rows {
             trace!("Got column: {:?}", col);
@@ -248,6 +289,13 @@ impl SqlSchemaDescriber {
             );
             let extra = col.get_expect_string("extra").to_lowercase();
             let auto_increment = matches!(extra.as_str(), "auto_increment");
+            let generated = col
+                .get_string("generation_expression")
+                .filter(|expression| !expression.is_empty())
+                .map(|expression| GeneratedColumn {
+                    expression,
+                    stored: extra.contains("stored") || extra.contains("persistent"),
+                });
 
             let entry = map.entry(table_name).or_insert((Vec::new(), Vec::new()));
 
@@ -308,6 +356,8 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                comment: col.get_string("column_comment").filter(|comment| !comment.is_empty()),
+                generated,
             };
 
             entry.0.push(col);
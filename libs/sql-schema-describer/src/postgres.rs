@@ -39,12 +39,19 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_columns(schema, &enums, &sequences).await?;
         let mut foreign_keys = self.get_foreign_keys(schema).await?;
         let mut indexes = self.get_indices(schema, &sequences).await?;
+        let mut comments = self.get_table_comments(schema).await?;
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            tables.push(self.get_table(
+                &table_name,
+                &mut columns,
+                &mut foreign_keys,
+                &mut indexes,
+                &mut comments,
+            ));
         }
 
         Ok(SqlSchema {
@@ -112,6 +119,27 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    /// Returns a map from table name to the table's comment.
+    #[tracing::instrument]
+    async fn get_table_comments(&self, schema: &str) -> DescriberResult<HashMap<String, String>> {
+        let sql = "
+            SELECT cl.relname AS table_name, obj_description(cl.oid, 'pg_class') AS comment
+            FROM pg_class cl
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1
+            AND cl.relkind = 'r'
+            AND obj_description(cl.oid, 'pg_class') IS NOT NULL";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let comments = rows
+            .into_iter()
+            .map(|row| (row.get_expect_string("table_name"), row.get_expect_string("comment")))
+            .collect();
+
+        trace!("Found table comments: {:?}", comments);
+
+        Ok(comments)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql =
@@ -126,13 +154,14 @@ impl SqlSchemaDescriber {
         Ok(size.try_into().expect("size is not a valid usize"))
     }
 
-    #[tracing::instrument(skip(columns, foreign_keys, indices))]
+    #[tracing::instrument(skip(columns, foreign_keys, indices, comments))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, Vec<Column>>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
         indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+        comments: &mut HashMap<String, String>,
     ) -> Table {
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
@@ -143,6 +172,7 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices,
             primary_key,
+            comment: comments.remove(name),
         }
     }
 
@@ -169,7 +199,9 @@ impl SqlSchemaDescriber {
                 info.is_nullable,
                 info.is_identity,
                 info.data_type,
-                info.character_maximum_length
+                info.character_maximum_length,
+                info.generation_expression,
+                col_description(att.attrelid, att.attnum) as comment
             FROM information_schema.columns info
             JOIN pg_attribute  att on att.attname = info.column_name
             And att.attrelid = (
@@ -209,6 +241,14 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                comment: col.get_string("comment"),
+                // Postgres only supports stored generated columns.
+                generated: col
+                    .get_string("generation_expression")
+                    .map(|expression| GeneratedColumn {
+                        expression,
+                        stored: true,
+                    }),
             };
 
             columns.entry(table_name).or_default().push(col);
@@ -512,24 +552,114 @@ impl SqlSchemaDescriber {
 
     #[tracing::instrument]
     async fn get_sequences(&self, schema: &str) -> DescriberResult<Vec<Sequence>> {
-        let sql = "SELECT sequence_name
-                  FROM information_schema.sequences
-                  WHERE sequence_schema = $1";
+        // Serial sequences are owned by their column through an automatic dependency (deptype
+        // 'a'), identity sequences through an internal one (deptype 'i').
+        let sql = "
+            SELECT
+                seq.sequence_name,
+                seq.start_value::BIGINT AS start_value,
+                seq.increment::BIGINT AS increment,
+                owner_table.relname AS owner_table,
+                owner_column.attname AS owner_column,
+                dep.deptype::TEXT AS owner_kind
+            FROM information_schema.sequences seq
+            JOIN pg_namespace ns ON ns.nspname = seq.sequence_schema
+            JOIN pg_class cl ON cl.relname = seq.sequence_name AND cl.relnamespace = ns.oid
+            LEFT JOIN pg_depend dep
+                ON dep.objid = cl.oid
+                AND dep.classid = 'pg_class'::regclass
+                AND dep.refclassid = 'pg_class'::regclass
+                AND dep.deptype IN ('a', 'i')
+            LEFT JOIN pg_class owner_table ON owner_table.oid = dep.refobjid
+            LEFT JOIN pg_attribute owner_column
+                ON owner_column.attrelid = dep.refobjid
+                AND owner_column.attnum = dep.refobjsubid
+            WHERE seq.sequence_schema = $1
+            ORDER BY seq.sequence_name";
         let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
-        let sequences = rows
+        let mut sequences: Vec<Sequence> = rows
             .into_iter()
             .map(|seq| {
                 trace!("Got sequence: {:?}", seq);
+
+                let owned_by = match (seq.get_string("owner_table"), seq.get_string("owner_column")) {
+                    (Some(table), Some(column)) => Some(SequenceOwner {
+                        table,
+                        column,
+                        identity: seq.get_string("owner_kind").as_deref() == Some("i"),
+                    }),
+                    _ => None,
+                };
+
                 Sequence {
                     name: seq.get_expect_string("sequence_name"),
+                    start: seq.get_i64("start_value").unwrap_or(1),
+                    increment: seq.get_i64("increment").unwrap_or(1),
+                    owned_by,
                 }
             })
             .collect();
 
+        // Identity sequences are not listed in information_schema.sequences on all versions, the
+        // identity options of the columns are authoritative.
+        for sequence in self.get_identity_sequences(schema).await? {
+            sequences.retain(|existing| existing.name != sequence.name);
+            sequences.push(sequence);
+        }
+
         trace!("Found sequences: {:?}", sequences);
         Ok(sequences)
     }
 
+    #[tracing::instrument]
+    async fn get_identity_sequences(&self, schema: &str) -> DescriberResult<Vec<Sequence>> {
+        let sql = "
+            SELECT
+                info.table_name,
+                info.column_name,
+                info.identity_start::BIGINT AS identity_start,
+                info.identity_increment::BIGINT AS identity_increment,
+                pg_get_serial_sequence(
+                    quote_ident(info.table_schema) || '.' || quote_ident(info.table_name),
+                    info.column_name
+                ) AS sequence_name
+            FROM information_schema.columns info
+            WHERE info.table_schema = $1
+            AND info.is_identity = 'YES'
+            ORDER BY info.table_name, info.ordinal_position";
+        let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
+        let sequences = rows
+            .into_iter()
+            .map(|row| {
+                let table = row.get_expect_string("table_name");
+                let column = row.get_expect_string("column_name");
+
+                let name = row
+                    .get_string("sequence_name")
+                    .and_then(|name| {
+                        RE_SEQ
+                            .captures(&name)
+                            .and_then(|captures| captures.get(1))
+                            .map(|name| name.as_str().to_owned())
+                    })
+                    .unwrap_or_else(|| format!("{}_{}_seq", table, column));
+
+                Sequence {
+                    name,
+                    start: row.get_i64("identity_start").unwrap_or(1),
+                    increment: row.get_i64("identity_increment").unwrap_or(1),
+                    owned_by: Some(SequenceOwner {
+                        table,
+                        column,
+                        identity: true,
+                    }),
+                }
+            })
+            .collect();
+
+        Ok(sequences)
+    }
+
     #[tracing::instrument]
     async fn get_enums(&self, schema: &str) -> DescriberResult<Vec<Enum>> {
         let sql = "
@@ -812,21 +942,11 @@ mod tests {
     #[test]
     fn postgres_is_autoincrement_works() {
         let sequences = vec![
-            Sequence {
-                name: "first_sequence".to_string(),
-            },
-            Sequence {
-                name: "second_sequence".to_string(),
-            },
-            Sequence {
-                name: "third_Sequence".to_string(),
-            },
-            Sequence {
-                name: "fourth_Sequence".to_string(),
-            },
-            Sequence {
-                name: "fifth_sequence".to_string(),
-            },
+            Sequence::new("first_sequence"),
+            Sequence::new("second_sequence"),
+            Sequence::new("third_Sequence"),
+            Sequence::new("fourth_Sequence"),
+            Sequence::new("fifth_sequence"),
         ];
 
         let first_autoincrement = r#"nextval('first_sequence'::regclass)"#;
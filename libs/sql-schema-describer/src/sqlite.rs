@@ -135,15 +135,36 @@ impl SqlSchemaDescriber {
             indices,
             primary_key,
             foreign_keys,
+            comment: None,
         })
     }
 
+    #[tracing::instrument]
+    async fn get_table_sql(&self, table: &str) -> DescriberResult<Option<String>> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?"#;
+        let result_set = self.conn.query_raw(sql, &[table.into()]).await?;
+
+        Ok(result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string())))
+    }
+
     #[tracing::instrument]
     async fn get_columns(&self, table: &str) -> DescriberResult<(Vec<Column>, Option<PrimaryKey>)> {
-        let sql = format!(r#"PRAGMA table_info ("{}")"#, table);
-        let result_set = self.conn.query_raw(&sql, &[]).await?;
+        // `table_xinfo` also returns the generated columns, which `table_info` hides.
+        let sql = format!(r#"PRAGMA table_xinfo ("{}")"#, table);
+        let rows: Vec<_> = self.conn.query_raw(&sql, &[]).await?.into_iter().collect();
+
+        // The generation expressions are only available in the table definition.
+        let table_sql = if rows.iter().any(|row| generated_column_kind(row).is_some()) {
+            self.get_table_sql(table).await?
+        } else {
+            None
+        };
+
         let mut pk_cols: HashMap<i64, String> = HashMap::new();
-        let mut cols: Vec<Column> = result_set
+        let mut cols: Vec<Column> = rows
             .into_iter()
             .map(|row| {
                 trace!("Got column row {:?}", row);
@@ -211,11 +232,21 @@ impl SqlSchemaDescriber {
                 };
 
                 let pk_col = row.get("pk").and_then(|x| x.as_i64()).expect("primary key");
+                let name = row.get("name").and_then(|x| x.to_string()).expect("name");
+                let generated = generated_column_kind(&row).map(|stored| GeneratedColumn {
+                    expression: table_sql
+                        .as_deref()
+                        .and_then(|sql| generation_expression(sql, &name))
+                        .unwrap_or_default(),
+                    stored,
+                });
                 let col = Column {
-                    name: row.get("name").and_then(|x| x.to_string()).expect("name"),
+                    name,
                     tpe,
                     default,
                     auto_increment: false,
+                    comment: None,
+                    generated,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -493,6 +524,75 @@ fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// Returns `Some(stored)` if the `table_xinfo` row describes a generated column.
+///
+/// See https://www.sqlite.org/pragma.html#pragma_table_xinfo
+fn generated_column_kind(row: &quaint::connector::ResultRow) -> Option<bool> {
+    match row.get("hidden").and_then(|x| x.as_i64()) {
+        Some(2) => Some(false),
+        Some(3) => Some(true),
+        _ => None,
+    }
+}
+
+/// SQLite does not expose generation expressions, so we extract them from the `CREATE TABLE`
+/// statement: the parenthesized expression following `AS` in the column's definition.
+fn generation_expression(create_table_sql: &str, column_name: &str) -> Option<String> {
+    static AS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)\bAS\s*\("#).unwrap());
+
+    let column_re = Regex::new(&format!(r#"(?i)[(,]\s*["`\[]?{}["`\]]?\s"#, regex::escape(column_name))).ok()?;
+
+    // The column name can also appear inside other expressions, so we try every candidate.
+    column_re.find_iter(create_table_sql).find_map(|column_match| {
+        let definition = &create_table_sql[column_match.end()..];
+        let as_match = AS_RE.find(definition)?;
+
+        // The `AS` must belong to this column, not to one of the following ones.
+        if leaves_column_definition(&definition[..as_match.start()]) {
+            return None;
+        }
+
+        let expression = &definition[as_match.end()..];
+        let mut depth = 1;
+        let mut in_string = false;
+
+        for (idx, c) in expression.char_indices() {
+            match c {
+                '\'' => in_string = !in_string,
+                '(' if !in_string => depth += 1,
+                ')' if !in_string => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return Some(expression[..idx].trim().to_owned());
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        None
+    })
+}
+
+/// Whether `s` reaches the end of the current column definition: a comma outside of any
+/// parentheses, or the closing parenthesis of the enclosing expression or table.
+fn leaves_column_definition(s: &str) -> bool {
+    let mut depth = 0;
+
+    for c in s.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return true,
+            ')' => depth -= 1,
+            ',' if depth == 0 => return true,
+            _ => (),
+        }
+    }
+
+    false
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -508,3 +608,28 @@ const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_stat3",
     "sqlite_stat4",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generation_expressions_are_extracted_from_the_table_definition() {
+        let sql = r#"CREATE TABLE "Rectangle" (
+            "id" INTEGER PRIMARY KEY,
+            "width" DECIMAL(10, 2) NOT NULL,
+            "area" DECIMAL(10, 2) GENERATED ALWAYS AS (width * (height + 1)) STORED,
+            half REAL AS (width / 2),
+            "label" TEXT AS ('w: (' || width) VIRTUAL
+        )"#;
+
+        assert_eq!(
+            generation_expression(sql, "area").as_deref(),
+            Some("width * (height + 1)")
+        );
+        assert_eq!(generation_expression(sql, "half").as_deref(), Some("width / 2"));
+        assert_eq!(generation_expression(sql, "label").as_deref(), Some("'w: (' || width"));
+        assert_eq!(generation_expression(sql, "width"), None);
+        assert_eq!(generation_expression(sql, "missing"), None);
+    }
+}
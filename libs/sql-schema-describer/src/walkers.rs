@@ -5,8 +5,8 @@
 use std::fmt;
 
 use crate::{
-    Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index,
-    IndexType, PrimaryKey, SqlSchema, Table,
+    Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultKind, DefaultValue, Enum, ForeignKey, ForeignKeyAction,
+    GeneratedColumn, Index, IndexType, PrimaryKey, Sequence, SqlSchema, Table,
 };
 
 /// Traverse all the columns in the schema.
@@ -76,6 +76,16 @@ impl<'a> ColumnWalker<'a> {
         &self.column().name
     }
 
+    /// The comment on the column.
+    pub fn comment(&self) -> Option<&'a str> {
+        self.column().comment.as_deref()
+    }
+
+    /// The generation expression of the column, if it is computed by the database.
+    pub fn generated(&self) -> Option<&'a GeneratedColumn> {
+        self.column().generated.as_ref()
+    }
+
     /// The default value for the column.
     pub fn default(&self) -> Option<&'a DefaultValue> {
         self.column().default.as_ref()
@@ -91,6 +101,35 @@ impl<'a> ColumnWalker<'a> {
         self.column().auto_increment
     }
 
+    /// The sequence owned by the column, backing a serial or identity column.
+    pub fn owned_sequence(&self) -> Option<&'a Sequence> {
+        self.schema.sequences.iter().find(|sequence| {
+            sequence
+                .owned_by
+                .as_ref()
+                .map(|owner| owner.table == self.table().name() && owner.column == self.name())
+                .unwrap_or(false)
+        })
+    }
+
+    /// The standalone sequence the column default draws its values from.
+    pub fn standalone_sequence(&self) -> Option<&'a Sequence> {
+        match self.default().map(|default| default.kind()) {
+            Some(DefaultKind::SEQUENCE(name)) => self
+                .schema
+                .get_sequence(name)
+                .filter(|sequence| sequence.is_standalone()),
+            _ => None,
+        }
+    }
+
+    /// Is this column an identity column?
+    pub fn is_identity(&self) -> bool {
+        self.owned_sequence()
+            .map(|sequence| sequence.is_identity())
+            .unwrap_or(false)
+    }
+
     /// Is this column a part of the table's primary key?
     pub fn is_part_of_primary_key(&self) -> bool {
         self.table().table().is_part_of_primary_key(self.name())
@@ -240,6 +279,11 @@ impl<'a> TableWalker<'a> {
         &self.table().name
     }
 
+    /// The comment on the table.
+    pub fn comment(&self) -> Option<&'a str> {
+        self.table().comment.as_deref()
+    }
+
     /// Try to traverse a foreign key for a single column.
     pub fn foreign_key_for_column(&self, column: &str) -> Option<&'a ForeignKey> {
         self.table().foreign_key_for_column(column)
@@ -101,6 +101,8 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "column2".to_string(),
@@ -115,6 +117,8 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
     ];
 
@@ -158,6 +162,8 @@ async fn foreign_keys_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        comment: None,
+        generated: None,
     }];
 
     let on_delete_action = match api.sql_family() {
@@ -194,6 +200,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             }],
+            comment: None,
         }
     );
 }
@@ -253,6 +260,8 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "city_name".to_string(),
@@ -270,6 +279,8 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
     ];
 
@@ -309,6 +320,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             },],
+            comment: None,
         }
     );
 }
@@ -337,6 +349,8 @@ async fn names_with_hyphens_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        comment: None,
+        generated: None,
     }];
     assert_eq!(user_table.columns, expected_columns);
 }
@@ -388,6 +402,8 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "name".to_string(),
@@ -405,6 +421,8 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -425,6 +443,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 }
             }),
             foreign_keys: vec![],
+            comment: None,
         }
     );
 }
@@ -461,6 +480,8 @@ async fn indices_must_work(api: &TestApi) {
 
             default,
             auto_increment: true,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "count".to_string(),
@@ -475,11 +496,20 @@ async fn indices_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
     ];
     let pk_sequence = match api.sql_family() {
         SqlFamily::Postgres => Some(Sequence {
             name: "User_id_seq".to_string(),
+            start: 1,
+            increment: 1,
+            owned_by: Some(SequenceOwner {
+                table: "User".to_string(),
+                column: "id".to_string(),
+                identity: false,
+            }),
         }),
         _ => None,
     };
@@ -543,6 +573,8 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "uniq2".to_string(),
@@ -558,6 +590,8 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
     ];
     let mut expected_indices = vec![Index {
@@ -620,6 +654,7 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                     indices: expected_indices,
                     primary_key: None,
                     foreign_keys: vec![],
+                    comment: None,
                 }
             );
         }
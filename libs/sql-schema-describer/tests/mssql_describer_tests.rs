@@ -64,6 +64,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "bit_col".to_string(),
@@ -78,6 +80,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -92,6 +96,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -105,6 +111,8 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "money_col".to_string(),
@@ -119,6 +127,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -133,6 +143,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -147,6 +159,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "smallmoney_col".to_string(),
@@ -161,6 +175,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "tinyint_col".to_string(),
@@ -175,6 +191,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -189,6 +207,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -203,6 +223,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -217,6 +239,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -230,6 +254,8 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "datetime2_col".to_string(),
@@ -244,6 +270,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "datetimeoffset_col".to_string(),
@@ -258,6 +286,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "smalldatetime_col".to_string(),
@@ -272,6 +302,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -286,6 +318,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -300,6 +334,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -314,6 +350,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "varchar_max_col".to_string(),
@@ -328,6 +366,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -342,6 +382,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "nvarchar_col".to_string(),
@@ -356,6 +398,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "nvarchar_max_col".to_string(),
@@ -370,6 +414,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "ntext_col".to_string(),
@@ -384,6 +430,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -398,6 +446,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -412,6 +462,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "varbinary_max_col".to_string(),
@@ -426,6 +478,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "image_col".to_string(),
@@ -440,6 +494,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "xml_col".to_string(),
@@ -454,6 +510,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -517,6 +575,8 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -530,6 +590,8 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -543,6 +605,8 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
             ],
             indices: vec![],
@@ -569,6 +633,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
             ],
+            comment: None,
         }
     );
 }
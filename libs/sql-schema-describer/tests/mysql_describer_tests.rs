@@ -77,6 +77,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -91,6 +93,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -105,6 +109,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -118,6 +124,8 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -132,6 +140,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -146,6 +156,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -160,6 +172,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -174,6 +188,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -188,6 +204,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -202,6 +220,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -216,6 +236,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -230,6 +252,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -244,6 +268,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -258,6 +284,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -272,6 +300,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: Some(DefaultValue::now()),
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -286,6 +316,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -300,6 +332,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -314,6 +348,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -328,6 +364,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -342,6 +380,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -356,6 +396,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -370,6 +412,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -384,6 +428,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -398,6 +444,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -412,6 +460,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -426,6 +476,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -440,6 +492,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -455,6 +509,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -469,6 +525,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -483,6 +541,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -497,6 +557,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -511,6 +573,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -525,6 +589,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -539,6 +605,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -553,6 +621,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -567,6 +637,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -581,6 +653,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -595,6 +669,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -609,6 +685,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -625,6 +703,7 @@ async fn all_mysql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            comment: None,
         }
     );
 }
@@ -670,6 +749,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -683,6 +764,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -696,6 +779,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -709,6 +794,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -722,6 +809,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
             ],
             indices: vec![
@@ -785,6 +874,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            comment: None,
         }
     );
 }
@@ -80,6 +80,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -93,6 +95,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "array_date_col".into(),
@@ -106,6 +110,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -119,6 +125,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -132,6 +140,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -145,6 +155,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -158,6 +170,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -171,6 +185,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "binary_col".into(),
@@ -184,6 +200,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -197,6 +215,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "date_time_col".into(),
@@ -210,6 +230,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "double_col".into(),
@@ -223,6 +245,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "float_col".into(),
@@ -236,6 +260,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "int_col".into(),
@@ -249,6 +275,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "primary_col".into(),
@@ -262,6 +290,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_primary_col_seq".to_string())),
             auto_increment: true,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "string1_col".into(),
@@ -275,6 +305,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "string2_col".into(),
@@ -288,6 +320,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -301,6 +335,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -314,6 +350,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_bigserial_col_seq".to_string())),
             auto_increment: true,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "bit_col".into(),
@@ -327,6 +365,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -340,6 +380,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "box_col".into(),
@@ -353,6 +395,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "char_col".into(),
@@ -366,6 +410,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "circle_col".into(),
@@ -379,6 +425,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "line_col".into(),
@@ -392,6 +440,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "time_col".into(),
@@ -405,6 +455,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "timetz_col".into(),
@@ -418,6 +470,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "timestamp_col".into(),
@@ -431,6 +485,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "timestamptz_col".into(),
@@ -444,6 +500,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -457,6 +515,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "numeric_col".into(),
@@ -470,6 +530,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "path_col".into(),
@@ -483,6 +545,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -496,6 +560,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -509,6 +575,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -522,6 +590,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -535,6 +605,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_smallserial_col_seq".to_string())),
             auto_increment: true,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "serial_col".into(),
@@ -548,6 +620,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_serial_col_seq".to_string())),
             auto_increment: true,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -561,6 +635,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -574,6 +650,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "txid_col".into(),
@@ -587,6 +665,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "json_col".into(),
@@ -600,6 +680,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -613,6 +695,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -627,6 +711,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -645,10 +731,18 @@ async fn all_postgres_column_types_must_work() {
                 columns: vec!["primary_col".into()],
                 sequence: Some(Sequence {
                     name: "User_primary_col_seq".into(),
+                    start: 1,
+                    increment: 1,
+                    owned_by: Some(SequenceOwner {
+                        table: "User".into(),
+                        column: "primary_col".into(),
+                        identity: false,
+                    }),
                 },),
                 constraint_name: Some("User_pkey".into()),
             }),
             foreign_keys: vec![],
+            comment: None,
         }
     );
 }
@@ -693,6 +787,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city".into(),
@@ -707,6 +803,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_cascade".into(),
@@ -721,6 +819,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_restrict".into(),
@@ -735,6 +835,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_null".into(),
@@ -749,6 +851,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_default".into(),
@@ -763,6 +867,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
             ],
             indices: vec![],
@@ -813,6 +919,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            comment: None,
         }
     );
 }
@@ -849,7 +956,79 @@ async fn postgres_sequences_must_work() {
     let schema = inspector.describe(SCHEMA).await.expect("describing");
     let got_seq = schema.get_sequence("test").expect("get sequence");
 
-    assert_eq!(got_seq, &Sequence { name: "test".into() },);
+    assert_eq!(got_seq, &Sequence::new("test"));
+}
+
+#[tokio::test]
+async fn postgres_sequence_options_must_work() {
+    let inspector = get_postgres_describer(
+        &format!(
+            "CREATE SEQUENCE \"{}\".\"invoice_numbers\" START WITH 1000 INCREMENT BY 10",
+            SCHEMA
+        ),
+        "postgres_sequence_options_must_work",
+    )
+    .await;
+
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let got_seq = schema.get_sequence("invoice_numbers").expect("get sequence");
+
+    assert_eq!(
+        got_seq,
+        &Sequence {
+            name: "invoice_numbers".into(),
+            start: 1000,
+            increment: 10,
+            owned_by: None,
+        }
+    );
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_identity_columns_must_work(api: &TestApi) -> TestResult {
+    // Identity columns were introduced in Postgres 10.
+    if api.connector_name() == "postgres9" {
+        return Ok(());
+    }
+
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Invoice" (
+                id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY (START WITH 100 INCREMENT BY 5),
+                serial SERIAL NOT NULL
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&sql, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_walkers().find(|table| table.name() == "Invoice").unwrap();
+
+    let id = table.column("id").unwrap();
+    assert!(id.is_autoincrement());
+    assert!(id.is_identity());
+    assert_eq!(
+        id.owned_sequence(),
+        Some(&Sequence {
+            name: "Invoice_id_seq".into(),
+            start: 100,
+            increment: 5,
+            owned_by: Some(SequenceOwner {
+                table: "Invoice".into(),
+                column: "id".into(),
+                identity: true,
+            }),
+        })
+    );
+
+    let serial = table.column("serial").unwrap();
+    assert!(serial.is_autoincrement());
+    assert!(!serial.is_identity());
+    assert!(serial.standalone_sequence().is_none());
+
+    Ok(())
 }
 
 #[tokio::test]
@@ -38,6 +38,8 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "column2".to_string(),
@@ -52,6 +54,8 @@ fn database_schema_is_serializable() {
                         },
                         default: Some(DefaultValue::value("default value".to_string())),
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                     Column {
                         name: "column3".to_string(),
@@ -66,6 +70,8 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                 ],
                 indices: vec![Index {
@@ -86,6 +92,7 @@ fn database_schema_is_serializable() {
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 }],
+                comment: None,
             },
             Table {
                 name: "table2".to_string(),
@@ -102,6 +109,8 @@ fn database_schema_is_serializable() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
+                    generated: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -110,15 +119,14 @@ fn database_schema_is_serializable() {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                comment: None,
             },
         ],
         enums: vec![Enum {
             name: "enum1".to_string(),
             values: enum_values,
         }],
-        sequences: vec![Sequence {
-            name: "sequence1".to_string(),
-        }],
+        sequences: vec![Sequence::new("sequence1")],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -150,10 +158,13 @@ fn database_schema_without_primary_key_is_serializable() {
                 },
                 default: None,
                 auto_increment: false,
+                comment: None,
+                generated: None,
             }],
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
@@ -197,6 +208,8 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         },
         default: None,
         auto_increment: false,
+        comment: None,
+        generated: None,
     })
     .collect();
     let schema = SqlSchema {
@@ -206,6 +219,7 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
@@ -241,6 +255,8 @@ fn database_schema_is_serializable_for_every_column_arity() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         })
         .collect();
     let schema = SqlSchema {
@@ -250,6 +266,7 @@ fn database_schema_is_serializable_for_every_column_arity() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
@@ -285,6 +302,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                     default: None,
                 },
                 Column {
@@ -299,6 +318,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                     default: None,
                 },
                 Column {
@@ -313,6 +334,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                     default: None,
                 },
                 Column {
@@ -327,6 +350,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                     default: None,
                 },
                 Column {
@@ -341,6 +366,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                     default: None,
                 },
             ],
@@ -388,6 +415,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
             ],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
@@ -40,6 +40,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "int4_col".to_string(),
@@ -53,6 +55,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -66,6 +70,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -79,6 +85,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -92,6 +100,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: true,
+            comment: None,
+            generated: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -105,6 +115,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
+            generated: None,
         },
     ];
 
@@ -120,6 +132,7 @@ async fn sqlite_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            comment: None,
         }
     );
 }
@@ -159,6 +172,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -172,6 +187,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -185,6 +202,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -198,6 +217,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -211,6 +232,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -225,6 +248,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
+                    generated: None,
                 },
             ],
             indices: vec![],
@@ -275,6 +300,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            comment: None,
         }
     );
 }
@@ -148,6 +148,26 @@ pub struct CannotRollBackSucceededMigration {
 )]
 pub struct DeprecatedProviderArray;
 
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3014",
+    message = "The migration `{migration_name}` could not be found in the migrations directory, it cannot be squashed."
+)]
+pub struct MigrationToSquashNotFound {
+    /// The name of the migration.
+    pub migration_name: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3015",
+    message = "The migrations to squash are only partially applied to the database. Apply or roll back the following migrations before squashing: {unapplied_migration_names}"
+)]
+pub struct MigrationsToSquashPartiallyApplied {
+    /// The names of the migrations in the range that are not applied, comma separated.
+    pub unapplied_migration_names: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,3 +241,39 @@ pub struct TableDoesNotExist {
 pub struct ColumnDoesNotExist {
     pub column: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2023",
+    message = "The persisted operation `{id}` was not found. Please send the query along with its id to register it."
+)]
+pub struct PersistedOperationNotFound {
+    pub id: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2024",
+    message = "The `{model_name}` record was modified concurrently: its current version does not match the expected version `{expected_version}`."
+)]
+pub struct VersionConflict {
+    pub model_name: String,
+    pub expected_version: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2025",
+    message = "The query did not finish within the timeout of `{timeout}` and was cancelled."
+)]
+pub struct QueryTimeout {
+    /// The timeout of the request, in ms.
+    pub timeout: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2026",
+    message = "Timed out fetching a new connection from the connection pool. The pool may be too small for the load, see the `connection_limit` and `pool_timeout` parameters of the connection string."
+)]
+pub struct ConnectionPoolTimeout;
@@ -4,7 +4,7 @@ mod tests;
 
 use error::CliError;
 use futures::FutureExt;
-use migration_core::migration_api;
+use migration_core::{migration_api, CoreError, DiffInput, DiffTarget};
 use structopt::StructOpt;
 use user_facing_errors::{
     common::{InvalidDatabaseString, SchemaParserError},
@@ -13,21 +13,29 @@ use user_facing_errors::{
 
 #[derive(Debug, StructOpt)]
 pub(crate) struct Cli {
-    /// The connection string to the database
+    /// The connection string to the database. Every command but `diff`
+    /// requires it.
     #[structopt(long, short = "d", parse(try_from_str = parse_base64_string))]
-    datasource: String,
+    datasource: Option<String>,
     #[structopt(subcommand)]
     command: CliCommand,
 }
 
 impl Cli {
     pub(crate) async fn run(self, enabled_preview_features: Vec<String>) -> ! {
+        let print_output = matches!(self.command, CliCommand::Diff(_));
+
         match std::panic::AssertUnwindSafe(self.run_inner(enabled_preview_features))
             .catch_unwind()
             .await
         {
             Ok(Ok(msg)) => {
                 tracing::info!("{}", msg);
+
+                if print_output {
+                    println!("{}", msg);
+                }
+
                 std::process::exit(0);
             }
             Ok(Err(error)) => {
@@ -52,17 +60,26 @@ impl Cli {
 
     pub(crate) async fn run_inner(self, enabled_preview_features: Vec<String>) -> Result<String, CliError> {
         match self.command {
-            CliCommand::CreateDatabase => create_database(&self.datasource).await,
-            CliCommand::CanConnectToDatabase => connect_to_database(&self.datasource, enabled_preview_features).await,
-            CliCommand::DropDatabase => drop_database(&self.datasource).await,
+            CliCommand::CreateDatabase => create_database(required_datasource(&self.datasource)?).await,
+            CliCommand::CanConnectToDatabase => {
+                connect_to_database(required_datasource(&self.datasource)?, enabled_preview_features).await
+            }
+            CliCommand::DropDatabase => drop_database(required_datasource(&self.datasource)?).await,
             CliCommand::QeSetup => {
-                qe_setup(&self.datasource).await?;
+                qe_setup(required_datasource(&self.datasource)?).await?;
                 Ok(String::new())
             }
+            CliCommand::Diff(diff_command) => diff(self.datasource.as_deref(), diff_command).await,
         }
     }
 }
 
+fn required_datasource(datasource: &Option<String>) -> Result<&str, CliError> {
+    datasource.as_deref().ok_or_else(|| {
+        CoreError::Generic(anyhow::anyhow!("The --datasource flag is required for this command.")).into()
+    })
+}
+
 #[derive(Debug, StructOpt)]
 enum CliCommand {
     /// Create an empty database defined in the configuration string.
@@ -73,6 +90,52 @@ enum CliCommand {
     DropDatabase,
     /// Set up the database for connector-test-kit.
     QeSetup,
+    /// Print the migration between two schemas. The datasource is only
+    /// required when diffing a migrations directory, which is replayed on it
+    /// as a shadow database. Live databases are passed with --from-url and
+    /// --to-url.
+    Diff(DiffCommand),
+}
+
+#[derive(Debug, StructOpt)]
+struct DiffCommand {
+    /// Diff from an empty schema.
+    #[structopt(long)]
+    from_empty: bool,
+    /// Diff from the Prisma schema at this path.
+    #[structopt(long)]
+    from_schema_datamodel: Option<String>,
+    /// Diff from the migrations directory at this path.
+    #[structopt(long)]
+    from_migrations: Option<String>,
+    /// Diff from the SQL schema snapshot at this path.
+    #[structopt(long)]
+    from_schema_snapshot: Option<String>,
+    /// Diff from the database at this URL.
+    #[structopt(long)]
+    from_url: Option<String>,
+    /// Diff to an empty schema.
+    #[structopt(long)]
+    to_empty: bool,
+    /// Diff to the Prisma schema at this path.
+    #[structopt(long)]
+    to_schema_datamodel: Option<String>,
+    /// Diff to the migrations directory at this path.
+    #[structopt(long)]
+    to_migrations: Option<String>,
+    /// Diff to the SQL schema snapshot at this path.
+    #[structopt(long)]
+    to_schema_snapshot: Option<String>,
+    /// Diff to the database at this URL.
+    #[structopt(long)]
+    to_url: Option<String>,
+    /// The provider of the SQL dialect to diff with, when no schema or URL
+    /// determines it, for example between two schema snapshots.
+    #[structopt(long)]
+    provider: Option<String>,
+    /// Print the migration as an SQL script instead of a JSON summary.
+    #[structopt(long)]
+    script: bool,
 }
 
 fn parse_base64_string(s: &str) -> Result<String, CliError> {
@@ -110,6 +173,80 @@ async fn drop_database(database_str: &str) -> Result<String, CliError> {
     Ok(format!("The database was successfully dropped."))
 }
 
+async fn diff(database_str: Option<&str>, command: DiffCommand) -> Result<String, CliError> {
+    let from = diff_target(
+        "from",
+        command.from_empty,
+        command.from_schema_datamodel,
+        command.from_migrations,
+        command.from_schema_snapshot,
+        command.from_url,
+    )?;
+    let to = diff_target(
+        "to",
+        command.to_empty,
+        command.to_schema_datamodel,
+        command.to_migrations,
+        command.to_schema_snapshot,
+        command.to_url,
+    )?;
+
+    let diffs_migrations = [&from, &to]
+        .iter()
+        .any(|target| matches!(target, DiffTarget::Migrations { .. }));
+
+    if diffs_migrations && database_str.is_none() {
+        return Err(CoreError::Generic(anyhow::anyhow!(
+            "The --datasource flag is required to diff a migrations directory. It is used as shadow database."
+        ))
+        .into());
+    }
+
+    let input = DiffInput {
+        from,
+        to,
+        shadow_database_url: database_str.map(String::from),
+        provider: command.provider,
+    };
+
+    let output = migration_core::diff(&input).await?;
+
+    if command.script {
+        Ok(output.script)
+    } else {
+        Ok(serde_json::to_string_pretty(&output).expect("failed to serialize the diff"))
+    }
+}
+
+fn diff_target(
+    side: &str,
+    empty: bool,
+    schema_datamodel: Option<String>,
+    migrations: Option<String>,
+    schema_snapshot: Option<String>,
+    url: Option<String>,
+) -> Result<DiffTarget, CliError> {
+    let mut targets = Vec::new();
+
+    if empty {
+        targets.push(DiffTarget::Empty);
+    }
+
+    targets.extend(schema_datamodel.map(|path| DiffTarget::SchemaDatamodel { path }));
+    targets.extend(migrations.map(|path| DiffTarget::Migrations { path }));
+    targets.extend(schema_snapshot.map(|path| DiffTarget::SchemaSnapshot { path }));
+    targets.extend(url.map(|url| DiffTarget::Url { url }));
+
+    match targets.len() {
+        1 => Ok(targets.pop().unwrap()),
+        _ => Err(CoreError::Generic(anyhow::anyhow!(
+            "Exactly one of the --{side}-* flags must be passed to diff.",
+            side = side
+        ))
+        .into()),
+    }
+}
+
 async fn qe_setup(prisma_schema: &str) -> Result<(), CliError> {
     migration_core::qe_setup(&prisma_schema).await?;
 
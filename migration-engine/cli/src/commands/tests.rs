@@ -173,6 +173,200 @@ async fn test_drop_sqlite_database() {
     assert!(!sqlite_path.exists());
 }
 
+#[tokio::test]
+async fn test_diff_sqlite_schema_from_empty() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let sqlite_path = base_dir.path().join("test.db");
+    let url = format!("file:{}", sqlite_path.to_string_lossy());
+    let schema_path = base_dir.path().join("schema.prisma");
+
+    let schema = format!(
+        r#"
+            datasource db {{
+                provider = "sqlite"
+                url = "{}"
+            }}
+
+            model Cat {{
+                id Int @id
+                name String
+            }}
+        "#,
+        url
+    );
+
+    std::fs::write(&schema_path, schema).unwrap();
+
+    let script = run(&[
+        "--datasource",
+        &url,
+        "diff",
+        "--from-empty",
+        "--to-schema-datamodel",
+        &schema_path.to_string_lossy(),
+        "--script",
+    ])
+    .await
+    .unwrap();
+
+    assert!(script.contains("CREATE TABLE"));
+    assert!(script.contains("Cat"));
+    assert!(!sqlite_path.exists());
+}
+
+#[tokio::test]
+async fn test_diff_sqlite_schema_to_schema_without_datasource() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let url = format!("file:{}", base_dir.path().join("test.db").to_string_lossy());
+    let from_path = base_dir.path().join("from.prisma");
+    let to_path = base_dir.path().join("to.prisma");
+
+    let schema = |fields: &str| {
+        format!(
+            r#"
+                datasource db {{
+                    provider = "sqlite"
+                    url = "{}"
+                }}
+
+                model Cat {{
+                    id Int @id
+                    {}
+                }}
+            "#,
+            url, fields
+        )
+    };
+
+    std::fs::write(&from_path, schema("")).unwrap();
+    std::fs::write(&to_path, schema("name String?")).unwrap();
+
+    let script = run(&[
+        "diff",
+        "--from-schema-datamodel",
+        &from_path.to_string_lossy(),
+        "--to-schema-datamodel",
+        &to_path.to_string_lossy(),
+        "--script",
+    ])
+    .await
+    .unwrap();
+
+    assert!(script.contains("name"));
+    assert!(!script.contains("CREATE TABLE"));
+}
+
+#[tokio::test]
+async fn test_diff_schema_snapshots_with_a_provider_and_without_datasource() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let snapshot_path = base_dir.path().join("snapshot.json");
+
+    std::fs::write(&snapshot_path, r#"{"tables":[],"enums":[],"sequences":[]}"#).unwrap();
+
+    let output = run(&[
+        "diff",
+        "--from-schema-snapshot",
+        &snapshot_path.to_string_lossy(),
+        "--to-schema-snapshot",
+        &snapshot_path.to_string_lossy(),
+        "--provider",
+        "sqlite",
+    ])
+    .await
+    .unwrap();
+
+    assert!(output.contains(r#""isEmpty": true"#));
+}
+
+#[tokio::test]
+async fn test_diff_schema_with_an_unset_url_env_var_and_without_datasource() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let schema_path = base_dir.path().join("schema.prisma");
+
+    let schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = env("DIFF_TEST_UNSET_DATABASE_URL")
+        }
+
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    std::fs::write(&schema_path, schema).unwrap();
+
+    let script = run(&[
+        "diff",
+        "--from-empty",
+        "--to-schema-datamodel",
+        &schema_path.to_string_lossy(),
+        "--script",
+    ])
+    .await
+    .unwrap();
+
+    assert!(script.contains(r#"CREATE TABLE "Cat""#));
+}
+
+#[tokio::test]
+async fn test_diff_across_providers_must_error() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let from_path = base_dir.path().join("from.prisma");
+    let to_path = base_dir.path().join("to.prisma");
+
+    let schema = |provider: &str, url: &str| {
+        format!(
+            r#"
+                datasource db {{
+                    provider = "{}"
+                    url = "{}"
+                }}
+
+                model Cat {{
+                    id Int @id
+                }}
+            "#,
+            provider, url
+        )
+    };
+
+    std::fs::write(&from_path, schema("sqlite", "file:dev.db")).unwrap();
+    std::fs::write(&to_path, schema("postgresql", "postgresql://localhost/dev")).unwrap();
+
+    let error = run(&[
+        "diff",
+        "--from-schema-datamodel",
+        &from_path.to_string_lossy(),
+        "--to-schema-datamodel",
+        &to_path.to_string_lossy(),
+    ])
+    .await
+    .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("The from schema uses the `sqlite` provider, but the to schema uses the `postgresql` provider."));
+}
+
+#[tokio::test]
+async fn test_diff_migrations_without_datasource_must_error() {
+    let base_dir = tempfile::tempdir().unwrap();
+
+    let error = run(&[
+        "diff",
+        "--from-migrations",
+        &base_dir.path().to_string_lossy(),
+        "--to-empty",
+    ])
+    .await
+    .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("The --datasource flag is required to diff a migrations directory."));
+}
+
 #[tokio::test]
 async fn test_drop_postgres_database() {
     let db_name = "this_should_be_dropped";
@@ -37,11 +37,28 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
         target_schema: &Datamodel,
     ) -> ConnectorResult<T>;
 
+    /// Infer a migration equivalent to `squashed_migrations`, applied after
+    /// `previous_migrations`. Both histories are replayed on a temporary
+    /// database, and the resulting migration takes the schema at the end of the
+    /// first to the schema at the end of the second.
+    async fn infer_squashed_migration(
+        &self,
+        previous_migrations: &[MigrationDirectory],
+        squashed_migrations: &[MigrationDirectory],
+    ) -> ConnectorResult<T>;
+
     /// Check that the current local database's schema matches its expected
     /// state at the end of the passed in migrations history. If there is drift,
     /// it should return a script to attempt to correct it.
     async fn calculate_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<Option<String>>;
 
+    /// Describe the schema at the end of the migration history, serialized as
+    /// a snapshot to store with the last migration. Connectors that do not
+    /// support schema snapshots return `None`.
+    async fn schema_snapshot(&self, _migrations: &[MigrationDirectory]) -> ConnectorResult<Option<serde_json::Value>> {
+        Ok(None)
+    }
+
     /// If possible, check that the passed in migrations apply cleanly.
     async fn validate_migrations(&self, migrations: &[MigrationDirectory]) -> ConnectorResult<()>;
 }
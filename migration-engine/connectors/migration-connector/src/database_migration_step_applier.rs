@@ -18,6 +18,31 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     /// Apply a migration script to the database. The migration persistence is
     /// managed by the core.
     async fn apply_script(&self, script: &str) -> ConnectorResult<()>;
+
+    /// Apply a script from a migration directory to the database, one
+    /// statement at a time. When `transactional` is true, the statements are
+    /// applied in a single transaction on databases with transactional DDL.
+    /// Errors mention the statement that failed and its position in the script.
+    async fn apply_migration_script(&self, script_name: &str, script: &str, transactional: bool)
+        -> ConnectorResult<()>;
+
+    /// Apply one batch of a batched data transformation step, and record that
+    /// the step of the migration with `migration_id` was applied up to and
+    /// including `cursor`. When `transactional` is true, the batch and its
+    /// progress are committed together, so an interrupted step resumes right
+    /// after the last committed batch.
+    async fn apply_batch(
+        &self,
+        migration_id: &str,
+        script_name: &str,
+        script: &str,
+        cursor: i64,
+        transactional: bool,
+    ) -> ConnectorResult<()>;
+
+    /// The smallest and largest values of `column` in `table`, used to split
+    /// batched data transformation steps. Returns `None` if the table is empty.
+    async fn batch_bounds(&self, table: &str, column: &str) -> ConnectorResult<Option<(i64, i64)>>;
 }
 
 /// A helper struct to serialize a database migration with an additional `raw` field containing the
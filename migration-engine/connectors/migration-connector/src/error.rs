@@ -48,6 +48,18 @@ impl ConnectorError {
         }
     }
 
+    /// Add context to the error report. The user-facing error, if any, is
+    /// left untouched.
+    pub fn with_context<C>(self, context: C) -> Self
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        ConnectorError {
+            report: self.report.context(context),
+            ..self
+        }
+    }
+
     /// Turn the error into a nested, user-facing MigrationDoesNotApplyCleanly error.
     pub fn into_migration_does_not_apply_cleanly(self, migration_name: String) -> Self {
         let context = self.context.clone();
@@ -55,7 +55,8 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     /// `record_migration_started()` instead.
     async fn record_migration_started_impl(&self, migration_name: &str, checksum: &str) -> ConnectorResult<String>;
 
-    /// Increase the applied_steps_count counter.
+    /// Increase the applied_steps_count counter, reset the batch cursor, and
+    /// clear the logs of an earlier failed attempt at the step.
     async fn record_successful_step(&self, id: &str) -> ConnectorResult<()>;
 
     /// Report logs for a failed migration step. We assume the next steps in the
@@ -67,7 +68,8 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()>;
 
     /// List all applied migrations, ordered by `started_at`. This should fail
-    /// hard if the migration persistence is not initialized.
+    /// hard if the migration persistence is not initialized. It is called on
+    /// read-only paths, so it must not change the migrations table.
     async fn list_migrations(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>>;
 }
 
@@ -107,4 +109,9 @@ pub struct MigrationRecord {
     pub started_at: Timestamp,
     /// The number of migration steps that were successfully applied.
     pub applied_steps_count: u32,
+    /// The last id covered by the applied batches of the step currently being
+    /// applied, if it is a batched data transformation. Missing in migrations
+    /// tables that were not upgraded yet.
+    #[serde(default)]
+    pub batch_cursor: Option<i64>,
 }
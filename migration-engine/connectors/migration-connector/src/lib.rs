@@ -26,7 +26,11 @@ pub use imperative_migrations_persistence::{
 };
 pub use migration_applier::*;
 pub use migration_persistence::*;
-pub use migrations_directory::{create_migration_directory, list_migrations, ListMigrationsError, MigrationDirectory};
+pub use migrations_directory::{
+    create_migration_directory, create_squashed_migration_directory, list_migrations, migration_history_checksum,
+    migration_history_checksums, BatchDirective, ListMigrationsError, MigrationDirectory, MigrationStepScript,
+    NO_TRANSACTION_DIRECTIVE,
+};
 pub use steps::MigrationStep;
 
 use sha2::{Digest, Sha256};
@@ -7,7 +7,16 @@
 //! directorys, named after the migration id, and each containing:
 //!
 //! - A migration script
+//! - Optionally, additional step scripts, applied in lexicographic order after
+//!   the migration script. Steps starting with a `-- prisma:batch` directive
+//!   are data transformations, applied in batches over a range of ids.
+//!
+//!   Each script is applied in a transaction on databases with transactional
+//!   DDL, unless its header has a `-- prisma:no-transaction` directive.
+//! - Optionally, a snapshot of the database schema after the migration, valid
+//!   as long as the migration history up to that migration is unchanged.
 
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256, Sha512};
 use std::{
     error::Error,
@@ -23,12 +32,81 @@ use crate::FormatChecksum;
 /// The file name for migration scripts, not including the file extension.
 pub const MIGRATION_SCRIPT_FILENAME: &str = "migration";
 
+/// The file extension of migration and step scripts.
+const SCRIPT_EXTENSION: &str = "sql";
+
+/// The file name for database schema snapshots.
+pub const SCHEMA_SNAPSHOT_FILENAME: &str = "schema_snapshot.json";
+
+/// The header directive marking a step script as a batched data transformation.
+pub const BATCH_DIRECTIVE: &str = "-- prisma:batch";
+
+/// The header directive opting a script out of the migration transaction, for
+/// statements that cannot run in a transaction block.
+pub const NO_TRANSACTION_DIRECTIVE: &str = "-- prisma:no-transaction";
+
+/// The placeholder for the first id of the current batch in batched step scripts.
+pub const BATCH_START_PLACEHOLDER: &str = "{{batch_start}}";
+
+/// The placeholder for the last id of the current batch in batched step scripts.
+pub const BATCH_END_PLACEHOLDER: &str = "{{batch_end}}";
+
 /// Create a directory for a new migration.
 pub fn create_migration_directory(
     migrations_directory_path: &Path,
     migration_name: &str,
 ) -> io::Result<MigrationDirectory> {
-    let timestamp = chrono::Utc::now().format("%Y%m%d%H%M%S");
+    let timestamp = chrono::Utc::now().format("%Y%m%d%H%M%S").to_string();
+
+    create_migration_directory_with_timestamp(migrations_directory_path, &timestamp, migration_name)
+}
+
+/// Create a directory for a migration replacing `squashed_migrations`. It
+/// reuses the timestamp of the last squashed migration, so it takes the place
+/// of the squashed migrations in the history: after `previous_migration`, the
+/// last migration before them, and before `next_migration`, the first
+/// migration after them. A later timestamp could collide with the next
+/// migration. It is an error if the new name does not sort between them.
+pub fn create_squashed_migration_directory(
+    migrations_directory_path: &Path,
+    previous_migration: Option<&MigrationDirectory>,
+    squashed_migrations: &[MigrationDirectory],
+    next_migration: Option<&MigrationDirectory>,
+    migration_name: &str,
+) -> io::Result<MigrationDirectory> {
+    let last_migration_name = squashed_migrations
+        .last()
+        .map(|migration| migration.migration_name())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "There are no migrations to squash."))?;
+
+    let timestamp = last_migration_name.splitn(2, '_').next().unwrap_or(last_migration_name);
+    let directory_name = format!("{}_{}", timestamp, migration_name);
+
+    let sorts_after_previous = previous_migration
+        .map(|migration| directory_name.as_str() > migration.migration_name())
+        .unwrap_or(true);
+    let sorts_before_next = next_migration
+        .map(|migration| directory_name.as_str() < migration.migration_name())
+        .unwrap_or(true);
+
+    if !sorts_after_previous || !sorts_before_next {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "The squashed migration `{}` would not sort between the migrations before and after the squashed ones. Please choose another name.",
+                directory_name,
+            ),
+        ));
+    }
+
+    create_migration_directory_with_timestamp(migrations_directory_path, timestamp, migration_name)
+}
+
+fn create_migration_directory_with_timestamp(
+    migrations_directory_path: &Path,
+    timestamp: &str,
+    migration_name: &str,
+) -> io::Result<MigrationDirectory> {
     let directory_name = format!(
         "{timestamp}_{migration_name}",
         timestamp = timestamp,
@@ -126,6 +204,45 @@ impl Error for ReadMigrationScriptError {
     }
 }
 
+/// Compute the checksum of a migration history: the names and scripts of all
+/// the migrations, in order. Schema snapshots are only valid for the history
+/// they were taken after.
+pub fn migration_history_checksum(migrations: &[MigrationDirectory]) -> Result<String, ReadMigrationScriptError> {
+    match migration_history_checksums(migrations)?.pop() {
+        Some(checksum) => Ok(checksum),
+        None => {
+            let checksum: [u8; 32] = Sha256::new().finalize().into();
+
+            Ok(checksum.format_checksum())
+        }
+    }
+}
+
+/// The checksums of every prefix of a migration history, in one pass: the
+/// checksum at index `i` is the `migration_history_checksum` of the first
+/// `i + 1` migrations.
+pub fn migration_history_checksums(migrations: &[MigrationDirectory]) -> Result<Vec<String>, ReadMigrationScriptError> {
+    let mut hasher = Sha256::new();
+    let mut checksums = Vec::with_capacity(migrations.len());
+
+    for migration in migrations {
+        hasher.update(migration.migration_name().as_bytes());
+        hasher.update(&[0]);
+
+        for step in migration.read_migration_steps()? {
+            hasher.update(step.file_name().as_bytes());
+            hasher.update(&[0]);
+            hasher.update(step.script().as_bytes());
+            hasher.update(&[0]);
+        }
+
+        let checksum: [u8; 32] = hasher.clone().finalize().into();
+        checksums.push(checksum.format_checksum());
+    }
+
+    Ok(checksums)
+}
+
 impl MigrationDirectory {
     /// Initialize a MigrationDirectory at the provided path. This will not
     /// validate that the path is valid and exists.
@@ -181,10 +298,124 @@ impl MigrationDirectory {
         Ok(())
     }
 
-    /// Read the migration script to a string.
+    /// Write an additional step script to the directory.
+    #[tracing::instrument(skip(script))]
+    pub fn write_migration_step(&self, file_name: &str, script: &str) -> std::io::Result<()> {
+        let path = self.path.join(file_name);
+
+        tracing::debug!("Writing migration step at {:?}", &path);
+
+        std::fs::write(&path, script)
+    }
+
+    /// Read the scripts of all the steps in the migration, concatenated in
+    /// order. For migrations with a single step, this is the migration script.
+    /// Otherwise, each step is preceded by a comment line with its file name
+    /// and length, so the checksum of the script tells apart migrations that
+    /// split the same SQL into different steps.
     #[tracing::instrument]
     pub fn read_migration_script(&self) -> Result<String, ReadMigrationScriptError> {
-        Ok(std::fs::read_to_string(&self.path.join("migration.sql"))?)
+        use std::fmt::Write as _;
+
+        let mut steps = self.read_migration_steps()?;
+
+        if steps.len() == 1 {
+            return Ok(steps.pop().unwrap().script().to_owned());
+        }
+
+        let mut script = String::new();
+
+        for step in steps {
+            writeln!(script, "-- Step: {} ({} bytes)", step.file_name(), step.script().len()).unwrap();
+            script.push_str(step.script());
+            script.push('\n');
+        }
+
+        Ok(script)
+    }
+
+    /// Read the steps of the migration, in the order they should be applied.
+    /// The migration script always comes first, followed by the additional
+    /// step scripts sorted by file name.
+    #[tracing::instrument]
+    pub fn read_migration_steps(&self) -> Result<Vec<MigrationStepScript>, ReadMigrationScriptError> {
+        let migration_script_file_name = format!("{}.{}", MIGRATION_SCRIPT_FILENAME, SCRIPT_EXTENSION);
+        let mut additional_step_file_names = Vec::new();
+
+        for entry in read_dir(&self.path)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if !entry.file_type()?.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some(SCRIPT_EXTENSION)
+            {
+                continue;
+            }
+
+            let file_name = entry
+                .file_name()
+                .into_string()
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Step file name is not valid UTF-8."))?;
+
+            if file_name != migration_script_file_name {
+                additional_step_file_names.push(file_name);
+            }
+        }
+
+        additional_step_file_names.sort();
+
+        std::iter::once(migration_script_file_name)
+            .chain(additional_step_file_names)
+            .map(|file_name| {
+                let script = std::fs::read_to_string(self.path.join(&file_name))?;
+
+                MigrationStepScript::new(file_name, script)
+            })
+            .collect()
+    }
+
+    /// Write a snapshot of the database schema after the migration, taken
+    /// after the migration history with the given checksum.
+    #[tracing::instrument(skip(schema))]
+    pub fn write_schema_snapshot(&self, history_checksum: &str, schema: serde_json::Value) -> std::io::Result<()> {
+        let path = self.path.join(SCHEMA_SNAPSHOT_FILENAME);
+
+        tracing::debug!("Writing schema snapshot at {:?}", &path);
+
+        let snapshot = SchemaSnapshot {
+            history_checksum: history_checksum.to_owned(),
+            schema,
+        };
+
+        let file = std::fs::File::create(&path)?;
+        serde_json::to_writer(io::BufWriter::new(file), &snapshot)?;
+
+        Ok(())
+    }
+
+    /// Read the snapshot of the database schema after the migration. Returns
+    /// `None` if there is no snapshot, or if it was taken after a different
+    /// migration history.
+    #[tracing::instrument]
+    pub fn read_schema_snapshot(&self, history_checksum: &str) -> Option<serde_json::Value> {
+        let path = self.path.join(SCHEMA_SNAPSHOT_FILENAME);
+
+        let snapshot: SchemaSnapshot = match std::fs::read_to_string(&path)
+            .map_err(anyhow::Error::new)
+            .and_then(|contents| serde_json::from_str(&contents).map_err(anyhow::Error::new))
+        {
+            Ok(snapshot) => snapshot,
+            Err(err) => {
+                tracing::debug!("Could not read the schema snapshot at {:?}: {}", &path, err);
+                return None;
+            }
+        };
+
+        if snapshot.history_checksum != history_checksum {
+            tracing::debug!("The schema snapshot at {:?} is outdated.", &path);
+            return None;
+        }
+
+        Some(snapshot.schema)
     }
 
     /// The filesystem path to the directory.
@@ -193,6 +424,150 @@ impl MigrationDirectory {
     }
 }
 
+/// The contents of a schema snapshot file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SchemaSnapshot {
+    history_checksum: String,
+    schema: serde_json::Value,
+}
+
+/// One step of a migration, as read from a script in the migration directory.
+#[derive(Debug, Clone)]
+pub struct MigrationStepScript {
+    file_name: String,
+    script: String,
+    batch: Option<BatchDirective>,
+    transactional: bool,
+}
+
+impl MigrationStepScript {
+    fn new(file_name: String, script: String) -> Result<Self, ReadMigrationScriptError> {
+        let batch = BatchDirective::parse(&script).map_err(|message| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid batch directive in `{}`: {}", file_name, message),
+            )
+        })?;
+
+        let transactional = !header_comments(&script).any(|line| line.trim() == NO_TRANSACTION_DIRECTIVE);
+
+        Ok(MigrationStepScript {
+            file_name,
+            script,
+            batch,
+            transactional,
+        })
+    }
+
+    /// The name of the script file, including the extension.
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// The raw contents of the script.
+    pub fn script(&self) -> &str {
+        &self.script
+    }
+
+    /// The batching configuration, if the step is a batched data transformation.
+    pub fn batch(&self) -> Option<&BatchDirective> {
+        self.batch.as_ref()
+    }
+
+    /// Whether the script should be applied in a transaction, on databases
+    /// that support it.
+    pub fn is_transactional(&self) -> bool {
+        self.transactional
+    }
+
+    /// Render the script for the batch covering the ids from `start` to `end`, inclusive.
+    pub fn render_batch(&self, start: i64, end: i64) -> String {
+        self.script
+            .replace(BATCH_START_PLACEHOLDER, &start.to_string())
+            .replace(BATCH_END_PLACEHOLDER, &end.to_string())
+    }
+}
+
+/// The configuration of a batched data transformation step, read from a
+/// header line of the form:
+///
+/// ```sql
+/// -- prisma:batch table=User column=id size=10000
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchDirective {
+    /// The table the batches range over.
+    pub table: String,
+    /// The integer column the batches range over.
+    pub column: String,
+    /// The number of ids covered by each batch.
+    pub size: i64,
+}
+
+impl BatchDirective {
+    fn parse(script: &str) -> Result<Option<Self>, String> {
+        let directive = match header_comments(script).find_map(|line| line.trim().strip_prefix(BATCH_DIRECTIVE)) {
+            Some(directive) => directive,
+            None => return Ok(None),
+        };
+
+        let mut table = None;
+        let mut column = None;
+        let mut size = None;
+
+        for argument in directive.split_whitespace() {
+            let mut parts = argument.splitn(2, '=');
+
+            match (parts.next(), parts.next()) {
+                (Some("table"), Some(value)) => table = Some(value.to_owned()),
+                (Some("column"), Some(value)) => column = Some(value.to_owned()),
+                (Some("size"), Some(value)) => {
+                    size = Some(
+                        value
+                            .parse::<i64>()
+                            .ok()
+                            .filter(|size| *size > 0)
+                            .ok_or_else(|| format!("`{}` is not a valid batch size.", value))?,
+                    )
+                }
+                _ => return Err(format!("Unknown argument `{}`.", argument)),
+            }
+        }
+
+        Ok(Some(BatchDirective {
+            table: table.ok_or("The `table` argument is missing.")?,
+            column: column.ok_or("The `column` argument is missing.")?,
+            size: size.ok_or("The `size` argument is missing.")?,
+        }))
+    }
+}
+
+/// The comment lines at the start of a script, where directives are read.
+/// Blank lines and block comments, like the warnings of generated migrations,
+/// are skipped.
+fn header_comments(script: &str) -> impl Iterator<Item = &str> {
+    script
+        .lines()
+        .map(str::trim)
+        .scan(false, |in_block_comment, line| {
+            if *in_block_comment {
+                *in_block_comment = !line.ends_with("*/");
+                Some(None)
+            } else if line.starts_with("/*") {
+                *in_block_comment = !line[2..].ends_with("*/");
+                Some(None)
+            } else if line.starts_with("--") {
+                Some(Some(line))
+            } else if line.is_empty() {
+                Some(None)
+            } else {
+                None
+            }
+        })
+        .flatten()
+}
+
 impl From<DirEntry> for MigrationDirectory {
     fn from(entry: DirEntry) -> MigrationDirectory {
         MigrationDirectory { path: entry.path() }
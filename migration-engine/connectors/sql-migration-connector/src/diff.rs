@@ -0,0 +1,133 @@
+//! Diffing of SQL schemas from arbitrary sources, without an initialized
+//! connector.
+
+use crate::{
+    connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, flavour, flavour::SqlFlavour,
+    sql_database_migration_inferrer::infer, sql_database_step_applier, sql_schema_calculator, SqlMigration,
+};
+use datamodel::{Datamodel, ReferentialIntegrity};
+use migration_connector::{
+    ConnectorError, ConnectorResult, DatabaseMigrationMarker, DestructiveChangeDiagnostics, MigrationDirectory,
+    PrettyDatabaseMigrationStep,
+};
+use quaint::{prelude::ConnectionInfo, single::Quaint};
+use sql_schema_describer::SqlSchema;
+
+/// A source of SQL schema to diff.
+#[derive(Debug)]
+pub enum DiffTarget<'a> {
+    /// An empty schema.
+    Empty,
+    /// A Prisma schema, with the referential integrity of its datasource. The
+    /// SQL schema it describes is calculated, no database is involved.
+    Datamodel(&'a Datamodel, ReferentialIntegrity),
+    /// A migrations directory. Its schema snapshot is used when it is valid,
+    /// otherwise the migrations are replayed on the shadow database, or an
+    /// in-memory database on SQLite.
+    Migrations(&'a [MigrationDirectory]),
+    /// A serialized `SqlSchema`, in JSON format.
+    SqlSchemaSnapshot(&'a str),
+    /// The connection string of a live database to describe.
+    Database(&'a str),
+}
+
+/// The result of diffing two SQL schemas.
+#[derive(Debug)]
+pub struct SqlSchemaDiff {
+    /// The migration from the first schema to the second.
+    pub migration: SqlMigration,
+    /// The migration rendered as an SQL script.
+    pub script: String,
+    /// The migration steps, each with their rendered SQL.
+    pub steps: Vec<PrettyDatabaseMigrationStep>,
+}
+
+impl SqlSchemaDiff {
+    /// Whether the two schemas are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.migration.is_empty()
+    }
+}
+
+/// Compute the migration from one schema source to the other. The SQL dialect
+/// is determined by `database_str`, which is never connected to. Migrations
+/// directories without a valid schema snapshot are replayed on
+/// `shadow_database_url`.
+pub async fn diff(
+    database_str: &str,
+    shadow_database_url: Option<&str>,
+    from: DiffTarget<'_>,
+    to: DiffTarget<'_>,
+) -> ConnectorResult<SqlSchemaDiff> {
+    let connection_info =
+        ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+    let flavour = flavour::from_connection_info(&connection_info);
+
+    let from = sql_schema_from_diff_target(from, shadow_database_url, flavour.as_ref()).await?;
+    let to = sql_schema_from_diff_target(to, shadow_database_url, flavour.as_ref()).await?;
+
+    let migration = infer(from, to, flavour.as_ref());
+    let script =
+        sql_database_step_applier::render_script(&migration, &DestructiveChangeDiagnostics::new(), flavour.as_ref());
+    let steps = sql_database_step_applier::render_steps_pretty(&migration, flavour.as_ref());
+
+    Ok(SqlSchemaDiff {
+        migration,
+        script,
+        steps,
+    })
+}
+
+async fn sql_schema_from_diff_target(
+    target: DiffTarget<'_>,
+    shadow_database_url: Option<&str>,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> ConnectorResult<SqlSchema> {
+    match target {
+        DiffTarget::Empty => Ok(SqlSchema::empty()),
+        DiffTarget::Datamodel(datamodel, referential_integrity) => Ok(sql_schema_calculator::calculate_sql_schema(
+            datamodel,
+            flavour,
+            referential_integrity,
+        )),
+        DiffTarget::Migrations(migrations) => {
+            if let Some(schema) = flavour::sql_schema_from_snapshot(migrations) {
+                return Ok(schema);
+            }
+
+            let shadow_database_url = shadow_database_url.ok_or_else(|| {
+                ConnectorError::generic(anyhow::anyhow!(
+                    "A shadow database URL is required to diff a migrations directory without a valid schema snapshot."
+                ))
+            })?;
+            let connection = shadow_database_connection(shadow_database_url).await?;
+
+            flavour
+                .sql_schema_from_migration_history(None, migrations, &connection)
+                .await
+        }
+        DiffTarget::SqlSchemaSnapshot(json) => serde_json::from_str(json).map_err(|err| {
+            ConnectorError::generic(anyhow::Error::new(err).context("Failed to deserialize the SQL schema snapshot."))
+        }),
+        DiffTarget::Database(database_str) => {
+            let connection = connect(database_str).await?;
+            let database_flavour = flavour::from_connection_info(connection.connection_info());
+
+            database_flavour.describe_schema(&connection).await
+        }
+    }
+}
+
+/// SQLite migrations are replayed on an in-memory database, so we avoid
+/// creating a database file by connecting to it.
+async fn shadow_database_connection(database_str: &str) -> ConnectorResult<Connection> {
+    match ConnectionInfo::from_url(database_str) {
+        Ok(ConnectionInfo::Sqlite { db_name, .. }) => {
+            let quaint = Quaint::new_in_memory(Some(db_name.clone()))
+                .map_err(|err| quaint_error_to_connector_error(err, &ConnectionInfo::InMemorySqlite { db_name }))?;
+
+            Ok(Connection::new(quaint))
+        }
+        _ => connect(database_str).await,
+    }
+}
@@ -15,7 +15,7 @@ pub(crate) use sqlite::SqliteFlavour;
 use crate::{
     connection_wrapper::Connection, sql_destructive_change_checker::DestructiveChangeCheckerFlavour,
     sql_renderer::SqlRenderer, sql_schema_calculator::SqlSchemaCalculatorFlavour,
-    sql_schema_differ::SqlSchemaDifferFlavour,
+    sql_schema_differ::SqlSchemaDifferFlavour, sql_script,
 };
 use datamodel::Datamodel;
 use migration_connector::{ConnectorResult, MigrationDirectory};
@@ -47,6 +47,137 @@ pub(crate) fn from_connection_info(connection_info: &ConnectionInfo) -> Box<dyn
     }
 }
 
+/// Create the base schema, if any, then apply the migrations to a shadow
+/// database.
+pub(crate) async fn apply_migrations_to_shadow_database(
+    base_schema: Option<&SqlSchema>,
+    migrations: &[MigrationDirectory],
+    connection: &Connection,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> ConnectorResult<()> {
+    if let Some(base_schema) = base_schema {
+        tracing::debug!("Creating the base schema on the temporary database.");
+
+        let migration = crate::sql_database_migration_inferrer::infer(SqlSchema::empty(), base_schema.clone(), flavour);
+        let script = crate::sql_database_step_applier::render_script(
+            &migration,
+            &migration_connector::DestructiveChangeDiagnostics::new(),
+            flavour,
+        );
+
+        sql_script::apply_migration_script(connection, flavour, "schema snapshot", &script, true).await?;
+    }
+
+    for migration in migrations {
+        apply_migration_to_shadow_database(migration, connection, flavour).await?;
+    }
+
+    Ok(())
+}
+
+/// Apply the schema steps of a migration to a shadow database. Batched data
+/// transformation steps are skipped: they do not change the schema, and the
+/// tables of a shadow database are empty.
+pub(crate) async fn apply_migration_to_shadow_database(
+    migration: &MigrationDirectory,
+    connection: &Connection,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> ConnectorResult<()> {
+    tracing::debug!(
+        "Applying migration `{}` to temporary database.",
+        migration.migration_name()
+    );
+
+    for step in migration.read_migration_steps()? {
+        if step.batch().is_some() {
+            continue;
+        }
+
+        sql_script::apply_migration_script(
+            connection,
+            flavour,
+            step.file_name(),
+            step.script(),
+            step.is_transactional(),
+        )
+        .await
+        .map_err(|connector_error| {
+            connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
+        })?;
+    }
+
+    Ok(())
+}
+
+/// The number of migrations covered by the last schema snapshot that is still
+/// valid for the migration history, with the schema it describes. Only the
+/// migrations after it have to be replayed on a temporary database.
+pub(crate) fn last_valid_snapshot(migrations: &[MigrationDirectory]) -> Option<(usize, SqlSchema)> {
+    let history_checksums = match migration_connector::migration_history_checksums(migrations) {
+        Ok(checksums) => checksums,
+        Err(err) => {
+            tracing::debug!("Could not compute the migration history checksums: {}", err);
+            return None;
+        }
+    };
+
+    migrations
+        .iter()
+        .zip(history_checksums.iter())
+        .enumerate()
+        .rev()
+        .find_map(|(idx, (migration, history_checksum))| {
+            let snapshot = migration.read_schema_snapshot(history_checksum)?;
+
+            match serde_json::from_value(snapshot) {
+                Ok(schema) => {
+                    tracing::debug!(
+                        "Using the schema snapshot of migration `{}`.",
+                        migration.migration_name()
+                    );
+
+                    Some((idx + 1, schema))
+                }
+                Err(err) => {
+                    tracing::debug!("Could not deserialize the schema snapshot: {}", err);
+                    None
+                }
+            }
+        })
+}
+
+/// Read the schema snapshot of the last migration, if it is still valid for
+/// the migration history. This spares us replaying the whole history on a
+/// temporary database.
+pub(crate) fn sql_schema_from_snapshot(migrations: &[MigrationDirectory]) -> Option<SqlSchema> {
+    let last_migration = migrations.last()?;
+
+    let history_checksum = match migration_connector::migration_history_checksum(migrations) {
+        Ok(checksum) => checksum,
+        Err(err) => {
+            tracing::debug!("Could not compute the migration history checksum: {}", err);
+            return None;
+        }
+    };
+
+    let snapshot = last_migration.read_schema_snapshot(&history_checksum)?;
+
+    match serde_json::from_value(snapshot) {
+        Ok(schema) => {
+            tracing::debug!(
+                "Using the schema snapshot of migration `{}`.",
+                last_migration.migration_name()
+            );
+
+            Some(schema)
+        }
+        Err(err) => {
+            tracing::debug!("Could not deserialize the schema snapshot: {}", err);
+            None
+        }
+    }
+}
+
 #[async_trait::async_trait]
 pub(crate) trait SqlFlavour:
     DestructiveChangeCheckerFlavour + SqlRenderer + SqlSchemaDifferFlavour + SqlSchemaCalculatorFlavour + Debug
@@ -64,6 +195,11 @@ pub(crate) trait SqlFlavour:
     /// Initialize the `_prisma_migrations` table.
     async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()>;
 
+    /// Add the columns introduced after the first version of the
+    /// `_prisma_migrations` table, if they are missing. This must be a no-op
+    /// when the table does not exist or is already up to date.
+    async fn upgrade_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()>;
+
     /// Describe the SQL schema.
     async fn describe_schema<'a>(&'a self, conn: &Connection) -> ConnectorResult<SqlSchema>;
 
@@ -84,10 +220,17 @@ pub(crate) trait SqlFlavour:
     /// This should be considered deprecated.
     fn sql_family(&self) -> SqlFamily;
 
+    /// Whether DDL statements can be rolled back as part of a transaction.
+    fn has_transactional_ddl(&self) -> bool {
+        true
+    }
+
     /// Apply the given migration history to a temporary database, and return
-    /// the final introspected SQL schema.
+    /// the final introspected SQL schema. When a base schema is given, it
+    /// is created first and the migrations are applied on top of it.
     async fn sql_schema_from_migration_history(
         &self,
+        base_schema: Option<&SqlSchema>,
         migrations: &[MigrationDirectory],
         connection: &Connection,
     ) -> ConnectorResult<SqlSchema>;
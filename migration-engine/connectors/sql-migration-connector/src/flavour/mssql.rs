@@ -60,13 +60,25 @@ impl SqlFlavour for MssqlFlavour {
                 logs                    NVARCHAR(MAX) NULL,
                 rolled_back_at          DATETIMEOFFSET,
                 started_at              DATETIMEOFFSET NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                applied_steps_count     INT NOT NULL DEFAULT 0
+                applied_steps_count     INT NOT NULL DEFAULT 0,
+                batch_cursor            BIGINT
             );
         "#, self.schema_name(), self.imperative_migrations_table_name()};
 
         Ok(connection.raw_cmd(&sql).await?)
     }
 
+    async fn upgrade_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
+        let table = format!("[{}].[{}]", self.schema_name(), self.imperative_migrations_table_name());
+
+        let sql = formatdoc! {r#"
+            IF OBJECT_ID(N'{table}', N'U') IS NOT NULL AND COL_LENGTH(N'{table}', N'batch_cursor') IS NULL
+                ALTER TABLE {table} ADD batch_cursor BIGINT;
+        "#, table = table};
+
+        Ok(connection.raw_cmd(&sql).await?)
+    }
+
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.quaint().clone())
             .describe(connection.connection_info().schema_name())
@@ -167,6 +179,7 @@ impl SqlFlavour for MssqlFlavour {
 
     async fn sql_schema_from_migration_history(
         &self,
+        base_schema: Option<&SqlSchema>,
         migrations: &[MigrationDirectory],
         connection: &Connection,
     ) -> ConnectorResult<SqlSchema> {
@@ -200,22 +213,7 @@ impl SqlFlavour for MssqlFlavour {
             }
 
             (|| async {
-                for migration in migrations {
-                    let script = migration.read_migration_script()?;
-
-                    tracing::debug!(
-                        "Applying migration `{}` to temporary database.",
-                        migration.migration_name()
-                    );
-
-                    temp_database
-                        .raw_cmd(&script)
-                        .await
-                        .map_err(ConnectorError::from)
-                        .map_err(|connector_error| {
-                            connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
-                        })?;
-                }
+                super::apply_migrations_to_shadow_database(base_schema, migrations, &temp_database, self).await?;
 
                 self.describe_schema(&temp_database).await
             })()
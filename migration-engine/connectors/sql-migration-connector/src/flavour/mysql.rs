@@ -101,13 +101,41 @@ impl SqlFlavour for MysqlFlavour {
                 logs                    TEXT,
                 rolled_back_at          DATETIME(3),
                 started_at              DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
-                applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0
+                applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0,
+                batch_cursor            BIGINT
             ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
         "#};
 
         Ok(connection.raw_cmd(sql).await?)
     }
 
+    async fn upgrade_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
+        // MySQL has no `ADD COLUMN IF NOT EXISTS`.
+        let sql = indoc! {r#"
+            SELECT column_name
+            FROM information_schema.columns
+            WHERE table_schema = DATABASE() AND table_name = ?
+        "#};
+
+        let columns: Vec<String> = connection
+            .query_raw(sql, &[self.imperative_migrations_table_name().into()])
+            .await?
+            .into_iter()
+            .filter_map(|row| row.at(0).and_then(|value| value.to_string()))
+            .collect();
+
+        if columns.is_empty() || columns.iter().any(|column| column.eq_ignore_ascii_case("batch_cursor")) {
+            return Ok(());
+        }
+
+        let sql = format!(
+            "ALTER TABLE `{}` ADD COLUMN batch_cursor BIGINT",
+            self.imperative_migrations_table_name()
+        );
+
+        Ok(connection.raw_cmd(&sql).await?)
+    }
+
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::mysql::SqlSchemaDescriber::new(connection.quaint().clone())
             .describe(connection.connection_info().schema_name())
@@ -213,9 +241,15 @@ impl SqlFlavour for MysqlFlavour {
         SqlFamily::Mysql
     }
 
+    /// DDL statements cause an implicit commit on MySQL.
+    fn has_transactional_ddl(&self) -> bool {
+        false
+    }
+
     #[tracing::instrument(skip(self, migrations, connection))]
     async fn sql_schema_from_migration_history(
         &self,
+        base_schema: Option<&SqlSchema>,
         migrations: &[MigrationDirectory],
         connection: &Connection,
     ) -> ConnectorResult<SqlSchema> {
@@ -237,22 +271,7 @@ impl SqlFlavour for MysqlFlavour {
         // leaving shadow databases behind in case of e.g. faulty migrations.
 
         let sql_schema_result = (|| async {
-            for migration in migrations {
-                let script = migration.read_migration_script()?;
-
-                tracing::debug!(
-                    "Applying migration `{}` to temporary database.",
-                    migration.migration_name()
-                );
-
-                temp_database
-                    .raw_cmd(&script)
-                    .await
-                    .map_err(ConnectorError::from)
-                    .map_err(|connector_error| {
-                        connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
-                    })?;
-            }
+            super::apply_migrations_to_shadow_database(base_schema, migrations, &temp_database, self).await?;
 
             self.describe_schema(&temp_database).await
         })()
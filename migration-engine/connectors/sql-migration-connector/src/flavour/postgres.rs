@@ -67,13 +67,23 @@ impl SqlFlavour for PostgresFlavour {
                 logs                    TEXT,
                 rolled_back_at          TIMESTAMPTZ,
                 started_at              TIMESTAMPTZ NOT NULL DEFAULT now(),
-                applied_steps_count     INTEGER NOT NULL DEFAULT 0
+                applied_steps_count     INTEGER NOT NULL DEFAULT 0,
+                batch_cursor            BIGINT
             );
         "#};
 
         Ok(connection.raw_cmd(sql).await?)
     }
 
+    async fn upgrade_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = format!(
+            r#"ALTER TABLE IF EXISTS "{}" ADD COLUMN IF NOT EXISTS batch_cursor BIGINT"#,
+            self.imperative_migrations_table_name()
+        );
+
+        Ok(connection.raw_cmd(&sql).await?)
+    }
+
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.quaint().clone())
             .describe(connection.connection_info().schema_name())
@@ -172,6 +182,7 @@ impl SqlFlavour for PostgresFlavour {
     #[tracing::instrument(skip(self, migrations, connection))]
     async fn sql_schema_from_migration_history(
         &self,
+        base_schema: Option<&SqlSchema>,
         migrations: &[MigrationDirectory],
         connection: &Connection,
     ) -> ConnectorResult<SqlSchema> {
@@ -197,22 +208,7 @@ impl SqlFlavour for PostgresFlavour {
 
                 temporary_database.raw_cmd(&create_schema).await?;
 
-                for migration in migrations {
-                    let script = migration.read_migration_script()?;
-
-                    tracing::debug!(
-                        "Applying migration `{}` to temporary database.",
-                        migration.migration_name()
-                    );
-
-                    temporary_database
-                        .raw_cmd(&script)
-                        .await
-                        .map_err(ConnectorError::from)
-                        .map_err(|connector_error| {
-                            connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
-                        })?;
-                }
+                super::apply_migrations_to_shadow_database(base_schema, migrations, &temporary_database, self).await?;
 
                 // the connection to the temporary database is dropped at the end of
                 // the block.
@@ -44,13 +44,36 @@ impl SqlFlavour for SqliteFlavour {
                 "logs"                  TEXT,
                 "rolled_back_at"        DATETIME,
                 "started_at"            DATETIME NOT NULL DEFAULT current_timestamp,
-                "applied_steps_count"   INTEGER UNSIGNED NOT NULL DEFAULT 0
+                "applied_steps_count"   INTEGER UNSIGNED NOT NULL DEFAULT 0,
+                "batch_cursor"          INTEGER
             );
         "#};
 
         Ok(connection.raw_cmd(&sql).await?)
     }
 
+    async fn upgrade_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
+        // SQLite has no `ADD COLUMN IF NOT EXISTS`.
+        let table_info = format!(r#"PRAGMA table_info("{}")"#, self.imperative_migrations_table_name());
+        let columns: Vec<String> = connection
+            .query_raw(&table_info, &[])
+            .await?
+            .into_iter()
+            .filter_map(|row| row.get("name").and_then(|value| value.to_string()))
+            .collect();
+
+        if columns.is_empty() || columns.iter().any(|column| column == "batch_cursor") {
+            return Ok(());
+        }
+
+        let sql = format!(
+            r#"ALTER TABLE "{}" ADD COLUMN "batch_cursor" INTEGER"#,
+            self.imperative_migrations_table_name()
+        );
+
+        Ok(connection.raw_cmd(&sql).await?)
+    }
+
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::sqlite::SqlSchemaDescriber::new(connection.quaint().clone())
             .describe(connection.connection_info().schema_name())
@@ -103,6 +126,7 @@ impl SqlFlavour for SqliteFlavour {
     #[tracing::instrument(skip(self, migrations, _connection))]
     async fn sql_schema_from_migration_history(
         &self,
+        base_schema: Option<&SqlSchema>,
         migrations: &[MigrationDirectory],
         _connection: &Connection,
     ) -> ConnectorResult<SqlSchema> {
@@ -117,21 +141,7 @@ impl SqlFlavour for SqliteFlavour {
         })?;
         let conn = Connection::new(quaint);
 
-        for migration in migrations {
-            let script = migration.read_migration_script()?;
-
-            tracing::debug!(
-                "Applying migration `{}` to temporary database.",
-                migration.migration_name()
-            );
-
-            conn.raw_cmd(&script)
-                .await
-                .map_err(ConnectorError::from)
-                .map_err(|connector_error| {
-                    connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
-                })?;
-        }
+        super::apply_migrations_to_shadow_database(base_schema, migrations, &conn, self).await?;
 
         let sql_schema = self.describe_schema(&conn).await?;
 
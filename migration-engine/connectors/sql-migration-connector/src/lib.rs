@@ -4,6 +4,7 @@
 #![allow(clippy::trivial_regex)] // these will grow
 
 mod connection_wrapper;
+mod diff;
 mod error;
 mod flavour;
 mod pair;
@@ -16,12 +17,14 @@ mod sql_migration_persistence;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
+mod sql_script;
 
+pub use diff::{diff, DiffTarget, SqlSchemaDiff};
 pub use sql_migration::SqlMigration;
 pub use sql_migration_persistence::MIGRATION_TABLE_NAME;
 
 use connection_wrapper::Connection;
-use datamodel::Datamodel;
+use datamodel::{Datamodel, ReferentialIntegrity};
 use error::quaint_error_to_connector_error;
 use flavour::SqlFlavour;
 use migration_connector::*;
@@ -33,6 +36,7 @@ use user_facing_errors::{common::InvalidDatabaseString, KnownError};
 pub struct SqlMigrationConnector {
     connection: Connection,
     flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
+    referential_integrity: ReferentialIntegrity,
 }
 
 impl SqlMigrationConnector {
@@ -43,7 +47,18 @@ impl SqlMigrationConnector {
 
         flavour.ensure_connection_validity(&connection).await?;
 
-        Ok(Self { flavour, connection })
+        Ok(Self {
+            flavour,
+            connection,
+            referential_integrity: ReferentialIntegrity::default(),
+        })
+    }
+
+    /// Where the referential integrity of relations is enforced, from the datasource of the
+    /// schema. With `ReferentialIntegrity::Prisma`, relations get no foreign keys.
+    pub fn with_referential_integrity(mut self, referential_integrity: ReferentialIntegrity) -> Self {
+        self.referential_integrity = referential_integrity;
+        self
     }
 
     /// Create the database corresponding to the connection string, without initializing the connector.
@@ -81,6 +96,10 @@ impl SqlMigrationConnector {
         self.flavour.as_ref()
     }
 
+    fn referential_integrity(&self) -> ReferentialIntegrity {
+        self.referential_integrity
+    }
+
     /// For tests.
     pub fn quaint(&self) -> &Quaint {
         self.connection.quaint()
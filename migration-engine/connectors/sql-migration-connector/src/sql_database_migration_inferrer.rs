@@ -1,6 +1,8 @@
 use crate::{
-    flavour::SqlFlavour, pair::Pair, sql_migration::SqlMigration, sql_schema_calculator, sql_schema_differ,
-    SqlMigrationConnector,
+    flavour::{self, SqlFlavour},
+    pair::Pair,
+    sql_migration::SqlMigration,
+    sql_schema_calculator, sql_schema_differ, SqlMigrationConnector,
 };
 use datamodel::*;
 use migration_connector::{
@@ -17,14 +19,16 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema = self.describe_schema().await?;
-        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
+        let expected_database_schema =
+            sql_schema_calculator::calculate_sql_schema(next, self.flavour(), self.referential_integrity());
         Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
     }
 
     /// Infer the database migration steps, skipping the schema describer and assuming an empty database.
     fn infer_from_empty(&self, next: &Datamodel) -> ConnectorResult<SqlMigration> {
         let current_database_schema = SqlSchema::empty();
-        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
+        let expected_database_schema =
+            sql_schema_calculator::calculate_sql_schema(next, self.flavour(), self.referential_integrity());
 
         Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
     }
@@ -35,8 +39,10 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         next: &Datamodel,
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema: SqlSchema = sql_schema_calculator::calculate_sql_schema(previous, self.flavour());
-        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
+        let current_database_schema: SqlSchema =
+            sql_schema_calculator::calculate_sql_schema(previous, self.flavour(), self.referential_integrity());
+        let expected_database_schema =
+            sql_schema_calculator::calculate_sql_schema(next, self.flavour(), self.referential_integrity());
 
         Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
     }
@@ -47,21 +53,34 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
     ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema = self
-            .flavour()
-            .sql_schema_from_migration_history(previous_migrations, self.conn())
-            .await?;
-        let expected_database_schema = sql_schema_calculator::calculate_sql_schema(target_schema, self.flavour());
+        let current_database_schema = self.sql_schema_from_migration_history(previous_migrations).await?;
+        let expected_database_schema =
+            sql_schema_calculator::calculate_sql_schema(target_schema, self.flavour(), self.referential_integrity());
 
         Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
     }
 
+    #[tracing::instrument(skip(self, previous_migrations, squashed_migrations))]
+    async fn infer_squashed_migration(
+        &self,
+        previous_migrations: &[MigrationDirectory],
+        squashed_migrations: &[MigrationDirectory],
+    ) -> ConnectorResult<SqlMigration> {
+        let all_migrations: Vec<MigrationDirectory> = previous_migrations
+            .iter()
+            .chain(squashed_migrations.iter())
+            .cloned()
+            .collect();
+
+        let schema_before = self.sql_schema_from_migration_history(previous_migrations).await?;
+        let schema_after = self.sql_schema_from_migration_history(&all_migrations).await?;
+
+        Ok(infer(schema_before, schema_after, self.flavour()))
+    }
+
     #[tracing::instrument(skip(self, applied_migrations))]
     async fn calculate_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<Option<String>> {
-        let expected_schema = self
-            .flavour()
-            .sql_schema_from_migration_history(applied_migrations, self.conn())
-            .await?;
+        let expected_schema = self.sql_schema_from_migration_history(applied_migrations).await?;
 
         let actual_schema = self.describe_schema().await?;
 
@@ -86,17 +105,65 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         Ok(Some(rollback))
     }
 
+    /// The snapshot is described from a temporary database the history was
+    /// replayed on, not calculated from the datamodel, so that it compares
+    /// cleanly with the described schema of the database on drift checks.
+    /// Only the migrations after the last valid snapshot are replayed, on top
+    /// of the schema it describes. Objects a snapshot does not describe, like
+    /// functions, are lost this way, so we fall back to replaying the whole
+    /// history when that fails.
+    #[tracing::instrument(skip(self, migrations))]
+    async fn schema_snapshot(&self, migrations: &[MigrationDirectory]) -> ConnectorResult<Option<serde_json::Value>> {
+        let from_snapshot = match flavour::last_valid_snapshot(migrations) {
+            Some((snapshot_len, base_schema)) if snapshot_len == migrations.len() => Some(base_schema),
+            Some((snapshot_len, base_schema)) => self
+                .flavour()
+                .sql_schema_from_migration_history(Some(&base_schema), &migrations[snapshot_len..], self.conn())
+                .await
+                .map_err(|err| tracing::debug!("Could not replay the migrations on the last snapshot: {}", err))
+                .ok(),
+            None => None,
+        };
+
+        let schema = match from_snapshot {
+            Some(schema) => schema,
+            None => {
+                self.flavour()
+                    .sql_schema_from_migration_history(None, migrations, self.conn())
+                    .await?
+            }
+        };
+
+        Ok(serde_json::to_value(&schema).ok())
+    }
+
     #[tracing::instrument(skip(self, migrations))]
     async fn validate_migrations(&self, migrations: &[MigrationDirectory]) -> ConnectorResult<()> {
         self.flavour()
-            .sql_schema_from_migration_history(migrations, self.conn())
+            .sql_schema_from_migration_history(None, migrations, self.conn())
             .await?;
 
         Ok(())
     }
 }
 
-fn infer(
+impl SqlMigrationConnector {
+    /// The schema resulting from the migration history, read from the schema
+    /// snapshot of the last migration when it is valid, or replayed on a
+    /// temporary database otherwise.
+    async fn sql_schema_from_migration_history(&self, migrations: &[MigrationDirectory]) -> ConnectorResult<SqlSchema> {
+        match flavour::sql_schema_from_snapshot(migrations) {
+            Some(schema) => Ok(schema),
+            None => {
+                self.flavour()
+                    .sql_schema_from_migration_history(None, migrations, self.conn())
+                    .await
+            }
+        }
+    }
+}
+
+pub(crate) fn infer(
     current_database_schema: SqlSchema,
     expected_database_schema: SqlSchema,
     flavour: &dyn SqlFlavour,
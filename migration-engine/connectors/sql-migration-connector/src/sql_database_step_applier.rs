@@ -1,12 +1,13 @@
 use crate::{
     pair::Pair,
     sql_migration::{CreateTable, DropTable, SqlMigration, SqlMigrationStep},
-    SqlFlavour, SqlMigrationConnector,
+    sql_script, SqlFlavour, SqlMigrationConnector,
 };
 use migration_connector::{
     ConnectorResult, DatabaseMigrationMarker, DatabaseMigrationStepApplier, DestructiveChangeDiagnostics,
-    PrettyDatabaseMigrationStep,
+    PrettyDatabaseMigrationStep, NO_TRANSACTION_DIRECTIVE,
 };
+use quaint::ast::{max, min, Aliasable, Column, Comparable, Select, Update};
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 
 #[async_trait::async_trait]
@@ -26,88 +27,65 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
         &self,
         database_migration: &SqlMigration,
     ) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>> {
-        let mut steps = Vec::with_capacity(database_migration.steps.len());
-
-        for step in &database_migration.steps {
-            let sql = render_raw_sql(&step, self.flavour(), database_migration.schemas()).join(";\n");
-
-            if !sql.is_empty() {
-                steps.push(PrettyDatabaseMigrationStep {
-                    step: serde_json::to_value(&step).unwrap_or_else(|_| serde_json::json!({})),
-                    raw: sql,
-                });
-            }
-        }
-
-        Ok(steps)
+        Ok(render_steps_pretty(database_migration, self.flavour()))
     }
 
     fn render_script(&self, database_migration: &SqlMigration, diagnostics: &DestructiveChangeDiagnostics) -> String {
-        if database_migration.is_empty() {
-            return "-- This is an empty migration.".to_string();
-        }
-
-        let mut script = String::with_capacity(40 * database_migration.steps.len());
-
-        // Note: it would be much nicer if we could place the warnings next to
-        // the SQL for the steps that triggered them.
-        if diagnostics.has_warnings() || !diagnostics.unexecutable_migrations.is_empty() {
-            script.push_str("/*\n  Warnings:\n\n");
-
-            for warning in &diagnostics.warnings {
-                script.push_str("  - ");
-                script.push_str(&warning.description);
-                script.push('\n');
-            }
+        render_script(database_migration, diagnostics, self.flavour())
+    }
 
-            for unexecutable in &diagnostics.unexecutable_migrations {
-                script.push_str("  - ");
-                script.push_str(&unexecutable.description);
-                script.push('\n');
-            }
+    async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
+        Ok(self.conn().raw_cmd(script).await?)
+    }
 
-            script.push_str("\n*/\n")
-        }
+    async fn apply_migration_script(
+        &self,
+        script_name: &str,
+        script: &str,
+        transactional: bool,
+    ) -> ConnectorResult<()> {
+        sql_script::apply_migration_script(self.conn(), self.flavour(), script_name, script, transactional).await
+    }
 
-        // Whether we are on the first *rendered* step, to avoid printing a
-        // newline before it. This can't be `enumerate()` on the loop because
-        // some steps don't render anything.
-        let mut is_first_step = true;
+    async fn apply_batch(
+        &self,
+        migration_id: &str,
+        script_name: &str,
+        script: &str,
+        cursor: i64,
+        transactional: bool,
+    ) -> ConnectorResult<()> {
+        let progress = Update::table(self.flavour().imperative_migrations_table())
+            .so_that(Column::from("id").equals(migration_id))
+            .set("batch_cursor", cursor);
 
-        for step in &database_migration.steps {
-            let statements: Vec<String> = render_raw_sql(
-                step,
-                self.flavour(),
-                Pair::new(&database_migration.before, &database_migration.after),
-            );
+        sql_script::apply_batch_script(
+            self.conn(),
+            self.flavour(),
+            script_name,
+            script,
+            progress,
+            transactional,
+        )
+        .await
+    }
 
-            if !statements.is_empty() {
-                if is_first_step {
-                    is_first_step = false;
-                } else {
-                    script.push('\n');
-                }
+    async fn batch_bounds(&self, table: &str, column: &str) -> ConnectorResult<Option<(i64, i64)>> {
+        let conn = self.conn();
+        let select = Select::from_table(conn.table_name(table))
+            .value(min(Column::from(column)).alias("batch_start"))
+            .value(max(Column::from(column)).alias("batch_end"));
 
-                // We print a newline *before* migration steps and not after,
-                // because we do not want two newlines at the end of the file:
-                // many editors will remove trailing newlines, and automatically
-                // edit the migration.
-                script.push_str("-- ");
-                script.push_str(step.description());
-                script.push('\n');
+        let result = conn.query(select).await?;
 
-                for statement in statements {
-                    script.push_str(&statement);
-                    script.push_str(";\n");
-                }
-            }
-        }
+        let bounds = result.into_single().ok().and_then(|row| {
+            let start = row.get("batch_start").and_then(|value| value.as_i64())?;
+            let end = row.get("batch_end").and_then(|value| value.as_i64())?;
 
-        script
-    }
+            Some((start, end))
+        });
 
-    async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
-        Ok(self.conn().raw_cmd(script).await?)
+        Ok(bounds)
     }
 }
 
@@ -128,15 +106,141 @@ impl SqlMigrationConnector {
         let step = &steps[index];
         tracing::debug!(?step);
 
+        // Migration scripts are applied in a transaction, but single steps
+        // are not. Enums are altered by swapping them with a new type, which
+        // must not be left half done.
+        let use_transaction = matches!(step, SqlMigrationStep::AlterEnum(_)) && renderer.has_transactional_ddl();
+
+        if use_transaction {
+            self.conn().raw_cmd("BEGIN").await?;
+        }
+
         for sql_string in render_raw_sql(&step, renderer, schemas) {
             tracing::debug!(index, %sql_string);
-            self.conn().raw_cmd(&sql_string).await?;
+
+            if let Err(err) = self.conn().raw_cmd(&sql_string).await {
+                if use_transaction {
+                    self.conn().raw_cmd("ROLLBACK").await.ok();
+                }
+
+                return Err(err.into());
+            }
+        }
+
+        if use_transaction {
+            self.conn().raw_cmd("COMMIT").await?;
         }
 
         Ok(true)
     }
 }
 
+pub(crate) fn render_steps_pretty(
+    database_migration: &SqlMigration,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> Vec<PrettyDatabaseMigrationStep> {
+    let mut steps = Vec::with_capacity(database_migration.steps.len());
+
+    for step in &database_migration.steps {
+        let sql = render_raw_sql(&step, flavour, database_migration.schemas()).join(";\n");
+
+        if !sql.is_empty() {
+            steps.push(PrettyDatabaseMigrationStep {
+                step: serde_json::to_value(&step).unwrap_or_else(|_| serde_json::json!({})),
+                raw: sql,
+            });
+        }
+    }
+
+    steps
+}
+
+pub(crate) fn render_script(
+    database_migration: &SqlMigration,
+    diagnostics: &DestructiveChangeDiagnostics,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> String {
+    if database_migration.is_empty() {
+        return "-- This is an empty migration.".to_string();
+    }
+
+    let mut script = String::with_capacity(40 * database_migration.steps.len());
+
+    // `ALTER TYPE ... ADD VALUE` cannot run in a transaction block before
+    // PostgreSQL 12, so the script opts out of the migration transaction.
+    if adds_enum_values_on_postgres(database_migration, flavour) {
+        script.push_str(NO_TRANSACTION_DIRECTIVE);
+        script.push('\n');
+    }
+
+    // Note: it would be much nicer if we could place the warnings next to
+    // the SQL for the steps that triggered them.
+    if diagnostics.has_warnings() || !diagnostics.unexecutable_migrations.is_empty() {
+        script.push_str("/*\n  Warnings:\n\n");
+
+        for warning in &diagnostics.warnings {
+            script.push_str("  - ");
+            script.push_str(&warning.description);
+            script.push('\n');
+        }
+
+        for unexecutable in &diagnostics.unexecutable_migrations {
+            script.push_str("  - ");
+            script.push_str(&unexecutable.description);
+            script.push('\n');
+        }
+
+        script.push_str("\n*/\n")
+    }
+
+    // Whether we are on the first *rendered* step, to avoid printing a
+    // newline before it. This can't be `enumerate()` on the loop because
+    // some steps don't render anything.
+    let mut is_first_step = true;
+
+    for step in &database_migration.steps {
+        let statements: Vec<String> = render_raw_sql(
+            step,
+            flavour,
+            Pair::new(&database_migration.before, &database_migration.after),
+        );
+
+        if !statements.is_empty() {
+            if is_first_step {
+                is_first_step = false;
+            } else {
+                script.push('\n');
+            }
+
+            // We print a newline *before* migration steps and not after,
+            // because we do not want two newlines at the end of the file:
+            // many editors will remove trailing newlines, and automatically
+            // edit the migration.
+            script.push_str("-- ");
+            script.push_str(step.description());
+            script.push('\n');
+
+            for statement in statements {
+                script.push_str(&statement);
+                script.push_str(";\n");
+            }
+        }
+    }
+
+    script
+}
+
+fn adds_enum_values_on_postgres(database_migration: &SqlMigration, flavour: &(dyn SqlFlavour + Send + Sync)) -> bool {
+    flavour.sql_family().is_postgres()
+        && database_migration.steps.iter().any(|step| match step {
+            // Enums with dropped variants are recreated, not altered in place.
+            SqlMigrationStep::AlterEnum(alter_enum) => {
+                alter_enum.dropped_variants.is_empty() && !alter_enum.created_variants.is_empty()
+            }
+            _ => false,
+        })
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
@@ -151,10 +255,25 @@ fn render_raw_sql(
         SqlMigrationStep::DropEnum(drop_enum) => {
             renderer.render_drop_enum(&schemas.previous().enum_walker_at(drop_enum.enum_index))
         }
+        SqlMigrationStep::CreateSequence(create_sequence) => {
+            renderer.render_create_sequence(&schemas.next().sequences[create_sequence.sequence_index])
+        }
+        SqlMigrationStep::AlterSequence(alter_sequence) => renderer.render_alter_sequence(
+            schemas
+                .as_ref()
+                .zip(alter_sequence.index.as_ref())
+                .map(|(schema, index)| &schema.sequences[*index]),
+        ),
+        SqlMigrationStep::DropSequence(drop_sequence) => {
+            renderer.render_drop_sequence(&schemas.previous().sequences[drop_sequence.sequence_index])
+        }
         SqlMigrationStep::CreateTable(CreateTable { table_index }) => {
             let table = schemas.next().table_walker_at(*table_index);
 
-            vec![renderer.render_create_table(&table)]
+            let mut statements = vec![renderer.render_create_table(&table)];
+            statements.extend(renderer.render_create_table_comments(&table));
+
+            statements
         }
         SqlMigrationStep::DropTable(DropTable { table_index }) => {
             renderer.render_drop_table(schemas.previous().table_walker_at(*table_index).name())
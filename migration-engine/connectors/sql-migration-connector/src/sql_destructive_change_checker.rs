@@ -26,7 +26,7 @@ pub(crate) use destructive_change_checker_flavour::DestructiveChangeCheckerFlavo
 use crate::{
     pair::Pair,
     sql_migration::ColumnTypeChange,
-    sql_migration::{AlterEnum, AlterTable, CreateIndex, DropTable, SqlMigrationStep, TableChange},
+    sql_migration::{AlterEnum, AlterTable, CreateIndex, DropSequence, DropTable, SqlMigrationStep, TableChange},
     SqlMigration, SqlMigrationConnector,
 };
 use destructive_check_plan::DestructiveCheckPlan;
@@ -100,6 +100,21 @@ impl SqlMigrationConnector {
                             TableChange::AlterColumn(alter_column) => {
                                 let columns = tables.columns(&alter_column.column_index);
 
+                                // Comments are only written from documentation, so comments
+                                // added outside of Prisma are dropped.
+                                if alter_column.changes.comment_changed()
+                                    && columns.previous().comment().is_some()
+                                    && columns.next().comment().is_none()
+                                {
+                                    plan.push_warning(
+                                        SqlMigrationWarningCheck::ColumnCommentDrop {
+                                            table: tables.previous().name().to_owned(),
+                                            column: columns.previous().name().to_owned(),
+                                        },
+                                        step_index,
+                                    );
+                                }
+
                                 self.flavour()
                                     .check_alter_column(&alter_column, &columns, &mut plan, step_index)
                             }
@@ -120,6 +135,16 @@ impl SqlMigrationConnector {
                                 self.flavour
                                     .check_drop_and_recreate_column(&columns, changes, &mut plan, step_index)
                             }
+                            TableChange::AlterComment => {
+                                if tables.previous().comment().is_some() && tables.next().comment().is_none() {
+                                    plan.push_warning(
+                                        SqlMigrationWarningCheck::TableCommentDrop {
+                                            table: tables.previous().name().to_owned(),
+                                        },
+                                        step_index,
+                                    );
+                                }
+                            }
                             TableChange::AddPrimaryKey { .. } => (),
                         }
                     }
@@ -240,6 +265,12 @@ impl SqlMigrationConnector {
                     },
                     step_index,
                 ),
+                SqlMigrationStep::DropSequence(DropSequence { sequence_index }) => plan.push_warning(
+                    SqlMigrationWarningCheck::SequenceDrop {
+                        sequence: schemas.previous().sequences[*sequence_index].name.clone(),
+                    },
+                    step_index,
+                ),
                 _ => (),
             }
         }
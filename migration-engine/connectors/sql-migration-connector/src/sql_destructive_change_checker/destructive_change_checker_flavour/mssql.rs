@@ -77,6 +77,21 @@ impl DestructiveChangeCheckerFlavour for MssqlFlavour {
         plan: &mut DestructiveCheckPlan,
         step_index: usize,
     ) {
+        // Generated columns are computed by the database, so they can always be recreated.
+        if columns.next().generated().is_some() {
+            if columns.previous().generated().is_none() {
+                plan.push_warning(
+                    SqlMigrationWarningCheck::DropAndRecreateColumn {
+                        column: columns.previous().name().to_owned(),
+                        table: columns.previous().table().name().to_owned(),
+                    },
+                    step_index,
+                )
+            }
+
+            return;
+        }
+
         // Unexecutable drop and recreate.
         if changes.arity_changed()
             && columns.previous().arity().is_nullable()
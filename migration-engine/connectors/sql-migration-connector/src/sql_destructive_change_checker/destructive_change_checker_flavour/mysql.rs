@@ -78,12 +78,24 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
 
     fn check_drop_and_recreate_column(
         &self,
-        _columns: &Pair<ColumnWalker<'_>>,
+        columns: &Pair<ColumnWalker<'_>>,
         _changes: &ColumnChanges,
-        _plan: &mut DestructiveCheckPlan,
-        _step_index: usize,
+        plan: &mut DestructiveCheckPlan,
+        step_index: usize,
     ) {
-        panic!("check_drop_and_recreate_column on MySQL")
+        // Columns are only dropped and recreated on MySQL when they start or stop being
+        // generated. The values of generated columns are computed, so they are not lost.
+        if columns.previous().generated().is_some() && columns.next().generated().is_some() {
+            return;
+        }
+
+        plan.push_warning(
+            SqlMigrationWarningCheck::DropAndRecreateColumn {
+                column: columns.previous().name().to_owned(),
+                table: columns.previous().table().name().to_owned(),
+            },
+            step_index,
+        )
     }
 }
 
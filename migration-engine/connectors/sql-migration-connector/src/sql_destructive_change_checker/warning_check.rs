@@ -36,6 +36,16 @@ pub(super) enum SqlMigrationWarningCheck {
         enm: String,
         values: Vec<String>,
     },
+    SequenceDrop {
+        sequence: String,
+    },
+    TableCommentDrop {
+        table: String,
+    },
+    ColumnCommentDrop {
+        table: String,
+        column: String,
+    },
 }
 
 impl Check for SqlMigrationWarningCheck {
@@ -104,6 +114,9 @@ impl Check for SqlMigrationWarningCheck {
             },
             SqlMigrationWarningCheck::UniqueConstraintAddition { table, columns } =>  Some(format!("The migration will add a unique constraint covering the columns `{columns}` on the table `{table}`. If there are existing duplicate values, the migration will fail.", table = table, columns = format!("[{}]",columns.join(",")))),
             SqlMigrationWarningCheck::EnumValueRemoval { enm, values } =>  Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+            SqlMigrationWarningCheck::SequenceDrop { sequence } => Some(format!("You are about to drop the sequence `{sequence}`, which is not in the Prisma schema. Its current value will be lost, and anything outside of Prisma using it will break.", sequence = sequence)),
+            SqlMigrationWarningCheck::TableCommentDrop { table } => Some(format!("You are about to drop the comment on the `{table}` table. Add a `///` documentation comment to the model to keep it.", table = table)),
+            SqlMigrationWarningCheck::ColumnCommentDrop { table, column } => Some(format!("You are about to drop the comment on the column `{column}` of the `{table}` table. Add a `///` documentation comment to the field to keep it.", table = table, column = column)),
 
         }
     }
@@ -21,7 +21,8 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
             .iter()
             .any(|table| table.name == self.flavour().imperative_migrations_table_name())
         {
-            return Ok(());
+            // Migrations tables created by older versions lack the newer columns.
+            return self.flavour.upgrade_imperative_migrations_table(&self.conn()).await;
         }
 
         if !schema.is_empty() {
@@ -91,7 +92,9 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
             .set(
                 "applied_steps_count",
                 Expression::from(Column::from("applied_steps_count")) + Expression::from(1),
-            );
+            )
+            .set("batch_cursor", Value::Integer(None))
+            .set("logs", Value::Text(None));
 
         self.conn().execute(update).await?;
 
@@ -120,16 +123,10 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
 
     #[tracing::instrument(skip(self))]
     async fn list_migrations(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>> {
-        let select = Select::from_table(self.flavour().imperative_migrations_table())
-            .column("id")
-            .column("checksum")
-            .column("finished_at")
-            .column("migration_name")
-            .column("logs")
-            .column("rolled_back_at")
-            .column("started_at")
-            .column("applied_steps_count")
-            .order_by("started_at".ascend());
+        // All the columns are selected, because the migrations tables created
+        // by older versions lack the newer columns until they are upgraded by
+        // `initialize()`. Reading the migrations must not change the table.
+        let select = Select::from_table(self.flavour().imperative_migrations_table()).order_by("started_at".ascend());
 
         let result = match self.conn().query(select).await {
             Ok(result) => result,
@@ -44,6 +44,9 @@ pub(crate) enum SqlMigrationStep {
     CreateEnum(CreateEnum),
     DropEnum(DropEnum),
     AlterEnum(AlterEnum),
+    CreateSequence(CreateSequence),
+    AlterSequence(AlterSequence),
+    DropSequence(DropSequence),
 }
 
 impl Serialize for SqlMigrationStep {
@@ -76,6 +79,9 @@ impl SqlMigrationStep {
             SqlMigrationStep::CreateEnum(_) => "CreateEnum",
             SqlMigrationStep::DropEnum(_) => "DropEnum",
             SqlMigrationStep::AlterEnum(_) => "AlterEnum",
+            SqlMigrationStep::CreateSequence(_) => "CreateSequence",
+            SqlMigrationStep::AlterSequence(_) => "AlterSequence",
+            SqlMigrationStep::DropSequence(_) => "DropSequence",
         }
     }
 }
@@ -112,6 +118,8 @@ pub(crate) enum TableChange {
     AddPrimaryKey {
         columns: Vec<String>,
     },
+    /// The comment on the table changed. The new comment is in the next schema.
+    AlterComment,
 }
 
 #[derive(Debug)]
@@ -190,6 +198,26 @@ impl AlterEnum {
     }
 }
 
+/// Only standalone sequences are created, altered and dropped with their own
+/// steps. Sequences owned by a column follow the column.
+#[derive(Debug)]
+pub(crate) struct CreateSequence {
+    /// The index of the sequence in the next schema.
+    pub sequence_index: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct AlterSequence {
+    /// Index in (previous_schema, next_schema).
+    pub index: Pair<usize>,
+}
+
+#[derive(Debug)]
+pub(crate) struct DropSequence {
+    /// The index of the sequence in the previous schema.
+    pub sequence_index: usize,
+}
+
 #[derive(Debug)]
 pub(crate) struct RedefineTable {
     pub added_columns: Vec<usize>,
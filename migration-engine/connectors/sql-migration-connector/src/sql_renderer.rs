@@ -26,7 +26,7 @@ use sql_schema_describer::{
     walkers::ForeignKeyWalker,
     walkers::IndexWalker,
     walkers::{ColumnWalker, TableWalker},
-    ColumnTypeFamily, DefaultValue, SqlSchema,
+    ColumnTypeFamily, DefaultValue, Sequence, SqlSchema,
 };
 use std::borrow::Cow;
 
@@ -54,6 +54,16 @@ pub(crate) trait SqlRenderer {
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render a `CreateSequence` step.
+    fn render_create_sequence(&self, _sequence: &Sequence) -> Vec<String> {
+        unreachable!("unreachable render_create_sequence")
+    }
+
+    /// Render an `AlterSequence` step.
+    fn render_alter_sequence(&self, _sequences: Pair<&Sequence>) -> Vec<String> {
+        unreachable!("unreachable render_alter_sequence")
+    }
+
     /// Render a table creation step.
     fn render_create_table(&self, table: &TableWalker<'_>) -> String {
         self.render_create_table_as(table, table.name())
@@ -62,6 +72,12 @@ pub(crate) trait SqlRenderer {
     /// Render a table creation with the provided table name.
     fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String;
 
+    /// Render the statements setting the table and column comments of a
+    /// created table, for databases where they cannot be part of `CREATE TABLE`.
+    fn render_create_table_comments(&self, _table: &TableWalker<'_>) -> Vec<String> {
+        Vec::new()
+    }
+
     fn render_drop_and_recreate_index(&self, _indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
         unreachable!("unreachable render_drop_and_recreate_index")
     }
@@ -69,6 +85,11 @@ pub(crate) trait SqlRenderer {
     /// Render a `DropEnum` step.
     fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> Vec<String>;
 
+    /// Render a `DropSequence` step.
+    fn render_drop_sequence(&self, _sequence: &Sequence) -> Vec<String> {
+        unreachable!("unreachable render_drop_sequence")
+    }
+
     /// Render a `DropForeignKey` step.
     fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
 
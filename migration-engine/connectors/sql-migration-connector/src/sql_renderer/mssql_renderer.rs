@@ -1,6 +1,6 @@
 mod alter_table;
 
-use super::common::render_on_delete;
+use super::common::{render_on_delete, render_on_update};
 use super::{common, IteratorJoin, Quoted, SqlRenderer};
 use crate::{
     flavour::MssqlFlavour,
@@ -37,6 +37,48 @@ impl MssqlFlavour {
             name,
         }
     }
+
+    /// Comments are stored in the `MS_Description` extended property of the
+    /// table or column. Depending on whether the property existed before, it
+    /// is added, updated or dropped.
+    fn render_comment(
+        &self,
+        comments: Pair<Option<&str>>,
+        table_name: &str,
+        column_name: Option<&str>,
+    ) -> Option<String> {
+        let (procedure, value) = match (*comments.previous(), *comments.next()) {
+            (None, None) => return None,
+            (None, Some(next)) => ("sp_addextendedproperty", Some(next)),
+            (Some(_), Some(next)) => ("sp_updateextendedproperty", Some(next)),
+            (Some(_), None) => ("sp_dropextendedproperty", None),
+        };
+
+        let mut sql = format!("EXEC {} @name = N'MS_Description'", procedure);
+
+        if let Some(value) = value {
+            write!(sql, ", @value = N'{}'", escape_string_literal(value)).unwrap();
+        }
+
+        write!(
+            sql,
+            ", @level0type = N'SCHEMA', @level0name = N'{}', @level1type = N'TABLE', @level1name = N'{}'",
+            escape_string_literal(self.schema_name()),
+            escape_string_literal(table_name),
+        )
+        .unwrap();
+
+        if let Some(column_name) = column_name {
+            write!(
+                sql,
+                ", @level2type = N'COLUMN', @level2name = N'{}'",
+                escape_string_literal(column_name)
+            )
+            .unwrap();
+        }
+
+        Some(sql)
+    }
 }
 
 impl SqlRenderer for MssqlFlavour {
@@ -63,7 +105,7 @@ impl SqlRenderer for MssqlFlavour {
 
         let default = column
             .default()
-            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(_)))
+            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(expr) if expr.is_empty()))
             .map(|default| {
                 let constraint_name = format!("DF__{}__{}", column.table().name(), column.name());
 
@@ -77,6 +119,12 @@ impl SqlRenderer for MssqlFlavour {
 
         if column.is_autoincrement() {
             format!("{} INT IDENTITY(1,1)", column_name)
+        } else if let Some(generated) = column.generated() {
+            // Computed columns have no type, and can only be NOT NULL when persisted.
+            match generated.stored {
+                true => format!("{} AS ({}) PERSISTED{}", column_name, generated.expression, nullability),
+                false => format!("{} AS ({})", column_name, generated.expression),
+            }
         } else {
             format!("{} {}{}{}", column_name, r#type, nullability, default)
         }
@@ -90,10 +138,11 @@ impl SqlRenderer for MssqlFlavour {
             .join(",");
 
         format!(
-            " REFERENCES {}({}) {} ON UPDATE CASCADE",
+            " REFERENCES {}({}) {} {}",
             self.quote_with_schema(&foreign_key.referenced_table().name()),
             cols,
             render_on_delete(&foreign_key.on_delete_action()),
+            render_on_update(&foreign_key.on_update_action()),
         )
     }
 
@@ -213,6 +262,15 @@ impl SqlRenderer for MssqlFlavour {
         )
     }
 
+    fn render_create_table_comments(&self, table: &TableWalker<'_>) -> Vec<String> {
+        self.render_comment(Pair::new(None, table.comment()), table.name(), None)
+            .into_iter()
+            .chain(table.columns().filter_map(|column| {
+                self.render_comment(Pair::new(None, column.comment()), table.name(), Some(column.name()))
+            }))
+            .collect()
+    }
+
     fn render_drop_enum(&self, _: &EnumWalker<'_>) -> Vec<String> {
         unreachable!("render_drop_enum on MSSQL")
     }
@@ -338,6 +396,9 @@ impl SqlRenderer for MssqlFlavour {
             // Rename the temporary table with the name defined in the migration.
             result.push(self.render_rename_table(&temporary_table_name, tables.next().name()));
 
+            // The comments were extended properties of the dropped table.
+            result.extend(self.render_create_table_comments(&tables.next()));
+
             // Recreating all foreign keys pointing to this table
             for fk in tables.next().referencing_foreign_keys() {
                 result.push(self.render_add_foreign_key(&fk));
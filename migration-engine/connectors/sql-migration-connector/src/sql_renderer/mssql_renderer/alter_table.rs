@@ -27,6 +27,7 @@ pub(crate) fn create_statements(
         add_columns: Vec::new(),
         drop_columns: Vec::new(),
         column_mods: Vec::new(),
+        comments: Vec::new(),
     };
 
     constructor.into_statements()
@@ -41,6 +42,7 @@ struct AlterTableConstructor<'a> {
     add_columns: Vec<String>,
     drop_columns: Vec<String>,
     column_mods: Vec<String>,
+    comments: Vec<String>,
 }
 
 impl<'a> AlterTableConstructor<'a> {
@@ -69,6 +71,9 @@ impl<'a> AlterTableConstructor<'a> {
                 }) => {
                     self.alter_column(*column_index, &changes);
                 }
+                TableChange::AlterComment => {
+                    self.alter_comment();
+                }
             };
         }
 
@@ -111,6 +116,8 @@ impl<'a> AlterTableConstructor<'a> {
             ));
         }
 
+        statements.extend(self.comments);
+
         statements
     }
 
@@ -145,6 +152,7 @@ impl<'a> AlterTableConstructor<'a> {
     fn add_column(&mut self, column_index: usize) {
         let column = self.tables.next().column_at(column_index);
         self.add_columns.push(self.renderer.render_column(&column));
+        self.add_column_comment(Pair::new(None, column.comment()), &column);
     }
 
     fn drop_column(&mut self, column_index: usize) {
@@ -162,12 +170,33 @@ impl<'a> AlterTableConstructor<'a> {
             .push(format!("{}", self.renderer.quote(columns.previous().name())));
 
         self.add_columns.push(self.renderer.render_column(columns.next()));
+        // The extended property is dropped together with the column.
+        self.add_column_comment(Pair::new(None, columns.next().comment()), columns.next());
+    }
+
+    fn alter_comment(&mut self) {
+        let comments = self.tables.as_ref().map(|table| table.comment());
+
+        self.comments
+            .extend(self.renderer.render_comment(comments, self.tables.next().name(), None));
+    }
+
+    fn add_column_comment(&mut self, comments: Pair<Option<&str>>, column: &ColumnWalker<'_>) {
+        self.comments.extend(
+            self.renderer
+                .render_comment(comments, self.tables.next().name(), Some(column.name())),
+        );
     }
 
     fn alter_column(&mut self, columns: Pair<usize>, changes: &ColumnChanges) {
         let columns = self.tables.columns(&columns);
         let expanded = expand_alter_column(&columns, changes);
 
+        if changes.comment_changed() {
+            let comments = columns.as_ref().map(|column| column.comment());
+            self.add_column_comment(comments, columns.next());
+        }
+
         for alter in expanded.into_iter() {
             match alter {
                 MsSqlAlterColumn::DropDefault { constraint_name } => {
@@ -280,7 +309,7 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
         if let Some(next_default) = columns.next().default() {
             changes.push(MsSqlAlterColumn::SetDefault(next_default.clone()));
         }
-    } else {
+    } else if !column_changes.only_comment_changed() {
         changes.push(MsSqlAlterColumn::Modify);
     }
 
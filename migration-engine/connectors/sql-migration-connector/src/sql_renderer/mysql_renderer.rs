@@ -1,6 +1,6 @@
 use super::{
     common::SQL_INDENTATION,
-    common::{render_nullability, render_on_delete, Quoted},
+    common::{render_nullability, render_on_delete, render_on_update, Quoted},
     IteratorJoin, SqlRenderer,
 };
 use crate::{
@@ -104,7 +104,16 @@ impl SqlRenderer for MysqlFlavour {
                         )),
                     };
                 }
-                TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on MySQL"),
+                TableChange::DropAndRecreateColumn { column_index, .. } => {
+                    let columns = tables.columns(column_index);
+
+                    lines.push(format!("DROP COLUMN {}", self.quote(columns.previous().name())));
+                    lines.push(format!("ADD COLUMN {}", self.render_column(columns.next())));
+                }
+                TableChange::AlterComment => lines.push(format!(
+                    "COMMENT = '{}'",
+                    escape_string_literal(tables.next().comment().unwrap_or(""))
+                )),
             };
         }
 
@@ -126,7 +135,8 @@ impl SqlRenderer for MysqlFlavour {
         let default_str = column
             .default()
             .filter(|default| {
-                !matches!(default.kind(), DefaultKind::DBGENERATED(_) | DefaultKind::SEQUENCE(_))
+                !matches!(default.kind(), DefaultKind::SEQUENCE(_))
+                    && !matches!(default.kind(), DefaultKind::DBGENERATED(expr) if expr.is_empty())
                     // We do not want to render JSON defaults because they are not supported by MySQL.
                     && !matches!(column.column_type_family(), ColumnTypeFamily::Json)
                     // We do not want to render binary defaults because they are not supported by MySQL.
@@ -145,15 +155,28 @@ impl SqlRenderer for MysqlFlavour {
         } else {
             ""
         };
+        let comment_str = render_comment(column.comment());
+
+        if column.generated().is_some() {
+            return format!(
+                "{}{} {}{}{}{}",
+                SQL_INDENTATION,
+                column_name,
+                tpe_str,
+                render_generated(column),
+                nullability_str,
+                comment_str
+            );
+        }
 
         match foreign_key {
             Some(_) => format!(
-                "{}{} {}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str
+                "{}{} {}{}{}{}",
+                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str, comment_str
             ),
             None => format!(
-                "{}{} {}{}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str, auto_increment_str
+                "{}{} {}{}{}{}{}",
+                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str, auto_increment_str, comment_str
             ),
         }
     }
@@ -166,10 +189,11 @@ impl SqlRenderer for MysqlFlavour {
             .join(",");
 
         format!(
-            " REFERENCES `{table_name}`({column_names}) {on_delete} ON UPDATE CASCADE",
+            " REFERENCES `{table_name}`({column_names}) {on_delete} {on_update}",
             table_name = foreign_key.referenced_table().name(),
             column_names = referenced_columns,
-            on_delete = render_on_delete(foreign_key.on_delete_action())
+            on_delete = render_on_delete(foreign_key.on_delete_action()),
+            on_update = render_on_update(foreign_key.on_update_action())
         )
     }
 
@@ -255,11 +279,15 @@ impl SqlRenderer for MysqlFlavour {
         };
 
         format!(
-            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci{comment}",
             table_name = self.quote(table_name),
             columns = columns,
             indexes = indexes,
             primary_key = primary_key,
+            comment = table
+                .comment()
+                .map(|comment| format!(" COMMENT='{}'", escape_string_literal(comment)))
+                .unwrap_or_else(String::new),
         )
     }
 
@@ -328,9 +356,10 @@ fn render_mysql_modify(
         .unwrap_or_else(String::new);
 
     format!(
-        "MODIFY {column_name} {column_type}{nullability}{default}{sequence}",
+        "MODIFY {column_name} {column_type}{generated}{nullability}{default}{sequence}{comment}",
         column_name = Quoted::mysql_ident(&next_column.name()),
         column_type = column_type,
+        generated = render_generated(next_column),
         nullability = if next_column.arity().is_required() {
             " NOT NULL"
         } else {
@@ -342,9 +371,33 @@ fn render_mysql_modify(
         } else {
             ""
         },
+        comment = render_comment(next_column.comment()),
     )
 }
 
+/// Render the `GENERATED ALWAYS AS` clause of a column definition. Like the comment, it has to
+/// be rendered on MODIFY, otherwise the column would stop being generated.
+fn render_generated(column: &ColumnWalker<'_>) -> String {
+    column
+        .generated()
+        .map(|generated| {
+            format!(
+                " GENERATED ALWAYS AS ({}) {}",
+                generated.expression,
+                if generated.stored { "STORED" } else { "VIRTUAL" }
+            )
+        })
+        .unwrap_or_else(String::new)
+}
+
+/// Render the `COMMENT` clause of a column definition. MODIFY replaces the
+/// whole column definition, so it has to be rendered even if it did not change.
+fn render_comment(comment: Option<&str>) -> String {
+    comment
+        .map(|comment| format!(" COMMENT '{}'", escape_string_literal(comment)))
+        .unwrap_or_else(String::new)
+}
+
 pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
     if !column.column_type().full_data_type.is_empty() {
         return column.column_type().full_data_type.clone().into();
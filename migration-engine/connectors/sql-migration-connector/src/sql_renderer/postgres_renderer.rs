@@ -3,7 +3,7 @@ use crate::{
     flavour::PostgresFlavour,
     pair::Pair,
     sql_migration::{AddColumn, AlterColumn, AlterEnum, AlterTable, DropColumn, RedefineTable, TableChange},
-    sql_schema_differ::{ColumnChange, ColumnChanges},
+    sql_schema_differ::{ColumnChange, ColumnChanges, SequenceKind},
 };
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
@@ -59,8 +59,6 @@ impl SqlRenderer for PostgresFlavour {
         let tmp_name = format!("{}_new", &enums.next().name());
         let tmp_old_name = format!("{}_old", &enums.previous().name());
 
-        stmts.push("BEGIN".to_string());
-
         // create the new enum with tmp name
         {
             let create_new_enum = format!(
@@ -123,8 +121,6 @@ impl SqlRenderer for PostgresFlavour {
             stmts.push(sql)
         }
 
-        stmts.push("COMMIT".to_string());
-
         stmts
     }
 
@@ -166,6 +162,10 @@ impl SqlRenderer for PostgresFlavour {
                     let col_sql = self.render_column(&column);
 
                     lines.push(format!("ADD COLUMN {}", col_sql));
+
+                    if column.comment().is_some() {
+                        after_statements.push(render_column_comment(&column));
+                    }
                 }
                 TableChange::DropColumn(DropColumn { index }) => {
                     let name = self.quote(tables.previous().column_at(*index).name());
@@ -198,12 +198,17 @@ impl SqlRenderer for PostgresFlavour {
 
                     let col_sql = self.render_column(columns.next());
                     lines.push(format!("ADD COLUMN {}", col_sql));
+
+                    if columns.next().comment().is_some() {
+                        after_statements.push(render_column_comment(columns.next()));
+                    }
                 }
+                TableChange::AlterComment => after_statements.push(render_table_comment(tables.next())),
             };
         }
 
         if lines.is_empty() {
-            return Vec::new();
+            return before_statements.into_iter().chain(after_statements).collect();
         }
 
         let alter_table = format!(
@@ -225,13 +230,33 @@ impl SqlRenderer for PostgresFlavour {
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
-            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(_)))
+            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(expr) if expr.is_empty()))
             .map(|default| format!(" DEFAULT {}", self.render_default(default, column.column_type_family())))
             .unwrap_or_else(String::new);
         let is_serial = column.is_autoincrement();
 
-        if is_serial {
+        if let Some(sequence) = column.owned_sequence().filter(|sequence| sequence.is_identity()) {
+            format!(
+                "{}{} {}{} GENERATED BY DEFAULT AS IDENTITY {}",
+                SQL_INDENTATION,
+                column_name,
+                tpe_str,
+                nullability_str,
+                render_identity_options(sequence.start, sequence.increment)
+            )
+        } else if column.standalone_sequence().is_some() {
+            format!(
+                "{}{} {}{}{}",
+                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str
+            )
+        } else if is_serial {
             format!("{} SERIAL", column_name)
+        } else if let Some(generated) = column.generated() {
+            // Postgres only supports stored generated columns.
+            format!(
+                "{}{} {}{} GENERATED ALWAYS AS ({}) STORED",
+                SQL_INDENTATION, column_name, tpe_str, nullability_str, generated.expression
+            )
         } else {
             format!(
                 "{}{} {}{}{}",
@@ -248,10 +273,11 @@ impl SqlRenderer for PostgresFlavour {
             .join(",");
 
         format!(
-            "REFERENCES {}({}) {} ON UPDATE CASCADE",
+            "REFERENCES {}({}) {} {}",
             self.quote(&foreign_key.referenced_table().name()),
             referenced_columns,
-            render_on_delete(&foreign_key.on_delete_action())
+            render_on_delete(&foreign_key.on_delete_action()),
+            render_on_update(&foreign_key.on_update_action())
         )
     }
 
@@ -270,10 +296,33 @@ impl SqlRenderer for PostgresFlavour {
             (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
             (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
             (DefaultKind::VALUE(val), _) => val.to_string().into(),
-            (DefaultKind::SEQUENCE(_), _) => "".into(),
+            (DefaultKind::SEQUENCE(name), _) if name.is_empty() => "".into(),
+            (DefaultKind::SEQUENCE(name), _) => render_nextval(name).into(),
         }
     }
 
+    fn render_create_sequence(&self, sequence: &Sequence) -> Vec<String> {
+        vec![format!(
+            "CREATE SEQUENCE {} START WITH {} INCREMENT BY {}",
+            self.quote(&sequence.name),
+            sequence.start,
+            sequence.increment
+        )]
+    }
+
+    fn render_alter_sequence(&self, sequences: Pair<&Sequence>) -> Vec<String> {
+        vec![format!(
+            "ALTER SEQUENCE {} START WITH {} INCREMENT BY {}",
+            self.quote(&sequences.next().name),
+            sequences.next().start,
+            sequences.next().increment
+        )]
+    }
+
+    fn render_drop_sequence(&self, sequence: &Sequence) -> Vec<String> {
+        vec![format!("DROP SEQUENCE {}", self.quote(&sequence.name))]
+    }
+
     fn render_create_enum(&self, enm: &EnumWalker<'_>) -> Vec<String> {
         let sql = format!(
             r#"CREATE TYPE "{enum_name}" AS ENUM ({variants})"#,
@@ -326,6 +375,20 @@ impl SqlRenderer for PostgresFlavour {
         )
     }
 
+    fn render_create_table_comments(&self, table: &TableWalker<'_>) -> Vec<String> {
+        table
+            .comment()
+            .map(|_| render_table_comment(table))
+            .into_iter()
+            .chain(
+                table
+                    .columns()
+                    .filter(|column| column.comment().is_some())
+                    .map(|column| render_column_comment(&column)),
+            )
+            .collect()
+    }
+
     fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> Vec<String> {
         let sql = format!(
             "DROP TYPE {enum_name}",
@@ -396,6 +459,40 @@ fn escape_string_literal(s: &str) -> Cow<'_, str> {
     STRING_LITERAL_CHARACTER_TO_ESCAPE_RE.replace_all(s, "\\$0")
 }
 
+fn render_comment(comment: Option<&str>) -> String {
+    comment
+        .map(|comment| format!("'{}'", comment.replace('\'', "''")))
+        .unwrap_or_else(|| "NULL".to_owned())
+}
+
+fn render_table_comment(table: &TableWalker<'_>) -> String {
+    format!(
+        "COMMENT ON TABLE {table_name} IS {comment}",
+        table_name = Quoted::postgres_ident(table.name()),
+        comment = render_comment(table.comment()),
+    )
+}
+
+fn render_identity_options(start: i64, increment: i64) -> String {
+    format!("(START WITH {} INCREMENT BY {})", start, increment)
+}
+
+fn render_nextval(sequence_name: &str) -> String {
+    format!(
+        "nextval({}::regclass)",
+        Quoted::postgres_string(Quoted::postgres_ident(sequence_name))
+    )
+}
+
+fn render_column_comment(column: &ColumnWalker<'_>) -> String {
+    format!(
+        "COMMENT ON COLUMN {table_name}.{column_name} IS {comment}",
+        table_name = Quoted::postgres_ident(column.table().name()),
+        column_name = Quoted::postgres_ident(column.name()),
+        comment = render_comment(column.comment()),
+    )
+}
+
 fn render_alter_column(
     renderer: &PostgresFlavour,
     columns: &Pair<ColumnWalker<'_>>,
@@ -416,7 +513,13 @@ fn render_alter_column(
                 clauses.push(format!("{} DROP DEFAULT", &alter_column_prefix));
 
                 // We also need to drop the sequence, in case it isn't used by any other column.
-                if let Some(DefaultKind::SEQUENCE(sequence_name)) = columns.previous().default().map(|d| d.kind()) {
+                // Standalone sequences are dropped with their own step.
+                if let Some(DefaultKind::SEQUENCE(sequence_name)) = columns
+                    .previous()
+                    .default()
+                    .map(|d| d.kind())
+                    .filter(|_| columns.previous().standalone_sequence().is_none())
+                {
                     let sequence_is_still_used = walk_columns(columns.next().schema()).any(|column| matches!(column.default().map(|d| d.kind()), Some(DefaultKind::SEQUENCE(other_sequence)) if other_sequence == sequence_name) && !column.is_same_column(columns.next()));
 
                     if !sequence_is_still_used {
@@ -464,8 +567,27 @@ fn render_alter_column(
                     column_name = column_name,
                 ));
             }
+            PostgresAlterColumn::SetSequenceDefault(sequence_name) => clauses.push(format!(
+                "{} SET DEFAULT {}",
+                &alter_column_prefix,
+                render_nextval(&sequence_name)
+            )),
+            PostgresAlterColumn::AddIdentity { start, increment } => clauses.push(format!(
+                "{} ADD GENERATED BY DEFAULT AS IDENTITY {}",
+                &alter_column_prefix,
+                render_identity_options(start, increment)
+            )),
+            PostgresAlterColumn::SetIdentityOptions { start, increment } => clauses.push(format!(
+                "{} SET START WITH {} SET INCREMENT BY {}",
+                &alter_column_prefix, start, increment
+            )),
+            PostgresAlterColumn::DropIdentity => clauses.push(format!("{} DROP IDENTITY", &alter_column_prefix)),
         }
     }
+
+    if column_changes.comment_changed() {
+        after_statements.push(render_column_comment(columns.next()));
+    }
 }
 
 fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &ColumnChanges) -> Vec<PostgresAlterColumn> {
@@ -497,16 +619,49 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
                 | (ColumnArity::List, ColumnArity::List) => (),
             },
             ColumnChange::TypeChanged => set_type = true,
+            // Comments are set in a separate statement.
+            ColumnChange::Comment => (),
             ColumnChange::Sequence => {
-                if columns.previous().is_autoincrement() {
-                    // The sequence should be dropped.
-                    changes.push(PostgresAlterColumn::DropDefault)
-                } else {
-                    // The sequence should be created.
-                    changes.push(PostgresAlterColumn::AddSequence)
+                let kinds = columns.as_ref().map(SequenceKind::of);
+
+                match kinds.as_tuple() {
+                    (SequenceKind::Identity { .. }, SequenceKind::Identity { start, increment }) => {
+                        changes.push(PostgresAlterColumn::SetIdentityOptions {
+                            start: *start,
+                            increment: *increment,
+                        })
+                    }
+                    (previous, next) => {
+                        // The previous sequence should be dropped.
+                        match previous {
+                            SequenceKind::Identity { .. } => changes.push(PostgresAlterColumn::DropIdentity),
+                            SequenceKind::Serial | SequenceKind::Standalone(_)
+                                if !matches!(next, SequenceKind::Serial) =>
+                            {
+                                changes.push(PostgresAlterColumn::DropDefault)
+                            }
+                            _ => (),
+                        }
+
+                        // The next sequence should be created.
+                        match next {
+                            SequenceKind::None => (),
+                            SequenceKind::Serial => changes.push(PostgresAlterColumn::AddSequence),
+                            SequenceKind::Identity { start, increment } => {
+                                changes.push(PostgresAlterColumn::AddIdentity {
+                                    start: *start,
+                                    increment: *increment,
+                                })
+                            }
+                            SequenceKind::Standalone(name) => {
+                                changes.push(PostgresAlterColumn::SetSequenceDefault((*name).to_owned()))
+                            }
+                        }
+                    }
                 }
             }
             ColumnChange::Renaming => unreachable!("column renaming"),
+            ColumnChange::Generated => unreachable!("generated columns are dropped and recreated"),
         }
     }
 
@@ -528,4 +683,17 @@ enum PostgresAlterColumn {
     SetNotNull,
     /// Add an auto-incrementing sequence as a default on the column.
     AddSequence,
+    /// Draw the default of the column from an existing standalone sequence.
+    SetSequenceDefault(String),
+    /// Turn the column into an identity column.
+    AddIdentity {
+        start: i64,
+        increment: i64,
+    },
+    /// Change the options of the sequence of an identity column.
+    SetIdentityOptions {
+        start: i64,
+        increment: i64,
+    },
+    DropIdentity,
 }
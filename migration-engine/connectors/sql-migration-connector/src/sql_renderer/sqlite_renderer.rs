@@ -43,7 +43,10 @@ impl SqlRenderer for SqliteFlavour {
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
-            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(_) | DefaultKind::SEQUENCE(_)))
+            .filter(|default| {
+                !matches!(default.kind(), DefaultKind::DBGENERATED(expr) if expr.is_empty())
+                    && !matches!(default.kind(), DefaultKind::SEQUENCE(_))
+            })
             .map(|default| format!(" DEFAULT {}", self.render_default(default, column.column_type_family())))
             .unwrap_or_else(String::new);
         let auto_increment_str = if column.is_autoincrement() && column.is_single_primary_key() {
@@ -52,6 +55,18 @@ impl SqlRenderer for SqliteFlavour {
             ""
         };
 
+        if let Some(generated) = column.generated() {
+            return format!(
+                "{indentation}{column_name} {tpe_str}{nullability_str} GENERATED ALWAYS AS ({expression}) {storage}",
+                indentation = SQL_INDENTATION,
+                column_name = column_name,
+                tpe_str = tpe_str,
+                nullability_str = nullability_str,
+                expression = generated.expression,
+                storage = if generated.stored { "STORED" } else { "VIRTUAL" },
+            );
+        }
+
         format!(
             "{indentation}{column_name} {tpe_str}{nullability_str}{default_str}{auto_increment}",
             indentation = SQL_INDENTATION,
@@ -71,10 +86,11 @@ impl SqlRenderer for SqliteFlavour {
             .join(",");
 
         format!(
-            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} ON UPDATE CASCADE",
+            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} {on_update_action}",
             referenced_table = self.quote(foreign_key.referenced_table().name()),
             referenced_fields = referenced_fields,
-            on_delete_action = render_on_delete(foreign_key.on_delete_action())
+            on_delete_action = render_on_delete(foreign_key.on_delete_action()),
+            on_update_action = render_on_update(foreign_key.on_update_action())
         )
     }
 
@@ -124,6 +140,7 @@ impl SqlRenderer for SqliteFlavour {
                 }
                 TableChange::AddPrimaryKey { .. } => unreachable!("AddPrimaryKey on SQLite"),
                 TableChange::AlterColumn(_) => unreachable!("AlterColumn on SQLite"),
+                TableChange::AlterComment => unreachable!("AlterComment on SQLite"),
                 TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on SQLite"),
                 TableChange::DropColumn(_) => unreachable!("DropColumn on SQLite"),
                 TableChange::DropPrimaryKey { .. } => unreachable!("DropPrimaryKey on SQLite"),
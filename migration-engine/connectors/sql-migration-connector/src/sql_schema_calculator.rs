@@ -4,16 +4,22 @@ pub(super) use sql_schema_calculator_flavour::SqlSchemaCalculatorFlavour;
 
 use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin};
 use datamodel::{
-    walkers::{walk_models, walk_relations, ModelWalker, ScalarFieldWalker, TypeWalker},
-    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ScalarType, ValueGenerator, ValueGeneratorFn,
+    walkers::{walk_models, walk_relations, ModelWalker, RelationFieldWalker, ScalarFieldWalker, TypeWalker},
+    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, OnDeleteStrategy, OnUpdateStrategy,
+    ReferentialIntegrity, ScalarType, SequenceOptions, ValueGenerator, ValueGeneratorFn,
 };
 use prisma_value::PrismaValue;
 use sql_schema_describer::{self as sql, ColumnArity};
 
-pub(crate) fn calculate_sql_schema(datamodel: &Datamodel, flavour: &dyn SqlFlavour) -> sql::SqlSchema {
+pub(crate) fn calculate_sql_schema(
+    datamodel: &Datamodel,
+    flavour: &dyn SqlFlavour,
+    referential_integrity: ReferentialIntegrity,
+) -> sql::SqlSchema {
     let calculator = SqlSchemaCalculator {
         data_model: datamodel,
         flavour,
+        referential_integrity,
     };
     calculator.calculate_internal()
 }
@@ -21,6 +27,9 @@ pub(crate) fn calculate_sql_schema(datamodel: &Datamodel, flavour: &dyn SqlFlavo
 struct SqlSchemaCalculator<'a> {
     data_model: &'a Datamodel,
     flavour: &'a dyn SqlFlavour,
+    /// With `ReferentialIntegrity::Prisma`, relations are enforced by the
+    /// query engine and no foreign keys are created.
+    referential_integrity: ReferentialIntegrity,
 }
 
 impl<'a> SqlSchemaCalculator<'a> {
@@ -36,7 +45,7 @@ impl<'a> SqlSchemaCalculator<'a> {
         tables.extend(self.calculate_relation_tables());
 
         let enums = self.flavour.calculate_enums(&self.data_model);
-        let sequences = Vec::new();
+        let sequences = self.calculate_sequences();
 
         sql::SqlSchema {
             tables,
@@ -51,13 +60,13 @@ impl<'a> SqlSchemaCalculator<'a> {
                 .scalar_fields()
                 .flat_map(|f| match f.field_type() {
                     TypeWalker::Base(_) => {
-                        let has_auto_increment_default = matches!(f.default_value(), Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Autoincrement, .. })));
-
                         Some(sql::Column {
                             name: f.db_name().to_owned(),
                             tpe: column_type(&f),
                             default: migration_value_new(&f),
-                            auto_increment: has_auto_increment_default || self.flavour.field_is_implicit_autoincrement_primary_key(&f),
+                            auto_increment: has_auto_increment_default(&f) || self.flavour.field_is_implicit_autoincrement_primary_key(&f),
+                            comment: f.documentation().map(String::from),
+                            generated: generated_column(&f),
                         })
                     },
                     TypeWalker::Enum(r#enum) => {
@@ -67,16 +76,18 @@ impl<'a> SqlSchemaCalculator<'a> {
                             tpe: self.flavour.enum_column_type(&f,  enum_db_name),
                             default: migration_value_new(&f),
                             auto_increment: false,
+                            comment: f.documentation().map(String::from),
+                            generated: generated_column(&f),
                         })
                     }
                     TypeWalker::NativeType(scalar_type, native_type_instance) =>{
-                        let has_auto_increment_default = matches!(f.default_value(), Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Autoincrement, .. })));
-
                         Some(sql::Column {
                             name: f.db_name().to_owned(),
                             tpe: self.flavour.column_type_for_native_type(&f, scalar_type, native_type_instance),
                             default: migration_value_new(&f),
-                            auto_increment: has_auto_increment_default || self.flavour.field_is_implicit_autoincrement_primary_key(&f)
+                            auto_increment: has_auto_increment_default(&f) || self.flavour.field_is_implicit_autoincrement_primary_key(&f),
+                            comment: f.documentation().map(String::from),
+                            generated: generated_column(&f),
                         })
                     } ,
                     _ => None,
@@ -140,12 +151,50 @@ impl<'a> SqlSchemaCalculator<'a> {
                 indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                 primary_key,
                 foreign_keys: Vec::new(),
+                comment: model.documentation().map(String::from),
             };
 
             (model, table)
         })
     }
 
+    /// Standalone sequences are shared by all the fields naming them, unnamed sequences belong to
+    /// an identity column.
+    fn calculate_sequences(&self) -> Vec<sql::Sequence> {
+        let mut sequences: Vec<sql::Sequence> = Vec::new();
+
+        for model in walk_models(self.data_model) {
+            for field in model.scalar_fields() {
+                let options = match sequence_options(&field) {
+                    Some(options) => options,
+                    None => continue,
+                };
+
+                let (name, owned_by) = match options.name {
+                    Some(name) if sequences.iter().any(|sequence| sequence.name == name) => continue,
+                    Some(name) => (name, None),
+                    None => (
+                        format!("{}_{}_seq", model.db_name(), field.db_name()),
+                        Some(sql::SequenceOwner {
+                            table: model.db_name().to_owned(),
+                            column: field.db_name().to_owned(),
+                            identity: true,
+                        }),
+                    ),
+                };
+
+                sequences.push(sql::Sequence {
+                    name,
+                    start: options.start.unwrap_or(1),
+                    increment: options.increment.unwrap_or(1),
+                    owned_by,
+                });
+            }
+        }
+
+        sequences
+    }
+
     fn add_inline_relations_to_model_tables(&self, model: ModelWalker<'a>, table: &mut sql::Table) {
         let relation_fields = model
             .relation_fields()
@@ -160,17 +209,14 @@ impl<'a> SqlSchemaCalculator<'a> {
             }
 
             // Foreign key
-            {
+            if self.uses_foreign_keys() {
                 let fk = sql::ForeignKey {
                     constraint_name: None,
                     columns: fk_columns,
                     referenced_table: relation_field.referenced_model().database_name().to_owned(),
                     referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
-                    on_update_action: sql::ForeignKeyAction::Cascade,
-                    on_delete_action: match column_arity(relation_field.arity()) {
-                        ColumnArity::Required => sql::ForeignKeyAction::Cascade,
-                        _ => sql::ForeignKeyAction::SetNull,
-                    },
+                    on_update_action: on_update_action(&relation_field),
+                    on_delete_action: on_delete_action(&relation_field),
                 };
 
                 table.foreign_keys.push(fk);
@@ -178,6 +224,10 @@ impl<'a> SqlSchemaCalculator<'a> {
         }
     }
 
+    fn uses_foreign_keys(&self) -> bool {
+        self.referential_integrity == ReferentialIntegrity::ForeignKeys
+    }
+
     #[allow(clippy::needless_lifetimes)] // clippy is wrong here
     fn calculate_relation_tables<'b>(&'b self) -> impl Iterator<Item = sql::Table> + 'b {
         walk_relations(self.data_model)
@@ -189,24 +239,28 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let model_a = model_a_id.model();
                 let model_b = model_b_id.model();
 
-                let foreign_keys = vec![
-                    sql::ForeignKey {
-                        constraint_name: None,
-                        columns: vec![m2m.model_a_column().into()],
-                        referenced_table: model_a.db_name().into(),
-                        referenced_columns: vec![model_a_id.db_name().into()],
-                        on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
-                        on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
-                    },
-                    sql::ForeignKey {
-                        constraint_name: None,
-                        columns: vec![m2m.model_b_column().into()],
-                        referenced_table: model_b.db_name().into(),
-                        referenced_columns: vec![model_b_id.db_name().into()],
-                        on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
-                        on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
-                    },
-                ];
+                let foreign_keys = if !self.uses_foreign_keys() {
+                    Vec::new()
+                } else {
+                    vec![
+                        sql::ForeignKey {
+                            constraint_name: None,
+                            columns: vec![m2m.model_a_column().into()],
+                            referenced_table: model_a.db_name().into(),
+                            referenced_columns: vec![model_a_id.db_name().into()],
+                            on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
+                            on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
+                        },
+                        sql::ForeignKey {
+                            constraint_name: None,
+                            columns: vec![m2m.model_b_column().into()],
+                            referenced_table: model_b.db_name().into(),
+                            referenced_columns: vec![model_b_id.db_name().into()],
+                            on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
+                            on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
+                        },
+                    ]
+                };
 
                 let indexes = vec![
                     sql::Index {
@@ -227,12 +281,16 @@ impl<'a> SqlSchemaCalculator<'a> {
                         tpe: column_type(&model_a_id),
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                     sql::Column {
                         name: m2m.model_b_column().into(),
                         tpe: column_type(&model_b_id),
                         default: None,
                         auto_increment: false,
+                        comment: None,
+                        generated: None,
                     },
                 ];
 
@@ -242,11 +300,49 @@ impl<'a> SqlSchemaCalculator<'a> {
                     indices: indexes,
                     primary_key: None,
                     foreign_keys,
+                    comment: None,
                 }
             })
     }
 }
 
+/// The `ON DELETE` action for the foreign key of a relation field. Without an
+/// explicit `onDelete`, required relations cascade and optional relations set
+/// the columns to null.
+fn on_delete_action(relation_field: &RelationFieldWalker<'_>) -> sql::ForeignKeyAction {
+    match relation_field.on_delete() {
+        OnDeleteStrategy::Cascade => sql::ForeignKeyAction::Cascade,
+        OnDeleteStrategy::SetNull => sql::ForeignKeyAction::SetNull,
+        OnDeleteStrategy::None => match column_arity(relation_field.arity()) {
+            ColumnArity::Required => sql::ForeignKeyAction::Cascade,
+            _ => sql::ForeignKeyAction::SetNull,
+        },
+    }
+}
+
+/// The `ON UPDATE` action for the foreign key of a relation field. Without an
+/// explicit `onUpdate`, updates cascade.
+fn on_update_action(relation_field: &RelationFieldWalker<'_>) -> sql::ForeignKeyAction {
+    match relation_field.on_update() {
+        OnUpdateStrategy::Cascade | OnUpdateStrategy::None => sql::ForeignKeyAction::Cascade,
+        OnUpdateStrategy::SetNull => sql::ForeignKeyAction::SetNull,
+    }
+}
+
+/// Whether the values of the field are drawn from a sequence, with `autoincrement()` or `sequence()`.
+fn has_auto_increment_default(field: &ScalarFieldWalker<'_>) -> bool {
+    matches!(
+        field.default_value(),
+        Some(DefaultValue::Expression(ValueGenerator {
+            generator: ValueGeneratorFn::Autoincrement,
+            ..
+        })) | Some(DefaultValue::Expression(ValueGenerator {
+            generator: ValueGeneratorFn::Sequence,
+            ..
+        }))
+    )
+}
+
 fn migration_value_new(field: &ScalarFieldWalker<'_>) -> Option<sql_schema_describer::DefaultValue> {
     let value = match &field.default_value()? {
         datamodel::DefaultValue::Single(s) => match field.field_type() {
@@ -264,22 +360,43 @@ fn migration_value_new(field: &ScalarFieldWalker<'_>) -> Option<sql_schema_descr
         datamodel::DefaultValue::Expression(expression) if expression.name == "now" && expression.args.is_empty() => {
             return Some(sql_schema_describer::DefaultValue::now())
         }
-        datamodel::DefaultValue::Expression(expression)
-            if expression.name == "dbgenerated" && expression.args.is_empty() =>
-        {
-            return Some(sql_schema_describer::DefaultValue::db_generated(String::new()))
+        datamodel::DefaultValue::Expression(expression) if expression.name == "dbgenerated" => {
+            let expression = expression.db_generated_expression().unwrap_or_default();
+
+            return Some(sql_schema_describer::DefaultValue::db_generated(expression));
         }
         datamodel::DefaultValue::Expression(expression)
             if expression.name == "autoincrement" && expression.args.is_empty() =>
         {
             return Some(sql_schema_describer::DefaultValue::sequence(String::new()))
         }
+        // Identity columns have no default, their values come from the owned sequence.
+        datamodel::DefaultValue::Expression(expression) if expression.generator == ValueGeneratorFn::Sequence => {
+            return expression
+                .sequence_options()
+                .and_then(|options| options.name)
+                .map(sql_schema_describer::DefaultValue::sequence)
+        }
         datamodel::DefaultValue::Expression(_) => return None,
     };
 
     Some(sql_schema_describer::DefaultValue::value(value))
 }
 
+fn sequence_options(field: &ScalarFieldWalker<'_>) -> Option<SequenceOptions> {
+    match field.default_value()? {
+        DefaultValue::Expression(generator) => generator.sequence_options(),
+        DefaultValue::Single(_) => None,
+    }
+}
+
+fn generated_column(field: &ScalarFieldWalker<'_>) -> Option<sql::GeneratedColumn> {
+    field.generated_as().map(|generated| sql::GeneratedColumn {
+        expression: generated.expression.clone(),
+        stored: generated.stored,
+    })
+}
+
 fn column_type(field: &ScalarFieldWalker<'_>) -> sql::ColumnType {
     column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field.arity()))
 }
@@ -4,20 +4,24 @@ mod index;
 mod sql_schema_differ_flavour;
 mod table;
 
-pub(crate) use column::{ColumnChange, ColumnChanges};
+pub(crate) use column::{ColumnChange, ColumnChanges, SequenceKind};
 pub(crate) use sql_schema_differ_flavour::SqlSchemaDifferFlavour;
 
 use crate::{
     pair::Pair,
     sql_migration::{
-        self, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterTable, CreateEnum, CreateIndex, CreateTable,
-        DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, RedefineTable, SqlMigrationStep, TableChange,
+        self, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterSequence, AlterTable, CreateEnum, CreateIndex,
+        CreateSequence, CreateTable, DropColumn, DropEnum, DropForeignKey, DropIndex, DropSequence, DropTable,
+        RedefineTable, SqlMigrationStep, TableChange,
     },
     SqlFlavour, SqlSchema, MIGRATION_TABLE_NAME,
 };
 use column::ColumnTypeChange;
 use enums::EnumDiffer;
-use sql_schema_describer::walkers::{EnumWalker, ForeignKeyWalker, TableWalker};
+use sql_schema_describer::{
+    walkers::{ColumnWalker, EnumWalker, ForeignKeyWalker, TableWalker},
+    ForeignKeyAction, Sequence,
+};
 use std::collections::HashSet;
 use table::TableDiffer;
 
@@ -51,6 +55,9 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
         .into_iter()
         .map(SqlMigrationStep::CreateEnum)
         .chain(differ.alter_enums().into_iter().map(SqlMigrationStep::AlterEnum))
+        // Order matters: sequences must exist before the columns drawing from them are created.
+        .chain(differ.create_sequences().map(SqlMigrationStep::CreateSequence))
+        .chain(differ.alter_sequences().map(SqlMigrationStep::AlterSequence))
         .chain(drop_indexes.into_iter().map(SqlMigrationStep::DropIndex))
         .chain(drop_foreign_keys.into_iter().map(SqlMigrationStep::DropForeignKey))
         .chain(
@@ -68,6 +75,8 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
         // because on Postgres and SQLite, we may create indexes whose names
         // clash with the names of indexes on the dropped tables.
         .chain(drop_tables.into_iter().map(SqlMigrationStep::DropTable))
+        // Order matters: sequences can only be dropped once no column uses them anymore.
+        .chain(differ.drop_sequences().map(SqlMigrationStep::DropSequence))
         // Order matters: we must create indexes after ALTER TABLEs because the indexes can be
         // on fields that are dropped/created there.
         .chain(create_indexes.into_iter().map(SqlMigrationStep::CreateIndex))
@@ -164,6 +173,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     .chain(SqlSchemaDiffer::add_columns(&differ))
                     .chain(SqlSchemaDiffer::alter_columns(&differ))
                     .chain(SqlSchemaDiffer::add_primary_key(&differ))
+                    .chain(differ.comment_change().map(|_| TableChange::AlterComment))
                     .collect();
 
                 Some(changes)
@@ -205,6 +215,11 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
             let column_index = Pair::new(column_differ.previous.column_index(), column_differ.next.column_index());
 
+            // Generated columns can't be altered in place.
+            if changes.generated_changed() {
+                return Some(TableChange::DropAndRecreateColumn { column_index, changes });
+            }
+
             match type_change {
                 Some(ColumnTypeChange::NotCastable) => {
                     Some(TableChange::DropAndRecreateColumn { column_index, changes })
@@ -391,12 +406,60 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         self.flavour.alter_enums(self)
     }
 
+    #[allow(clippy::needless_lifetimes)] // clippy is wrong here
+    fn create_sequences<'a>(&'a self) -> impl Iterator<Item = CreateSequence> + 'a {
+        self.next_sequences()
+            .filter(move |(_, next)| {
+                !self
+                    .previous_sequences()
+                    .any(|(_, previous)| previous.name == next.name)
+            })
+            .map(|(sequence_index, _)| CreateSequence { sequence_index })
+    }
+
+    #[allow(clippy::needless_lifetimes)] // clippy is wrong here
+    fn alter_sequences<'a>(&'a self) -> impl Iterator<Item = AlterSequence> + 'a {
+        self.previous_sequences().filter_map(move |(previous_index, previous)| {
+            self.next_sequences()
+                .find(|(_, next)| next.name == previous.name)
+                .filter(|(_, next)| next.start != previous.start || next.increment != previous.increment)
+                .map(|(next_index, _)| AlterSequence {
+                    index: Pair::new(previous_index, next_index),
+                })
+        })
+    }
+
+    /// Only the sequences a column default drew from, as with `sequence(name: ...)`, are dropped.
+    /// Other sequences in the database are left alone, and so are the sequences of the columns
+    /// that keep drawing from them with `autoincrement()`.
+    #[allow(clippy::needless_lifetimes)] // clippy is wrong here
+    fn drop_sequences<'a>(&'a self) -> impl Iterator<Item = DropSequence> + 'a {
+        self.previous_sequences()
+            .filter(move |(_, previous)| !self.next_sequences().any(|(_, next)| next.name == previous.name))
+            .filter(move |(_, previous)| {
+                self.previous_tables()
+                    .flat_map(|table| table.columns())
+                    .any(|column| draws_from(&column, previous))
+            })
+            .filter(move |(_, previous)| {
+                !self.table_pairs().any(|tables| {
+                    tables.column_pairs().any(|columns| {
+                        draws_from(&columns.previous, previous)
+                            && SequenceKind::of(&columns.next) == SequenceKind::Serial
+                    })
+                })
+            })
+            .map(|(sequence_index, _)| DropSequence { sequence_index })
+    }
+
     fn redefine_tables(&self, tables_to_redefine: &HashSet<String>) -> Vec<RedefineTable> {
         self.table_pairs()
             .filter(|tables| tables_to_redefine.contains(tables.next().name()))
             .map(|differ| {
                 let column_pairs = differ
                     .column_pairs()
+                    // Generated columns are computed by the database, their data is not copied.
+                    .filter(|columns| columns.next.generated().is_none())
                     .map(|columns| {
                         let (changes, type_change) = columns.all_changes();
                         (
@@ -521,6 +584,16 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(move |previous| !self.next_enums().any(|next| enums_match(previous, &next)))
     }
 
+    /// The standalone sequences of the previous schema, with their index.
+    fn previous_sequences(&self) -> impl Iterator<Item = (usize, &'schema Sequence)> {
+        standalone_sequences(*self.schemas.previous())
+    }
+
+    /// The standalone sequences of the next schema, with their index.
+    fn next_sequences(&self) -> impl Iterator<Item = (usize, &'schema Sequence)> {
+        standalone_sequences(*self.schemas.next())
+    }
+
     fn previous_enums(&self) -> impl Iterator<Item = EnumWalker<'schema>> {
         self.schemas.previous().enum_walkers()
     }
@@ -575,11 +648,42 @@ fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'
         .zip(next.referenced_column_names())
         .all(|(previous, next)| previous == next);
 
+    let same_on_delete_action = foreign_key_actions_match(previous.on_delete_action(), next.on_delete_action());
+    let same_on_update_action = foreign_key_actions_match(previous.on_update_action(), next.on_update_action());
+
     references_same_table
         && references_same_column_count
         && constrains_same_column_count
         && constrains_same_columns
         && references_same_columns
+        && same_on_delete_action
+        && same_on_update_action
+}
+
+/// `NO ACTION` and `RESTRICT` only differ in when the constraint is checked,
+/// and some databases report one for the other.
+fn foreign_key_actions_match(previous: &ForeignKeyAction, next: &ForeignKeyAction) -> bool {
+    match (previous, next) {
+        (ForeignKeyAction::NoAction, ForeignKeyAction::Restrict)
+        | (ForeignKeyAction::Restrict, ForeignKeyAction::NoAction) => true,
+        (previous, next) => previous == next,
+    }
+}
+
+fn standalone_sequences(schema: &SqlSchema) -> impl Iterator<Item = (usize, &Sequence)> {
+    schema
+        .sequences
+        .iter()
+        .enumerate()
+        .filter(|(_, sequence)| sequence.is_standalone())
+}
+
+/// Whether the default of the column draws from the standalone sequence.
+fn draws_from(column: &ColumnWalker<'_>, sequence: &Sequence) -> bool {
+    column
+        .standalone_sequence()
+        .map(|used| used.name == sequence.name)
+        .unwrap_or(false)
 }
 
 fn enums_match(previous: &EnumWalker<'_>, next: &EnumWalker<'_>) -> bool {
@@ -1,6 +1,8 @@
 use crate::flavour::SqlFlavour;
 use enumflags2::BitFlags;
+use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
+use regex::Regex;
 use sql_schema_describer::{walkers::ColumnWalker, ColumnTypeFamily, DefaultKind};
 
 #[derive(Debug)]
@@ -31,13 +33,40 @@ impl<'a> ColumnDiffer<'a> {
             changes |= ColumnChange::Default;
         };
 
-        if self.previous.is_autoincrement() != self.next.is_autoincrement() {
+        if self.sequence_changed() {
             changes |= ColumnChange::Sequence;
         };
 
+        if self.comment_changed() {
+            changes |= ColumnChange::Comment;
+        };
+
+        if self.generated_changed() {
+            changes |= ColumnChange::Generated;
+        };
+
         (ColumnChanges { changes }, column_type_change)
     }
 
+    fn comment_changed(&self) -> bool {
+        self.flavour.supports_comments() && self.previous.comment() != self.next.comment()
+    }
+
+    /// Generation expressions are rewritten by the database, so they are compared in a
+    /// normalized form, see `normalize_expression`. An empty expression could
+    /// not be read from the database and is not compared.
+    fn generated_changed(&self) -> bool {
+        match (self.previous.generated(), self.next.generated()) {
+            (Some(previous), Some(next)) => {
+                previous.stored != next.stored
+                    || (!previous.expression.is_empty()
+                        && normalize_expression(&previous.expression) != normalize_expression(&next.expression))
+            }
+            (None, None) => false,
+            _ => true,
+        }
+    }
+
     pub(crate) fn arity_changed(&self) -> bool {
         self.previous.arity() != self.next.arity()
     }
@@ -46,6 +75,16 @@ impl<'a> ColumnDiffer<'a> {
         self.previous.is_autoincrement() != self.next.is_autoincrement()
     }
 
+    /// `autoincrement()` accepts whatever sequence the column already draws its values from, only
+    /// an explicit `sequence()` default is migrated to.
+    fn sequence_changed(&self) -> bool {
+        match (SequenceKind::of(&self.previous), SequenceKind::of(&self.next)) {
+            (SequenceKind::None, SequenceKind::Serial) => true,
+            (_, SequenceKind::Serial) => false,
+            (previous, next) => previous != next,
+        }
+    }
+
     fn column_type_change(&self) -> Option<ColumnTypeChange> {
         match (self.previous.column_type_family(), self.next.column_type_family()) {
             (_, _) if self.arity_changed() => self.flavour.column_type_change(self),
@@ -110,14 +149,114 @@ impl<'a> ColumnDiffer<'a> {
             (None, Some(DefaultKind::VALUE(_))) => false,
             (None, Some(DefaultKind::NOW)) => false,
 
-            // We can never migrate to @dbgenerated
-            (_, Some(DefaultKind::DBGENERATED(_))) => true,
+            // An argument-less @default(dbgenerated()) can never be migrated to.
+            (_, Some(DefaultKind::DBGENERATED(next))) if next.is_empty() => true,
+            // The database rewrites @default(dbgenerated("expression")), see `normalize_expression`.
+            (Some(DefaultKind::DBGENERATED(prev)), Some(DefaultKind::DBGENERATED(next))) => {
+                normalize_expression(prev) == normalize_expression(next)
+            }
+            (_, Some(DefaultKind::DBGENERATED(_))) => false,
             // Sequence migrations are handled separately.
             (_, Some(DefaultKind::SEQUENCE(_))) => true,
         }
     }
 }
 
+/// Where the values of an auto-incrementing column come from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SequenceKind<'a> {
+    /// The column is not auto-incrementing.
+    None,
+    /// A serial column, or the native auto-increment of the database.
+    Serial,
+    /// An identity column with its sequence options.
+    Identity { start: i64, increment: i64 },
+    /// The column default draws from the named standalone sequence.
+    Standalone(&'a str),
+}
+
+impl<'a> SequenceKind<'a> {
+    pub(crate) fn of(column: &ColumnWalker<'a>) -> Self {
+        if let Some(sequence) = column.owned_sequence().filter(|sequence| sequence.is_identity()) {
+            return SequenceKind::Identity {
+                start: sequence.start,
+                increment: sequence.increment,
+            };
+        }
+
+        if let Some(sequence) = column.standalone_sequence() {
+            return SequenceKind::Standalone(&sequence.name);
+        }
+
+        if column.is_autoincrement() {
+            SequenceKind::Serial
+        } else {
+            SequenceKind::None
+        }
+    }
+}
+
+/// Databases store default expressions the way they print them back: with casts (`::text` on
+/// Postgres), character set introducers (`_utf8mb4'...'` on MySQL), quoted identifiers and
+/// parentheses around the whole expression or, on SQL Server, around numbers. These are removed,
+/// together with whitespace and letter case outside of string literals.
+fn normalize_expression(expression: &str) -> String {
+    static CASTS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)::\s*[a-z_]+(\s+varying)?(\(\d+\))?"#).unwrap());
+    static INTRODUCERS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)\b_[a-z0-9]+'"#).unwrap());
+    static WRAPPED_NUMBERS_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(^|[^a-z0-9_'])\((-?[0-9]+(\.[0-9]+)?)\)"#).unwrap());
+
+    let expression = CASTS_RE.replace_all(expression, "");
+    let expression = INTRODUCERS_RE.replace_all(&expression, "'");
+
+    let mut normalized = String::with_capacity(expression.len());
+    let mut in_string = false;
+
+    for c in expression.chars() {
+        match c {
+            '\'' => {
+                in_string = !in_string;
+                normalized.push(c);
+            }
+            _ if in_string => normalized.push(c),
+            '"' | '`' | '[' | ']' => (),
+            c if c.is_whitespace() => (),
+            c => normalized.extend(c.to_lowercase()),
+        }
+    }
+
+    let normalized = WRAPPED_NUMBERS_RE.replace_all(&normalized, "${1}${2}");
+
+    strip_wrapping_parentheses(&normalized).to_owned()
+}
+
+/// `((a + b))` becomes `a + b`, but `(a) + (b)` is left as is.
+fn strip_wrapping_parentheses(mut expression: &str) -> &str {
+    while expression.starts_with('(') && expression.ends_with(')') {
+        let inner = &expression[1..expression.len() - 1];
+        let mut depth = 0i32;
+
+        // The opening parenthesis must be closed by the last one.
+        let wraps = inner.chars().all(|c| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => (),
+            }
+
+            depth >= 0
+        });
+
+        if !wraps {
+            break;
+        }
+
+        expression = inner;
+    }
+
+    expression
+}
+
 fn json_defaults_match(previous: &str, next: &str) -> bool {
     serde_json::from_str::<serde_json::Value>(previous)
         .and_then(|previous| serde_json::from_str::<serde_json::Value>(next).map(|next| (previous, next)))
@@ -133,6 +272,8 @@ pub(crate) enum ColumnChange {
     Default = 0b0100,
     TypeChanged = 0b1000,
     Sequence = 0b0010000,
+    Comment = 0b0100000,
+    Generated = 0b1000000,
 }
 
 // This should be pub(crate), but SqlMigration is exported, so it has to be
@@ -168,6 +309,18 @@ impl ColumnChanges {
         self.changes == BitFlags::from(ColumnChange::Default)
     }
 
+    pub(crate) fn comment_changed(&self) -> bool {
+        self.changes.contains(ColumnChange::Comment)
+    }
+
+    pub(crate) fn only_comment_changed(&self) -> bool {
+        self.changes == BitFlags::from(ColumnChange::Comment)
+    }
+
+    pub(crate) fn generated_changed(&self) -> bool {
+        self.changes.contains(ColumnChange::Generated)
+    }
+
     pub(crate) fn only_type_changed(&self) -> bool {
         self.changes == BitFlags::from(ColumnChange::TypeChanged)
     }
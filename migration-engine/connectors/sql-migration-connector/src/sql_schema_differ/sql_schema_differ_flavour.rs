@@ -74,6 +74,12 @@ pub(crate) trait SqlSchemaDifferFlavour {
         false
     }
 
+    /// Whether table and column comments are stored in the database, and
+    /// should be diffed.
+    fn supports_comments(&self) -> bool {
+        true
+    }
+
     fn table_names_match(&self, names: Pair<&str>) -> bool {
         names.previous() == names.next()
     }
@@ -21,6 +21,10 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
         true
     }
 
+    fn supports_comments(&self) -> bool {
+        false
+    }
+
     fn tables_to_redefine(&self, differ: &SqlSchemaDiffer<'_>) -> HashSet<String> {
         differ
             .table_pairs()
@@ -29,6 +33,8 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
                     || differ.dropped_primary_key().is_some()
                     || differ.dropped_columns().next().is_some()
                     || differ.added_columns().any(|col| col.arity().is_required())
+                    // ADD COLUMN can't add stored generated columns.
+                    || differ.added_columns().any(|col| col.generated().map(|g| g.stored).unwrap_or(false))
                     || differ.column_pairs().any(|columns| columns.all_changes().0.differs_in_something())
                     // ALTER INDEX does not exist on SQLite
                     || differ.index_pairs().any(|pair| self.index_should_be_renamed(&pair))
@@ -25,6 +25,15 @@ impl<'schema> TableDiffer<'schema> {
             })
     }
 
+    /// The new table comment, if it changed.
+    pub(crate) fn comment_change(&self) -> Option<Option<&'schema str>> {
+        if !self.flavour.supports_comments() || self.previous().comment() == self.next().comment() {
+            return None;
+        }
+
+        Some(self.next().comment())
+    }
+
     pub(crate) fn dropped_columns<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
         self.previous_columns().filter(move |previous_column| {
             self.next_columns()
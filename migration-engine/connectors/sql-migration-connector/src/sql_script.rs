@@ -0,0 +1,528 @@
+//! Application of migration scripts, one statement at a time.
+
+use crate::{connection_wrapper::Connection, flavour::SqlFlavour};
+use migration_connector::{ConnectorError, ConnectorResult};
+use quaint::{ast::Update, prelude::SqlFamily};
+
+/// A statement in a migration script.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Statement<'a> {
+    /// The SQL of the statement, without leading comments.
+    pub(crate) sql: &'a str,
+    /// The line the statement starts on in the script, starting at 1.
+    pub(crate) line: usize,
+}
+
+/// Apply a migration script statement by statement. When `transactional` is
+/// true and the database has transactional DDL, the statements are applied
+/// in a single transaction, so a failing script leaves nothing behind.
+pub(crate) async fn apply_migration_script(
+    connection: &Connection,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+    script_name: &str,
+    script: &str,
+    transactional: bool,
+) -> ConnectorResult<()> {
+    let mut statements = split_statements(script, flavour.sql_family());
+    let use_transaction = transactional && flavour.has_transactional_ddl();
+
+    // Changing `PRAGMA foreign_keys` is a no-op in a transaction on SQLite, so
+    // the foreign keys are disabled around the transaction instead.
+    let disable_foreign_keys = use_transaction
+        && flavour.sql_family().is_sqlite()
+        && statements.iter().any(|statement| is_foreign_keys_pragma(statement.sql));
+
+    if disable_foreign_keys {
+        statements.retain(|statement| !is_foreign_keys_pragma(statement.sql));
+        connection.raw_cmd("PRAGMA foreign_keys=OFF").await?;
+    }
+
+    let result = apply_statements(connection, script_name, &statements, None, use_transaction).await;
+
+    if disable_foreign_keys {
+        connection.raw_cmd("PRAGMA foreign_keys=ON").await?;
+    }
+
+    result
+}
+
+/// Apply one batch of a batched data transformation step, then `progress`,
+/// the update recording it in the migrations table. Batches only change data,
+/// so when `transactional` is true they are applied in a transaction even on
+/// databases without transactional DDL, and the batch is committed together
+/// with its progress.
+pub(crate) async fn apply_batch_script(
+    connection: &Connection,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+    script_name: &str,
+    script: &str,
+    progress: Update<'_>,
+    transactional: bool,
+) -> ConnectorResult<()> {
+    let statements = split_statements(script, flavour.sql_family());
+
+    apply_statements(connection, script_name, &statements, Some(progress), transactional).await
+}
+
+async fn apply_statements(
+    connection: &Connection,
+    script_name: &str,
+    statements: &[Statement<'_>],
+    progress: Option<Update<'_>>,
+    use_transaction: bool,
+) -> ConnectorResult<()> {
+    if use_transaction {
+        connection.raw_cmd("BEGIN TRANSACTION").await?;
+    }
+
+    for (idx, statement) in statements.iter().enumerate() {
+        if let Err(err) = connection.raw_cmd(statement.sql).await {
+            let err = ConnectorError::from(err).with_context(format!(
+                "Failed to apply statement {index} of {count} in `{script_name}`, at line {line}:\n\n{sql}\n",
+                index = idx + 1,
+                count = statements.len(),
+                script_name = script_name,
+                line = statement.line,
+                sql = statement.sql,
+            ));
+
+            if use_transaction {
+                rollback(connection).await;
+            }
+
+            return Err(err);
+        }
+    }
+
+    if let Some(progress) = progress {
+        if let Err(err) = connection.execute(progress).await {
+            let err = ConnectorError::from(err);
+
+            if use_transaction {
+                rollback(connection).await;
+            }
+
+            return Err(err);
+        }
+    }
+
+    if use_transaction {
+        connection.raw_cmd("COMMIT").await?;
+    }
+
+    Ok(())
+}
+
+async fn rollback(connection: &Connection) {
+    if let Err(rollback_err) = connection.raw_cmd("ROLLBACK").await {
+        tracing::warn!("Failed to roll back the migration transaction: {:?}", rollback_err);
+    }
+}
+
+fn is_foreign_keys_pragma(sql: &str) -> bool {
+    let sql: String = sql
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    sql.starts_with("pragmaforeign_keys=")
+}
+
+/// Split a script into the statements sent to the database one by one.
+///
+/// - On SQL Server, the statements are the batches separated by `GO` lines,
+///   like in `sqlcmd`. Batches are not split further, since variables and
+///   `BEGIN TRY ... END CATCH` blocks are scoped to the batch.
+/// - Elsewhere, the script is split on the semicolons that are not in
+///   comments, quotes or `BEGIN ... END` blocks. On PostgreSQL, dollar-quoted
+///   strings are skipped too. On MySQL, the `DELIMITER` command changes the
+///   statement delimiter, like in the `mysql` client.
+///
+/// Statements consisting only of comments are left out.
+pub(crate) fn split_statements(script: &str, sql_family: SqlFamily) -> Vec<Statement<'_>> {
+    match sql_family {
+        SqlFamily::Mssql => split_batches(script),
+        _ => split_on_delimiters(script, sql_family),
+    }
+}
+
+/// Split a T-SQL script on the lines consisting only of `GO`.
+fn split_batches(script: &str) -> Vec<Statement<'_>> {
+    let mut statements = Vec::new();
+    let mut batch_start = 0;
+
+    let mut push_batch = |start: usize, end: usize| {
+        let batch = &script[start..end];
+        let trimmed = batch.trim_start();
+
+        if !trimmed.is_empty() {
+            let code_start = start + (batch.len() - trimmed.len());
+
+            statements.push(Statement {
+                sql: trimmed.trim_end(),
+                line: script[..code_start].matches('\n').count() + 1,
+            });
+        }
+    };
+
+    let mut line_start = 0;
+
+    while line_start < script.len() {
+        let line_end = script[line_start..]
+            .find('\n')
+            .map(|position| line_start + position + 1)
+            .unwrap_or_else(|| script.len());
+
+        if script[line_start..line_end].trim().eq_ignore_ascii_case("GO") {
+            push_batch(batch_start, line_start);
+            batch_start = line_end;
+        }
+
+        line_start = line_end;
+    }
+
+    push_batch(batch_start, script.len());
+
+    statements
+}
+
+fn split_on_delimiters(script: &str, sql_family: SqlFamily) -> Vec<Statement<'_>> {
+    let bytes = script.as_bytes();
+    let mut statements = Vec::new();
+    let mut code_start: Option<usize> = None;
+    let mut block_depth: usize = 0;
+    let mut delimiter = ";";
+    let mut idx = 0;
+
+    let mut push_statement = |code_start: &mut Option<usize>, end: usize| {
+        if let Some(start) = code_start.take() {
+            statements.push(Statement {
+                sql: script[start..end].trim_end(),
+                line: script[..start].matches('\n').count() + 1,
+            });
+        }
+    };
+
+    while idx < bytes.len() {
+        let byte = bytes[idx];
+
+        match byte {
+            b'-' if bytes.get(idx + 1) == Some(&b'-') => {
+                idx = find(bytes, idx, b"\n")
+                    .map(|end| end + 1)
+                    .unwrap_or_else(|| bytes.len());
+                continue;
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                idx = find(bytes, idx + 2, b"*/")
+                    .map(|end| end + 2)
+                    .unwrap_or_else(|| bytes.len());
+                continue;
+            }
+            _ if byte.is_ascii_whitespace() => {
+                idx += 1;
+                continue;
+            }
+            _ if code_start.is_none()
+                && sql_family.is_mysql()
+                && next_word(script, idx).eq_ignore_ascii_case("DELIMITER") =>
+            {
+                let line_end = find(bytes, idx, b"\n").unwrap_or_else(|| bytes.len());
+                let new_delimiter = script[idx + "DELIMITER".len()..line_end].trim();
+
+                if !new_delimiter.is_empty() {
+                    delimiter = new_delimiter;
+                }
+
+                idx = line_end;
+                continue;
+            }
+            _ => (),
+        }
+
+        code_start.get_or_insert(idx);
+
+        if delimiter != ";" && script[idx..].starts_with(delimiter) {
+            // The custom delimiter is not part of the statement.
+            push_statement(&mut code_start, idx);
+            block_depth = 0;
+            idx += delimiter.len();
+            continue;
+        }
+
+        match byte {
+            b'\'' | b'"' | b'`' => idx = skip_quoted(bytes, idx),
+            b'$' if sql_family.is_postgres() && !preceded_by_identifier(bytes, idx) => {
+                idx = skip_dollar_quoted(bytes, idx)
+            }
+            b';' if delimiter == ";" && block_depth == 0 => {
+                idx += 1;
+                push_statement(&mut code_start, idx);
+            }
+            _ if is_identifier_byte(byte) && !preceded_by_identifier(bytes, idx) => {
+                let word_end = identifier_end(bytes, idx);
+                let word = &script[idx..word_end];
+
+                if word.eq_ignore_ascii_case("BEGIN") {
+                    if opens_block(script, word_end) {
+                        block_depth += 1;
+                    }
+                } else if word.eq_ignore_ascii_case("CASE") {
+                    block_depth += 1;
+                } else if word.eq_ignore_ascii_case("END") && !closes_control_flow(script, word_end) {
+                    block_depth = block_depth.saturating_sub(1);
+                }
+
+                idx = word_end;
+            }
+            _ => idx += 1,
+        }
+    }
+
+    push_statement(&mut code_start, bytes.len());
+
+    statements
+}
+
+fn find(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes[from..]
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|position| from + position)
+}
+
+/// Skip a quoted string or identifier, where the quote is escaped by doubling
+/// it.
+fn skip_quoted(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut idx = start + 1;
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            byte if byte == quote && bytes.get(idx + 1) == Some(&quote) => idx += 2,
+            byte if byte == quote => return idx + 1,
+            _ => idx += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Skip a Postgres dollar-quoted string, like `$body$ ... $body$`.
+fn skip_dollar_quoted(bytes: &[u8], start: usize) -> usize {
+    let tag_end = match bytes[start + 1..]
+        .iter()
+        .position(|byte| !is_identifier_byte(*byte))
+        .map(|position| start + 1 + position)
+    {
+        Some(tag_end) if bytes[tag_end] == b'$' => tag_end,
+        _ => return start + 1,
+    };
+
+    let tag = &bytes[start..=tag_end];
+
+    find(bytes, tag_end + 1, tag)
+        .map(|end| end + tag.len())
+        .unwrap_or_else(|| bytes.len())
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+fn preceded_by_identifier(bytes: &[u8], idx: usize) -> bool {
+    idx > 0 && (is_identifier_byte(bytes[idx - 1]) || bytes[idx - 1] == b'$')
+}
+
+fn identifier_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|byte| !is_identifier_byte(*byte))
+        .map(|position| start + position)
+        .unwrap_or_else(|| bytes.len())
+}
+
+fn next_word(script: &str, from: usize) -> &str {
+    let rest = script[from..].trim_start();
+    let end = identifier_end(rest.as_bytes(), 0);
+
+    &rest[..end]
+}
+
+/// The keywords following `BEGIN` when it starts a transaction.
+const TRANSACTION_KEYWORDS: &[&str] = &[
+    "TRANSACTION",
+    "TRAN",
+    "WORK",
+    "DISTRIBUTED",
+    "DEFERRED",
+    "IMMEDIATE",
+    "EXCLUSIVE",
+];
+
+/// `BEGIN` opens a block, unless it starts a transaction.
+fn opens_block(script: &str, begin_end: usize) -> bool {
+    let rest = script[begin_end..].trim_start();
+
+    if rest.is_empty() || rest.starts_with(';') {
+        return false;
+    }
+
+    let next = next_word(script, begin_end);
+
+    !TRANSACTION_KEYWORDS
+        .iter()
+        .any(|keyword| next.eq_ignore_ascii_case(keyword))
+}
+
+/// `END IF`, `END LOOP`, `END WHILE` and `END REPEAT` close control flow
+/// statements that did not open a block.
+fn closes_control_flow(script: &str, end_end: usize) -> bool {
+    let next = next_word(script, end_end);
+
+    ["IF", "LOOP", "WHILE", "REPEAT"]
+        .iter()
+        .any(|keyword| next.eq_ignore_ascii_case(keyword))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sqls(script: &str, sql_family: SqlFamily) -> Vec<&str> {
+        split_statements(script, sql_family)
+            .into_iter()
+            .map(|statement| statement.sql)
+            .collect()
+    }
+
+    #[test]
+    fn split_statements_splits_on_semicolons_outside_of_strings_and_comments() {
+        let script = r#"-- CreateTable
+CREATE TABLE "Cat" ("id" INTEGER, "name" TEXT DEFAULT 'a;b');
+
+/* a comment; with a semicolon */
+INSERT INTO "Cat" VALUES (1, 'it''s; here');
+-- trailing comment;
+"#;
+
+        let statements = split_statements(script, SqlFamily::Sqlite);
+
+        assert_eq!(
+            statements,
+            &[
+                Statement {
+                    sql: r#"CREATE TABLE "Cat" ("id" INTEGER, "name" TEXT DEFAULT 'a;b');"#,
+                    line: 2,
+                },
+                Statement {
+                    sql: r#"INSERT INTO "Cat" VALUES (1, 'it''s; here');"#,
+                    line: 5,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn split_statements_keeps_dollar_quoted_bodies_together() {
+        let script = r#"CREATE FUNCTION f() RETURNS trigger AS $body$
+BEGIN
+    NEW.x := 1;
+    RETURN NEW;
+END;
+$body$ LANGUAGE plpgsql;
+SELECT $1;"#;
+
+        assert_eq!(sqls(script, SqlFamily::Postgres).len(), 2);
+    }
+
+    #[test]
+    fn split_statements_keeps_begin_end_blocks_together() {
+        let script = r#"BEGIN;
+CREATE TRIGGER t AFTER INSERT ON "Cat" BEGIN
+    UPDATE "Cat" SET name = CASE WHEN id > 1 THEN 'a' ELSE 'b' END;
+    DELETE FROM "Dog";
+END;
+COMMIT;"#;
+
+        assert_eq!(
+            sqls(script, SqlFamily::Sqlite),
+            &[
+                "BEGIN;",
+                "CREATE TRIGGER t AFTER INSERT ON \"Cat\" BEGIN\n    UPDATE \"Cat\" SET name = CASE WHEN id > 1 THEN 'a' ELSE 'b' END;\n    DELETE FROM \"Dog\";\nEND;",
+                "COMMIT;",
+            ]
+        );
+    }
+
+    #[test]
+    fn split_statements_keeps_the_last_statement_without_semicolon() {
+        assert_eq!(
+            sqls("SELECT 1;\nSELECT 2\n", SqlFamily::Postgres),
+            &["SELECT 1;", "SELECT 2"]
+        );
+    }
+
+    #[test]
+    fn split_statements_follows_mysql_delimiter_commands() {
+        let script = r#"CREATE TABLE `Cat` (`id` INTEGER);
+
+DELIMITER $$
+CREATE PROCEDURE `count_cats`()
+BEGIN
+    DECLARE total INT;
+    SELECT COUNT(*) INTO total FROM `Cat`;
+    IF total > 1 THEN SELECT 'many;'; END IF;
+END$$
+DELIMITER ;
+
+INSERT INTO `Cat` VALUES (1);"#;
+
+        assert_eq!(
+            sqls(script, SqlFamily::Mysql),
+            &[
+                "CREATE TABLE `Cat` (`id` INTEGER);",
+                "CREATE PROCEDURE `count_cats`()\nBEGIN\n    DECLARE total INT;\n    SELECT COUNT(*) INTO total FROM `Cat`;\n    IF total > 1 THEN SELECT 'many;'; END IF;\nEND",
+                "INSERT INTO `Cat` VALUES (1);",
+            ]
+        );
+    }
+
+    #[test]
+    fn split_statements_splits_mssql_scripts_on_go_only() {
+        let script = r#"BEGIN TRY
+    BEGIN TRAN;
+    CREATE TABLE [dbo].[Cat] ([id] INT);
+    COMMIT TRAN;
+END TRY
+BEGIN CATCH
+    IF @@TRANCOUNT > 0 ROLLBACK TRAN;
+    THROW;
+END CATCH
+GO
+CREATE PROCEDURE [dbo].[count_cats] AS SELECT COUNT(*) FROM [dbo].[Cat];
+go
+"#;
+
+        let statements = split_statements(script, SqlFamily::Mssql);
+
+        assert_eq!(statements.len(), 2);
+        assert!(statements[0].sql.starts_with("BEGIN TRY"));
+        assert!(statements[0].sql.ends_with("END CATCH"));
+        assert_eq!(
+            statements[1],
+            Statement {
+                sql: "CREATE PROCEDURE [dbo].[count_cats] AS SELECT COUNT(*) FROM [dbo].[Cat];",
+                line: 11,
+            }
+        );
+    }
+
+    #[test]
+    fn foreign_keys_pragmas_are_recognized() {
+        assert!(is_foreign_keys_pragma("PRAGMA foreign_keys=OFF"));
+        assert!(is_foreign_keys_pragma("pragma foreign_keys = on;"));
+        assert!(!is_foreign_keys_pragma("PRAGMA foreign_key_check"));
+    }
+}
@@ -73,6 +73,7 @@ pub trait GenericApi: Send + Sync + 'static {
     async fn plan_migration(&self, input: &PlanMigrationInput) -> CoreResult<PlanMigrationOutput>;
     async fn reset(&self, input: &()) -> CoreResult<()>;
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput>;
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput>;
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput>;
 }
 
@@ -239,6 +240,15 @@ where
             .await
     }
 
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput> {
+        self.handle_command::<SquashMigrationsCommand>(input)
+            .instrument(tracing::info_span!(
+                "SquashMigrations",
+                migration_name = input.migration_name.as_str()
+            ))
+            .await
+    }
+
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput> {
         self.handle_command::<UnapplyMigrationCommand<'_>>(input)
             .instrument(tracing::info_span!("UnapplyMigration"))
@@ -32,6 +32,7 @@ enum RpcCommand {
     PlanMigration,
     Reset,
     SchemaPush,
+    SquashMigrations,
     UnapplyMigration,
 }
 
@@ -58,6 +59,7 @@ impl RpcCommand {
             RpcCommand::PlanMigration => "planMigration",
             RpcCommand::Reset => "reset",
             RpcCommand::SchemaPush => "schemaPush",
+            RpcCommand::SquashMigrations => "squashMigrations",
             RpcCommand::UnapplyMigration => "unapplyMigration",
         }
     }
@@ -84,6 +86,7 @@ const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::PlanMigration,
     RpcCommand::Reset,
     RpcCommand::SchemaPush,
+    RpcCommand::SquashMigrations,
     RpcCommand::UnapplyMigration,
 ];
 
@@ -159,6 +162,7 @@ impl RpcApi {
             RpcCommand::PlanMigration => render(executor.plan_migration(&params.parse()?).await?),
             RpcCommand::Reset => render(executor.reset(&()).await?),
             RpcCommand::SchemaPush => render(executor.schema_push(&params.parse()?).await?),
+            RpcCommand::SquashMigrations => render(executor.squash_migrations(&params.parse()?).await?),
             RpcCommand::UnapplyMigration => render(executor.unapply_migration(&params.parse()?).await?),
         })
     }
@@ -15,6 +15,7 @@ mod command;
 mod create_migration;
 mod debug_panic;
 mod diagnose_migration_history;
+mod diff;
 mod evaluate_data_loss;
 mod get_database_version;
 #[allow(missing_docs)]
@@ -30,6 +31,7 @@ mod migration_progress;
 mod plan_migration;
 mod reset;
 mod schema_push;
+mod squash_migrations;
 #[allow(missing_docs)]
 mod unapply_migration;
 
@@ -45,6 +47,7 @@ pub use diagnose_migration_history::{
     DiagnoseMigrationHistoryCommand, DiagnoseMigrationHistoryInput, DiagnoseMigrationHistoryOutput, DriftDiagnostic,
     HistoryDiagnostic,
 };
+pub use diff::{diff, DiffInput, DiffOutput, DiffTarget};
 pub use evaluate_data_loss::*;
 pub use get_database_version::*;
 pub use infer_migration_steps::*;
@@ -59,6 +62,7 @@ pub use migration_progress::*;
 pub use plan_migration::{PlanMigrationCommand, PlanMigrationInput, PlanMigrationOutput};
 pub use reset::ResetCommand;
 pub use schema_push::{SchemaPushCommand, SchemaPushInput, SchemaPushOutput};
+pub use squash_migrations::{SquashMigrationsCommand, SquashMigrationsInput, SquashMigrationsOutput};
 pub use unapply_migration::*;
 
 use migration_connector::{MigrationStep, MigrationWarning, PrettyDatabaseMigrationStep, UnexecutableMigration};
@@ -1,6 +1,9 @@
 use super::MigrationCommand;
 use crate::{migration_engine::MigrationEngine, CoreError, CoreResult};
-use migration_connector::{ConnectorError, MigrationDirectory, MigrationRecord, PersistenceNotInitializedError};
+use migration_connector::{
+    BatchDirective, ConnectorError, ConnectorResult, DatabaseMigrationStepApplier, MigrationDirectory, MigrationRecord,
+    MigrationStepScript, PersistenceNotInitializedError,
+};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use user_facing_errors::migration_engine::FoundFailedMigrations;
@@ -24,6 +27,10 @@ pub struct ApplyMigrationsOutput {
 /// Read the contents of the migrations directory and the migrations table, and
 /// returns their relative statuses. At this stage, the migration engine only
 /// reads, it does not write to the dev database nor the migrations directory.
+///
+/// Each migration script is applied in a transaction on databases with
+/// transactional DDL, unless it opts out with a `-- prisma:no-transaction`
+/// directive. Batched steps get one transaction per batch.
 pub struct ApplyMigrationsCommand;
 
 #[async_trait::async_trait]
@@ -50,7 +57,7 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
             .await?
             .map_err(PersistenceNotInitializedError::into_connector_error)?;
 
-        detect_failed_migrations(&migrations_from_database)?;
+        let resumable_migration = detect_failed_migrations(&migrations_from_filesystem, &migrations_from_database)?;
 
         // We are now on the Happy Path™.
         tracing::debug!("Migration history is OK, applying unapplied migrations.");
@@ -60,6 +67,7 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
                 !migrations_from_database
                     .iter()
                     .filter(|db_migration| db_migration.rolled_back_at.is_none())
+                    .filter(|db_migration| Some(&db_migration.id) != resumable_migration.map(|migration| &migration.id))
                     .any(|db_migration| fs_migration.migration_name() == db_migration.migration_name)
             })
             .collect();
@@ -73,37 +81,72 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
             );
             let _span = span.enter();
 
-            let script = unapplied_migration
-                .read_migration_script()
+            let steps = unapplied_migration
+                .read_migration_steps()
                 .map_err(ConnectorError::from)?;
 
-            tracing::info!(
-                script = script.as_str(),
-                "Applying `{}`",
-                unapplied_migration.migration_name()
-            );
+            let (migration_id, applied_steps_count, mut batch_cursor) = match resumable_migration {
+                Some(record) if record.migration_name == unapplied_migration.migration_name() => {
+                    tracing::info!(
+                        "Resuming `{}` at step {}",
+                        unapplied_migration.migration_name(),
+                        record.applied_steps_count + 1
+                    );
+
+                    (
+                        record.id.clone(),
+                        record.applied_steps_count as usize,
+                        record.batch_cursor,
+                    )
+                }
+                _ => {
+                    let script = unapplied_migration
+                        .read_migration_script()
+                        .map_err(ConnectorError::from)?;
+
+                    tracing::info!(
+                        script = script.as_str(),
+                        "Applying `{}`",
+                        unapplied_migration.migration_name()
+                    );
 
-            let migration_id = migration_persistence
-                .record_migration_started(unapplied_migration.migration_name(), &script)
-                .await?;
+                    let migration_id = migration_persistence
+                        .record_migration_started(unapplied_migration.migration_name(), &script)
+                        .await?;
 
-            match applier.apply_script(&script).await {
-                Ok(()) => {
-                    tracing::debug!("Successfully applied the script.");
-                    migration_persistence.record_successful_step(&migration_id).await?;
-                    migration_persistence.record_migration_finished(&migration_id).await?;
-                    applied_migration_names.push(unapplied_migration.migration_name().to_owned());
+                    (migration_id, 0, None)
                 }
-                Err(err) => {
-                    tracing::debug!("Failed to apply the script.");
+            };
 
-                    let logs = err.to_string();
+            for step in steps.iter().skip(applied_steps_count) {
+                let result = match step.batch() {
+                    Some(batch) => apply_batched_step(applier, &migration_id, step, batch, batch_cursor.take()).await,
+                    None => {
+                        applier
+                            .apply_migration_script(step.file_name(), step.script(), step.is_transactional())
+                            .await
+                    }
+                };
 
-                    migration_persistence.record_failed_step(&migration_id, &logs).await?;
+                match result {
+                    Ok(()) => {
+                        tracing::debug!("Successfully applied `{}`.", step.file_name());
+                        migration_persistence.record_successful_step(&migration_id).await?;
+                    }
+                    Err(err) => {
+                        tracing::debug!("Failed to apply `{}`.", step.file_name());
 
-                    return Err(err.into());
+                        let logs = err.to_string();
+
+                        migration_persistence.record_failed_step(&migration_id, &logs).await?;
+
+                        return Err(err.into());
+                    }
                 }
             }
+
+            migration_persistence.record_migration_finished(&migration_id).await?;
+            applied_migration_names.push(unapplied_migration.migration_name().to_owned());
         }
 
         Ok(ApplyMigrationsOutput {
@@ -112,18 +155,107 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
     }
 }
 
-fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> CoreResult<()> {
+/// Apply a batched data transformation step, one range of ids at a time,
+/// starting after `batch_cursor` when resuming an interrupted step. The range
+/// is computed once, when the step starts.
+async fn apply_batched_step<D>(
+    applier: &dyn DatabaseMigrationStepApplier<D>,
+    migration_id: &str,
+    step: &MigrationStepScript,
+    batch: &BatchDirective,
+    batch_cursor: Option<i64>,
+) -> ConnectorResult<()> {
+    let (min, max) = match applier.batch_bounds(&batch.table, &batch.column).await? {
+        Some(bounds) => bounds,
+        None => return Ok(()),
+    };
+
+    let mut start = match batch_cursor {
+        Some(cursor) if cursor >= max => return Ok(()),
+        Some(cursor) => std::cmp::max(cursor + 1, min),
+        None => min,
+    };
+
+    loop {
+        let end = std::cmp::min(start.saturating_add(batch.size - 1), max);
+
+        tracing::info!(
+            step = step.file_name(),
+            "Applying batch {}..={} of {}..={}",
+            start,
+            end,
+            min,
+            max
+        );
+
+        applier
+            .apply_batch(
+                migration_id,
+                step.file_name(),
+                &step.render_batch(start, end),
+                end,
+                step.is_transactional(),
+            )
+            .await?;
+
+        if end >= max {
+            return Ok(());
+        }
+
+        start = end + 1;
+    }
+}
+
+/// Whether the unfinished migration was interrupted in the middle of a
+/// batched data transformation step. It can be resumed where it stopped, as
+/// long as its steps were not edited in the meantime.
+fn is_resumable_migration(
+    migrations_from_filesystem: &[MigrationDirectory],
+    candidate: &MigrationRecord,
+) -> CoreResult<bool> {
+    let fs_migration = match migrations_from_filesystem
+        .iter()
+        .find(|fs_migration| fs_migration.migration_name() == candidate.migration_name)
+    {
+        Some(fs_migration) => fs_migration,
+        None => return Ok(false),
+    };
+
+    if !fs_migration
+        .matches_checksum(&candidate.checksum)
+        .map_err(ConnectorError::from)?
+    {
+        return Ok(false);
+    }
+
+    let steps = fs_migration.read_migration_steps().map_err(ConnectorError::from)?;
+    let interrupted_step = steps.get(candidate.applied_steps_count as usize);
+
+    Ok(interrupted_step.map(|step| step.batch().is_some()).unwrap_or(false))
+}
+
+/// Check that no migration failed, and return the failed migration that can be
+/// resumed, if any. A failed migration is only resumed when it is the only
+/// one, and it stopped in the middle of a batched data transformation step.
+fn detect_failed_migrations<'a>(
+    migrations_from_filesystem: &[MigrationDirectory],
+    migrations_from_database: &'a [MigrationRecord],
+) -> CoreResult<Option<&'a MigrationRecord>> {
     use std::fmt::Write as _;
 
     tracing::debug!("Checking for failed migrations.");
 
-    let mut failed_migrations = migrations_from_database
+    let failed_migrations: Vec<&MigrationRecord> = migrations_from_database
         .iter()
         .filter(|migration| migration.finished_at.is_none() && migration.rolled_back_at.is_none())
-        .peekable();
+        .collect();
 
-    if failed_migrations.peek().is_none() {
-        return Ok(());
+    match failed_migrations.as_slice() {
+        [] => return Ok(None),
+        [failed_migration] if is_resumable_migration(migrations_from_filesystem, failed_migration)? => {
+            return Ok(Some(*failed_migration))
+        }
+        _ => (),
     }
 
     let mut details = String::new();
@@ -1,5 +1,6 @@
 use super::MigrationCommand;
 use crate::{migration_engine::MigrationEngine, parse_datamodel, CoreError, CoreResult};
+use migration_connector::{ConnectorError, DatabaseMigrationInferrer, MigrationDirectory};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use user_facing_errors::migration_engine::MigrationNameTooLong;
@@ -82,8 +83,49 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
                 )))
             })?;
 
+        // Write the schema snapshot, to avoid replaying the history next time.
+        // Draft migrations are meant to be edited, which would invalidate it.
+        if !input.draft {
+            let mut history = previous_migrations;
+            history.push(directory.clone());
+
+            write_schema_snapshot(database_migration_inferrer, &history).await?;
+        }
+
         Ok(CreateMigrationOutput {
             generated_migration_name: Some(directory.migration_name().to_owned()),
         })
     }
 }
+
+/// Write the schema snapshot of the last migration of `history`. Failing to
+/// take the snapshot is not an error: the history is replayed without it.
+pub(super) async fn write_schema_snapshot<D>(
+    inferrer: &dyn DatabaseMigrationInferrer<D>,
+    history: &[MigrationDirectory],
+) -> CoreResult<()> {
+    let directory = match history.last() {
+        Some(directory) => directory,
+        None => return Ok(()),
+    };
+
+    let snapshot = match inferrer.schema_snapshot(history).await {
+        Ok(Some(snapshot)) => snapshot,
+        Ok(None) => return Ok(()),
+        Err(err) => {
+            tracing::warn!("Could not take a schema snapshot of the new migration: {}", err);
+            return Ok(());
+        }
+    };
+
+    let history_checksum = migration_connector::migration_history_checksum(history).map_err(ConnectorError::from)?;
+
+    directory
+        .write_schema_snapshot(&history_checksum, snapshot)
+        .map_err(|err| {
+            CoreError::Generic(anyhow::Error::new(err).context(format!(
+                "Failed to write the schema snapshot to `{:?}`",
+                directory.path(),
+            )))
+        })
+}
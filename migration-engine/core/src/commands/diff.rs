@@ -0,0 +1,257 @@
+use crate::{parse_configuration, CoreError, CoreResult};
+use anyhow::anyhow;
+use migration_connector::{MigrationDirectory, PrettyDatabaseMigrationStep};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A source of database schema for the `diff` command.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "tag", rename_all = "camelCase")]
+pub enum DiffTarget {
+    /// An empty schema.
+    Empty,
+    /// The path to a Prisma schema file.
+    SchemaDatamodel {
+        /// The filesystem path of the schema file.
+        path: String,
+    },
+    /// The path to a migrations directory.
+    Migrations {
+        /// The filesystem path of the migrations directory.
+        path: String,
+    },
+    /// The path to a JSON snapshot of a database schema.
+    SchemaSnapshot {
+        /// The filesystem path of the snapshot file.
+        path: String,
+    },
+    /// A live database.
+    Url {
+        /// The connection string of the database.
+        url: String,
+    },
+}
+
+/// The input to the `diff` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffInput {
+    /// The schema the migration starts from.
+    pub from: DiffTarget,
+    /// The schema the migration leads to.
+    pub to: DiffTarget,
+    /// A database to replay migrations directories on. It is only required
+    /// to diff a migrations directory without a valid schema snapshot.
+    pub shadow_database_url: Option<String>,
+    /// The provider of the SQL dialect to diff with, when none of the other
+    /// inputs determine it, for example between two schema snapshots.
+    #[serde(default)]
+    pub provider: Option<String>,
+}
+
+/// The output of the `diff` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffOutput {
+    /// The migration from `from` to `to`, as an SQL script.
+    pub script: String,
+    /// The steps of the migration, with their rendered SQL.
+    pub steps: Vec<PrettyDatabaseMigrationStep>,
+    /// Whether the two schemas are equivalent.
+    pub is_empty: bool,
+}
+
+/// Compute the migration between two schema sources, without a configured
+/// migration engine. Only live databases and migrations directories without a
+/// valid schema snapshot require a connection. All the sources must use the
+/// same provider.
+pub async fn diff(input: &DiffInput) -> CoreResult<DiffOutput> {
+    let from = LoadedDiffTarget::load(&input.from)?;
+    let to = LoadedDiffTarget::load(&input.to)?;
+
+    let providers = [
+        ("from", from.provider()),
+        ("to", to.provider()),
+        (
+            "shadow database",
+            input.shadow_database_url.as_deref().and_then(provider_from_url),
+        ),
+        ("requested", input.provider.as_deref().and_then(canonical_provider)),
+    ];
+    let mut providers = providers
+        .iter()
+        .filter_map(|(side, provider)| provider.map(|provider| (side, provider)));
+
+    let first_provider = providers.next();
+
+    if let Some((first_side, first_provider)) = first_provider {
+        if let Some((side, provider)) = providers.find(|(_, provider)| provider != &first_provider) {
+            return Err(CoreError::Generic(anyhow!(
+                "The {} schema uses the `{}` provider, but the {} schema uses the `{}` provider. Both sides of a diff must use the same provider.",
+                first_side,
+                first_provider,
+                side,
+                provider
+            )));
+        }
+    }
+
+    // The connection string only determines the SQL dialect. It is not
+    // connected to.
+    let database_str = input
+        .shadow_database_url
+        .as_deref()
+        .or_else(|| from.database_str())
+        .or_else(|| to.database_str())
+        .or_else(|| first_provider.and_then(|(_, provider)| dialect_url(provider)))
+        .ok_or_else(|| {
+            CoreError::Generic(anyhow!(
+                "Could not determine the SQL dialect to diff with. Please pass a Prisma schema with a datasource, a database URL or a provider."
+            ))
+        })?
+        .to_owned();
+
+    let diff = sql_migration_connector::diff(
+        &database_str,
+        input.shadow_database_url.as_deref(),
+        from.as_sql_diff_target(),
+        to.as_sql_diff_target(),
+    )
+    .await?;
+
+    Ok(DiffOutput {
+        is_empty: diff.is_empty(),
+        script: diff.script,
+        steps: diff.steps,
+    })
+}
+
+enum LoadedDiffTarget {
+    Empty,
+    Datamodel {
+        datamodel: datamodel::Datamodel,
+        referential_integrity: datamodel::ReferentialIntegrity,
+        provider: Option<String>,
+        url: Option<String>,
+    },
+    Migrations(Vec<MigrationDirectory>),
+    SchemaSnapshot(String),
+    Url(String),
+}
+
+impl LoadedDiffTarget {
+    fn load(target: &DiffTarget) -> CoreResult<Self> {
+        match target {
+            DiffTarget::Empty => Ok(LoadedDiffTarget::Empty),
+            DiffTarget::SchemaDatamodel { path } => {
+                let schema = read_file(path)?;
+
+                // The datasource URL only determines the SQL dialect, so the
+                // schema can be diffed when its URL environment variable is
+                // not set, like in CI.
+                let (config, has_url) = match parse_configuration(&schema) {
+                    Ok(config) => (config, true),
+                    Err(_) => (parse_configuration_and_ignore_datasource_urls(&schema)?, false),
+                };
+                let datamodel = parse_datamodel_and_ignore_datasource_urls(&schema)?;
+                let datasource = config.datasources.first();
+
+                Ok(LoadedDiffTarget::Datamodel {
+                    datamodel,
+                    referential_integrity: datasource
+                        .map(|source| source.referential_integrity)
+                        .unwrap_or_default(),
+                    provider: datasource.map(|source| source.active_provider.clone()),
+                    url: datasource.filter(|_| has_url).map(|source| source.url().value.clone()),
+                })
+            }
+            DiffTarget::Migrations { path } => Ok(LoadedDiffTarget::Migrations(migration_connector::list_migrations(
+                Path::new(path),
+            )?)),
+            DiffTarget::SchemaSnapshot { path } => Ok(LoadedDiffTarget::SchemaSnapshot(read_file(path)?)),
+            DiffTarget::Url { url } => Ok(LoadedDiffTarget::Url(url.clone())),
+        }
+    }
+
+    fn database_str(&self) -> Option<&str> {
+        match self {
+            LoadedDiffTarget::Datamodel { url, .. } => url.as_deref(),
+            LoadedDiffTarget::Url(url) => Some(url),
+            _ => None,
+        }
+    }
+
+    /// The provider of the target, when it is known without a connection.
+    fn provider(&self) -> Option<&str> {
+        match self {
+            LoadedDiffTarget::Datamodel { provider, .. } => provider.as_deref(),
+            LoadedDiffTarget::Url(url) => provider_from_url(url),
+            _ => None,
+        }
+    }
+
+    fn as_sql_diff_target(&self) -> sql_migration_connector::DiffTarget<'_> {
+        match self {
+            LoadedDiffTarget::Empty => sql_migration_connector::DiffTarget::Empty,
+            LoadedDiffTarget::Datamodel {
+                datamodel,
+                referential_integrity,
+                ..
+            } => sql_migration_connector::DiffTarget::Datamodel(datamodel, *referential_integrity),
+            LoadedDiffTarget::Migrations(migrations) => sql_migration_connector::DiffTarget::Migrations(migrations),
+            LoadedDiffTarget::SchemaSnapshot(json) => sql_migration_connector::DiffTarget::SqlSchemaSnapshot(json),
+            LoadedDiffTarget::Url(url) => sql_migration_connector::DiffTarget::Database(url),
+        }
+    }
+}
+
+/// The canonical name of a provider, as used in the other diff inputs.
+fn canonical_provider(provider: &str) -> Option<&'static str> {
+    match provider {
+        "postgres" | "postgresql" => Some("postgresql"),
+        "mysql" => Some("mysql"),
+        "sqlite" => Some("sqlite"),
+        "sqlserver" => Some("sqlserver"),
+        _ => None,
+    }
+}
+
+/// A connection string with the default settings of the provider, to pick
+/// the SQL dialect when no connection string is available.
+fn dialect_url(provider: &str) -> Option<&'static str> {
+    match provider {
+        "postgresql" => Some("postgresql://localhost/prisma"),
+        "mysql" => Some("mysql://localhost/prisma"),
+        "sqlite" => Some("file:prisma.db"),
+        "sqlserver" => Some("sqlserver://localhost"),
+        _ => None,
+    }
+}
+
+/// The provider matching the scheme of a connection string.
+fn provider_from_url(url: &str) -> Option<&'static str> {
+    match url.split(':').next()? {
+        "postgres" | "postgresql" => Some("postgresql"),
+        "mysql" => Some("mysql"),
+        "file" | "sqlite" => Some("sqlite"),
+        "sqlserver" => Some("sqlserver"),
+        _ => None,
+    }
+}
+
+fn parse_configuration_and_ignore_datasource_urls(schema: &str) -> CoreResult<datamodel::Configuration> {
+    datamodel::parse_configuration_and_ignore_datasource_urls(schema)
+        .map(|validated_config| validated_config.subject)
+        .map_err(|err| CoreError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", schema)))
+}
+
+fn parse_datamodel_and_ignore_datasource_urls(schema: &str) -> CoreResult<datamodel::Datamodel> {
+    datamodel::parse_datamodel_and_ignore_datasource_urls(schema)
+        .map(|validated_datamodel| validated_datamodel.subject)
+        .map_err(|err| CoreError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", schema)))
+}
+
+fn read_file(path: &str) -> CoreResult<String> {
+    std::fs::read_to_string(path)
+        .map_err(|err| CoreError::Generic(anyhow::Error::new(err).context(format!("Failed to read `{}`", path))))
+}
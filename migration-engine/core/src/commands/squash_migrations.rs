@@ -0,0 +1,283 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, CoreError, CoreResult};
+use migration_connector::{ConnectorError, DestructiveChangeDiagnostics, MigrationDirectory};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use user_facing_errors::migration_engine::{
+    MigrationNameTooLong, MigrationToSquashNotFound, MigrationsToSquashPartiallyApplied,
+};
+
+/// The input to the `squashMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The filesystem path of the migrations directory to use.
+    pub migrations_directory_path: String,
+    /// The name of the first migration to squash. Defaults to the first
+    /// migration in the migrations directory.
+    pub from_migration_name: Option<String>,
+    /// The name of the last migration to squash, inclusive. Defaults to the
+    /// last migration in the migrations directory.
+    pub to_migration_name: Option<String>,
+    /// The user-given name for the squashed migration.
+    pub migration_name: String,
+}
+
+/// The output of the `squashMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsOutput {
+    /// The name of the migration directory replacing the squashed migrations.
+    pub generated_migration_name: String,
+    /// The names of the migrations that were squashed, and whose directories
+    /// were removed.
+    pub squashed_migration_names: Vec<String>,
+    /// Whether the squashed migration was marked as applied in the database,
+    /// because all the migrations it replaces were already applied there.
+    pub marked_applied: bool,
+}
+
+/// Replace a range of migrations in the migrations directory with a single,
+/// equivalent migration. The squashed migration is inferred by replaying the
+/// history on a temporary database. The batched data transformation steps of
+/// the squashed migrations are kept, in order, as additional steps of the
+/// squashed migration. Other data transformations are not carried over.
+///
+/// The batched steps run after all the schema changes of the range, so a range
+/// where a batched step is followed by schema changes cannot be squashed: the
+/// step could use tables or columns that the later changes drop or rename.
+///
+/// The range must be either fully applied to the database, or not at all. If
+/// it is fully applied, the new migration is marked as applied. The rows of
+/// the squashed migrations in the migrations table are left untouched.
+///
+/// The squashed migration directories are only removed once the new migration
+/// is written and recorded.
+pub struct SquashMigrationsCommand;
+
+#[async_trait::async_trait]
+impl MigrationCommand for SquashMigrationsCommand {
+    type Input = SquashMigrationsInput;
+
+    type Output = SquashMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let inferrer = connector.database_migration_inferrer();
+        let applier = connector.database_migration_step_applier();
+        let persistence = connector.new_migration_persistence();
+
+        if input.migration_name.len() > 200 {
+            return Err(CoreError::user_facing(MigrationNameTooLong));
+        }
+
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+        let migrations_from_filesystem = migration_connector::list_migrations(migrations_directory_path)?;
+
+        let start = match &input.from_migration_name {
+            Some(name) => find_migration(&migrations_from_filesystem, name)?,
+            None => 0,
+        };
+
+        let end = match &input.to_migration_name {
+            Some(name) => find_migration(&migrations_from_filesystem, name)?,
+            None => migrations_from_filesystem.len().saturating_sub(1),
+        };
+
+        if migrations_from_filesystem.is_empty() || start > end {
+            return Err(CoreError::Generic(anyhow::anyhow!(
+                "There are no migrations to squash in the requested range."
+            )));
+        }
+
+        let previous_migrations = &migrations_from_filesystem[..start];
+        let squashed_migrations = &migrations_from_filesystem[start..=end];
+        let next_migration = migrations_from_filesystem.get(end + 1);
+
+        check_batched_steps_come_last(squashed_migrations)?;
+
+        let migrations_from_database = match persistence.list_migrations().await? {
+            Ok(migrations) => migrations,
+            Err(_) => Vec::new(),
+        };
+
+        let unapplied_migration_names: Vec<&str> = squashed_migrations
+            .iter()
+            .map(|squashed_migration| squashed_migration.migration_name())
+            .filter(|migration_name| {
+                !migrations_from_database.iter().any(|db_migration| {
+                    db_migration.migration_name == *migration_name
+                        && db_migration.finished_at.is_some()
+                        && db_migration.rolled_back_at.is_none()
+                })
+            })
+            .collect();
+
+        let range_is_applied = unapplied_migration_names.is_empty();
+
+        if !range_is_applied && unapplied_migration_names.len() < squashed_migrations.len() {
+            return Err(CoreError::user_facing(MigrationsToSquashPartiallyApplied {
+                unapplied_migration_names: unapplied_migration_names.join(", "),
+            }));
+        }
+
+        let migration = inferrer
+            .infer_squashed_migration(previous_migrations, squashed_migrations)
+            .await?;
+
+        let script = applier.render_script(&migration, &DestructiveChangeDiagnostics::new());
+
+        let directory = migration_connector::create_squashed_migration_directory(
+            migrations_directory_path,
+            previous_migrations.last(),
+            squashed_migrations,
+            next_migration,
+            &input.migration_name,
+        )
+        .map_err(|err| CoreError::Generic(anyhow::anyhow!("Failed to create a new migration directory: {}", err)))?;
+
+        if let Err(err) = write_squashed_migration::<D>(&directory, &script, squashed_migrations) {
+            // Leave the migrations directory as we found it.
+            std::fs::remove_dir_all(directory.path()).ok();
+
+            return Err(err);
+        }
+
+        let history: Vec<MigrationDirectory> = previous_migrations
+            .iter()
+            .cloned()
+            .chain(std::iter::once(directory.clone()))
+            .collect();
+
+        if let Err(err) = super::create_migration::write_schema_snapshot(inferrer, &history).await {
+            std::fs::remove_dir_all(directory.path()).ok();
+
+            return Err(err);
+        }
+
+        if range_is_applied {
+            let script = directory.read_migration_script().map_err(ConnectorError::from)?;
+
+            persistence
+                .mark_migration_applied(directory.migration_name(), &script)
+                .await?;
+        }
+
+        for squashed_migration in squashed_migrations {
+            tracing::debug!("Removing squashed migration at {:?}", squashed_migration.path());
+
+            std::fs::remove_dir_all(squashed_migration.path()).map_err(|err| {
+                CoreError::Generic(anyhow::Error::new(err).context(format!(
+                    "Failed to remove the squashed migration at `{:?}`",
+                    squashed_migration.path(),
+                )))
+            })?;
+        }
+
+        Ok(SquashMigrationsOutput {
+            generated_migration_name: directory.migration_name().to_owned(),
+            squashed_migration_names: squashed_migrations
+                .iter()
+                .map(|migration| migration.migration_name().to_owned())
+                .collect(),
+            marked_applied: range_is_applied,
+        })
+    }
+}
+
+/// Write the migration script of the squashed migration, followed by the
+/// batched steps of the squashed migrations, numbered to keep their order.
+fn write_squashed_migration<D: migration_connector::DatabaseMigrationMarker>(
+    directory: &MigrationDirectory,
+    script: &str,
+    squashed_migrations: &[MigrationDirectory],
+) -> CoreResult<()> {
+    directory
+        .write_migration_script(script, D::FILE_EXTENSION)
+        .map_err(|err| {
+            CoreError::Generic(anyhow::Error::new(err).context(format!(
+                "Failed to write the migration script to `{:?}`",
+                directory.path(),
+            )))
+        })?;
+
+    let mut batched_steps_count = 0;
+
+    for squashed_migration in squashed_migrations {
+        let steps = squashed_migration
+            .read_migration_steps()
+            .map_err(ConnectorError::from)?;
+
+        for step in steps.iter().filter(|step| step.batch().is_some()) {
+            batched_steps_count += 1;
+
+            let file_name = format!("{:03}_{}", batched_steps_count, step.file_name());
+
+            directory
+                .write_migration_step(&file_name, step.script())
+                .map_err(|err| {
+                    CoreError::Generic(anyhow::Error::new(err).context(format!(
+                        "Failed to write the migration step `{}` to `{:?}`",
+                        file_name,
+                        directory.path(),
+                    )))
+                })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that no schema change follows a batched step in the squashed
+/// migrations. Steps with only comments, like the scripts of empty migrations,
+/// are not schema changes.
+fn check_batched_steps_come_last(squashed_migrations: &[MigrationDirectory]) -> CoreResult<()> {
+    let mut first_batched_step: Option<String> = None;
+
+    for squashed_migration in squashed_migrations {
+        let steps = squashed_migration
+            .read_migration_steps()
+            .map_err(ConnectorError::from)?;
+
+        for step in &steps {
+            let step_name = format!("{}/{}", squashed_migration.migration_name(), step.file_name());
+
+            match &first_batched_step {
+                None if step.batch().is_some() => first_batched_step = Some(step_name),
+                Some(batched_step) if step.batch().is_none() && !is_comments_only(step.script()) => {
+                    return Err(CoreError::Generic(anyhow::anyhow!(
+                        "The batched data transformation `{}` is followed by schema changes in `{}`. The squashed migration would apply them before it. Please squash the migrations before and after `{}` separately.",
+                        batched_step,
+                        step_name,
+                        batched_step,
+                    )))
+                }
+                _ => (),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_comments_only(script: &str) -> bool {
+    script
+        .lines()
+        .map(str::trim)
+        .all(|line| line.is_empty() || line.starts_with("--"))
+}
+
+fn find_migration(migrations: &[MigrationDirectory], migration_name: &str) -> CoreResult<usize> {
+    migrations
+        .iter()
+        .position(|migration| migration.migration_name() == migration_name)
+        .ok_or_else(|| {
+            CoreError::user_facing(MigrationToSquashNotFound {
+                migration_name: migration_name.to_owned(),
+            })
+        })
+}
@@ -15,7 +15,10 @@ mod gate_keeper;
 
 use anyhow::anyhow;
 pub use api::GenericApi;
-pub use commands::{ApplyMigrationInput, InferMigrationStepsInput, MigrationStepsResultOutput, SchemaPushInput};
+pub use commands::{
+    diff, ApplyMigrationInput, DiffInput, DiffOutput, DiffTarget, InferMigrationStepsInput, MigrationStepsResultOutput,
+    SchemaPushInput,
+};
 pub use core_error::{CoreError, CoreResult};
 pub use gate_keeper::GateKeeper;
 
@@ -53,7 +56,7 @@ pub async fn migration_api(
     let connector = match &source.active_provider {
         #[cfg(feature = "sql")]
         provider if POSTGRES_SOURCE_NAME == provider => {
-            let database_str = &source.url().value;
+            let database_str = &source.connection_url();
 
             let mut u = url::Url::parse(database_str).map_err(|err| {
                 let details = user_facing_errors::quaint::invalid_url_description(
@@ -80,11 +83,15 @@ pub async fn migration_api(
                 u.query_pairs_mut().append_pair("statement_cache_size", "0");
             }
 
-            SqlMigrationConnector::new(u.as_str()).await?
+            SqlMigrationConnector::new(u.as_str())
+                .await?
+                .with_referential_integrity(source.referential_integrity)
         }
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, SQLITE_SOURCE_NAME, MSSQL_SOURCE_NAME].contains(&provider.as_str()) => {
-            SqlMigrationConnector::new(&source.url().value).await?
+            SqlMigrationConnector::new(&source.connection_url())
+                .await?
+                .with_referential_integrity(source.referential_integrity)
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -113,7 +120,7 @@ pub async fn create_database(schema: &str) -> CoreResult<String> {
             ]
             .contains(&provider.as_str()) =>
         {
-            Ok(SqlMigrationConnector::create_database(&source.url().value).await?)
+            Ok(SqlMigrationConnector::create_database(&source.connection_url()).await?)
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     }
@@ -138,7 +145,7 @@ pub async fn drop_database(schema: &str) -> CoreResult<()> {
             ]
             .contains(&provider.as_str()) =>
         {
-            Ok(SqlMigrationConnector::drop_database(&source.url().value).await?)
+            Ok(SqlMigrationConnector::drop_database(&source.connection_url()).await?)
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     }
@@ -164,8 +171,10 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
             .contains(&provider.as_str()) =>
         {
             // 1. creates schema & database
-            SqlMigrationConnector::qe_setup(&source.url().value).await?;
-            SqlMigrationConnector::new(&source.url().value).await?
+            SqlMigrationConnector::qe_setup(&source.connection_url()).await?;
+            SqlMigrationConnector::new(&source.connection_url())
+                .await?
+                .with_referential_integrity(source.referential_integrity)
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -1,7 +1,7 @@
 use pretty_assertions::assert_eq;
 use sql_schema_describer::{
     Column, ColumnTypeFamily, DefaultKind, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index, IndexType,
-    PrimaryKey, SqlSchema, Table,
+    PrimaryKey, Sequence, SqlSchema, Table,
 };
 
 pub(crate) type AssertionResult<T> = Result<T, anyhow::Error>;
@@ -68,6 +68,16 @@ impl SchemaAssertion {
         Ok(self)
     }
 
+    pub fn assert_has_no_table(self, table_name: &str) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.table(table_name).is_err(),
+            "Assertion failed. Expected no table named `{}`.",
+            table_name
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_has_no_enum(self, enum_name: &str) -> AssertionResult<Self> {
         assert!(self.0.get_enum(enum_name).is_none());
 
@@ -102,6 +112,20 @@ impl SchemaAssertion {
         Ok(self)
     }
 
+    pub fn assert_sequence<F>(self, sequence_name: &str, sequence_assertions: F) -> AssertionResult<Self>
+    where
+        F: for<'a> FnOnce(SequenceAssertion<'a>) -> AssertionResult<SequenceAssertion<'a>>,
+    {
+        let sequence = self
+            .0
+            .get_sequence(sequence_name)
+            .ok_or_else(|| anyhow::anyhow!("Assertion failed. Sequence `{}` not found", sequence_name))?;
+
+        sequence_assertions(SequenceAssertion(sequence))?;
+
+        Ok(self)
+    }
+
     pub fn debug_print(self) -> Self {
         dbg!(&self.0);
 
@@ -125,9 +149,52 @@ impl<'a> EnumAssertion<'a> {
     }
 }
 
+pub struct SequenceAssertion<'a>(&'a Sequence);
+
+impl<'a> SequenceAssertion<'a> {
+    pub fn assert_options(self, start: i64, increment: i64) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            (self.0.start, self.0.increment) == (start, increment),
+            "Assertion failed. Expected the `{}` sequence to start with {} and increment by {}, found {} and {}.",
+            self.0.name,
+            start,
+            increment,
+            self.0.start,
+            self.0.increment,
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_is_identity(self, expected: bool) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.is_identity() == expected,
+            "Assertion failed. Expected the `{}` sequence to be an identity sequence: {}",
+            self.0.name,
+            expected,
+        );
+
+        Ok(self)
+    }
+}
+
 pub struct TableAssertion<'a>(&'a Table);
 
 impl<'a> TableAssertion<'a> {
+    pub fn assert_comment(self, expected: Option<&str>) -> AssertionResult<Self> {
+        let found = self.0.comment.as_deref();
+
+        anyhow::ensure!(
+            found == expected,
+            "Assertion failed. Expected the comment on `{}` to be {:?}, found {:?}",
+            self.0.name,
+            expected,
+            found
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_column_count(self, n: usize) -> AssertionResult<Self> {
         let columns_count = self.0.columns.len();
 
@@ -298,6 +365,45 @@ impl<'a> ColumnAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_comment(self, expected: Option<&str>) -> AssertionResult<Self> {
+        let found = self.0.comment.as_deref();
+
+        anyhow::ensure!(
+            found == expected,
+            "Assertion failed. Expected the comment on column `{}` to be {:?}, found {:?}",
+            self.0.name,
+            expected,
+            found
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_is_generated(self, stored: bool) -> AssertionResult<Self> {
+        let found = self.0.generated.as_ref().map(|generated| generated.stored);
+
+        anyhow::ensure!(
+            found == Some(stored),
+            "Assertion failed. Expected column `{}` to be generated (stored: {}), found {:?}",
+            self.0.name,
+            stored,
+            self.0.generated
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_is_not_generated(self) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.generated.is_none(),
+            "Assertion failed. Expected column `{}` not to be generated, found {:?}",
+            self.0.name,
+            self.0.generated
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_data_type(self, data_type: &str) -> AssertionResult<Self> {
         let found = &self.0.tpe.data_type;
 
@@ -527,6 +633,28 @@ impl<'a> ForeignKeyAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_on_delete(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_delete_action == action,
+            "Assertion failed: expected foreign key to have `{:?}` as on delete action, found `{:?}`.",
+            action,
+            self.0.on_delete_action,
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_on_update(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_update_action == action,
+            "Assertion failed: expected foreign key to have `{:?}` as on update action, found `{:?}`.",
+            action,
+            self.0.on_update_action,
+        );
+
+        Ok(self)
+    }
 }
 
 pub struct IndexAssertion<'a>(&'a Index);
@@ -11,6 +11,7 @@ mod mark_migration_applied;
 mod mark_migration_rolled_back;
 mod reset;
 mod schema_push;
+mod squash_migrations;
 mod unapply_migration;
 
 pub use apply::Apply;
@@ -24,6 +25,7 @@ pub use infer_apply::InferApply;
 pub use mark_migration_applied::MarkMigrationApplied;
 pub use reset::Reset;
 pub use schema_push::SchemaPush;
+pub use squash_migrations::SquashMigrations;
 pub use unapply_migration::UnapplyMigration;
 
 use crate::AssertionResult;
@@ -38,6 +40,7 @@ use super::{
 };
 use crate::connectors::Tags;
 use crate::test_api::list_migration_directories::ListMigrationDirectories;
+use datamodel::ReferentialIntegrity;
 use enumflags2::BitFlags;
 use migration_connector::{
     ImperativeMigrationsPersistence, MigrationConnector, MigrationPersistence, MigrationRecord, MigrationStep,
@@ -61,6 +64,8 @@ pub struct TestApi {
     database: Quaint,
     api: MigrationApi<SqlMigrationConnector, SqlMigration>,
     tags: BitFlags<Tags>,
+    connector_name: &'static str,
+    connection_string: String,
 }
 
 impl TestApi {
@@ -88,6 +93,11 @@ impl TestApi {
         self.tags.contains(Tags::Mariadb)
     }
 
+    /// The name of the connector the test runs against, like `postgres9` or `mysql_8`.
+    pub fn connector_name(&self) -> &'static str {
+        self.connector_name
+    }
+
     pub async fn migration_persistence(&self) -> &dyn MigrationPersistence {
         let persistence = self.api.connector().migration_persistence();
 
@@ -256,6 +266,24 @@ impl TestApi {
         SchemaPush::new(&self.api, dm.into())
     }
 
+    /// A migration API on the same database, for a datasource with `referentialIntegrity = "prisma"`.
+    pub async fn api_without_foreign_keys(&self) -> MigrationApi<SqlMigrationConnector, SqlMigration> {
+        let connector = SqlMigrationConnector::new(&self.connection_string)
+            .await
+            .unwrap()
+            .with_referential_integrity(ReferentialIntegrity::Prisma);
+
+        test_api(connector).await
+    }
+
+    pub fn squash_migrations<'a>(
+        &'a self,
+        migration_name: &'a str,
+        migrations_directory: &'a TempDir,
+    ) -> SquashMigrations<'a> {
+        SquashMigrations::new(&self.api, migration_name, migrations_directory)
+    }
+
     pub fn barrel(&self) -> BarrelMigrationExecutor<'_> {
         BarrelMigrationExecutor {
             api: self,
@@ -375,6 +403,8 @@ pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "mysql_8",
+        connection_string: url,
     }
 }
 
@@ -387,6 +417,8 @@ pub async fn mysql_5_6_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "mysql_5_6",
+        connection_string: url,
     }
 }
 
@@ -399,6 +431,8 @@ pub async fn mysql_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "mysql",
+        connection_string: url,
     }
 }
 
@@ -411,6 +445,8 @@ pub async fn mysql_mariadb_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "mysql_mariadb",
+        connection_string: url,
     }
 }
 
@@ -423,6 +459,8 @@ pub async fn postgres9_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "postgres9",
+        connection_string: url,
     }
 }
 
@@ -435,6 +473,8 @@ pub async fn postgres_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "postgres",
+        connection_string: url,
     }
 }
 
@@ -447,6 +487,8 @@ pub async fn postgres11_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "postgres11",
+        connection_string: url,
     }
 }
 
@@ -458,6 +500,8 @@ pub async fn postgres12_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "postgres12",
+        connection_string: url,
     }
 }
 
@@ -469,6 +513,8 @@ pub async fn postgres13_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "postgres13",
+        connection_string: url,
     }
 }
 
@@ -480,18 +526,20 @@ pub async fn sqlite_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name: "sqlite",
+        connection_string: sqlite_test_url(db_name),
     }
 }
 
 pub async fn mssql_2017_test_api(args: TestAPIArgs) -> TestApi {
-    mssql_test_api(mssql_2017_url("master"), args).await
+    mssql_test_api(mssql_2017_url("master"), "mssql_2017", args).await
 }
 
 pub async fn mssql_2019_test_api(args: TestAPIArgs) -> TestApi {
-    mssql_test_api(mssql_2019_url("master"), args).await
+    mssql_test_api(mssql_2019_url("master"), "mssql_2019", args).await
 }
 
-async fn mssql_test_api(connection_string: String, args: TestAPIArgs) -> TestApi {
+async fn mssql_test_api(connection_string: String, connector_name: &'static str, args: TestAPIArgs) -> TestApi {
     let schema = args.test_function_name;
     let connection_string = format!("{};schema={}", connection_string, schema);
 
@@ -505,6 +553,8 @@ async fn mssql_test_api(connection_string: String, args: TestAPIArgs) -> TestApi
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connector_name,
+        connection_string,
     }
 }
 
@@ -148,6 +148,41 @@ impl<'a> CreateMigrationAssertion<'a> {
         Ok(self)
     }
 
+    /// Modify the schema snapshot of the generated migration, keeping its
+    /// history checksum.
+    pub fn modify_schema_snapshot<F>(self, modify: F) -> AssertionResult<Self>
+    where
+        F: FnOnce(&mut serde_json::Value),
+    {
+        let snapshot_path = self
+            .migrations_directory
+            .path()
+            .join(self.output.generated_migration_name.as_ref().unwrap())
+            .join("schema_snapshot.json");
+
+        let mut snapshot: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&snapshot_path).context("Reading schema snapshot")?)?;
+
+        modify(&mut snapshot["schema"]);
+
+        std::fs::write(&snapshot_path, serde_json::to_string(&snapshot)?).context("Writing schema snapshot")?;
+
+        Ok(self)
+    }
+
+    /// Write an additional step script to the generated migration directory.
+    pub fn add_migration_step(self, file_name: &str, contents: &str) -> AssertionResult<Self> {
+        let step_script_path = self
+            .migrations_directory
+            .path()
+            .join(self.output.generated_migration_name.as_ref().unwrap())
+            .join(file_name);
+
+        std::fs::write(&step_script_path, contents).context("Writing migration step script")?;
+
+        Ok(self)
+    }
+
     pub fn into_output(self) -> CreateMigrationOutput {
         self.output
     }
@@ -158,6 +193,18 @@ pub struct MigrationAssertion<'a> {
 }
 
 impl MigrationAssertion<'_> {
+    pub fn assert_schema_snapshot(self) -> AssertionResult<Self> {
+        let snapshot_path = self.path.join("schema_snapshot.json");
+
+        anyhow::ensure!(
+            snapshot_path.exists(),
+            "Assertion failed. Expected a schema snapshot at {:?}.",
+            snapshot_path
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_contents(self, expected_contents: &str) -> AssertionResult<Self> {
         let migration_file_path = self.path.join("migration.sql");
         let contents: String = std::fs::read_to_string(&migration_file_path)
@@ -167,4 +214,19 @@ impl MigrationAssertion<'_> {
 
         Ok(self)
     }
+
+    pub fn assert_contents_start_with(self, expected_start: &str) -> AssertionResult<Self> {
+        let migration_file_path = self.path.join("migration.sql");
+        let contents: String = std::fs::read_to_string(&migration_file_path)
+            .with_context(|| format!("Trying to read migration file at {:?}", migration_file_path))?;
+
+        anyhow::ensure!(
+            contents.starts_with(expected_start),
+            "Assertion failed. Expected the migration to start with {:?}, found:\n{}",
+            expected_start,
+            contents
+        );
+
+        Ok(self)
+    }
 }
@@ -0,0 +1,97 @@
+use migration_core::{
+    commands::{SquashMigrationsInput, SquashMigrationsOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+use crate::AssertionResult;
+
+#[must_use = "This struct does nothing on its own. See SquashMigrations::send()"]
+pub struct SquashMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    migration_name: &'a str,
+    from_migration_name: Option<String>,
+    to_migration_name: Option<String>,
+}
+
+impl<'a> SquashMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migration_name: &'a str, migrations_directory: &'a TempDir) -> Self {
+        SquashMigrations {
+            api,
+            migrations_directory,
+            migration_name,
+            from_migration_name: None,
+            to_migration_name: None,
+        }
+    }
+
+    pub fn from(mut self, migration_name: impl Into<String>) -> Self {
+        self.from_migration_name = Some(migration_name.into());
+
+        self
+    }
+
+    pub fn to(mut self, migration_name: impl Into<String>) -> Self {
+        self.to_migration_name = Some(migration_name.into());
+
+        self
+    }
+
+    pub async fn send(self) -> CoreResult<SquashMigrationsAssertion<'a>> {
+        let output = self
+            .api
+            .squash_migrations(&SquashMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                from_migration_name: self.from_migration_name,
+                to_migration_name: self.to_migration_name,
+                migration_name: self.migration_name.to_owned(),
+            })
+            .await?;
+
+        Ok(SquashMigrationsAssertion {
+            output,
+            _api: self.api,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+pub struct SquashMigrationsAssertion<'a> {
+    output: SquashMigrationsOutput,
+    _api: &'a dyn GenericApi,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for SquashMigrationsAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SquashMigrationsAssertion {{ .. }}")
+    }
+}
+
+impl<'a> SquashMigrationsAssertion<'a> {
+    pub fn assert_squashed_migrations_count(self, expected_count: usize) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.output.squashed_migration_names.len() == expected_count,
+            "Assertion failed. Expected {} squashed migrations, found {:?}.",
+            expected_count,
+            self.output.squashed_migration_names
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_marked_applied(self, expected: bool) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.output.marked_applied == expected,
+            "Assertion failed. Expected marked_applied to be {}.",
+            expected
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> SquashMigrationsOutput {
+        self.output
+    }
+}
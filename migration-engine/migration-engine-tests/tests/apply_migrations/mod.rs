@@ -1,4 +1,5 @@
 use crate::*;
+use migration_core::commands::DiagnoseMigrationHistoryOutput;
 use pretty_assertions::assert_eq;
 use user_facing_errors::UserFacingError;
 
@@ -132,6 +133,352 @@ async fn migrations_should_fail_when_the_script_is_invalid(api: &TestApi) -> Tes
     Ok(())
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn failed_migrations_are_rolled_back_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| contents.push_str("\nSELECT (^.^)_n;\n"))?;
+
+    let result = api.apply_migrations(&migrations_directory).send().await;
+
+    assert!(result.is_err());
+
+    api.assert_schema().await?.assert_has_no_table("Cat")?;
+
+    let failed_migration = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    let logs = failed_migration.logs.clone().unwrap();
+
+    assert!(logs.contains("statement 2 of 2 in `migration.sql`"), logs);
+    assert!(logs.contains("SELECT (^.^)_n;"), logs);
+
+    failed_migration.assert_applied_steps_count(0)?.assert_failed()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn migrations_can_opt_out_of_the_transaction(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.create_migration("add-index", dm1, &migrations_directory)
+        .draft(true)
+        .send()
+        .await?
+        .modify_migration(|contents| {
+            *contents = indoc::indoc! {r#"
+                -- prisma:no-transaction
+                CREATE INDEX CONCURRENTLY "Cat_name_idx" ON "Cat"("name");
+            "#}
+            .to_owned();
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-index"])?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |index| Ok(index))
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn the_no_transaction_directive_can_follow_the_warnings_header(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.create_migration("add-index", dm1, &migrations_directory)
+        .draft(true)
+        .send()
+        .await?
+        .modify_migration(|contents| {
+            *contents = indoc::indoc! {r#"
+                /*
+                  Warnings:
+
+                  - This migration creates an index concurrently.
+
+                */
+                -- prisma:no-transaction
+                CREATE INDEX CONCURRENTLY "Cat_name_idx" ON "Cat"("name");
+            "#}
+            .to_owned();
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-index"])?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |index| Ok(index))
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn migrations_adding_enum_values_opt_out_of_the_transaction_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            mood    Mood
+        }
+
+        enum Mood {
+            HUNGRY
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            mood    Mood
+        }
+
+        enum Mood {
+            HUNGRY
+            SLEEPY
+        }
+    "#;
+
+    api.create_migration("add-sleepy", dm2, &migrations_directory)
+        .send()
+        .await?
+        .assert_migration("add-sleepy", |migration| {
+            migration.assert_contents_start_with("-- prisma:no-transaction\n")
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-sleepy"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("Mood", |enm| enm.assert_values(&["HUNGRY", "SLEEPY"]))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn tables_with_foreign_keys_pointing_to_them_can_be_redefined_in_a_transaction_on_sqlite(
+    api: &TestApi,
+) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+            toys    Toy[]
+        }
+
+        model Toy {
+            id      Int @id
+            catId   Int
+            cat     Cat @relation(fields: [catId], references: [id])
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    api.insert("Toy").value("id", 1).value("catId", 1).result_raw().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String @default("Felix")
+            toys    Toy[]
+        }
+
+        model Toy {
+            id      Int @id
+            catId   Int
+            cat     Cat @relation(fields: [catId], references: [id])
+        }
+    "#;
+
+    // Redefining `Cat` drops it, which would fail on the `Toy` foreign key if
+    // the `PRAGMA foreign_keys=OFF` was ignored in the transaction.
+    api.create_migration("redefine-cat", dm2, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["redefine-cat"])?;
+
+    api.select("Toy")
+        .column("catId")
+        .send()
+        .await?
+        .assert_single_row(|row| row.assert_int_value("catId", 1))?;
+
+    api.assert_schema().await?.assert_table("Toy", |table| {
+        table.assert_fk_on_columns(&["catId"], |fk| fk.assert_references("Cat", &["id"]))
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn mysql_migrations_can_change_the_delimiter(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.create_migration("add-trigger", dm1, &migrations_directory)
+        .draft(true)
+        .send()
+        .await?
+        .modify_migration(|contents| {
+            *contents = indoc::indoc! {r#"
+                DELIMITER $$
+                CREATE TRIGGER `Cat_name_upper` BEFORE INSERT ON `Cat` FOR EACH ROW
+                BEGIN
+                    SET NEW.`name` = UPPER(NEW.`name`);
+                END$$
+                DELIMITER ;
+            "#}
+            .to_owned();
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-trigger"])?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "felix")
+        .result_raw()
+        .await?;
+
+    api.select("Cat")
+        .column("name")
+        .send()
+        .await?
+        .assert_single_row(|row| row.assert_text_value("name", "FELIX"))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn mssql_migrations_are_applied_batch_by_batch(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let script = indoc::formatdoc! {r#"
+        BEGIN TRY
+            DECLARE @nickname_length INT = 100;
+            ALTER TABLE [{schema}].[Cat] ADD [nickname] NVARCHAR(100) NULL;
+        END TRY
+        BEGIN CATCH
+            THROW;
+        END CATCH
+        GO
+        CREATE VIEW [{schema}].[CatNames] AS SELECT [name] FROM [{schema}].[Cat];
+        GO
+    "#, schema = api.schema_name()};
+
+    api.create_migration("add-view", dm1, &migrations_directory)
+        .draft(true)
+        .send()
+        .await?
+        .modify_migration(|contents| *contents = script)?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-view"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("nickname"))?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn migrations_should_not_reapply_modified_migrations(api: &TestApi) -> TestResult {
     let dm1 = r#"
@@ -205,3 +552,283 @@ async fn migrations_should_fail_on_an_uninitialized_nonempty_database(api: &Test
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn batched_data_migration_steps_are_applied_in_order(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            nameLength  Int?
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    for (id, name) in &[(1, "Felix"), (2, "Tom"), (3, "Garfield"), (4, "Mittens"), (5, "Ginger")] {
+        api.insert("Cat")
+            .value("id", *id)
+            .value("name", *name)
+            .result_raw()
+            .await?;
+    }
+
+    let backfill = indoc::indoc! {r#"
+        -- prisma:batch table=Cat column=id size=2
+        UPDATE "Cat" SET "nameLength" = length("name") WHERE "id" BETWEEN {{batch_start}} AND {{batch_end}};
+    "#};
+
+    api.create_migration("backfill", dm1, &migrations_directory)
+        .draft(true)
+        .send()
+        .await?
+        .add_migration_step("01_backfill.sql", backfill)?
+        .add_migration_step("02_cleanup.sql", "DELETE FROM \"Cat\" WHERE \"nameLength\" < 4;\n")?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["backfill"])?;
+
+    let mut migrations = api.imperative_migration_persistence().list_migrations().await?.unwrap();
+
+    migrations
+        .pop()
+        .unwrap()
+        .assert_migration_name("backfill")?
+        .assert_applied_steps_count(3)?
+        .assert_success()?;
+
+    api.select("Cat")
+        .column("id")
+        .column("nameLength")
+        .send()
+        .await?
+        .assert_row_count(4)?
+        .assert_row(1, |row| {
+            row.assert_int_value("id", 3)?.assert_int_value("nameLength", 8)
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn batched_data_migration_steps_resume_after_a_failure(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+
+        model Visit {
+            catId   Int @id
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    for (id, name) in &[(1, "Felix"), (2, "Tom"), (3, "Garfield"), (4, "Mittens"), (5, "Ginger")] {
+        api.insert("Cat")
+            .value("id", *id)
+            .value("name", *name)
+            .result_raw()
+            .await?;
+    }
+
+    // Makes the second batch fail.
+    api.insert("Visit").value("catId", 4).result_raw().await?;
+
+    let backfill = indoc::indoc! {r#"
+        -- prisma:batch table=Cat column=id size=2
+        INSERT INTO "Visit" ("catId") SELECT "id" FROM "Cat" WHERE "id" BETWEEN {{batch_start}} AND {{batch_end}};
+    "#};
+
+    api.create_migration("backfill", dm1, &migrations_directory)
+        .draft(true)
+        .send()
+        .await?
+        .add_migration_step("01_backfill.sql", backfill)?;
+
+    assert!(api.apply_migrations(&migrations_directory).send().await.is_err());
+
+    let failed_migration = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap()
+        .assert_migration_name("backfill")?
+        .assert_applied_steps_count(1)?
+        .assert_failed()?;
+
+    assert_eq!(failed_migration.batch_cursor, Some(2));
+
+    api.apply_script(r#"DELETE FROM "Visit" WHERE "catId" = 4"#).await?;
+
+    // The first batch would fail on a unique constraint if it was applied again.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["backfill"])?;
+
+    let resumed_migration = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap()
+        .assert_migration_name("backfill")?
+        .assert_applied_steps_count(2)?
+        .assert_success()?;
+
+    // The logs of the failed attempt are cleared once the step succeeds.
+    assert_eq!(resumed_migration.logs, None);
+
+    api.select("Visit").column("catId").send().await?.assert_row_count(5)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn migrations_tables_without_a_batch_cursor_are_upgraded(api: &TestApi) -> TestResult {
+    let old_migrations_table = indoc::indoc! {r#"
+        CREATE TABLE "_prisma_migrations" (
+            "id"                    TEXT PRIMARY KEY NOT NULL,
+            "checksum"              TEXT NOT NULL,
+            "finished_at"           DATETIME,
+            "migration_name"        TEXT NOT NULL,
+            "logs"                  TEXT,
+            "rolled_back_at"        DATETIME,
+            "started_at"            DATETIME NOT NULL DEFAULT current_timestamp,
+            "applied_steps_count"   INTEGER UNSIGNED NOT NULL DEFAULT 0
+        );
+    "#};
+
+    api.apply_script(old_migrations_table).await?;
+
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    let migration = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap()
+        .assert_migration_name("initial")?
+        .assert_success()?;
+
+    assert_eq!(migration.batch_cursor, None);
+
+    // Upgrading is idempotent.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn listing_migrations_does_not_upgrade_the_migrations_table(api: &TestApi) -> TestResult {
+    let old_migrations_table = indoc::indoc! {r#"
+        CREATE TABLE "_prisma_migrations" (
+            "id"                    TEXT PRIMARY KEY NOT NULL,
+            "checksum"              TEXT NOT NULL,
+            "finished_at"           DATETIME,
+            "migration_name"        TEXT NOT NULL,
+            "logs"                  TEXT,
+            "rolled_back_at"        DATETIME,
+            "started_at"            DATETIME NOT NULL DEFAULT current_timestamp,
+            "applied_steps_count"   INTEGER UNSIGNED NOT NULL DEFAULT 0
+        );
+    "#};
+
+    api.apply_script(old_migrations_table).await?;
+
+    let migrations = api.imperative_migration_persistence().list_migrations().await?.unwrap();
+
+    assert!(migrations.is_empty());
+
+    api.assert_schema()
+        .await?
+        .assert_table("_prisma_migrations", |table| table.assert_columns_count(8))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn moving_statements_between_steps_edits_the_migration(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration = api
+        .create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .add_migration_step("01_seed.sql", "INSERT INTO \"Cat\" VALUES (1, 'Felix');\n")?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    // The concatenation of the steps is unchanged.
+    migration
+        .modify_migration(|script| script.push_str("INSERT INTO \"Cat\" VALUES (1, 'Felix');\n"))?
+        .add_migration_step("01_seed.sql", "")?;
+
+    let DiagnoseMigrationHistoryOutput {
+        edited_migration_names, ..
+    } = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert_eq!(edited_migration_names, &["initial"]);
+
+    Ok(())
+}
@@ -403,3 +403,48 @@ async fn create_enum_renders_correctly(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn create_migration_snapshots_the_schema_from_the_last_snapshot(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    // Add a table to the snapshot only. Replaying the whole history would fail
+    // to drop it in the next migration, so that migration can only be
+    // snapshotted on top of this snapshot.
+    api.create_migration("create-cats", dm1, &dir)
+        .send()
+        .await?
+        .modify_schema_snapshot(|schema| {
+            let mut marker = schema["tables"][0].clone();
+            marker["name"] = serde_json::json!("Marker");
+            marker["primaryKey"]["constraintName"] = serde_json::Value::Null;
+
+            schema["tables"].as_array_mut().unwrap().push(marker);
+        })?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+
+        model Dog {
+            id      Int @id
+        }
+    "#;
+
+    api.create_migration("create-dogs", dm2, &dir)
+        .send()
+        .await?
+        .assert_migration_directories_count(2)?
+        .assert_migration("create-dogs", |migration| migration.assert_schema_snapshot())?;
+
+    Ok(())
+}
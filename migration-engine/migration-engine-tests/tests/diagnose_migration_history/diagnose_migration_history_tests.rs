@@ -506,6 +506,113 @@ async fn diagnose_migrations_history_can_detect_edited_migrations(api: &TestApi)
     Ok(())
 }
 
+#[test_each_connector]
+async fn diagnose_migration_history_uses_schema_snapshots_while_the_history_is_unchanged(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let assertions = api
+        .create_migration("initial", dm1, &directory)
+        .send()
+        .await?
+        .assert_migration("initial", |migration| migration.assert_schema_snapshot())?;
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    // Tamper with the snapshot, so we can tell whether it is used.
+    let assertions = assertions.modify_schema_snapshot(|schema| {
+        schema["tables"] = serde_json::json!([]);
+    })?;
+
+    let drift = api
+        .diagnose_migration_history(&directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output()
+        .drift;
+
+    assert!(matches!(drift, Some(DriftDiagnostic::DriftDetected { rollback: _ })));
+
+    // Editing the migration invalidates the snapshot.
+    assertions.modify_migration(|script| script.push_str("\n-- edited\n"))?;
+
+    let drift = api
+        .diagnose_migration_history(&directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output()
+        .drift;
+
+    assert!(drift.is_none());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn schema_snapshots_do_not_cause_spurious_drift(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id        Int      @id @default(autoincrement())
+            name      String   @unique
+            createdAt DateTime @default(now())
+            boxId     Int?
+            box       Box?     @relation(fields: [boxId], references: [id])
+
+            @@index([name, createdAt])
+        }
+
+        model Box {
+            id   Int    @id
+            cats Cat[]
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory)
+        .send()
+        .await?
+        .assert_migration("initial", |migration| migration.assert_schema_snapshot())?;
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    let drift = api
+        .diagnose_migration_history(&directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output()
+        .drift;
+
+    assert!(drift.is_none());
+
+    // The next diff starts from the snapshot, and must find nothing to do.
+    api.create_migration("second", dm1, &directory)
+        .draft(true)
+        .send()
+        .await?
+        .assert_migration_directories_count(2)?
+        .assert_migration("second", |migration| {
+            migration.assert_contents("-- This is an empty migration.")
+        })?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn diagnose_migrations_history_reports_migrations_failing_to_apply_cleanly(api: &TestApi) -> TestResult {
     let directory = api.create_migrations_directory()?;
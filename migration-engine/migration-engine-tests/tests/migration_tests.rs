@@ -2392,6 +2392,63 @@ async fn schemas_with_dbgenerated_work(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn dbgenerated_expressions_are_rendered_verbatim_and_diffed(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+    model User {
+        id       Int    @id
+        nickname String @default(dbgenerated("md5('anonymous'::text)"))
+    }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("User", |table| {
+        table.assert_column("nickname", |column| {
+            column.assert_default(Some(DefaultValue::db_generated("md5('anonymous'::text)")))
+        })
+    })?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+    model User {
+        id       Int    @id
+        nickname String @default(dbgenerated("md5('nobody'::text)"))
+    }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("User", |table| {
+        table.assert_column("nickname", |column| {
+            column.assert_default(Some(DefaultValue::db_generated("md5('nobody'::text)")))
+        })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn dbgenerated_expressions_rewritten_by_the_database_are_not_migrated_again(api: &TestApi) -> TestResult {
+    // Postgres stores this as `md5('anonymous'::text)`.
+    let dm = r#"
+    model User {
+        id       Int    @id
+        nickname String @default(dbgenerated("(MD5( 'anonymous' ))"))
+    }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn models_with_an_autoincrement_field_as_part_of_a_multi_field_id_can_be_created(api: &TestApi) -> TestResult {
     let dm = r#"
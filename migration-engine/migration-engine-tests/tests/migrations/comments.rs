@@ -0,0 +1,150 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(ignore("sqlite"))]
+async fn documentation_is_stored_as_table_and_column_comments(api: &TestApi) -> TestResult {
+    let dm = r#"
+        /// A cat's place in the world.
+        model Cat {
+            id Int @id
+            /// What the cat is called. It's 'Garfield', usually.
+            name String
+            age Int
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_comment(Some("A cat's place in the world."))?
+            .assert_column("name", |column| {
+                column.assert_comment(Some("What the cat is called. It's 'Garfield', usually."))
+            })?
+            .assert_column("age", |column| column.assert_comment(None))
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn changing_documentation_alters_the_comments(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        /// A cat.
+        model Cat {
+            id Int @id
+            /// The name.
+            name String
+            age Int
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            /// What the cat is called.
+            name String
+            /// The age, in years.
+            age Int
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .force(true)
+        .send()
+        .await?
+        .assert_warnings(&["You are about to drop the comment on the `Cat` table. Add a `///` documentation comment to the model to keep it.".into()])?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_comment(None)?
+            .assert_column("name", |column| column.assert_comment(Some("What the cat is called.")))?
+            .assert_column("age", |column| column.assert_comment(Some("The age, in years.")))
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn dropping_comments_without_documentation_warns(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        /// A cat.
+        model Cat {
+            id Int @id
+            /// The name.
+            name String
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_warnings(&[
+            "You are about to drop the comment on the column `name` of the `Cat` table. Add a `///` documentation comment to the field to keep it.".into(),
+            "You are about to drop the comment on the `Cat` table. Add a `///` documentation comment to the model to keep it.".into(),
+        ])?
+        .assert_no_steps()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_comment(Some("A cat."))?
+            .assert_column("name", |column| column.assert_comment(Some("The name.")))
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn comments_are_kept_when_redefining_a_table_on_sql_server(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        /// A cat.
+        model Cat {
+            id Int @id
+            /// The name.
+            name String
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    // Adding an identity to a column redefines the table.
+    let dm2 = r#"
+        /// A cat.
+        model Cat {
+            id Int @id @default(autoincrement())
+            /// The name.
+            name String
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_comment(Some("A cat."))?
+            .assert_column("name", |column| column.assert_comment(Some("The name.")))
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
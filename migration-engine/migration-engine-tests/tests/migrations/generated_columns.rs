@@ -0,0 +1,156 @@
+use migration_engine_tests::sql::*;
+
+/// Generated columns were introduced in PostgreSQL 12.
+fn supports_generated_columns(api: &TestApi) -> bool {
+    !matches!(api.connector_name(), "postgres9" | "postgres" | "postgres11")
+}
+
+#[test_each_connector(tags("mysql_8", "mssql_2017", "mssql_2019", "postgres", "sqlite"))]
+async fn generated_columns_can_be_created(api: &TestApi) -> TestResult {
+    if !supports_generated_columns(api) {
+        return Ok(());
+    }
+
+    let dm = r#"
+        model Rectangle {
+            id     Int @id
+            width  Int
+            height Int
+            area   Int @generated("width * height", stored: true)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Rectangle", |table| {
+        table
+            .assert_column("area", |column| column.assert_is_generated(true))?
+            .assert_column("width", |column| column.assert_is_not_generated())
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8", "mssql_2017", "mssql_2019", "postgres", "sqlite"))]
+async fn columns_can_be_made_generated_and_back(api: &TestApi) -> TestResult {
+    if !supports_generated_columns(api) {
+        return Ok(());
+    }
+
+    let dm1 = r#"
+        model Rectangle {
+            id     Int @id
+            width  Int
+            height Int
+            area   Int
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Rectangle {
+            id     Int @id
+            width  Int
+            height Int
+            area   Int @generated("width * height", stored: true)
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Rectangle", |table| {
+        table.assert_column("area", |column| column.assert_is_generated(true))
+    })?;
+
+    api.schema_push(dm1)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Rectangle", |table| {
+        table.assert_column("area", |column| column.assert_is_not_generated())
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8", "mssql_2017", "mssql_2019", "postgres", "sqlite"))]
+async fn generation_expressions_can_be_changed(api: &TestApi) -> TestResult {
+    if !supports_generated_columns(api) {
+        return Ok(());
+    }
+
+    let dm1 = r#"
+        model Rectangle {
+            id     Int @id
+            width  Int
+            height Int
+            size   Int @generated("width * height", stored: true)
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Rectangle {
+            id     Int @id
+            width  Int
+            height Int
+            size   Int @generated("2 * (width + height)", stored: true)
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.insert("Rectangle")
+        .value("id", 1)
+        .value("width", 3)
+        .value("height", 4)
+        .result_raw()
+        .await?;
+
+    api.select("Rectangle")
+        .column("size")
+        .send()
+        .await?
+        .assert_single_row(|row| row.assert_int_value("size", 14))?;
+
+    // The database normalizes the expression, but that isn't a change.
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn computed_columns_that_are_not_persisted_can_be_created_on_sql_server(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Rectangle {
+            id     Int  @id
+            width  Int
+            height Int
+            area   Int? @generated("width * height")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Rectangle", |table| {
+        table.assert_column("area", |column| column.assert_is_generated(false))
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 mod apply_script_tests;
+mod comments;
 mod enums;
+mod generated_columns;
 mod indexes;
 mod json;
 mod mariadb;
@@ -7,6 +9,8 @@ mod mark_migration_applied_tests;
 mod mark_migration_rolled_back_tests;
 mod mysql;
 mod postgres;
+mod referential_actions;
+mod sequences;
 mod sql;
 mod sqlite;
 mod squashing_tests;
@@ -0,0 +1,223 @@
+use migration_engine_tests::sql::*;
+use sql_schema_describer::ForeignKeyAction;
+
+#[test_each_connector]
+async fn referential_actions_are_mapped_to_foreign_key_actions(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int   @id
+            authorId Int?
+            author   User? @relation(fields: [authorId], references: [id], onDelete: CASCADE, onUpdate: SET_NULL)
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["authorId"], |fk| {
+            fk.assert_references("User", &["id"])?
+                .assert_on_delete(ForeignKeyAction::Cascade)?
+                .assert_on_update(ForeignKeyAction::SetNull)
+        })
+    })?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int   @id
+            authorId Int?
+            author   User? @relation(fields: [authorId], references: [id], onDelete: SET_NULL, onUpdate: SET_NULL)
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["authorId"], |fk| {
+            fk.assert_on_delete(ForeignKeyAction::SetNull)?
+                .assert_on_update(ForeignKeyAction::SetNull)
+        })
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn relations_without_referential_actions_keep_the_default_foreign_key_actions(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id       Int       @id
+            posts    Post[]
+            comments Comment[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+
+        model Comment {
+            id       Int   @id
+            authorId Int?
+            author   User? @relation(fields: [authorId], references: [id])
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Post", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| {
+                fk.assert_on_delete(ForeignKeyAction::Cascade)?
+                    .assert_on_update(ForeignKeyAction::Cascade)
+            })
+        })?
+        .assert_table("Comment", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| {
+                fk.assert_on_delete(ForeignKeyAction::SetNull)?
+                    .assert_on_update(ForeignKeyAction::Cascade)
+            })
+        })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn prisma_referential_integrity_creates_no_foreign_keys(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id         Int        @id
+            posts      Post[]
+            categories Category[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id], onDelete: CASCADE)
+        }
+
+        model Category {
+            id    Int    @id
+            users User[]
+        }
+    "#;
+
+    let api_without_foreign_keys = api.api_without_foreign_keys().await;
+
+    SchemaPush::new(&api_without_foreign_keys, dm.into())
+        .send()
+        .await?
+        .assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Post", |table| {
+            table
+                .assert_foreign_keys_count(0)?
+                .assert_column("authorId", |column| column.assert_is_required())
+        })?
+        .assert_table("_CategoryToUser", |table| {
+            table
+                .assert_foreign_keys_count(0)?
+                .assert_index_on_columns(&["A", "B"], |index| index.assert_is_unique())
+        })?;
+
+    SchemaPush::new(&api_without_foreign_keys, dm.into())
+        .send()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn foreign_key_actions_of_existing_databases_are_compared_with_the_referential_actions(
+    api: &TestApi,
+) -> TestResult {
+    let sql = r#"
+        CREATE TABLE "User" (
+            id INTEGER PRIMARY KEY
+        );
+
+        CREATE TABLE "Post" (
+            id INTEGER PRIMARY KEY,
+            "authorId" INTEGER REFERENCES "User"(id) ON DELETE CASCADE ON UPDATE SET NULL
+        );
+
+        CREATE TABLE "Comment" (
+            id INTEGER PRIMARY KEY,
+            "authorId" INTEGER REFERENCES "User"(id) ON DELETE RESTRICT ON UPDATE CASCADE
+        );
+    "#;
+
+    api.database().raw_cmd(sql).await?;
+
+    let dm = r#"
+        model User {
+            id       Int       @id
+            posts    Post[]
+            comments Comment[]
+        }
+
+        model Post {
+            id       Int   @id
+            authorId Int?
+            author   User? @relation(fields: [authorId], references: [id], onDelete: CASCADE, onUpdate: SET_NULL)
+        }
+
+        model Comment {
+            id       Int   @id
+            authorId Int?
+            author   User? @relation(fields: [authorId], references: [id], onDelete: CASCADE)
+        }
+    "#;
+
+    // Only the foreign key of `Comment` has different actions.
+    api.schema_push(dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Post", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| {
+                fk.assert_on_delete(ForeignKeyAction::Cascade)?
+                    .assert_on_update(ForeignKeyAction::SetNull)
+            })
+        })?
+        .assert_table("Comment", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| {
+                fk.assert_on_delete(ForeignKeyAction::Cascade)?
+                    .assert_on_update(ForeignKeyAction::Cascade)
+            })
+        })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
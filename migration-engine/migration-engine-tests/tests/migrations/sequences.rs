@@ -0,0 +1,218 @@
+use migration_engine_tests::sql::*;
+use quaint::prelude::Queryable;
+
+#[test_each_connector(tags("postgres"))]
+async fn shared_sequences_can_be_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id @default(sequence(name: "animal_ids", start: 10, increment: 5))
+        }
+
+        model Dog {
+            id Int @id @default(sequence(name: "animal_ids", start: 10, increment: 5))
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_sequence("animal_ids", |sequence| {
+            sequence.assert_options(10, 5)?.assert_is_identity(false)
+        })?
+        .assert_table("Cat", |table| {
+            table.assert_column("id", |column| column.assert_has_autoincrement())
+        })?
+        .assert_table("Dog", |table| {
+            table.assert_column("id", |column| column.assert_has_autoincrement())
+        })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn sequence_options_can_be_changed(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id @default(sequence(name: "cat_ids", start: 10))
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id @default(sequence(name: "cat_ids", start: 10, increment: 3))
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_sequence("cat_ids", |sequence| sequence.assert_options(10, 3))?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unused_sequences_are_dropped(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id @default(sequence(name: "cat_ids"))
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let expected_warning = "You are about to drop the sequence `cat_ids`, which is not in the Prisma schema. Its current value will be lost, and anything outside of Prisma using it will break.";
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_warnings(&[expected_warning.into()])?
+        .assert_no_steps()?;
+
+    api.schema_push(dm2)
+        .force(true)
+        .send()
+        .await?
+        .assert_warnings(&[expected_warning.into()])?
+        .assert_has_executed_steps()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.get_sequence("cat_ids").is_none());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unmanaged_sequences_are_not_dropped(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.database().raw_cmd(r#"CREATE SEQUENCE "invoice_numbers""#).await?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.get_sequence("invoice_numbers").is_some());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn identity_columns_can_be_created_and_changed(api: &TestApi) -> TestResult {
+    // Identity columns were introduced in PostgreSQL 10.
+    if api.connector_name() == "postgres9" {
+        return Ok(());
+    }
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id @default(sequence(start: 100))
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_sequence("Cat_id_seq", |sequence| {
+        sequence.assert_options(100, 1)?.assert_is_identity(true)
+    })?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id @default(sequence(start: 100, increment: 2))
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_sequence("Cat_id_seq", |sequence| sequence.assert_options(100, 2))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn autoincrement_keeps_identity_columns(api: &TestApi) -> TestResult {
+    // Identity columns were introduced in PostgreSQL 10.
+    if api.connector_name() == "postgres9" {
+        return Ok(());
+    }
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id @default(sequence(start: 100))
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id @default(autoincrement())
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema().await?.assert_sequence("Cat_id_seq", |sequence| {
+        sequence.assert_options(100, 1)?.assert_is_identity(true)
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn autoincrement_keeps_standalone_sequences(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id @default(sequence(name: "cat_ids", start: 10))
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id @default(autoincrement())
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_sequence("cat_ids", |sequence| sequence.assert_options(10, 1))?;
+
+    Ok(())
+}
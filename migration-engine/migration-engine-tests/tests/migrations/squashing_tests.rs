@@ -1,6 +1,23 @@
 use crate::*;
 use migration_core::commands::{DiagnoseMigrationHistoryOutput, HistoryDiagnostic};
 use std::io::Write;
+use tempfile::TempDir;
+
+/// Rename the migrations so they are ten seconds apart, instead of all being
+/// created within the same second.
+fn spread_migration_timestamps(directory: &TempDir, migration_names: &[String]) -> std::io::Result<Vec<String>> {
+    let mut new_names = Vec::with_capacity(migration_names.len());
+
+    for (idx, migration_name) in migration_names.iter().enumerate() {
+        let new_name = format!("202101010000{:02}{}", idx * 10, &migration_name[14..]);
+
+        std::fs::rename(directory.path().join(migration_name), directory.path().join(&new_name))?;
+
+        new_names.push(new_name);
+    }
+
+    Ok(new_names)
+}
 
 #[test_each_connector]
 async fn squashing_whole_migration_history_works(api: &TestApi) -> TestResult {
@@ -526,3 +543,331 @@ async fn squashing_migrations_history_at_the_end_works(api: &TestApi) -> TestRes
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn squash_migrations_command_replaces_the_range_and_marks_it_applied(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let dm3 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let mut migration_names: Vec<String> = Vec::with_capacity(3);
+
+    for (idx, schema) in [dm1, dm2, dm3].iter().enumerate() {
+        let name = api
+            .create_migration(&format!("migration{}", idx), schema, &directory)
+            .send()
+            .await?
+            .into_output()
+            .generated_migration_name
+            .unwrap();
+
+        migration_names.push(name);
+    }
+
+    let migration_names = spread_migration_timestamps(&directory, &migration_names)?;
+
+    api.apply_migrations(&directory).send().await?;
+
+    let initial_schema = api.assert_schema().await?.assert_tables_count(3)?.into_schema();
+
+    let output = api
+        .squash_migrations("migration0_to_1", &directory)
+        .to(migration_names[1].as_str())
+        .send()
+        .await?
+        .assert_squashed_migrations_count(2)?
+        .assert_marked_applied(true)?
+        .into_output();
+
+    // The timestamp of the last squashed migration.
+    assert_eq!(output.generated_migration_name, "20210101000010_migration0_to_1");
+
+    assert!(directory
+        .path()
+        .join(&output.generated_migration_name)
+        .join("schema_snapshot.json")
+        .exists());
+
+    api.list_migration_directories(&directory)
+        .send()
+        .await?
+        .assert_listed_directories(&["migration0_to_1", "migration2"])?;
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let DiagnoseMigrationHistoryOutput {
+        drift,
+        failed_migration_names,
+        edited_migration_names,
+        error_in_unapplied_migration,
+        ..
+    } = api
+        .diagnose_migration_history(&directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(drift.is_none());
+    assert!(failed_migration_names.is_empty());
+    assert!(edited_migration_names.is_empty());
+    assert!(error_in_unapplied_migration.is_none());
+
+    let migrations = api.imperative_migration_persistence().list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 4);
+
+    api.assert_schema().await?.assert_equals(&initial_schema)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squash_migrations_command_does_not_mark_unapplied_migrations_applied(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+    api.create_migration("second", dm2, &directory).send().await?;
+
+    api.squash_migrations("squashed", &directory)
+        .send()
+        .await?
+        .assert_squashed_migrations_count(2)?
+        .assert_marked_applied(false)?;
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["squashed"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squash_migrations_command_rejects_partially_applied_ranges(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+    api.create_migration("second", dm2, &directory).send().await?;
+
+    let err = api.squash_migrations("squashed", &directory).send().await.unwrap_err();
+
+    assert!(err.to_string().contains("only partially applied"), "{}", err);
+
+    api.list_migration_directories(&directory)
+        .send()
+        .await?
+        .assert_listed_directories(&["initial", "second"])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn squash_migrations_command_keeps_batched_steps(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            nameLength  Int?
+        }
+    "#;
+
+    let backfill = indoc::indoc! {r#"
+        -- prisma:batch table=Cat column=id size=2
+        UPDATE "Cat" SET "nameLength" = length("name") WHERE "id" BETWEEN {{batch_start}} AND {{batch_end}};
+    "#};
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+
+    api.create_migration("backfill", dm1, &directory)
+        .draft(true)
+        .send()
+        .await?
+        .add_migration_step("01_backfill.sql", backfill)?;
+
+    let output = api
+        .squash_migrations("squashed", &directory)
+        .send()
+        .await?
+        .assert_squashed_migrations_count(2)?
+        .into_output();
+
+    let step_path = directory
+        .path()
+        .join(&output.generated_migration_name)
+        .join("001_01_backfill.sql");
+
+    assert_eq!(std::fs::read_to_string(step_path)?, backfill);
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["squashed"])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn squash_migrations_command_rejects_schema_changes_after_batched_steps(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            nameLength  Int?
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            nameLength  Int?
+        }
+    "#;
+
+    let backfill = indoc::indoc! {r#"
+        -- prisma:batch table=Cat column=id size=2
+        UPDATE "Cat" SET "nameLength" = length("name") WHERE "id" BETWEEN {{batch_start}} AND {{batch_end}};
+    "#};
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+
+    api.create_migration("backfill", dm1, &directory)
+        .draft(true)
+        .send()
+        .await?
+        .add_migration_step("01_backfill.sql", backfill)?;
+
+    // Drops the column the backfill reads.
+    api.create_migration("drop_name", dm2, &directory).send().await?;
+
+    let err = api.squash_migrations("squashed", &directory).send().await.unwrap_err();
+
+    assert!(err.to_string().contains("is followed by schema changes"), "{}", err);
+
+    api.list_migration_directories(&directory)
+        .send()
+        .await?
+        .assert_listed_directories(&["initial", "backfill", "drop_name"])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn squash_migrations_command_rejects_names_sorting_after_the_next_migration(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let mut migration_names: Vec<String> = Vec::with_capacity(2);
+
+    for (idx, schema) in [dm1, dm2].iter().enumerate() {
+        let name = api
+            .create_migration(&format!("migration{}", idx), schema, &directory)
+            .send()
+            .await?
+            .into_output()
+            .generated_migration_name
+            .unwrap();
+
+        migration_names.push(name);
+    }
+
+    // Both migrations have the same timestamp.
+    let timestamp = &migration_names[0][..14];
+    let next_migration_name = format!("{}_migration1", timestamp);
+
+    std::fs::rename(
+        directory.path().join(&migration_names[1]),
+        directory.path().join(&next_migration_name),
+    )?;
+
+    let err = api
+        .squash_migrations("the_squashed_one", &directory)
+        .to(migration_names[0].as_str())
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("would not sort between"), "{}", err);
+
+    api.list_migration_directories(&directory)
+        .send()
+        .await?
+        .assert_listed_directories(&["migration0", "migration1"])?;
+
+    Ok(())
+}
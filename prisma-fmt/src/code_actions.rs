@@ -0,0 +1,50 @@
+use crate::{lint::rules, CodeActionsOpts};
+use datamodel::code_actions::{self, TextEdit};
+use std::io::{self, Read};
+
+#[derive(serde::Serialize)]
+pub struct MiniCodeAction {
+    pub title: String,
+    pub start: usize,
+    pub end: usize,
+    pub edits: Vec<TextEdit>,
+}
+
+pub fn run(opts: CodeActionsOpts) {
+    let mut datamodel_string = String::new();
+
+    io::stdin()
+        .read_to_string(&mut datamodel_string)
+        .expect("Unable to read from stdin.");
+
+    let mut actions: Vec<MiniCodeAction> = code_actions::code_actions(&datamodel_string)
+        .into_iter()
+        .map(|action| MiniCodeAction {
+            title: action.title,
+            start: action.span.start,
+            end: action.span.end,
+            edits: action.edits,
+        })
+        .collect();
+
+    // The fixes of the lint rules, e.g. adding missing indexes, are code actions as well.
+    if let Ok(validated_datamodel) = datamodel::parse_datamodel_and_ignore_datasource_urls(&datamodel_string) {
+        let ast = datamodel::parse_schema_ast(&datamodel_string).expect("Unable to parse a valid schema.");
+        // Configuration errors are reported by `lint`.
+        let (config, _) = rules::load_config(&datamodel_string, &opts.rules);
+        let lints = rules::run(&validated_datamodel.subject, &ast, &config);
+
+        actions.extend(lints.into_iter().filter_map(|lint| {
+            lint.fix.map(|fix| MiniCodeAction {
+                title: fix.description,
+                start: lint.span.start,
+                end: lint.span.end,
+                edits: fix.edits,
+            })
+        }));
+    }
+
+    let json = serde_json::to_string(&actions).expect("Failed to render JSON");
+
+    print!("{}", json)
+}
@@ -1,15 +1,22 @@
-use datamodel::ast::reformat::Reformatter;
+use datamodel::ast::{reformat::Reformatter, SchemaFiles};
 use std::{
     fs::{self, File},
     io::{self, BufWriter, Read},
+    path::Path,
 };
 
 use crate::FormatOpts;
 
 pub fn run(opts: FormatOpts) {
-    let datamodel_string = match opts.input {
+    if let Some(dir) = opts.input.as_ref().filter(|input| input.is_dir()) {
+        return format_dir(dir, opts.tabwidth);
+    }
+
+    // The files imported by the input are loaded for the relations spanning
+    // files, only the input itself is formatted.
+    let files = match opts.input {
         Some(file_name) => {
-            fs::read_to_string(&file_name).unwrap_or_else(|_| panic!("Unable to open file {}", file_name.display()))
+            SchemaFiles::load(&file_name).unwrap_or_else(|_| panic!("Unable to open file {}", file_name.display()))
         }
         None => {
             let mut buf = String::new();
@@ -18,19 +25,56 @@ pub fn run(opts: FormatOpts) {
                 .read_to_string(&mut buf)
                 .expect("Unable to read from stdin.");
 
-            buf
+            let mut files = SchemaFiles::new();
+            files.add("<stdin>", buf);
+            files
         }
     };
 
+    let files = if opts.fix {
+        let mut fixed = SchemaFiles::new();
+
+        for (_, file) in files.iter() {
+            fixed.add(
+                file.name.as_str(),
+                crate::lint::rules::apply_fixes(&file.contents, &opts.rules),
+            );
+        }
+
+        fixed
+    } else {
+        files
+    };
+
+    let (_, reformatter) = Reformatter::for_files(&files)
+        .into_iter()
+        .next()
+        .expect("The input is the first file.");
+
     match opts.output {
         Some(file_name) => {
             let file = File::open(&file_name).unwrap_or_else(|_| panic!("Unable to open file {}", file_name.display()));
             let mut stream = BufWriter::new(file);
 
-            Reformatter::new(&datamodel_string).reformat_to(&mut stream, opts.tabwidth);
+            reformatter.reformat_to(&mut stream, opts.tabwidth);
         }
         None => {
-            Reformatter::new(&datamodel_string).reformat_to(&mut io::stdout().lock(), opts.tabwidth);
+            reformatter.reformat_to(&mut io::stdout().lock(), opts.tabwidth);
         }
     }
 }
+
+/// Formats the files of a schema split over several files in place.
+fn format_dir(dir: &Path, tabwidth: usize) {
+    let files = SchemaFiles::load_dir(dir).unwrap_or_else(|_| panic!("Unable to read directory {}", dir.display()));
+
+    for (file_id, reformatter) in Reformatter::for_files(&files) {
+        let name = &files.get(file_id).expect("Unknown schema file.").name;
+
+        // Formatted before writing, so that a file is never left truncated.
+        let mut formatted = Vec::new();
+        reformatter.reformat_to(&mut formatted, tabwidth);
+
+        fs::write(name, formatted).unwrap_or_else(|_| panic!("Unable to write file {}", name));
+    }
+}
@@ -1,7 +1,10 @@
+pub(crate) mod rules;
+
 use crate::{LintOpts, MiniError};
 use datamodel::diagnostics::{DatamodelError, DatamodelWarning};
+use rules::Severity;
 
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 
 pub fn run(opts: LintOpts) {
     let mut datamodel_string = String::new();
@@ -25,6 +28,7 @@ pub fn run(opts: LintOpts) {
                     end: err.span().end,
                     text: format!("{}", err),
                     is_warning: false,
+                    fix: None,
                 })
                 .collect();
 
@@ -35,6 +39,7 @@ pub fn run(opts: LintOpts) {
                     end: warn.span().end,
                     text: format!("{}", warn),
                     is_warning: true,
+                    fix: None,
                 })
                 .collect();
 
@@ -43,7 +48,7 @@ pub fn run(opts: LintOpts) {
             print_diagnostics(mini_errors);
         }
         Ok(validated_datamodel) => {
-            let mini_warnings: Vec<MiniError> = validated_datamodel
+            let mut mini_warnings: Vec<MiniError> = validated_datamodel
                 .warnings
                 .into_iter()
                 .map(|warn: DatamodelWarning| MiniError {
@@ -51,14 +56,57 @@ pub fn run(opts: LintOpts) {
                     end: warn.span().end,
                     text: format!("{}", warn),
                     is_warning: true,
+                    fix: None,
                 })
                 .collect();
 
+            // The schema is valid at this point, so it parses.
+            let ast = datamodel::parse_schema_ast(&datamodel_string).expect("Unable to parse a valid schema.");
+            let (lints, has_errors) = lint_schema(&datamodel_string, &validated_datamodel.subject, &ast, &opts.rules);
+            mini_warnings.extend(lints);
+
             print_diagnostics(mini_warnings);
+
+            // Rules configured as errors fail the lint, e.g. in CI.
+            if has_errors {
+                io::stdout().flush().expect("Unable to flush stdout.");
+                std::process::exit(1);
+            }
         }
     }
 }
 
+/// Runs the lint rules with the configuration of the schema and the command line. Invalid
+/// configuration entries are reported as errors. Returns whether a rule reported an error.
+fn lint_schema(
+    datamodel_string: &str,
+    datamodel: &datamodel::Datamodel,
+    ast: &datamodel::ast::SchemaAst,
+    overrides: &[rules::RuleConfig],
+) -> (Vec<MiniError>, bool) {
+    let (config, config_errors) = rules::load_config(datamodel_string, overrides);
+    let lints = rules::run(datamodel, ast, &config);
+    let has_errors = lints.iter().any(|lint| lint.severity == Severity::Error);
+
+    let config_errors = config_errors.into_iter().map(|err| MiniError {
+        start: err.span.start,
+        end: err.span.end,
+        text: err.message,
+        is_warning: false,
+        fix: None,
+    });
+
+    let lints = lints.into_iter().map(|lint| MiniError {
+        start: lint.span.start,
+        end: lint.span.end,
+        text: format!("[{}] {}", lint.rule, lint.message),
+        is_warning: lint.severity == Severity::Warning,
+        fix: lint.fix,
+    });
+
+    (config_errors.chain(lints).collect(), has_errors)
+}
+
 fn print_diagnostics(diagnostics: Vec<MiniError>) {
     let json = serde_json::to_string(&diagnostics).expect("Failed to render JSON");
 
@@ -0,0 +1,830 @@
+//! Lint rules checking a valid schema for conventions and common mistakes.
+//! The severity of each rule can be configured in the schema, with comments on
+//! the top level:
+//!
+//! ```prisma
+//! // lint: modelNames=error, unusedEnums=off
+//! ```
+//!
+//! The command line takes precedence over the schema:
+//!
+//! ```text
+//! prisma-fmt lint --rule modelNames=error --rule unusedEnums=off
+//! ```
+use datamodel::{
+    ast::{self, FileId, Span},
+    code_actions::{attribute_insert_position, TextEdit},
+    walkers::{walk_enums, walk_models, walk_scalar_fields, ModelWalker, RelationFieldWalker},
+    Datamodel, FieldType, ScalarType,
+};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Off,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    fn from_str(s: &str) -> Option<Severity> {
+        match s {
+            "off" => Some(Severity::Off),
+            "warn" | "warning" => Some(Severity::Warning),
+            "error" => Some(Severity::Error),
+            _ => None,
+        }
+    }
+}
+
+/// A lint rule, checking the schema as a whole.
+pub struct Rule {
+    pub name: &'static str,
+    pub default_severity: Severity,
+    check: fn(&LintContext<'_>, &mut Vec<Lint>),
+}
+
+pub const RULES: &[Rule] = &[
+    Rule {
+        name: "modelNames",
+        default_severity: Severity::Warning,
+        check: check_model_names,
+    },
+    Rule {
+        name: "fieldNames",
+        default_severity: Severity::Warning,
+        check: check_field_names,
+    },
+    Rule {
+        name: "relationScalarIndexes",
+        default_severity: Severity::Warning,
+        check: check_relation_scalar_indexes,
+    },
+    Rule {
+        name: "stringIdDefaults",
+        default_severity: Severity::Warning,
+        check: check_string_id_defaults,
+    },
+    Rule {
+        name: "updatedAtFields",
+        default_severity: Severity::Warning,
+        check: check_updated_at_fields,
+    },
+    Rule {
+        name: "unusedEnums",
+        default_severity: Severity::Warning,
+        check: check_unused_enums,
+    },
+];
+
+/// The severity of a lint rule, given as `<rule>=<severity>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RuleConfig {
+    pub rule: &'static str,
+    pub severity: Severity,
+}
+
+impl FromStr for RuleConfig {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '=');
+        let name = parts.next().unwrap_or_default().trim();
+        let severity = parts
+            .next()
+            .ok_or_else(|| format!("Expected a lint rule configuration like `{}=error`.", name))?
+            .trim();
+
+        let rule = RULES
+            .iter()
+            .find(|rule| rule.name == name)
+            .ok_or_else(|| format!("Unknown lint rule `{}`.", name))?;
+
+        let severity = Severity::from_str(severity).ok_or_else(|| {
+            format!(
+                "Invalid severity for lint rule `{}`. Expected one of \"off\", \"warn\" or \"error\".",
+                rule.name
+            )
+        })?;
+
+        Ok(RuleConfig {
+            rule: rule.name,
+            severity,
+        })
+    }
+}
+
+/// The comments configuring lint rules in the schema start with this prefix.
+const CONFIG_COMMENT_PREFIX: &str = "// lint:";
+
+/// An invalid lint rule configuration in the schema.
+#[derive(Debug, PartialEq)]
+pub struct ConfigError {
+    pub span: Span,
+    pub message: String,
+}
+
+/// Reads the rule configuration of the schema and appends the overrides, e.g.
+/// from the command line, which take precedence in `run`.
+pub fn load_config(datamodel_string: &str, overrides: &[RuleConfig]) -> (Vec<RuleConfig>, Vec<ConfigError>) {
+    let mut config = Vec::new();
+    let mut errors = Vec::new();
+
+    for line in datamodel_string.lines() {
+        let trimmed = line.trim_start();
+
+        if !trimmed.starts_with(CONFIG_COMMENT_PREFIX) {
+            continue;
+        }
+
+        let mut start = offset_in(datamodel_string, trimmed) + CONFIG_COMMENT_PREFIX.len();
+
+        for entry in trimmed[CONFIG_COMMENT_PREFIX.len()..].split(',') {
+            let end = start + entry.len();
+
+            if !entry.trim().is_empty() {
+                match entry.parse::<RuleConfig>() {
+                    Ok(rule_config) => config.push(rule_config),
+                    Err(message) => errors.push(ConfigError {
+                        span: Span::new(start, end, FileId::default()),
+                        message,
+                    }),
+                }
+            }
+
+            // Skip the comma.
+            start = end + 1;
+        }
+    }
+
+    config.extend_from_slice(overrides);
+
+    (config, errors)
+}
+
+/// The byte offset of `part` in `whole`, which `part` is a slice of.
+fn offset_in(whole: &str, part: &str) -> usize {
+    part.as_ptr() as usize - whole.as_ptr() as usize
+}
+
+/// A violation of a lint rule.
+#[derive(Debug)]
+pub struct Lint {
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub span: Span,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+/// A suggested fix for a lint, applied by `format --fix`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Fix {
+    pub description: String,
+    pub edits: Vec<TextEdit>,
+}
+
+pub struct LintContext<'a> {
+    datamodel: &'a Datamodel,
+    ast: &'a ast::SchemaAst,
+}
+
+/// Runs all rules that are not turned off against the schema. Later entries of
+/// the configuration take precedence.
+pub fn run(datamodel: &Datamodel, ast: &ast::SchemaAst, config: &[RuleConfig]) -> Vec<Lint> {
+    let severities: HashMap<&str, Severity> = config.iter().map(|config| (config.rule, config.severity)).collect();
+    let ctx = LintContext { datamodel, ast };
+    let mut lints = Vec::new();
+
+    for rule in RULES {
+        let severity = severities.get(rule.name).copied().unwrap_or(rule.default_severity);
+
+        if severity == Severity::Off {
+            continue;
+        }
+
+        let mut rule_lints = Vec::new();
+        (rule.check)(&ctx, &mut rule_lints);
+
+        lints.extend(rule_lints.into_iter().map(|lint| Lint { severity, ..lint }));
+    }
+
+    lints
+}
+
+/// Applies the fixes of all lints to the schema, with the rule configuration of
+/// the schema and the overrides. Returns the schema unchanged if it is not valid.
+pub fn apply_fixes(datamodel_string: &str, overrides: &[RuleConfig]) -> String {
+    let datamodel = match datamodel::parse_datamodel_and_ignore_datasource_urls(datamodel_string) {
+        Ok(validated) => validated.subject,
+        Err(_) => return datamodel_string.to_owned(),
+    };
+    let ast = match datamodel::parse_schema_ast(datamodel_string) {
+        Ok(ast) => ast,
+        Err(_) => return datamodel_string.to_owned(),
+    };
+
+    let (config, _) = load_config(datamodel_string, overrides);
+    let lints = run(&datamodel, &ast, &config);
+    let mut edits: Vec<TextEdit> = lints
+        .into_iter()
+        .filter_map(|lint| lint.fix)
+        .flat_map(|fix| fix.edits)
+        .collect();
+
+    // Apply back to front, so that the positions of the remaining edits stay valid.
+    edits.sort_by(|a, b| b.start.cmp(&a.start).then(b.end.cmp(&a.end)));
+    edits.dedup();
+
+    let mut result = datamodel_string.to_owned();
+    let mut applied_from = usize::MAX;
+
+    for edit in edits {
+        // Overlapping edits are skipped, they are fixed in a later run.
+        if edit.end > applied_from {
+            continue;
+        }
+
+        result.replace_range(edit.start..edit.end, &edit.text);
+        applied_from = edit.start;
+    }
+
+    result
+}
+
+fn check_model_names(ctx: &LintContext<'_>, lints: &mut Vec<Lint>) {
+    for model in walk_models(ctx.datamodel) {
+        let name = model.name();
+        let ast_model = match ctx.ast.find_model(name) {
+            Some(ast_model) => ast_model,
+            None => continue,
+        };
+
+        if !is_pascal_case(name) {
+            lints.push(Lint::new(
+                "modelNames",
+                ast_model.name.span,
+                format!(
+                    "Model names should be PascalCase. Consider renaming `{}` to `{}` and keeping the table name with `@@map(\"{}\")`.",
+                    name,
+                    to_pascal_case(name),
+                    model.database_name()
+                ),
+            ));
+        }
+    }
+}
+
+fn check_field_names(ctx: &LintContext<'_>, lints: &mut Vec<Lint>) {
+    for model in ctx.ast.models() {
+        for field in model.fields.iter().filter(|field| !field.is_commented_out) {
+            let name = &field.name.name;
+
+            if !is_camel_case(name) {
+                lints.push(Lint::new(
+                    "fieldNames",
+                    field.name.span,
+                    format!(
+                        "Field names should be camelCase. Consider renaming `{}` to `{}`.",
+                        name,
+                        to_camel_case(name)
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+fn check_relation_scalar_indexes(ctx: &LintContext<'_>, lints: &mut Vec<Lint>) {
+    for model in walk_models(ctx.datamodel) {
+        let ast_model = match ctx.ast.find_model(model.name()) {
+            Some(ast_model) => ast_model,
+            None => continue,
+        };
+
+        for relation_field in model.relation_fields() {
+            let fields: Vec<&str> = relation_field.referencing_fields().map(|field| field.name()).collect();
+
+            if fields.is_empty() || is_indexed(&model, &fields) {
+                continue;
+            }
+
+            let span = match ctx.ast.find_field(model.name(), relation_field.name()) {
+                Some(ast_field) => field_span(ast_field),
+                None => continue,
+            };
+
+            let index = format!("@@index([{}])", fields.join(", "));
+            let closing_brace = ast_model.span.end - 1;
+
+            lints.push(
+                Lint::new(
+                    "relationScalarIndexes",
+                    span,
+                    format!(
+                        "The relation field `{}` is not backed by an index on {}. Queries following the relation scan the whole table. Consider adding `{}`.",
+                        relation_field.name(),
+                        describe_fields(&fields),
+                        index
+                    ),
+                )
+                .with_fix(
+                    format!("Add `{}`", index),
+                    vec![TextEdit::insert(closing_brace, format!("  {}\n", index))],
+                ),
+            );
+        }
+    }
+}
+
+/// Whether an index, unique constraint or id starts with the given fields.
+fn is_indexed(model: &ModelWalker<'_>, fields: &[&str]) -> bool {
+    let starts_with = |index_fields: &[String]| {
+        index_fields.len() >= fields.len() && index_fields.iter().zip(fields).all(|(a, b)| a.as_str() == *b)
+    };
+
+    let single_field = match fields {
+        [field] => model
+            .find_scalar_field(field)
+            .map(|field| field.is_id() || field.is_unique())
+            .unwrap_or(false),
+        _ => false,
+    };
+
+    let id_fields: Vec<String> = model.id_fields().map(|field| field.name().to_owned()).collect();
+
+    single_field || starts_with(&id_fields) || model.indexes().any(|index| starts_with(&index.fields))
+}
+
+fn check_string_id_defaults(ctx: &LintContext<'_>, lints: &mut Vec<Lint>) {
+    for field in walk_scalar_fields(ctx.datamodel) {
+        let model = field.model();
+        let is_string = matches!(field.field_type().as_scalar_type(), Some(ScalarType::String));
+
+        if !field.is_id() || !is_string || field.default_value().is_some() {
+            continue;
+        }
+
+        // Ids referencing another model are set through the relation.
+        let is_foreign_key = model
+            .relation_fields()
+            .any(|relation_field| referenced_by(&relation_field, field.name()));
+
+        if is_foreign_key {
+            continue;
+        }
+
+        let ast_field = match ctx.ast.find_field(model.name(), field.name()) {
+            Some(ast_field) => ast_field,
+            None => continue,
+        };
+
+        lints.push(
+            Lint::new(
+                "stringIdDefaults",
+                field_span(ast_field),
+                format!(
+                    "The id field `{}` has no default value, so every create has to provide one. Consider adding `@default(cuid())` or `@default(uuid())`.",
+                    field.name()
+                ),
+            )
+            .with_fix(
+                "Add `@default(cuid())`",
+                vec![TextEdit::insert(attribute_insert_position(ast_field), " @default(cuid())")],
+            ),
+        );
+    }
+}
+
+fn check_updated_at_fields(ctx: &LintContext<'_>, lints: &mut Vec<Lint>) {
+    for field in walk_scalar_fields(ctx.datamodel) {
+        let is_date_time = matches!(field.field_type().as_scalar_type(), Some(ScalarType::DateTime));
+        let is_named_updated_at = field.name() == "updatedAt" || field.name() == "updated_at";
+
+        if !is_date_time || !is_named_updated_at || field.is_updated_at() || field.default_value().is_some() {
+            continue;
+        }
+
+        let ast_field = match ctx.ast.find_field(field.model().name(), field.name()) {
+            Some(ast_field) => ast_field,
+            None => continue,
+        };
+
+        lints.push(
+            Lint::new(
+                "updatedAtFields",
+                field_span(ast_field),
+                format!(
+                    "The field `{}` is not set automatically on updates. Consider adding `@updatedAt`.",
+                    field.name()
+                ),
+            )
+            .with_fix(
+                "Add `@updatedAt`",
+                vec![TextEdit::insert(attribute_insert_position(ast_field), " @updatedAt")],
+            ),
+        );
+    }
+}
+
+fn check_unused_enums(ctx: &LintContext<'_>, lints: &mut Vec<Lint>) {
+    let mut used: Vec<&str> = walk_scalar_fields(ctx.datamodel)
+        .filter_map(|field| field.field_type().as_enum().map(|r#enum| r#enum.name()))
+        .collect();
+
+    used.extend(
+        ctx.datamodel
+            .composite_types()
+            .flat_map(|composite_type| composite_type.fields())
+            .filter_map(|field| match &field.field_type {
+                FieldType::Enum(name) => Some(name.as_str()),
+                _ => None,
+            }),
+    );
+
+    for r#enum in walk_enums(ctx.datamodel) {
+        if used.contains(&r#enum.name()) {
+            continue;
+        }
+
+        if let Some(ast_enum) = ctx.ast.find_enum(r#enum.name()) {
+            lints.push(Lint::new(
+                "unusedEnums",
+                ast_enum.name.span,
+                format!("The enum `{}` is not used by any field.", r#enum.name()),
+            ));
+        }
+    }
+}
+
+impl Lint {
+    fn new(rule: &'static str, span: Span, message: String) -> Self {
+        Lint {
+            rule,
+            // Replaced by the configured severity of the rule.
+            severity: Severity::Warning,
+            span,
+            message,
+            fix: None,
+        }
+    }
+
+    fn with_fix(mut self, description: impl Into<String>, edits: Vec<TextEdit>) -> Self {
+        self.fix = Some(Fix {
+            description: description.into(),
+            edits,
+        });
+        self
+    }
+}
+
+fn referenced_by(relation_field: &RelationFieldWalker<'_>, field_name: &str) -> bool {
+    relation_field
+        .referencing_fields()
+        .any(|field| field.name() == field_name)
+}
+
+/// The span of a field declaration, without its documentation and trailing comments.
+fn field_span(field: &ast::Field) -> Span {
+    Span {
+        start: field.name.span.start,
+        end: attribute_insert_position(field),
+        file_id: field.span.file_id,
+    }
+}
+
+fn describe_fields(fields: &[&str]) -> String {
+    match fields {
+        [field] => format!("the field `{}`", field),
+        fields => format!("the fields `{}`", fields.join("`, `")),
+    }
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.chars().next().map(|c| c.is_ascii_uppercase()).unwrap_or(false) && !name.contains('_')
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.chars().next().map(|c| c.is_ascii_lowercase()).unwrap_or(false) && !name.contains('_')
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+fn to_camel_case(name: &str) -> String {
+    if name.chars().all(|c| !c.is_ascii_lowercase()) {
+        return to_camel_case(&name.to_ascii_lowercase());
+    }
+
+    let pascal = to_pascal_case(name);
+    let mut chars = pascal.chars();
+
+    match chars.next() {
+        Some(first) => std::iter::once(first.to_ascii_lowercase()).chain(chars).collect(),
+        None => pascal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lint(schema: &str, config: &[RuleConfig]) -> Vec<Lint> {
+        let datamodel = datamodel::parse_datamodel(schema).unwrap().subject;
+        let ast = datamodel::parse_schema_ast(schema).unwrap();
+
+        run(&datamodel, &ast, config)
+    }
+
+    fn lints_for(schema: &str, rule: &str) -> Vec<Lint> {
+        lint(schema, &[]).into_iter().filter(|lint| lint.rule == rule).collect()
+    }
+
+    #[test]
+    fn model_names() {
+        let schema = r#"
+            model user_account {
+              id Int @id
+            }
+        "#;
+
+        let lints = lints_for(schema, "modelNames");
+        assert_eq!(lints.len(), 1);
+        assert!(lints[0].message.contains("renaming `user_account` to `UserAccount`"));
+
+        let schema = r#"
+            model UserAccount {
+              id Int @id
+            }
+        "#;
+
+        assert!(lints_for(schema, "modelNames").is_empty());
+    }
+
+    #[test]
+    fn field_names() {
+        let schema = r#"
+            model User {
+              id         Int    @id
+              first_name String
+            }
+        "#;
+
+        let lints = lints_for(schema, "fieldNames");
+        assert_eq!(lints.len(), 1);
+        assert!(lints[0].message.contains("renaming `first_name` to `firstName`"));
+
+        let schema = r#"
+            model User {
+              id        Int    @id
+              firstName String
+            }
+        "#;
+
+        assert!(lints_for(schema, "fieldNames").is_empty());
+    }
+
+    #[test]
+    fn relation_scalar_indexes() {
+        let schema = r#"
+            model User {
+              id Int @id
+            }
+
+            model Post {
+              id       Int  @id
+              authorId Int
+              author   User @relation(fields: [authorId], references: [id])
+            }
+        "#;
+
+        let lints = lints_for(schema, "relationScalarIndexes");
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].fix.as_ref().unwrap().description, "Add `@@index([authorId])`");
+
+        let schema = r#"
+            model User {
+              id Int @id
+            }
+
+            model Post {
+              id       Int  @id
+              authorId Int
+              author   User @relation(fields: [authorId], references: [id])
+
+              @@index([authorId])
+            }
+        "#;
+
+        assert!(lints_for(schema, "relationScalarIndexes").is_empty());
+    }
+
+    #[test]
+    fn string_id_defaults() {
+        let schema = r#"
+            model User {
+              id String @id
+            }
+        "#;
+
+        let lints = lints_for(schema, "stringIdDefaults");
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].fix.as_ref().unwrap().description, "Add `@default(cuid())`");
+
+        let schema = r#"
+            model User {
+              id String @id @default(cuid())
+            }
+        "#;
+
+        assert!(lints_for(schema, "stringIdDefaults").is_empty());
+    }
+
+    #[test]
+    fn updated_at_fields() {
+        let schema = r#"
+            model User {
+              id        Int      @id
+              updatedAt DateTime
+            }
+        "#;
+
+        let lints = lints_for(schema, "updatedAtFields");
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].fix.as_ref().unwrap().description, "Add `@updatedAt`");
+
+        let schema = r#"
+            model User {
+              id        Int      @id
+              updatedAt DateTime @updatedAt
+            }
+        "#;
+
+        assert!(lints_for(schema, "updatedAtFields").is_empty());
+    }
+
+    #[test]
+    fn unused_enums() {
+        let schema = r#"
+            model User {
+              id Int @id
+            }
+
+            enum Role {
+              USER
+              ADMIN
+            }
+        "#;
+
+        let lints = lints_for(schema, "unusedEnums");
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].message, "The enum `Role` is not used by any field.");
+
+        let schema = r#"
+            model User {
+              id   Int  @id
+              role Role
+            }
+
+            enum Role {
+              USER
+              ADMIN
+            }
+        "#;
+
+        assert!(lints_for(schema, "unusedEnums").is_empty());
+    }
+
+    #[test]
+    fn rule_severities_can_be_configured() {
+        let schema = r#"
+            model user_account {
+              id Int @id
+            }
+
+            enum Role {
+              USER
+            }
+        "#;
+
+        let config: Vec<RuleConfig> = vec!["modelNames=error".parse().unwrap(), "unusedEnums=off".parse().unwrap()];
+        let lints = lint(schema, &config);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].rule, "modelNames");
+        assert_eq!(lints[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn invalid_rule_configurations_are_rejected() {
+        assert_eq!(
+            "modelNames=fatal".parse::<RuleConfig>(),
+            Err(
+                "Invalid severity for lint rule `modelNames`. Expected one of \"off\", \"warn\" or \"error\"."
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            "tableNames=error".parse::<RuleConfig>(),
+            Err("Unknown lint rule `tableNames`.".to_owned())
+        );
+        assert_eq!(
+            "modelNames".parse::<RuleConfig>(),
+            Err("Expected a lint rule configuration like `modelNames=error`.".to_owned())
+        );
+    }
+
+    #[test]
+    fn rules_are_configured_in_the_schema() {
+        let schema = r#"
+            // lint: modelNames=error, unusedEnums=off
+            // lint: fieldNames=off
+
+            model user_account {
+              id         Int  @id
+              first_name String
+              role       Role
+            }
+
+            enum Role {
+              USER
+            }
+
+            enum Unused {
+              VALUE
+            }
+        "#;
+
+        let (config, errors) = load_config(schema, &[]);
+        let lints = lint(schema, &config);
+
+        assert!(errors.is_empty());
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].rule, "modelNames");
+        assert_eq!(lints[0].severity, Severity::Error);
+
+        let overrides: Vec<RuleConfig> = vec!["modelNames=warn".parse().unwrap()];
+        let (config, _) = load_config(schema, &overrides);
+
+        assert_eq!(lint(schema, &config)[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn invalid_rule_configurations_in_the_schema_are_reported_at_their_entry() {
+        let schema = "// lint: modelNames=error, tableNames=off\nmodel User {\n  id Int @id\n}\n";
+
+        let (config, errors) = load_config(schema, &[]);
+
+        assert_eq!(config, vec!["modelNames=error".parse::<RuleConfig>().unwrap()]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Unknown lint rule `tableNames`.");
+        assert_eq!(&schema[errors[0].span.start..errors[0].span.end], " tableNames=off");
+    }
+
+    #[test]
+    fn apply_fixes_produces_a_schema_without_fixable_lints() {
+        let schema = r#"
+            model User {
+              id        String   @id
+              updatedAt DateTime
+            }
+
+            model Post {
+              id       Int    @id
+              authorId String
+              author   User   @relation(fields: [authorId], references: [id])
+            }
+        "#;
+
+        let fixed = apply_fixes(schema, &[]);
+
+        assert!(fixed.contains("id        String   @id @default(cuid())"));
+        assert!(fixed.contains("updatedAt DateTime @updatedAt"));
+        assert!(fixed.contains("  @@index([authorId])\n"));
+        assert!(lint(&fixed, &[]).iter().all(|lint| lint.fix.is_none()));
+        assert_eq!(apply_fixes(&fixed, &[]), fixed);
+    }
+
+    #[test]
+    fn apply_fixes_skips_rules_that_are_off() {
+        let schema = r#"
+            model User {
+              id        Int      @id
+              updatedAt DateTime
+            }
+        "#;
+
+        let config: Vec<RuleConfig> = vec!["updatedAtFields=off".parse().unwrap()];
+
+        assert_eq!(apply_fixes(schema, &config), schema);
+    }
+}
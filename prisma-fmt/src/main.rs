@@ -1,10 +1,13 @@
+mod code_actions;
 mod format;
 mod lint;
 mod native;
 mod preview;
+mod rename;
 
 use std::path::PathBuf;
 
+use lint::rules::RuleConfig;
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -12,12 +15,18 @@ pub struct LintOpts {
     /// If set, silences all `environment variable not found` errors
     #[structopt(long)]
     no_env_errors: bool,
+    /// Sets the severity of a lint rule, given as `<rule>=<off|warn|error>`,
+    /// overriding the `// lint:` comments of the schema. Can be repeated. Exits
+    /// with status 1 if a rule set to `error` reports a lint
+    #[structopt(long = "rule", number_of_values = 1)]
+    rules: Vec<RuleConfig>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
 pub struct FormatOpts {
     /// Specifies the input file to use. If none is given, the input is read
-    /// from STDIN
+    /// from STDIN. For a directory, the schema files in it are formatted in
+    /// place
     #[structopt(short = "i", long)]
     input: Option<PathBuf>,
     /// Specifies the output file to use. If none is given, the output is
@@ -27,6 +36,22 @@ pub struct FormatOpts {
     /// Specifies which tab width to use when formatting
     #[structopt(short = "s", long, default_value = "2")]
     tabwidth: usize,
+    /// If set, applies the fixes suggested by the lint rules before
+    /// formatting. Not supported for directories
+    #[structopt(long)]
+    fix: bool,
+    /// Sets the severity of a lint rule for `--fix`, given as
+    /// `<rule>=<off|warn|error>`. Can be repeated
+    #[structopt(long = "rule", number_of_values = 1)]
+    rules: Vec<RuleConfig>,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct CodeActionsOpts {
+    /// Sets the severity of a lint rule, given as `<rule>=<off|warn|error>`.
+    /// Rules that are off don't produce code actions. Can be repeated
+    #[structopt(long = "rule", number_of_values = 1)]
+    rules: Vec<RuleConfig>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -36,6 +61,25 @@ pub struct PreviewFeaturesOpts {
     datasource_only: bool,
 }
 
+#[derive(Debug, StructOpt, Clone)]
+pub struct RenameOpts {
+    /// The model to rename
+    #[structopt(long)]
+    model: Option<String>,
+    /// The field to rename, given as `Model.field`
+    #[structopt(long)]
+    field: Option<String>,
+    /// The enum to rename
+    #[structopt(long)]
+    r#enum: Option<String>,
+    /// The new name
+    #[structopt(long)]
+    to: String,
+    /// If set, keeps the database name with `@@map` or `@map`
+    #[structopt(long)]
+    map: bool,
+}
+
 #[derive(Debug, StructOpt, Clone)]
 #[structopt(version = env!("GIT_HASH"))]
 /// Prisma Datamodel v2 formatter
@@ -48,6 +92,10 @@ pub enum FmtOpts {
     NativeTypes,
     /// Specifies preview features mode
     PreviewFeatures(PreviewFeaturesOpts),
+    /// Returns the code actions resolving the diagnostics of the schema
+    CodeActions(CodeActionsOpts),
+    /// Returns the edits renaming a model, field or enum, read from STDIN, or the errors preventing it
+    Rename(RenameOpts),
 }
 
 #[derive(serde::Serialize)]
@@ -56,6 +104,8 @@ pub struct MiniError {
     pub end: usize,
     pub text: String,
     pub is_warning: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<lint::rules::Fix>,
 }
 
 fn main() {
@@ -64,5 +114,7 @@ fn main() {
         FmtOpts::Format(opts) => format::run(opts),
         FmtOpts::NativeTypes => native::run(),
         FmtOpts::PreviewFeatures(opts) => preview::run(opts),
+        FmtOpts::CodeActions(opts) => code_actions::run(opts),
+        FmtOpts::Rename(opts) => rename::run(opts),
     }
 }
@@ -0,0 +1,67 @@
+use crate::{MiniError, RenameOpts};
+use datamodel::{
+    code_actions::TextEdit,
+    diagnostics::DatamodelError,
+    rename::{self, RenameTarget},
+};
+use std::io::{self, Read};
+
+/// Either the edits of the rename, or the errors preventing it.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+enum RenameResult {
+    Edits(Vec<TextEdit>),
+    Errors(Vec<MiniError>),
+}
+
+pub fn run(opts: RenameOpts) {
+    let mut datamodel_string = String::new();
+
+    io::stdin()
+        .read_to_string(&mut datamodel_string)
+        .expect("Unable to read from stdin.");
+
+    let result = match target(opts.model, opts.field, opts.r#enum) {
+        Ok(target) => match rename::rename(&datamodel_string, &target, &opts.to, opts.map) {
+            Ok(edits) => RenameResult::Edits(edits),
+            Err(err) => RenameResult::Errors(
+                err.to_error_iter()
+                    .map(|err: &DatamodelError| MiniError {
+                        start: err.span().start,
+                        end: err.span().end,
+                        text: format!("{}", err),
+                        is_warning: false,
+                        fix: None,
+                    })
+                    .collect(),
+            ),
+        },
+        // Errors in the arguments have no position in the schema.
+        Err(message) => RenameResult::Errors(vec![MiniError {
+            start: 0,
+            end: 0,
+            text: message.to_owned(),
+            is_warning: false,
+            fix: None,
+        }]),
+    };
+
+    let json = serde_json::to_string(&result).expect("Failed to render JSON");
+
+    print!("{}", json)
+}
+
+fn target(model: Option<String>, field: Option<String>, r#enum: Option<String>) -> Result<RenameTarget, &'static str> {
+    match (model, field, r#enum) {
+        (Some(model), None, None) => Ok(RenameTarget::Model(model)),
+        (None, Some(field), None) => match field.find('.') {
+            Some(idx) => Ok(RenameTarget::Field {
+                model: field[..idx].to_owned(),
+                field: field[idx + 1..].to_owned(),
+            }),
+            None => Err("The field must be given as `Model.field`."),
+        },
+        (None, None, Some(r#enum)) => Ok(RenameTarget::Enum(r#enum)),
+        _ => Err("Exactly one of --model, --field and --enum must be given."),
+    }
+}
@@ -87,13 +87,24 @@ pub trait ScalarListCompare {
     where
         T: Into<PrismaValue>;
 
-    fn contains_every_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_every_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
-    fn contains_some_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_some_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
+
+    fn is_empty_list(&self, empty: bool) -> Filter;
+}
 
-    fn contains_none(&self) -> Filter;
+/// Comparing methods for fields holding composite values.
+pub trait CompositeCompare {
+    fn composite_contains<T>(&self, document: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
+    fn composite_equals<T>(&self, document: T) -> Filter
+    where
+        T: Into<PrismaValue>;
 }
@@ -30,6 +30,14 @@ impl ConnectorError {
                     column: column.clone(),
                 }))
             }
+            ErrorKind::ConnectionPoolTimeout => {
+                Some(KnownError::new(user_facing_errors::query_engine::ConnectionPoolTimeout))
+            }
+            ErrorKind::ListIndexOutOfRange { .. } => {
+                Some(KnownError::new(user_facing_errors::query_engine::ValueOutOfRange {
+                    details: kind.to_string(),
+                }))
+            }
             ErrorKind::InvalidDatabaseUrl { details, url } => {
                 let details = user_facing_errors::quaint::invalid_url_description(url, details);
 
@@ -71,6 +79,9 @@ pub enum ErrorKind {
     #[error("Error creating a database connection. ({})", _0)]
     ConnectionError(anyhow::Error),
 
+    #[error("Timed out fetching a new connection from the connection pool.")]
+    ConnectionPoolTimeout,
+
     #[error("Error querying the database: {}", _0)]
     QueryError(Box<dyn std::error::Error + Send + Sync>),
 
@@ -119,6 +130,9 @@ pub enum ErrorKind {
     #[error("Conversion error: {}", _0)]
     InternalConversionError(String),
 
+    #[error("The index {} is out of range for the list `{}` of length {}.", index, field, length)]
+    ListIndexOutOfRange { field: String, index: i64, length: usize },
+
     #[error("Database creation error: {}", _0)]
     DatabaseCreationError(&'static str),
 
@@ -136,6 +150,9 @@ pub enum ErrorKind {
 
     #[error("{}", details)]
     InvalidDatabaseUrl { details: String, url: String },
+
+    #[error("A request context is not supported by the {} connector.", connector)]
+    RequestContextNotSupported { connector: String },
 }
 
 impl From<DomainError> for ConnectorError {
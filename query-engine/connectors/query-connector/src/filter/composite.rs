@@ -0,0 +1,42 @@
+use super::Filter;
+use crate::compare::CompositeCompare;
+use prisma_models::{PrismaValue, ScalarField};
+use std::sync::Arc;
+
+/// Filters on fields holding composite values, which are stored as Json documents.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompositeFilter {
+    pub field: Arc<ScalarField>,
+    pub condition: CompositeCondition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositeCondition {
+    /// The composite value contains the given (partial) Json document.
+    Contains(PrismaValue),
+
+    /// The composite value is the given Json document.
+    Equals(PrismaValue),
+}
+
+impl CompositeCompare for Arc<ScalarField> {
+    fn composite_contains<T>(&self, document: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(CompositeFilter {
+            field: Arc::clone(self),
+            condition: CompositeCondition::Contains(document.into()),
+        })
+    }
+
+    fn composite_equals<T>(&self, document: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(CompositeFilter {
+            field: Arc::clone(self),
+            condition: CompositeCondition::Equals(document.into()),
+        })
+    }
+}
@@ -4,11 +4,13 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod composite;
 mod id_filter;
 mod list;
 mod relation;
 mod scalar;
 
+pub use composite::*;
 pub use id_filter::*;
 pub use list::*;
 pub use relation::*;
@@ -21,9 +23,9 @@ pub enum Filter {
     Not(Vec<Filter>),
     Scalar(ScalarFilter),
     ScalarList(ScalarListFilter),
+    Composite(CompositeFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
-    NodeSubscription,
     BoolFilter(bool),
     Empty,
 }
@@ -127,9 +129,9 @@ impl Filter {
             Filter::Not(inner) => inner.iter_mut().for_each(|f| f.set_mode(mode.clone())),
             Filter::Scalar(sf) => sf.mode = mode,
             Filter::ScalarList(_) => {}
+            Filter::Composite(_) => {}
             Filter::OneRelationIsNull(_) => {}
             Filter::Relation(_) => {}
-            Filter::NodeSubscription => {}
             Filter::BoolFilter(_) => {}
             Filter::Empty => {}
         }
@@ -148,6 +150,12 @@ impl From<ScalarListFilter> for Filter {
     }
 }
 
+impl From<CompositeFilter> for Filter {
+    fn from(cf: CompositeFilter) -> Self {
+        Filter::Composite(cf)
+    }
+}
+
 impl From<OneRelationIsNullFilter> for Filter {
     fn from(sf: OneRelationIsNullFilter) -> Self {
         Filter::OneRelationIsNull(sf)
@@ -1,7 +1,7 @@
 mod dispatch;
 pub use dispatch::*;
 
-use crate::{Filter, QueryArguments, WriteArgs};
+use crate::{Filter, QueryArguments, RequestContext, WriteArgs};
 use async_trait::async_trait;
 use dml::FieldArity;
 use prisma_models::*;
@@ -19,12 +19,26 @@ pub trait Connector {
 #[async_trait]
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
     async fn start_transaction<'a>(&'a self) -> crate::Result<Box<dyn Transaction + 'a>>;
+
+    /// Captures a token cancelling the statements of this connection, while the connection is idle.
+    /// `None` if the statements can't be cancelled safely.
+    async fn cancel_token(&self) -> crate::Result<Option<Box<dyn CancelToken>>>;
+}
+
+/// Cancels the statement running on the connection the token was captured from, see `Connection::cancel_token`.
+/// Only valid as long as the connection isn't returned to its pool.
+#[async_trait]
+pub trait CancelToken: Send + Sync {
+    async fn cancel(&self) -> crate::Result<()>;
 }
 
 #[async_trait]
 pub trait Transaction: ReadOperations + WriteOperations + Send + Sync {
     async fn commit(&self) -> crate::Result<()>;
     async fn rollback(&self) -> crate::Result<()>;
+
+    /// Applies the request context to the session for the rest of the transaction.
+    async fn set_context(&self, context: &RequestContext) -> crate::Result<()>;
 }
 
 pub enum ConnectionLike<'conn, 'tx>
@@ -6,12 +6,14 @@ pub mod filter;
 mod compare;
 mod interface;
 mod query_arguments;
+mod request_context;
 mod write_args;
 
 pub use compare::*;
 pub use filter::*;
 pub use interface::*;
 pub use query_arguments::*;
+pub use request_context::*;
 pub use write_args::*;
 
 pub type Result<T> = std::result::Result<T, error::ConnectorError>;
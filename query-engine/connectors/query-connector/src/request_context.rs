@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Request-scoped settings for the database session an operation runs in,
+/// e.g. the current user for the row-level security policies of Postgres
+/// (`{ "app.user_id": "42" }`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RequestContext(BTreeMap<String, String>);
+
+impl RequestContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.0.iter()
+    }
+
+    /// Adds the settings of `defaults` that are not set in this context.
+    pub fn merge_defaults(&mut self, defaults: RequestContext) {
+        for (key, value) in defaults.0 {
+            self.0.entry(key).or_insert(value);
+        }
+    }
+}
@@ -61,6 +61,31 @@ pub enum WriteExpression {
 
     /// Divide field by value.
     Divide(PrismaValue),
+
+    /// Append the values of a list to a scalar list field.
+    Push(PrismaValue),
+
+    /// Prepend the values of a list to a scalar list field.
+    Unshift(PrismaValue),
+
+    /// Remove all occurrences of value from a scalar list field.
+    RemoveAll(PrismaValue),
+
+    /// Replace the element at the given zero-based index of a scalar list field.
+    SetAt(i64, PrismaValue),
+}
+
+impl WriteExpression {
+    /// Whether the expression is an operation on the elements of a scalar list.
+    pub fn is_list_operation(&self) -> bool {
+        matches!(
+            self,
+            WriteExpression::Push(_)
+                | WriteExpression::Unshift(_)
+                | WriteExpression::RemoveAll(_)
+                | WriteExpression::SetAt(_, _)
+        )
+    }
 }
 
 impl From<PrismaValue> for WriteExpression {
@@ -138,6 +163,23 @@ impl WriteArgs {
         self.args.remove(field)
     }
 
+    /// Removes all scalar list operations from the args and returns them.
+    pub fn take_list_operations(&mut self) -> Vec<(DatasourceFieldName, WriteExpression)> {
+        let keys: Vec<_> = self
+            .args
+            .iter()
+            .filter(|(_, expr)| expr.is_list_operation())
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        keys.into_iter()
+            .map(|key| {
+                let expr = self.args.remove(&key).unwrap();
+                (key, expr)
+            })
+            .collect()
+    }
+
     pub fn keys(&self) -> Keys<DatasourceFieldName, WriteExpression> {
         self.args.keys()
     }
@@ -179,6 +221,35 @@ impl WriteArgs {
         }
     }
 
+    /// Sets the initial version of a created record if neither the input nor a default provides one.
+    pub fn add_version(&mut self, model: ModelRef) {
+        if let Some(field) = model.fields().version() {
+            if field.default_value.is_none() && self.args.get(field.db_name()).is_none() {
+                self.args.insert(field.into(), PrismaValue::Int(1).into());
+            }
+        }
+    }
+
+    /// Increments the version of the updated records, unless the version is explicitly written.
+    pub fn increment_version(&mut self, model: ModelRef) {
+        if !self.args.is_empty() {
+            if let Some(field) = model.fields().version() {
+                if self.args.get(field.db_name()).is_none() {
+                    self.args
+                        .insert(field.into(), WriteExpression::Add(PrismaValue::Int(1)));
+                }
+            }
+        }
+    }
+
+    /// Marks the written records as deleted by setting the soft delete field of the model to the current time.
+    pub fn mark_soft_deleted(&mut self, model: ModelRef) {
+        if let Some(field) = model.soft_delete_field() {
+            self.args
+                .insert((&field).into(), PrismaValue::DateTime(Utc::now().into()).into());
+        }
+    }
+
     pub fn as_record_projection(&self, model_projection: ModelProjection) -> Option<RecordProjection> {
         let pairs: Vec<_> = model_projection
             .scalar_fields()
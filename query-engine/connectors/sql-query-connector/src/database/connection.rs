@@ -2,17 +2,23 @@ use super::transaction::SqlConnectorTransaction;
 use crate::{database::operations::*, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationRow, AggregationSelection, Connection, QueryArguments,
+    self as connector, filter::Filter, AggregationRow, AggregationSelection, CancelToken, Connection, QueryArguments,
     ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
-use quaint::{connector::TransactionCapable, prelude::ConnectionInfo};
+use quaint::{
+    connector::TransactionCapable,
+    prelude::{ConnectionInfo, Queryable},
+    single::Quaint as SingleQuaint,
+};
 use std::future::Future;
 
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    /// Where statements of the connection are cancelled from, see `Connection::cancel_token`.
+    cancel_url: Option<String>,
 }
 
 impl<C> SqlConnection<C>
@@ -21,7 +27,17 @@ where
 {
     pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+        Self {
+            inner,
+            connection_info,
+            cancel_url: None,
+        }
+    }
+
+    /// Lets the statements of the connection be cancelled from connections to `url`.
+    pub fn cancellable_through(mut self, url: &str) -> Self {
+        self.cancel_url = Some(url.to_owned());
+        self
     }
 
     async fn catch<O>(
@@ -49,6 +65,57 @@ where
         })
         .await
     }
+
+    async fn cancel_token(&self) -> connector::Result<Option<Box<dyn CancelToken>>> {
+        let url = match &self.cancel_url {
+            Some(url) => url.clone(),
+            None => return Ok(None),
+        };
+
+        let (query, cancel): (&str, fn(u64) -> String) = match &self.connection_info {
+            ConnectionInfo::Postgres(_) => ("SELECT pg_backend_pid() AS id", |id| {
+                format!("SELECT pg_cancel_backend({})", id)
+            }),
+            ConnectionInfo::Mysql(_) => ("SELECT CAST(CONNECTION_ID() AS SIGNED) AS id", |id| {
+                format!("KILL QUERY {}", id)
+            }),
+            _ => return Ok(None),
+        };
+
+        self.catch(async move {
+            let result = self.inner.raw_json(query.to_owned(), Vec::new()).await?;
+
+            Ok(result[0]["id"].as_u64().map(|id| {
+                Box::new(SessionCancelToken {
+                    url,
+                    statement: cancel(id),
+                    connection_info: self.connection_info.clone(),
+                }) as Box<dyn CancelToken>
+            }))
+        })
+        .await
+    }
+}
+
+/// Cancels the statement of a database session with `statement`.
+struct SessionCancelToken {
+    url: String,
+    statement: String,
+    connection_info: ConnectionInfo,
+}
+
+#[async_trait]
+impl CancelToken for SessionCancelToken {
+    /// Runs on a connection of its own, the pool might be exhausted by the statement we're cancelling.
+    async fn cancel(&self) -> connector::Result<()> {
+        super::catch(&self.connection_info, async move {
+            let conn = SingleQuaint::new(&self.url).await.map_err(SqlError::from)?;
+            conn.raw_cmd(&self.statement).await.map_err(SqlError::from)?;
+
+            Ok(())
+        })
+        .await
+    }
 }
 
 #[async_trait]
@@ -23,6 +23,16 @@ pub trait FromSource {
         Self: Connector + Sized;
 }
 
+/// Timeouts while checking out a connection mean an exhausted pool, not an unreachable database.
+fn check_out_error(err: quaint::error::Error) -> crate::SqlError {
+    match crate::SqlError::from(err) {
+        crate::SqlError::ConnectionError(quaint::error::ErrorKind::Timeout(_)) => {
+            crate::SqlError::ConnectionPoolTimeout
+        }
+        err => err,
+    }
+}
+
 async fn catch<O>(
     connection_info: &quaint::prelude::ConnectionInfo,
     fut: impl std::future::Future<Output = Result<O, crate::SqlError>>,
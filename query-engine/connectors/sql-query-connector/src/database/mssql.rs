@@ -18,7 +18,7 @@ pub struct Mssql {
 #[async_trait]
 impl FromSource for Mssql {
     async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let database_str = &source.url().value;
+        let database_str = &source.connection_url();
 
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
@@ -46,7 +46,7 @@ impl FromSource for Mssql {
 impl Connector for Mssql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
+            let conn = self.pool.check_out().await.map_err(super::check_out_error)?;
             let conn = SqlConnection::new(conn, &self.connection_info);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
@@ -13,12 +13,13 @@ use std::time::Duration;
 pub struct Mysql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    url: String,
 }
 
 #[async_trait]
 impl FromSource for Mysql {
     async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let database_str = &source.url().value;
+        let database_str = &source.connection_url();
 
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
@@ -27,7 +28,7 @@ impl FromSource for Mysql {
             })
         })?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let mut builder = Quaint::builder(database_str)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -37,8 +38,13 @@ impl FromSource for Mysql {
 
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
+        let url = database_str.to_owned();
 
-        Ok(Mysql { pool, connection_info })
+        Ok(Mysql {
+            pool,
+            connection_info,
+            url,
+        })
     }
 }
 
@@ -46,8 +52,8 @@ impl FromSource for Mysql {
 impl Connector for Mysql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let conn = self.pool.check_out().await.map_err(super::check_out_error)?;
+            let conn = SqlConnection::new(conn, &self.connection_info).cancellable_through(&self.url);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
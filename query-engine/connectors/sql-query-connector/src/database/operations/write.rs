@@ -1,9 +1,9 @@
-use crate::{error::SqlError, query_builder::write, QueryExt};
+use crate::{error::SqlError, filter_conversion::AliasedCondition, query_builder::write, QueryExt};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
 use quaint::error::ErrorKind;
-use std::{collections::HashMap, convert::TryFrom};
+use std::{collections::HashMap, convert::TryFrom, sync::Arc};
 use user_facing_errors::query_engine::DatabaseConstraint;
 
 /// Create a single record to the database defined in `conn`, resulting into a
@@ -70,8 +70,11 @@ pub async fn update_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
     record_filter: RecordFilter,
-    args: WriteArgs,
+    mut args: WriteArgs,
 ) -> crate::Result<Vec<RecordProjection>> {
+    let version_guard = version_guard(model, &record_filter.filter);
+    let is_guarded = version_guard.is_some();
+
     let ids = conn.filter_selectors(model, record_filter).await?;
     let id_args = pick_args(&model.primary_identifier(), &args);
 
@@ -79,16 +82,146 @@ pub async fn update_records(
         return Ok(vec![]);
     }
 
+    let list_operations = args.take_list_operations();
+
     let updates = {
         let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
-        write::update_many(model, ids.as_slice(), args)?
+        let guard = version_guard.map(|filter| filter.aliased_cond(None));
+
+        write::update_many(model, ids.as_slice(), args, guard)?
     };
 
+    let is_guarded = is_guarded && !updates.is_empty();
+    let mut updated = 0;
+
     for update in updates {
-        conn.query(update).await?;
+        updated += conn.execute(update).await?;
+    }
+
+    // The version changed between selecting and updating the record.
+    if is_guarded && updated == 0 {
+        return Ok(vec![]);
+    }
+
+    // The update may have changed the ids of the records.
+    let ids = merge_write_args(ids, id_args);
+
+    if !list_operations.is_empty() {
+        update_scalar_lists(conn, model, &ids, list_operations).await?;
+    }
+
+    Ok(ids)
+}
+
+/// Applies the scalar list operations to the records with the given ids, in
+/// one statement per chunk of ids.
+async fn update_scalar_lists(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    ids: &[RecordProjection],
+    operations: Vec<(DatasourceFieldName, WriteExpression)>,
+) -> crate::Result<()> {
+    let scalar_fields = model.fields().scalar();
+    let operations: Vec<(ScalarFieldRef, WriteExpression)> = operations
+        .into_iter()
+        .map(|(DatasourceFieldName(name), expr)| {
+            let field = scalar_fields
+                .iter()
+                .find(|f| f.db_name() == &name)
+                .expect("Expected field to be valid");
+
+            (field.clone(), expr)
+        })
+        .collect();
+
+    let ids: Vec<&RecordProjection> = ids.iter().collect();
+    let mut updated = 0;
+
+    for (statement, params) in write::update_scalar_lists(model, &ids, &operations) {
+        updated += conn.execute_raw(&statement, &params).await?;
+    }
+
+    let sets_at_index = operations
+        .iter()
+        .any(|(_, expr)| matches!(expr, WriteExpression::SetAt(_, _)));
+
+    if sets_at_index && (updated as usize) < ids.len() {
+        return Err(list_index_out_of_range(conn, model, &ids, &operations).await?);
     }
 
-    Ok(merge_write_args(ids, id_args))
+    Ok(())
+}
+
+/// The error for a `setAt` index beyond the end of a list. The lists are only
+/// read when an update failed.
+async fn list_index_out_of_range(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    operations: &[(ScalarFieldRef, WriteExpression)],
+) -> crate::Result<SqlError> {
+    let set_at: Vec<(&ScalarFieldRef, i64)> = operations
+        .iter()
+        .filter_map(|(field, expr)| match expr {
+            WriteExpression::SetAt(index, _) => Some((field, *index)),
+            _ => None,
+        })
+        .collect();
+
+    let fields: Vec<ScalarFieldRef> = set_at.iter().map(|(field, _)| Arc::clone(field)).collect();
+    let model_id = model.primary_identifier();
+    let id_field_count = model_id.scalar_fields().count();
+
+    let idents: Vec<_> = model_id
+        .scalar_fields()
+        .chain(fields.iter().cloned())
+        .map(|field| field.type_identifier_with_arity())
+        .collect();
+
+    for select in write::select_scalar_lists(model, ids, &fields) {
+        for row in conn.filter(select, &idents).await? {
+            for ((field, index), list) in set_at.iter().zip(row.values.into_iter().skip(id_field_count)) {
+                let length = match list {
+                    PrismaValue::List(values) => values.len(),
+                    _ => 0,
+                };
+
+                if *index as usize >= length {
+                    return Ok(SqlError::ListIndexOutOfRange {
+                        field: field.name.clone(),
+                        index: *index,
+                        length,
+                    });
+                }
+            }
+        }
+    }
+
+    // The records were deleted concurrently.
+    Ok(SqlError::RecordDoesNotExist)
+}
+
+/// The condition on the version field of a unique selector, which guards
+/// updates and deletes against concurrent modifications of the record.
+fn version_guard(model: &ModelRef, filter: &Filter) -> Option<Filter> {
+    let version_field = model.fields().version().clone()?;
+
+    find_version_filter(filter, &version_field)
+}
+
+fn find_version_filter(filter: &Filter, version_field: &ScalarFieldRef) -> Option<Filter> {
+    match filter {
+        // Soft deletes nest the unique filter in another conjunction.
+        Filter::And(filters) => filters
+            .iter()
+            .find_map(|filter| find_version_filter(filter, version_field)),
+        Filter::Scalar(ScalarFilter {
+            projection: ScalarProjection::Single(field),
+            condition: ScalarCondition::Equals(_),
+            ..
+        }) if field == version_field => Some(filter.clone()),
+        _ => None,
+    }
 }
 
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
@@ -97,19 +230,22 @@ pub async fn delete_records(
     model: &ModelRef,
     record_filter: RecordFilter,
 ) -> crate::Result<usize> {
+    let version_guard = version_guard(model, &record_filter.filter);
     let ids = conn.filter_selectors(model, record_filter).await?;
     let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
-    let count = ids.len();
 
-    if count == 0 {
-        return Ok(count);
+    if ids.is_empty() {
+        return Ok(0);
     }
 
-    for delete in write::delete_many(model, ids.as_slice()) {
-        conn.query(delete).await?;
+    let guard = version_guard.map(|filter| filter.aliased_cond(None));
+    let mut count = 0;
+
+    for delete in write::delete_many(model, ids.as_slice(), guard) {
+        count += conn.execute(delete).await?;
     }
 
-    Ok(count)
+    Ok(count as usize)
 }
 
 /// Connect relations defined in `child_ids` to a parent defined in `parent_id`.
@@ -210,5 +346,9 @@ fn apply_expression(val: PrismaValue, expr: WriteExpression) -> PrismaValue {
         WriteExpression::Substract(rhs) => val - rhs,
         WriteExpression::Multiply(rhs) => val * rhs,
         WriteExpression::Divide(rhs) => val / rhs,
+        WriteExpression::Push(_)
+        | WriteExpression::Unshift(_)
+        | WriteExpression::RemoveAll(_)
+        | WriteExpression::SetAt(_, _) => unreachable!("Identifiers can't be scalar lists."),
     }
 }
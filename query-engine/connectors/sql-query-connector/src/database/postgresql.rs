@@ -8,16 +8,20 @@ use connector_interface::{
 use datamodel::Datasource;
 use quaint::{pooled::Quaint, prelude::ConnectionInfo};
 use std::time::Duration;
+use url::Url;
 
 pub struct PostgreSql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    /// Where statements are cancelled from, `None` behind PgBouncer: the server session of a client
+    /// changes between transactions, the statement of another client could be cancelled.
+    cancel_url: Option<String>,
 }
 
 #[async_trait]
 impl FromSource for PostgreSql {
     async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let database_str = &source.url().value;
+        let database_str = &source.connection_url();
 
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
@@ -26,7 +30,7 @@ impl FromSource for PostgreSql {
             })
         })?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let mut builder = Quaint::builder(database_str)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -36,7 +40,13 @@ impl FromSource for PostgreSql {
 
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
-        Ok(PostgreSql { pool, connection_info })
+        let cancel_url = Some(database_str.to_owned()).filter(|url| !uses_pgbouncer(url));
+
+        Ok(PostgreSql {
+            pool,
+            connection_info,
+            cancel_url,
+        })
     }
 }
 
@@ -44,8 +54,13 @@ impl FromSource for PostgreSql {
 impl Connector for PostgreSql {
     async fn get_connection<'a>(&'a self) -> connector_interface::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let conn = self.pool.check_out().await.map_err(super::check_out_error)?;
+            let mut conn = SqlConnection::new(conn, &self.connection_info);
+
+            if let Some(url) = &self.cancel_url {
+                conn = conn.cancellable_through(url);
+            }
+
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
         .await
@@ -55,3 +70,12 @@ impl Connector for PostgreSql {
         "postgres".to_owned()
     }
 }
+
+fn uses_pgbouncer(url: &str) -> bool {
+    Url::parse(url)
+        .map(|url| {
+            url.query_pairs()
+                .any(|(name, value)| name == "pgbouncer" && value == "true")
+        })
+        .unwrap_or(false)
+}
@@ -28,7 +28,7 @@ impl Sqlite {
 #[async_trait]
 impl FromSource for Sqlite {
     async fn from_source(source: &Datasource) -> connector_interface::Result<Sqlite> {
-        let database_str = &source.url().value;
+        let database_str = &source.connection_url();
 
         let connection_info = ConnectionInfo::from_url(database_str)
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
@@ -87,7 +87,7 @@ fn invalid_file_path_error(file_path: &str, connection_info: &ConnectionInfo) ->
 impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info(), async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
+            let conn = self.pool.check_out().await.map_err(super::check_out_error)?;
             let conn = SqlConnection::new(conn, self.connection_info());
 
             Ok(Box::new(conn) as Box<dyn Connection>)
@@ -1,9 +1,12 @@
 use crate::database::operations::*;
-use crate::SqlError;
+use crate::{QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationRow, AggregationSelection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector,
+    error::{ConnectorError, ErrorKind},
+    filter::Filter,
+    AggregationRow, AggregationSelection, QueryArguments, ReadOperations, RecordFilter, RequestContext, Transaction,
+    WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -45,6 +48,32 @@ impl<'tx> Transaction for SqlConnectorTransaction<'tx> {
         self.catch(async move { Ok(self.inner.rollback().await.map_err(SqlError::from)?) })
             .await
     }
+
+    /// The settings are transaction-local, as with `SET LOCAL`, so they can't leak
+    /// into later transactions on the pooled connection.
+    async fn set_context(&self, context: &RequestContext) -> connector::Result<()> {
+        let sql_family = self.connection_info.sql_family();
+
+        if !sql_family.is_postgres() {
+            return Err(ConnectorError::from_kind(ErrorKind::RequestContextNotSupported {
+                connector: sql_family.as_str().to_owned(),
+            }));
+        }
+
+        self.catch(async move {
+            for (key, value) in context.iter() {
+                self.inner
+                    .raw_count(
+                        "SELECT set_config($1, $2, true)".to_owned(),
+                        vec![PrismaValue::String(key.clone()), PrismaValue::String(value.clone())],
+                    )
+                    .await?;
+            }
+
+            Ok(())
+        })
+        .await
+    }
 }
 
 #[async_trait]
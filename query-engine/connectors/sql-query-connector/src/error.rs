@@ -61,6 +61,9 @@ pub enum SqlError {
     #[error("Error creating a database connection. ({})", _0)]
     ConnectionError(QuaintKind),
 
+    #[error("Timed out fetching a new connection from the connection pool.")]
+    ConnectionPoolTimeout,
+
     #[error("Error querying the database: {}", _0)]
     QueryError(Box<dyn std::error::Error + Send + Sync>),
 
@@ -105,6 +108,9 @@ pub enum SqlError {
     #[error("Conversion error: {0}")]
     ConversionError(anyhow::Error),
 
+    #[error("The index {} is out of range for the list `{}` of length {}.", index, field, length)]
+    ListIndexOutOfRange { field: String, index: i64, length: usize },
+
     #[error("Database error. error code: {}, error message: {}", code, message)]
     RawError { code: String, message: String },
 }
@@ -133,6 +139,7 @@ impl SqlError {
                 user_facing_error: user_facing_errors::quaint::render_quaint_error(&e, connection_info),
                 kind: ErrorKind::ConnectionError(e.into()),
             },
+            SqlError::ConnectionPoolTimeout => ConnectorError::from_kind(ErrorKind::ConnectionPoolTimeout),
             SqlError::ColumnReadFailure(e) => ConnectorError::from_kind(ErrorKind::ColumnReadFailure(e)),
             SqlError::FieldCannotBeNull { field } => ConnectorError::from_kind(ErrorKind::FieldCannotBeNull { field }),
             SqlError::DomainError(e) => ConnectorError::from_kind(ErrorKind::DomainError(e)),
@@ -158,6 +165,9 @@ impl SqlError {
                 child_name,
             }),
             SqlError::ConversionError(e) => ConnectorError::from_kind(ErrorKind::ConversionError(e)),
+            SqlError::ListIndexOutOfRange { field, index, length } => {
+                ConnectorError::from_kind(ErrorKind::ListIndexOutOfRange { field, index, length })
+            }
             SqlError::QueryError(e) => {
                 let quaint_error: Option<&QuaintKind> = e.downcast_ref();
                 match quaint_error {
@@ -116,6 +116,8 @@ impl AliasedCondition for Filter {
                 }
             },
             Filter::Scalar(filter) => filter.aliased_cond(alias),
+            Filter::ScalarList(filter) => filter.aliased_cond(alias),
+            Filter::Composite(filter) => filter.aliased_cond(alias),
             Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
             Filter::Relation(filter) => filter.aliased_cond(alias),
             Filter::BoolFilter(b) => {
@@ -126,7 +128,6 @@ impl AliasedCondition for Filter {
                 }
             }
             Filter::Empty => ConditionTree::NoCondition,
-            _ => unimplemented!(),
         }
     }
 }
@@ -169,6 +170,66 @@ impl AliasedCondition for ScalarFilter {
     }
 }
 
+impl AliasedCondition for ScalarListFilter {
+    /// Conversion from a `ScalarListFilter` to a query condition tree. Aliased when in a nested `SELECT`.
+    ///
+    /// Scalar lists are only supported on PostgreSQL, so the array operators are rendered as-is.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let column = match alias {
+            Some(alias) => self.field.as_column().table(alias.to_string(None)),
+            None => self.field.as_column(),
+        };
+
+        let empty = || Value::Array(Some(vec![]));
+
+        match self.condition {
+            ScalarListCondition::Contains(value) => {
+                ConditionTree::single(column.compare_raw("@>", Value::Array(Some(vec![self.field.value(value)]))))
+            }
+            ScalarListCondition::ContainsEvery(values) => {
+                ConditionTree::single(column.compare_raw("@>", self.field.value(PrismaValue::List(values))))
+            }
+            ScalarListCondition::ContainsSome(values) => {
+                ConditionTree::single(column.compare_raw("&&", self.field.value(PrismaValue::List(values))))
+            }
+            // `NULL` lists are empty, as with `COALESCE(cardinality(column), 0) = 0`.
+            ScalarListCondition::IsEmpty(true) => column.clone().is_null().or(column.equals(empty())).into(),
+            ScalarListCondition::IsEmpty(false) => column.clone().is_not_null().and(column.not_equals(empty())).into(),
+        }
+    }
+}
+
+impl AliasedCondition for CompositeFilter {
+    /// Conversion from a `CompositeFilter` to a query condition tree. Aliased when in a nested `SELECT`.
+    ///
+    /// Containment is rendered as `@>` on PostgreSQL and as `JSON_CONTAINS` on MySQL. Both match
+    /// nested partial documents recursively, so filters on nested composite fields need no path.
+    ///
+    /// Equality is containment in both directions: MySQL compares a Json column with a bound
+    /// document as a Json string, which never equals an object.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let column = match alias {
+            Some(alias) => self.field.as_column().table(alias.to_string(None)),
+            None => self.field.as_column(),
+        };
+
+        match self.condition {
+            CompositeCondition::Contains(document) => {
+                ConditionTree::single(column.json_array_contains(self.field.value(document)))
+            }
+            CompositeCondition::Equals(document) => {
+                let document = self.field.value(document);
+
+                column
+                    .clone()
+                    .json_array_contains(document.clone())
+                    .and(Expression::from(document).json_array_contains(column))
+                    .into()
+            }
+        }
+    }
+}
+
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
@@ -33,7 +33,14 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
     )
 }
 
-pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs) -> crate::Result<Vec<Query<'static>>> {
+/// `UPDATE` the records with the given ids. An optional `guard` condition is
+/// added to every statement, e.g. to only update records with an expected version.
+pub fn update_many(
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    args: WriteArgs,
+    guard: Option<ConditionTree<'static>>,
+) -> crate::Result<Vec<Query<'static>>> {
     if args.args.is_empty() || ids.is_empty() {
         return Ok(Vec::new());
     }
@@ -72,22 +79,147 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
                     let e: Expression<'_> = Column::from(name.clone()).into();
                     e / field.value(rhs).into()
                 }
+
+                WriteExpression::Push(_)
+                | WriteExpression::Unshift(_)
+                | WriteExpression::RemoveAll(_)
+                | WriteExpression::SetAt(_, _) => {
+                    unreachable!("Scalar list operations are applied by `update_scalar_lists`.")
+                }
             };
 
             acc.set(name, value)
         });
 
     let columns: Vec<_> = model.primary_identifier().as_columns().collect();
-    let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| query.clone().so_that(conditions));
+    let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| match guard {
+        Some(ref guard) => query.clone().so_that(conditions.and(guard.clone())),
+        None => query.clone().so_that(conditions),
+    });
 
     Ok(result)
 }
 
-pub fn delete_many(model: &ModelRef, ids: &[&RecordProjection]) -> Vec<Query<'static>> {
+/// `SELECT` the identifiers and the given scalar list fields of the records with the given ids.
+pub fn select_scalar_lists(
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    fields: &[ScalarFieldRef],
+) -> Vec<Query<'static>> {
+    let id_columns: Vec<_> = model.primary_identifier().as_columns().collect();
+    let columns: Vec<_> = id_columns
+        .iter()
+        .cloned()
+        .chain(fields.iter().map(|field| field.as_column()))
+        .collect();
+
+    super::chunked_conditions(&id_columns, ids, |conditions| {
+        Select::from_table(model.as_table())
+            .columns(columns.clone())
+            .so_that(conditions)
+    })
+}
+
+/// The `UPDATE` statements applying list operations to the records with the given ids, one statement per chunk of
+/// ids, e.g. `UPDATE "schema"."Post" SET "tags" = array_cat("tags", $1) WHERE ("id") IN (($2), ($3))`.
+///
+/// Scalar lists are only supported on PostgreSQL. The statements are rendered here with its array functions, which
+/// the query builder doesn't know. A `setAt` only updates the records whose list is long enough, so less updated
+/// records than ids means that an index was out of range.
+pub fn update_scalar_lists(
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    operations: &[(ScalarFieldRef, WriteExpression)],
+) -> Vec<(String, Vec<Value<'static>>)> {
+    let table = format!(
+        "{}.{}",
+        quote_identifier(&model.internal_data_model().db_name),
+        quote_identifier(model.db_name())
+    );
+
+    let id_columns: Vec<String> = model
+        .primary_identifier()
+        .scalar_fields()
+        .map(|field| quote_identifier(field.db_name()))
+        .collect();
+
+    ids.chunks(super::PARAMETER_LIMIT)
+        .map(|chunk| {
+            let mut params: Vec<Value<'static>> = Vec::new();
+            let mut param = |value: Value<'static>| {
+                params.push(value);
+                format!("${}", params.len())
+            };
+
+            let mut assignments = Vec::with_capacity(operations.len());
+            let mut conditions = Vec::new();
+
+            for (field, expr) in operations {
+                let column = quote_identifier(field.db_name());
+
+                let assignment = match expr {
+                    WriteExpression::Push(values) => {
+                        format!("{0} = array_cat({0}, {1})", column, param(field.value(values.clone())))
+                    }
+                    WriteExpression::Unshift(values) => {
+                        format!("{0} = array_cat({1}, {0})", column, param(field.value(values.clone())))
+                    }
+                    WriteExpression::RemoveAll(value) => {
+                        format!(
+                            "{0} = array_remove({0}, {1})",
+                            column,
+                            param(field.value(value.clone()))
+                        )
+                    }
+                    // Postgres arrays start at 1.
+                    WriteExpression::SetAt(index, value) => {
+                        conditions.push(format!("cardinality({}) > {}", column, index));
+                        format!("{}[{}] = {}", column, index + 1, param(field.value(value.clone())))
+                    }
+                    expr => unreachable!("{:?} is not a scalar list operation.", expr),
+                };
+
+                assignments.push(assignment);
+            }
+
+            let id_rows: Vec<String> = chunk
+                .iter()
+                .map(|id| {
+                    let values: Vec<String> = id.db_values().into_iter().map(&mut param).collect();
+                    format!("({})", values.join(", "))
+                })
+                .collect();
+
+            conditions.insert(0, format!("({}) IN ({})", id_columns.join(", "), id_rows.join(", ")));
+
+            let statement = format!(
+                "UPDATE {} SET {} WHERE {}",
+                table,
+                assignments.join(", "),
+                conditions.join(" AND ")
+            );
+
+            (statement, params)
+        })
+        .collect()
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+/// `DELETE` the records with the given ids. An optional `guard` condition is
+/// added to every statement, like in `update_many`.
+pub fn delete_many(
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    guard: Option<ConditionTree<'static>>,
+) -> Vec<Query<'static>> {
     let columns: Vec<_> = model.primary_identifier().as_columns().collect();
 
-    super::chunked_conditions(&columns, ids, |conditions| {
-        Delete::from_table(model.as_table()).so_that(conditions)
+    super::chunked_conditions(&columns, ids, |conditions| match guard {
+        Some(ref guard) => Delete::from_table(model.as_table()).so_that(conditions.and(guard.clone())),
+        None => Delete::from_table(model.as_table()).so_that(conditions),
     })
 }
 
@@ -0,0 +1,131 @@
+//! Change data capture: the records created, updated and deleted by write
+//! queries, published to subscribers once the writes are committed.
+use futures::channel::mpsc;
+use indexmap::IndexMap;
+use prisma_models::RecordProjection;
+use prisma_value::PrismaValue;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// The kind of change that happened to the records of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// Records of a model that changed in a write query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeEvent {
+    /// The name of the model.
+    pub model: String,
+    pub kind: ChangeKind,
+    /// The primary identifiers of the changed records, by field name.
+    pub ids: Vec<IndexMap<String, PrismaValue>>,
+}
+
+impl ChangeEvent {
+    pub fn new(model: String, kind: ChangeKind, ids: Vec<RecordProjection>) -> Self {
+        let ids = ids
+            .into_iter()
+            .map(|id| {
+                id.pairs
+                    .into_iter()
+                    .map(|(field, value)| (field.name.clone(), value))
+                    .collect()
+            })
+            .collect();
+
+        Self { model, kind, ids }
+    }
+}
+
+/// The number of events a subscriber can lag behind. Subscribers that fall
+/// further behind are dropped, so a slow client can't make the engine buffer
+/// events without limit.
+pub const SUBSCRIBER_CAPACITY: usize = 1024;
+
+/// The hub change events are published on. Cloning it is cheap, all clones
+/// share the same subscribers.
+///
+/// By default, events are delivered to the subscribers of the process. When a
+/// Postgres notification channel is configured, the executor sends events on
+/// the channel instead, and a listener is expected to feed the events back with
+/// `publish`, so changes made by other engine instances are delivered too.
+#[derive(Debug, Clone, Default)]
+pub struct ChangeEvents {
+    inner: Arc<ChangeEventsInner>,
+}
+
+#[derive(Debug, Default)]
+struct ChangeEventsInner {
+    subscribers: Mutex<Vec<mpsc::Sender<Arc<ChangeEvent>>>>,
+    notify_channel: Option<String>,
+}
+
+impl ChangeEvents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Change events sent with Postgres `NOTIFY` on the given channel.
+    pub fn with_notify_channel(channel: String) -> Self {
+        Self {
+            inner: Arc::new(ChangeEventsInner {
+                subscribers: Mutex::new(Vec::new()),
+                notify_channel: Some(channel),
+            }),
+        }
+    }
+
+    pub fn notify_channel(&self) -> Option<&str> {
+        self.inner.notify_channel.as_deref()
+    }
+
+    /// Whether write queries need to record their changes. Without a
+    /// notification channel, that is only the case if someone is listening.
+    pub fn is_recording(&self) -> bool {
+        self.notify_channel().is_some() || !self.inner.subscribers.lock().unwrap().is_empty()
+    }
+
+    /// Receive all events published from now on. Dropping the receiver
+    /// unsubscribes. The stream ends if the subscriber lags behind by more
+    /// than `SUBSCRIBER_CAPACITY` events.
+    pub fn subscribe(&self) -> mpsc::Receiver<Arc<ChangeEvent>> {
+        let (sender, receiver) = mpsc::channel(SUBSCRIBER_CAPACITY);
+        self.inner.subscribers.lock().unwrap().push(sender);
+
+        receiver
+    }
+
+    /// Deliver events to the subscribers.
+    pub fn publish(&self, events: Vec<ChangeEvent>) {
+        if events.is_empty() {
+            return;
+        }
+
+        let mut subscribers = self.inner.subscribers.lock().unwrap();
+
+        for event in events.into_iter().map(Arc::new) {
+            let current = std::mem::take(&mut *subscribers);
+
+            *subscribers = current
+                .into_iter()
+                .filter_map(|mut subscriber| match subscriber.try_send(Arc::clone(&event)) {
+                    Ok(()) => Some(subscriber),
+                    Err(err) if err.is_full() => {
+                        warn!(
+                            "Dropping a change event subscriber lagging behind by more than {} events.",
+                            SUBSCRIBER_CAPACITY
+                        );
+                        None
+                    }
+                    Err(_) => None,
+                })
+                .collect();
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
 };
 use connector::error::ConnectorError;
 use prisma_models::DomainError;
+use std::time::Duration;
 use thiserror::Error;
 
 // TODO: Cleanup unused errors after refactorings.
@@ -35,6 +36,9 @@ pub enum CoreError {
 
     #[error("{}", _0)]
     InterpreterError(InterpreterError),
+
+    #[error("Query timed out after {:?}", timeout)]
+    QueryTimeout { timeout: Duration },
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -134,6 +138,26 @@ impl From<CoreError> for user_facing_errors::Error {
                 details,
             })
             .into(),
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::VersionConflict {
+                model_name,
+                expected_version,
+            })
+            | CoreError::InterpreterError(InterpreterError::QueryGraphBuilderError(
+                QueryGraphBuilderError::VersionConflict {
+                    model_name,
+                    expected_version,
+                },
+            )) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::VersionConflict {
+                model_name,
+                expected_version,
+            })
+            .into(),
+            CoreError::QueryTimeout { timeout } => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::QueryTimeout {
+                    timeout: format!("{}ms", timeout.as_millis()),
+                })
+                .into()
+            }
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details }).into()
             }
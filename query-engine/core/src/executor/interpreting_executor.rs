@@ -1,8 +1,13 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use super::{pipeline::QueryPipeline, QueryExecutor, RequestContext};
+use crate::{
+    ChangeEvent, ChangeEvents, CoreError, Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData,
+};
 use async_trait::async_trait;
-use connector::{Connection, ConnectionLike, Connector};
+use connector::{CancelToken, Connection, ConnectionLike, Connector, Transaction, WriteOperations};
 use futures::future;
+use prisma_value::PrismaValue;
+use std::{future::Future, time::Duration};
+use tokio::time::Instant;
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
@@ -12,6 +17,9 @@ pub struct InterpretingExecutor<C> {
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Where the changes made by write queries are published.
+    change_events: ChangeEvents,
 }
 
 impl<C> InterpretingExecutor<C>
@@ -22,35 +30,130 @@ where
         InterpretingExecutor {
             connector,
             force_transactions,
+            change_events: ChangeEvents::new(),
+        }
+    }
+
+    pub fn with_change_events(mut self, change_events: ChangeEvents) -> Self {
+        self.change_events = change_events;
+        self
+    }
+
+    /// Publish the changes of a committed operation. With a notification
+    /// channel, they were already sent with `notify_changes`.
+    fn publish_changes(change_events: &ChangeEvents, changes: Vec<ChangeEvent>) {
+        if change_events.notify_channel().is_none() {
+            change_events.publish(changes);
+        }
+    }
+
+    /// Send the changes on the Postgres notification channel, if there is
+    /// one. Inside of a transaction, the notifications are only delivered on
+    /// commit. One notification is sent per record, to stay below the payload
+    /// size limit.
+    async fn notify_changes(
+        change_events: &ChangeEvents,
+        conn: &ConnectionLike<'_, '_>,
+        changes: &[ChangeEvent],
+    ) -> crate::Result<()> {
+        let channel = match change_events.notify_channel() {
+            Some(channel) => channel,
+            None => return Ok(()),
+        };
+
+        for change in changes {
+            for id in &change.ids {
+                let event = ChangeEvent {
+                    model: change.model.clone(),
+                    kind: change.kind,
+                    ids: vec![id.clone()],
+                };
+
+                let payload = serde_json::to_string(&event).expect("Failed to serialize a change event");
+
+                conn.execute_raw(
+                    "SELECT pg_notify($1, $2)".to_owned(),
+                    vec![PrismaValue::String(channel.to_owned()), PrismaValue::String(payload)],
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Starts a transaction and applies the request context to it, as the
+    /// settings of the context only last for the transaction.
+    async fn start_transaction<'a>(
+        conn: &'a dyn Connection,
+        context: &RequestContext,
+    ) -> crate::Result<Box<dyn Transaction + 'a>> {
+        let tx = conn.start_transaction().await?;
+
+        if !context.is_empty() {
+            if let Err(err) = tx.set_context(context).await {
+                tx.rollback().await?;
+                return Err(err.into());
+            }
         }
+
+        Ok(tx)
     }
 
     /// Async wrapper for executing an individual operation to allow code sharing with `execute_batch`.
+    /// Operations with a request context always run in a transaction.
     async fn execute_single_operation(
         operation: Operation,
         conn: Box<dyn Connection>,
         force_transactions: bool,
         query_schema: QuerySchemaRef,
+        change_events: ChangeEvents,
+        context: RequestContext,
+        deadline: Option<Deadline>,
     ) -> crate::Result<ResponseData> {
         // Parse, validate, and extract query graph from query document.
         let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
-        let is_transactional = force_transactions || query_graph.needs_transaction();
+        let cancel_token = Deadline::cancel_token(&deadline, conn.as_ref()).await?;
+        let is_transactional = force_transactions || query_graph.needs_transaction() || !context.is_empty();
+        let record_changes = change_events.is_recording();
 
         if is_transactional {
-            let tx = conn.start_transaction().await?;
-            let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-            let result = QueryPipeline::new(query_graph, interpreter, serializer).execute().await;
+            let tx = Self::start_transaction(conn.as_ref(), &context).await?;
+            let interpreter =
+                QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref())).recording_changes(record_changes);
+            let pipeline = QueryPipeline::new(query_graph, interpreter, serializer);
+            let mut result = Deadline::run(deadline, cancel_token, pipeline.execute_with_changes()).await;
 
-            if result.is_ok() {
-                tx.commit().await?;
-            } else {
-                tx.rollback().await?;
+            if let Ok((_, changes)) = &result {
+                if let Err(err) =
+                    Self::notify_changes(&change_events, &ConnectionLike::Transaction(tx.as_ref()), changes).await
+                {
+                    result = Err(err);
+                }
             }
 
-            result
+            match result {
+                Ok((response, changes)) => {
+                    tx.commit().await?;
+                    Self::publish_changes(&change_events, changes);
+
+                    Ok(response)
+                }
+                Err(err) => {
+                    tx.rollback().await?;
+                    Err(err)
+                }
+            }
         } else {
-            let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
-            QueryPipeline::new(query_graph, interpreter, serializer).execute().await
+            let interpreter =
+                QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref())).recording_changes(record_changes);
+            let pipeline = QueryPipeline::new(query_graph, interpreter, serializer);
+            let (response, changes) = Deadline::run(deadline, cancel_token, pipeline.execute_with_changes()).await?;
+
+            Self::notify_changes(&change_events, &ConnectionLike::Connection(conn.as_ref()), &changes).await?;
+            Self::publish_changes(&change_events, changes);
+
+            Ok(response)
         }
     }
 }
@@ -76,7 +179,11 @@ where
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        context: &RequestContext,
+        timeout: Option<Duration>,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
+        let deadline = timeout.map(Deadline::new);
+
         if transactional {
             let queries = operations
                 .into_iter()
@@ -84,32 +191,69 @@ where
                 .collect::<std::result::Result<Vec<_>, _>>()?;
 
             let conn = self.connector.get_connection().await?;
-            let tx = conn.start_transaction().await?;
-            let mut results = Vec::with_capacity(queries.len());
+            let cancel_token = Deadline::cancel_token(&deadline, conn.as_ref()).await?;
 
-            for (query, info) in queries {
-                let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-                let result = QueryPipeline::new(query, interpreter, info).execute().await;
+            let tx = Self::start_transaction(conn.as_ref(), context).await?;
+            let record_changes = self.change_events.is_recording();
 
-                if result.is_err() {
-                    tx.rollback().await?;
+            let batch = async {
+                let mut results = Vec::with_capacity(queries.len());
+                let mut batch_changes = Vec::new();
+
+                for (query, info) in queries {
+                    let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()))
+                        .recording_changes(record_changes);
+                    let (response, changes) = QueryPipeline::new(query, interpreter, info)
+                        .execute_with_changes()
+                        .await?;
+
+                    batch_changes.extend(changes);
+                    results.push(Ok(response));
                 }
 
-                results.push(Ok(result?));
+                Ok((results, batch_changes))
+            };
+
+            let mut result = Deadline::run(deadline, cancel_token, batch).await;
+
+            if let Ok((_, batch_changes)) = &result {
+                if let Err(err) = Self::notify_changes(
+                    &self.change_events,
+                    &ConnectionLike::Transaction(tx.as_ref()),
+                    batch_changes,
+                )
+                .await
+                {
+                    result = Err(err);
+                }
             }
 
-            tx.commit().await?;
-            Ok(results)
+            match result {
+                Ok((results, batch_changes)) => {
+                    tx.commit().await?;
+                    Self::publish_changes(&self.change_events, batch_changes);
+
+                    Ok(results)
+                }
+                Err(err) => {
+                    tx.rollback().await?;
+                    Err(err)
+                }
+            }
         } else {
             let mut futures = Vec::with_capacity(operations.len());
 
             for operation in operations {
                 let conn = self.connector.get_connection().await?;
+
                 futures.push(tokio::spawn(Self::execute_single_operation(
                     operation,
                     conn,
                     self.force_transactions,
                     query_schema.clone(),
+                    self.change_events.clone(),
+                    context.clone(),
+                    deadline,
                 )));
             }
 
@@ -124,12 +268,89 @@ where
     }
 
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        context: &RequestContext,
+        timeout: Option<Duration>,
+    ) -> crate::Result<ResponseData> {
         let conn = self.connector.get_connection().await?;
-        Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
+
+        Self::execute_single_operation(
+            operation,
+            conn,
+            self.force_transactions,
+            query_schema.clone(),
+            self.change_events.clone(),
+            context.clone(),
+            timeout.map(Deadline::new),
+        )
+        .await
     }
 
     fn primary_connector(&self) -> &dyn Connector {
         &self.connector
     }
+
+    fn change_events(&self) -> &ChangeEvents {
+        &self.change_events
+    }
+}
+
+/// The time limit of a request, shared by the operations of a batch.
+#[derive(Clone, Copy)]
+struct Deadline {
+    timeout: Duration,
+    at: Instant,
+}
+
+impl Deadline {
+    fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            at: Instant::now() + timeout,
+        }
+    }
+
+    /// Captures the cancel token of the connection of an operation before the operation runs on it, if the
+    /// request has a deadline.
+    async fn cancel_token(
+        deadline: &Option<Deadline>,
+        conn: &dyn Connection,
+    ) -> crate::Result<Option<Box<dyn CancelToken>>> {
+        match deadline {
+            Some(_) => Ok(conn.cancel_token().await?),
+            None => Ok(None),
+        }
+    }
+
+    /// Runs `fut` until the deadline passes. Then the running statement is cancelled and `fut` dropped. The
+    /// operation still holds its connection at that point, it's only released after rolling back, so the token
+    /// can't cancel the statement of an operation the connection was handed to next.
+    async fn run<T>(
+        deadline: Option<Deadline>,
+        cancel_token: Option<Box<dyn CancelToken>>,
+        fut: impl Future<Output = crate::Result<T>>,
+    ) -> crate::Result<T> {
+        let deadline = match deadline {
+            Some(deadline) => deadline,
+            None => return fut.await,
+        };
+
+        match tokio::time::timeout_at(deadline.at, fut).await {
+            Ok(result) => result,
+            Err(_) => {
+                if let Some(cancel_token) = cancel_token {
+                    if let Err(err) = cancel_token.cancel().await {
+                        tracing::warn!("Failed to cancel a timed out query: {}", err);
+                    }
+                }
+
+                Err(CoreError::QueryTimeout {
+                    timeout: deadline.timeout,
+                })
+            }
+        }
+    }
 }
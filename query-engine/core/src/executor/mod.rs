@@ -8,16 +8,26 @@
 mod interpreting_executor;
 mod pipeline;
 
+pub use connector::RequestContext;
 pub use interpreting_executor::*;
 
-use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
+use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef, ChangeEvents};
 use async_trait::async_trait;
 use connector::Connector;
+use std::time::Duration;
 
 #[async_trait]
 pub trait QueryExecutor {
     /// Executes a single operation and returns its result.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData>;
+    /// A non-empty `context` is applied to the database session of the operation.
+    /// Operations still running after `timeout` are cancelled.
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        context: &RequestContext,
+        timeout: Option<Duration>,
+    ) -> crate::Result<ResponseData>;
 
     // Executes a batch of operations as either a fanout of individual operations (non-transactional), or in series (transactional).
     async fn execute_batch(
@@ -25,7 +35,12 @@ pub trait QueryExecutor {
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        context: &RequestContext,
+        timeout: Option<Duration>,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>>;
 
     fn primary_connector(&self) -> &dyn Connector;
+
+    /// The hub the changes made by write queries are published on.
+    fn change_events(&self) -> &ChangeEvents;
 }
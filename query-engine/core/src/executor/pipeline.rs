@@ -1,4 +1,4 @@
-use crate::{Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, ResponseData};
+use crate::{ChangeEvent, Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, ResponseData};
 
 pub struct QueryPipeline<'conn, 'tx> {
     graph: QueryGraph,
@@ -16,11 +16,19 @@ impl<'conn, 'tx> QueryPipeline<'conn, 'tx> {
     }
 
     pub async fn execute(self) -> crate::Result<ResponseData> {
+        self.execute_with_changes().await.map(|(response, _)| response)
+    }
+
+    /// Executes the query graph, also returning the changes recorded by the
+    /// interpreter.
+    pub async fn execute_with_changes(self) -> crate::Result<(ResponseData, Vec<ChangeEvent>)> {
         let serializer = self.serializer;
         let expr = Expressionista::translate(self.graph)?;
         let result = self.interpreter.interpret(expr, Env::default(), 0).await;
 
         trace!("{}", self.interpreter.log_output());
-        serializer.serialize(result?)
+        let changes = self.interpreter.take_changes();
+
+        Ok((serializer.serialize(result?)?, changes))
     }
 }
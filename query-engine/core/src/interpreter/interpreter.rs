@@ -3,7 +3,7 @@ use super::{
     query_interpreters::{read, write},
     InterpretationResult, InterpreterError,
 };
-use crate::{Query, QueryResult};
+use crate::{ChangeEvent, Query, QueryResult};
 use connector::ConnectionLike;
 use crossbeam_queue::SegQueue;
 use futures::future::{BoxFuture, FutureExt};
@@ -123,6 +123,8 @@ impl Env {
 pub struct QueryInterpreter<'conn, 'tx> {
     pub(crate) conn: ConnectionLike<'conn, 'tx>,
     log: SegQueue<String>,
+    /// The records changed by write queries, only recorded if requested.
+    changes: Option<SegQueue<ChangeEvent>>,
 }
 
 impl<'conn, 'tx> QueryInterpreter<'conn, 'tx>
@@ -140,7 +142,31 @@ where
             log.push("\n".to_string());
         }
 
-        Self { conn, log }
+        Self {
+            conn,
+            log,
+            changes: None,
+        }
+    }
+
+    /// Record the changes made by `CreateRecord`, `UpdateRecord` and
+    /// `DeleteRecord` queries, see `take_changes`.
+    pub fn recording_changes(mut self, enabled: bool) -> Self {
+        self.changes = if enabled { Some(SegQueue::new()) } else { None };
+        self
+    }
+
+    /// The changes recorded so far, in the order they were made.
+    pub fn take_changes(&self) -> Vec<ChangeEvent> {
+        let mut changes = Vec::new();
+
+        if let Some(queue) = &self.changes {
+            while let Ok(change) = queue.pop() {
+                changes.push(change);
+            }
+        }
+
+        changes
     }
 
     pub fn interpret(
@@ -215,7 +241,9 @@ where
 
                         Query::Write(write) => {
                             self.log_line(level, || format!("WRITE {}", write));
-                            Ok(write::execute(&self.conn, write).await.map(ExpressionResult::Query)?)
+                            Ok(write::execute(&self.conn, write, self.changes.as_ref())
+                                .await
+                                .map(ExpressionResult::Query)?)
                         }
                     }
                 };
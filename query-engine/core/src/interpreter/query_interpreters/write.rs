@@ -1,19 +1,22 @@
 use crate::{
     interpreter::{InterpretationResult, InterpreterError},
     query_ast::*,
-    QueryResult,
+    ChangeEvent, ChangeKind, QueryResult,
 };
-use connector::{ConnectionLike, WriteOperations};
+use connector::{ConnectionLike, QueryArguments, ReadOperations, WriteOperations};
+use crossbeam_queue::SegQueue;
+use prisma_models::RecordProjection;
 use prisma_value::PrismaValue;
 
 pub async fn execute<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     write_query: WriteQuery,
+    changes: Option<&SegQueue<ChangeEvent>>,
 ) -> InterpretationResult<QueryResult> {
     match write_query {
-        WriteQuery::CreateRecord(q) => create_one(tx, q).await,
-        WriteQuery::UpdateRecord(q) => update_one(tx, q).await,
-        WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
+        WriteQuery::CreateRecord(q) => create_one(tx, q, changes).await,
+        WriteQuery::UpdateRecord(q) => update_one(tx, q, changes).await,
+        WriteQuery::DeleteRecord(q) => delete_one(tx, q, changes).await,
         WriteQuery::UpdateManyRecords(q) => update_many(tx, q).await,
         WriteQuery::DeleteManyRecords(q) => delete_many(tx, q).await,
         WriteQuery::ConnectRecords(q) => connect(tx, q).await,
@@ -43,19 +46,41 @@ async fn execute_raw<'a, 'b>(
     Ok(QueryResult::Json(num))
 }
 
-async fn create_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: CreateRecord) -> InterpretationResult<QueryResult> {
+fn record_change(changes: Option<&SegQueue<ChangeEvent>>, model: &str, kind: ChangeKind, ids: Vec<RecordProjection>) {
+    if let Some(changes) = changes {
+        if !ids.is_empty() {
+            changes.push(ChangeEvent::new(model.to_owned(), kind, ids));
+        }
+    }
+}
+
+async fn create_one<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: CreateRecord,
+    changes: Option<&SegQueue<ChangeEvent>>,
+) -> InterpretationResult<QueryResult> {
     let res = tx.create_record(&q.model, q.args).await?;
+    record_change(changes, &q.model.name, ChangeKind::Created, vec![res.clone()]);
 
     Ok(QueryResult::Id(Some(res)))
 }
 
-async fn update_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: UpdateRecord) -> InterpretationResult<QueryResult> {
+async fn update_one<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: UpdateRecord,
+    changes: Option<&SegQueue<ChangeEvent>>,
+) -> InterpretationResult<QueryResult> {
     let mut res = tx.update_records(&q.model, q.record_filter, q.args).await?;
+    record_change(changes, &q.model.name, ChangeKind::Updated, res.clone());
 
     Ok(QueryResult::Id(res.pop()))
 }
 
-async fn delete_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: DeleteRecord) -> InterpretationResult<QueryResult> {
+async fn delete_one<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: DeleteRecord,
+    changes: Option<&SegQueue<ChangeEvent>>,
+) -> InterpretationResult<QueryResult> {
     // We need to ensure that we have a record finder, else we delete everything (conversion to empty filter).
     let filter = match q.record_filter {
         Some(f) => Ok(f),
@@ -65,8 +90,27 @@ async fn delete_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: DeleteRecord) ->
         )),
     }?;
 
+    // The identifiers of deleted records have to be read beforehand.
+    let deleted_ids = match (changes, &filter.selectors) {
+        (None, _) => Vec::new(),
+        (Some(_), Some(selectors)) => selectors.clone(),
+        (Some(_), None) => {
+            let id_projection = q.model.primary_identifier();
+            let args = QueryArguments::from((q.model.clone(), filter.filter.clone()));
+
+            tx.get_many_records(&q.model, args, &id_projection)
+                .await?
+                .projections(&id_projection)?
+        }
+    };
+
     let res = tx.delete_records(&q.model, filter).await?;
 
+    // A filter that matched nothing deleted nothing, whatever the selectors said.
+    if res > 0 {
+        record_change(changes, &q.model.name, ChangeKind::Deleted, deleted_ids);
+    }
+
     Ok(QueryResult::Count(res))
 }
 
@@ -3,6 +3,7 @@
 #[macro_use]
 extern crate tracing;
 
+pub mod change_events;
 pub mod error;
 pub mod executor;
 pub mod interpreter;
@@ -15,6 +16,7 @@ pub mod result_ast;
 pub mod schema;
 pub mod schema_builder;
 
+pub use change_events::*;
 pub use error::*;
 pub use executor::*;
 pub use interpreter::*;
@@ -238,3 +238,48 @@ impl TryInto<bool> for ParsedInputValue {
         }
     }
 }
+
+/// Composite values are passed as maps and stored as Json documents.
+impl TryInto<serde_json::Value> for ParsedInputValue {
+    type Error = QueryParserError;
+
+    fn try_into(self) -> QueryParserResult<serde_json::Value> {
+        match self {
+            ParsedInputValue::Map(map) => map
+                .into_iter()
+                .map(|(key, value)| value.try_into().map(|value| (key, value)))
+                .collect::<QueryParserResult<serde_json::Map<String, serde_json::Value>>>()
+                .map(serde_json::Value::Object),
+
+            ParsedInputValue::List(values) => values
+                .into_iter()
+                .map(|value| value.try_into())
+                .collect::<QueryParserResult<Vec<serde_json::Value>>>()
+                .map(serde_json::Value::Array),
+
+            // Json values are nested as documents, not as strings.
+            ParsedInputValue::Single(PrismaValue::Json(s)) => {
+                serde_json::from_str(&s).map_err(|err| QueryParserError {
+                    path: QueryPath::default(),
+                    error_kind: QueryParserErrorKind::AssertionError(format!("Invalid Json value ({}): {}", s, err)),
+                })
+            }
+
+            ParsedInputValue::Single(value) => serde_json::to_value(&value).map_err(|err| QueryParserError {
+                path: QueryPath::default(),
+                error_kind: QueryParserErrorKind::AssertionError(format!(
+                    "Attempted conversion of Prisma value ({:?}) into Json failed: {}",
+                    value, err
+                )),
+            }),
+
+            v => Err(QueryParserError {
+                path: QueryPath::default(),
+                error_kind: QueryParserErrorKind::AssertionError(format!(
+                    "Attempted conversion of ParsedInputValue ({:?}) into Json failed.",
+                    v
+                )),
+            }),
+        }
+    }
+}
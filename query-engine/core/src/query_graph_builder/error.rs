@@ -39,6 +39,12 @@ pub enum QueryGraphBuilderError {
 
     RecordNotFound(String),
 
+    /// The record selected for an update exists, but its version field doesn't hold the expected version.
+    VersionConflict {
+        model_name: String,
+        expected_version: String,
+    },
+
     QueryGraphError(QueryGraphError),
 }
 
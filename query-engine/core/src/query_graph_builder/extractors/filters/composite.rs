@@ -0,0 +1,56 @@
+use crate::{
+    schema_builder::{composite_filter_shorthand_is_unambiguous, COMPOSITE_FILTER_OPERATIONS},
+    ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
+};
+use connector::{CompositeCompare, Filter, ScalarCompare};
+use prisma_models::{PrismaValue, ScalarFieldRef};
+use std::convert::TryInto;
+
+/// Whether `input` is a full composite filter object rather than a partial composite value.
+/// Composite values are matched by their fields, so an object is a filter object when all of
+/// its keys are filter operations. Composite types with fields named like filter operations
+/// have no partial value shorthand, their objects are always filter objects.
+pub fn is_filter_object(field: &ScalarFieldRef, input: &ParsedInputMap) -> bool {
+    let shorthand_is_unambiguous = field
+        .composite_type()
+        .map(|composite_type| composite_filter_shorthand_is_unambiguous(&composite_type))
+        .unwrap_or(false);
+
+    !shorthand_is_unambiguous
+        || (!input.is_empty()
+            && input
+                .keys()
+                .all(|key| COMPOSITE_FILTER_OPERATIONS.contains(&key.as_str())))
+}
+
+pub fn parse(filter_key: &str, field: &ScalarFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    let filter = match (filter_key, input) {
+        ("equals", ParsedInputValue::Single(PrismaValue::Null)) => field.equals(PrismaValue::Null),
+        ("equals", input @ ParsedInputValue::Map(_)) => field.composite_equals(document(input)?),
+
+        ("is", ParsedInputValue::Single(PrismaValue::Null)) => field.equals(PrismaValue::Null),
+        ("is", input @ ParsedInputValue::Map(_)) => contains(field, input)?,
+
+        ("isNot", ParsedInputValue::Single(PrismaValue::Null)) => field.not_equals(PrismaValue::Null),
+        ("isNot", input @ ParsedInputValue::Map(_)) => Filter::not(vec![contains(field, input)?]),
+
+        (key, input) => {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "{} is not a valid composite filter operation for {:?}",
+                key, input
+            )))
+        }
+    };
+
+    Ok(filter)
+}
+
+/// Matches composite values containing the given partial document.
+pub fn contains(field: &ScalarFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    Ok(field.composite_contains(document(input)?))
+}
+
+fn document(input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
+    let document: serde_json::Value = input.try_into()?;
+    Ok(PrismaValue::Json(document.to_string()))
+}
@@ -1,6 +1,8 @@
+mod composite;
 mod filter_grouping;
 mod relation;
 mod scalar;
+mod scalar_list;
 
 use super::utils;
 use crate::{
@@ -13,7 +15,20 @@ use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldR
 use std::{convert::TryInto, str::FromStr};
 
 /// Extracts a filter for a unique selector, i.e. a filter that selects exactly one record.
+/// The version field of the model may be given in addition to the unique criterion.
 pub fn extract_unique_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraphBuilderResult<Filter> {
+    if let Some(version_field) = model.fields().version() {
+        let has_version = value_map.contains_key(&version_field.name);
+        let num_criteria = value_map.len() - if has_version { 1 } else { 0 };
+
+        if num_criteria != 1 {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Exactly one unique criterion is required to select a `{}` record, optionally together with its version field `{}`.",
+                model.name, version_field.name
+            )));
+        }
+    }
+
     let filters = value_map
         .into_iter()
         .map(|(field_name, value): (String, ParsedInputValue)| {
@@ -36,6 +51,27 @@ pub fn extract_unique_filter(value_map: ParsedInputMap, model: &ModelRef) -> Que
     Ok(Filter::and(filters))
 }
 
+/// Restricts the given filter to records that are not soft deleted, if the model is in soft delete mode.
+pub fn exclude_soft_deleted(filter: Filter, model: &ModelRef) -> Filter {
+    match model.soft_delete_field() {
+        Some(field) => match filter {
+            Filter::Empty => field.equals(PrismaValue::Null),
+            filter => Filter::and(vec![filter, field.equals(PrismaValue::Null)]),
+        },
+        None => filter,
+    }
+}
+
+/// Soft deleted records do not count against an `every` relation filter.
+fn every_not_soft_deleted(filter: Filter, model: &ModelRef) -> Filter {
+    match (model.soft_delete_field(), filter) {
+        (Some(field), filter) if !matches!(filter, Filter::Empty) => {
+            Filter::or(vec![field.not_equals(PrismaValue::Null), filter])
+        }
+        (_, filter) => filter,
+    }
+}
+
 fn handle_compound_field(fields: Vec<ScalarFieldRef>, value: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
     let mut input_map: ParsedInputMap = value.try_into()?;
 
@@ -113,6 +149,7 @@ pub fn extract_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraph
                     Err(_) => {
                         let filters = match model.fields().find_from_all(&key)? {
                             Field::Relation(rf) => extract_relation_filters(rf, value),
+                            Field::Scalar(sf) if sf.is_composite() => extract_composite_filters(sf, value),
                             Field::Scalar(sf) => extract_scalar_filters(sf, value),
                         }?;
 
@@ -147,6 +184,10 @@ pub fn extract_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraph
 fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
     match value {
         ParsedInputValue::Single(pv) => Ok(vec![field.equals(pv)]),
+        ParsedInputValue::Map(filter_map) if field.is_list => filter_map
+            .into_iter()
+            .map(|(k, v)| scalar_list::parse(&k, field, v))
+            .collect::<QueryGraphBuilderResult<Vec<_>>>(),
         ParsedInputValue::Map(mut filter_map) => {
             let mode = match filter_map.remove("mode") {
                 Some(i) => parse_query_mode(i)?,
@@ -187,7 +228,10 @@ fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -
                 .map(|(k, v)| relation::parse(&k, field, v))
                 .collect::<QueryGraphBuilderResult<Vec<_>>>()
                 .or_else(|_| {
-                    extract_filter(filter_map, &field.related_model()).map(|filter| vec![field.to_one_related(filter)])
+                    let related_model = field.related_model();
+
+                    extract_filter(filter_map, &related_model)
+                        .map(|filter| vec![field.to_one_related(exclude_soft_deleted(filter, &related_model))])
                 })
         }
 
@@ -198,6 +242,29 @@ fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -
     }
 }
 
+/// Field is the field the filter is refering to and `value` is the passed filter. E.g. `where: { <field>: <value> }.
+/// `value` can be either a partial composite value (implicit `is`) or an object (full filter syntax).
+fn extract_composite_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
+    match value {
+        // Implicit null equality filter (`where: { <field>: null }`)
+        ParsedInputValue::Single(PrismaValue::Null) => Ok(vec![field.equals(PrismaValue::Null)]),
+
+        // Full filter object (`where: { <field>: { equals: ..., is: ..., isNot: ... } }`)
+        ParsedInputValue::Map(filter_map) if composite::is_filter_object(field, &filter_map) => filter_map
+            .into_iter()
+            .map(|(k, v)| composite::parse(&k, field, v))
+            .collect::<QueryGraphBuilderResult<Vec<_>>>(),
+
+        // Implicit `is` with a partial composite value (`where: { <field>: { <composite field>: ... } }`)
+        ParsedInputValue::Map(filter_map) => Ok(vec![composite::contains(field, ParsedInputValue::Map(filter_map))?]),
+
+        x => Err(QueryGraphBuilderError::InputError(format!(
+            "Invalid composite filter input: {:?}",
+            x
+        ))),
+    }
+}
+
 fn parse_query_mode(input: ParsedInputValue) -> QueryGraphBuilderResult<QueryMode> {
     let value: PrismaValue = input.try_into()?;
     let s = match value {
@@ -1,4 +1,4 @@
-use super::extract_filter;
+use super::{every_not_soft_deleted, exclude_soft_deleted, extract_filter};
 use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
 use connector::{Filter, RelationCompare};
 use prisma_models::RelationFieldRef;
@@ -6,17 +6,33 @@ use std::convert::TryInto;
 
 pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
     let value: Option<ParsedInputMap> = input.try_into()?;
+    let related_model = field.related_model();
 
     match (filter_key, value) {
         // Relation list filters
-        ("some", Some(value)) => Ok(field.at_least_one_related(extract_filter(value, &field.related_model())?)),
-        ("none", Some(value)) => Ok(field.no_related(extract_filter(value, &field.related_model())?)),
-        ("every", Some(value)) => Ok(field.every_related(extract_filter(value, &field.related_model())?)),
+        ("some", Some(value)) => Ok(field.at_least_one_related(exclude_soft_deleted(
+            extract_filter(value, &related_model)?,
+            &related_model,
+        ))),
+        ("none", Some(value)) => Ok(field.no_related(exclude_soft_deleted(
+            extract_filter(value, &related_model)?,
+            &related_model,
+        ))),
+        ("every", Some(value)) => Ok(field.every_related(every_not_soft_deleted(
+            extract_filter(value, &related_model)?,
+            &related_model,
+        ))),
 
         // One-relation filters
-        ("is", Some(value)) => Ok(field.to_one_related(extract_filter(value, &field.related_model())?)),
+        ("is", Some(value)) => Ok(field.to_one_related(exclude_soft_deleted(
+            extract_filter(value, &related_model)?,
+            &related_model,
+        ))),
         ("is", None) => Ok(field.one_relation_is_null()),
-        ("isNot", Some(value)) => Ok(field.no_related(extract_filter(value, &field.related_model())?)),
+        ("isNot", Some(value)) => Ok(field.no_related(exclude_soft_deleted(
+            extract_filter(value, &related_model)?,
+            &related_model,
+        ))),
         ("isNot", None) => Ok(Filter::not(vec![field.one_relation_is_null()])),
 
         _ => Err(QueryGraphBuilderError::InputError(format!(
@@ -0,0 +1,25 @@
+use crate::{ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
+use connector::{Filter, ScalarCompare, ScalarListCompare};
+use prisma_models::{PrismaValue, ScalarFieldRef};
+use std::convert::TryInto;
+
+pub fn parse(filter_key: &str, field: &ScalarFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    let value: PrismaValue = input.try_into()?;
+
+    let filter = match (filter_key, value) {
+        ("equals", value) => field.equals(value),
+        ("has", value) => field.contains_element(value),
+        ("hasEvery", PrismaValue::List(values)) => field.contains_every_element(values),
+        ("hasSome", PrismaValue::List(values)) => field.contains_some_element(values),
+        ("isEmpty", PrismaValue::Boolean(empty)) => field.is_empty_list(empty),
+
+        (key, value) => {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "{} is not a valid scalar list filter operation for {:?}",
+                key, value
+            )))
+        }
+    };
+
+    Ok(filter)
+}
@@ -3,9 +3,10 @@ use crate::{
     query_document::{ParsedArgument, ParsedInputMap},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::QueryArguments;
+use connector::{Filter, QueryArguments};
 use prisma_models::{
-    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder,
+    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, RelationFieldRef, ScalarFieldRef,
+    SortOrder,
 };
 use std::convert::{identity, TryInto};
 
@@ -13,7 +14,27 @@ use std::convert::{identity, TryInto};
 /// e.g. that the query schema guarantees that required fields are present.
 /// Errors occur if conversions fail.
 pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> QueryGraphBuilderResult<QueryArguments> {
-    let query_args = arguments.into_iter().fold(
+    let with_deleted = with_deleted(&arguments);
+    let query_args = fold_query_args(arguments, model)?;
+
+    Ok(finalize_arguments(query_args, model, with_deleted))
+}
+
+/// Extracts the query arguments of the records related through `parent_field`, see `extract_query_args`.
+/// A required to-one relation always resolves to its record, even if the record is soft deleted.
+pub fn extract_related_query_args(
+    arguments: Vec<ParsedArgument>,
+    model: &ModelRef,
+    parent_field: &RelationFieldRef,
+) -> QueryGraphBuilderResult<QueryArguments> {
+    let with_deleted = with_deleted(&arguments) || (parent_field.is_required && !parent_field.is_list);
+    let query_args = fold_query_args(arguments, model)?;
+
+    Ok(finalize_arguments(query_args, model, with_deleted))
+}
+
+fn fold_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> QueryGraphBuilderResult<QueryArguments> {
+    arguments.into_iter().fold(
         Ok(QueryArguments::new(model.clone())),
         |result: QueryGraphBuilderResult<QueryArguments>, arg| {
             if let Ok(res) = result {
@@ -60,9 +81,14 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                 result
             }
         },
-    )?;
+    )
+}
 
-    Ok(finalize_arguments(query_args, model))
+/// Whether or not soft deleted records have been explicitly requested with `withDeleted: true`.
+pub fn with_deleted(arguments: &[ParsedArgument]) -> bool {
+    arguments.iter().any(|arg| {
+        arg.name == "withDeleted" && matches!(arg.value, ParsedInputValue::Single(PrismaValue::Boolean(true)))
+    })
 }
 
 /// Extracts order by conditions in order of appearance, as defined in
@@ -195,7 +221,7 @@ fn extract_compound_cursor_field(
 }
 
 /// Runs final transformations on the QueryArguments.
-fn finalize_arguments(mut args: QueryArguments, model: &ModelRef) -> QueryArguments {
+fn finalize_arguments(mut args: QueryArguments, model: &ModelRef, with_deleted: bool) -> QueryArguments {
     // Check if the query requires an implicit ordering added to the arguments.
     // An implicit ordering is convenient for deterministic results for take and skip, for cursor it's _required_
     // as a cursor needs a direction to page. We simply take the primary identifier as a default order-by.
@@ -213,5 +239,11 @@ fn finalize_arguments(mut args: QueryArguments, model: &ModelRef) -> QueryArgume
         args.order_by.extend(order_bys);
     }
 
+    // Soft deleted records are hidden unless explicitly requested.
+    if !with_deleted && model.soft_delete_field().is_some() {
+        let filter = args.filter.take().unwrap_or(Filter::Empty);
+        args.filter = Some(exclude_soft_deleted(filter, model));
+    }
+
     args
 }
@@ -5,10 +5,17 @@ use std::convert::TryInto;
 
 /// Builds a read query from a parsed incoming read query field.
 pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    let with_deleted = extractors::with_deleted(&field.arguments);
     let filter = match field.arguments.lookup("where") {
         Some(where_arg) => {
             let arg: ParsedInputMap = where_arg.value.try_into()?;
-            Some(extractors::extract_unique_filter(arg, &model)?)
+            let filter = extractors::extract_unique_filter(arg, &model)?;
+
+            if with_deleted {
+                Some(filter)
+            } else {
+                Some(extractors::exclude_soft_deleted(filter, &model))
+            }
         }
         None => None,
     };
@@ -7,7 +7,7 @@ pub fn find_related(
     parent: RelationFieldRef,
     model: ModelRef,
 ) -> QueryGraphBuilderResult<ReadQuery> {
-    let args = extractors::extract_query_args(field.arguments, &model)?;
+    let args = extractors::extract_related_query_args(field.arguments, &model, &parent)?;
     let name = field.name;
     let alias = field.alias;
     let sub_selections = field.nested_fields.unwrap().fields;
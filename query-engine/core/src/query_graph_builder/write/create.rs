@@ -59,6 +59,7 @@ pub fn create_record_node(
     let mut args = create_args.args;
 
     args.add_datetimes(Arc::clone(&model));
+    args.add_version(Arc::clone(&model));
 
     let cr = CreateRecord { model, args };
     let create_node = graph.create_node(Query::Write(WriteQuery::CreateRecord(cr)));
@@ -1,46 +1,88 @@
 use super::*;
 use crate::{
     query_ast::*,
-    query_graph::{QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, FilteredQuery, ParsedField,
+    query_graph::{Node, QueryGraph, QueryGraphDependency},
+    ArgumentListLookup, FilteredQuery, ParsedField, ParsedInputMap, QueryResult,
 };
 use connector::filter::Filter;
 use prisma_models::ModelRef;
 use std::{convert::TryInto, sync::Arc};
 
+const DELETE_NOT_FOUND: &str = "Record to delete does not exist.";
+
 /// Creates a top level delete record query and adds it to the query graph.
 pub fn delete_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
-    let where_arg = field.arguments.lookup("where").unwrap();
-    let filter = extract_unique_filter(where_arg.value.try_into()?, &model)?;
+    let where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
+    let expected_version = update::expected_version(&where_arg, &model)?;
+    let filter = exclude_soft_deleted(extract_unique_filter(where_arg.clone(), &model)?, &model);
 
     // Prefetch read query for the delete
     let mut read_query = read::find_one(field, Arc::clone(&model))?;
     read_query.add_filter(filter.clone());
 
     let read_node = graph.create_node(Query::Read(read_query));
-    let delete_query = Query::Write(WriteQuery::DeleteRecord(DeleteRecord {
-        model: Arc::clone(&model),
-        record_filter: Some(filter.into()),
-    }));
+    let delete_query = if model.soft_delete_field().is_some() {
+        Query::Write(utils::delete_many_records_query(Arc::clone(&model), filter.into()))
+    } else {
+        Query::Write(WriteQuery::DeleteRecord(DeleteRecord {
+            model: Arc::clone(&model),
+            record_filter: Some(filter.into()),
+        }))
+    };
 
     let delete_node = graph.create_node(delete_query);
     utils::insert_deletion_checks(graph, &model, &read_node, &delete_node)?;
 
+    update::insert_existence_check(
+        graph,
+        &model,
+        where_arg,
+        &expected_version,
+        &read_node,
+        DELETE_NOT_FOUND,
+    )?;
+
+    // The version can change between the read and the delete, which is guarded by the version too.
+    if let Some(version) = expected_version.clone() {
+        let check_node = graph.create_node(Node::Empty);
+        let model_name = model.name.clone();
+
+        graph.create_edge(
+            &delete_node,
+            &check_node,
+            QueryGraphDependency::ParentResult(Box::new(move |check_node, result| {
+                if let Ok(QueryResult::Count(0)) = result.as_query_result() {
+                    return Err(update::missing_record_error(
+                        model_name,
+                        Some(version),
+                        DELETE_NOT_FOUND,
+                    ));
+                }
+
+                Ok(check_node)
+            })),
+        )?;
+    }
+
+    let model_name = model.name.clone();
+
     graph.create_edge(
         &read_node,
         &delete_node,
         QueryGraphDependency::ParentProjection(
             model.primary_identifier(),
-            Box::new(|delete_node, parent_ids| {
-                if !parent_ids.is_empty() {
-                    Ok(delete_node)
-                } else {
-                    Err(QueryGraphBuilderError::RecordNotFound(
-                        "Record to delete does not exist.".to_owned(),
-                    ))
+            Box::new(move |delete_node, parent_ids| {
+                if parent_ids.is_empty() {
+                    return Err(update::missing_record_error(
+                        model_name,
+                        expected_version,
+                        DELETE_NOT_FOUND,
+                    ));
                 }
+
+                Ok(delete_node)
             }),
         ),
     )?;
@@ -61,14 +103,12 @@ pub fn delete_many_records(
         Some(where_arg) => extract_filter(where_arg.value.try_into()?, &model)?,
         None => Filter::empty(),
     };
+    let filter = exclude_soft_deleted(filter, &model);
 
     let model_id = model.primary_identifier();
     let read_query = utils::read_ids_infallible(model.clone(), model_id, filter.clone());
     let record_filter = filter.into();
-    let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
-        model: model.clone(),
-        record_filter,
-    });
+    let delete_many = utils::delete_many_records_query(model.clone(), record_filter);
 
     let read_query_node = graph.create_node(read_query);
     let delete_many_node = graph.create_node(Query::Write(delete_many));
@@ -39,11 +39,8 @@ pub fn nested_delete(
             .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?;
 
         let filter_len = filters.len();
-        let or_filter = Filter::Or(filters);
-        let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
-            model: Arc::clone(&child_model),
-            record_filter: or_filter.clone().into(),
-        });
+        let or_filter = exclude_soft_deleted(Filter::Or(filters), child_model);
+        let delete_many = utils::delete_many_records_query(Arc::clone(&child_model), or_filter.clone().into());
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
         let find_child_records_node =
@@ -60,7 +57,7 @@ pub fn nested_delete(
             &delete_many_node,
             QueryGraphDependency::ParentProjection(
                 child_model_identifier,
-                Box::new(move |mut delete_many_node, child_ids| {
+                Box::new(move |delete_many_node, child_ids| {
                     if child_ids.len() != filter_len {
                         return Err(QueryGraphBuilderError::RecordsNotConnected {
                             relation_name,
@@ -69,11 +66,7 @@ pub fn nested_delete(
                         });
                     }
 
-                    if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) = delete_many_node {
-                        dmr.record_filter = child_ids.into();
-                    }
-
-                    Ok(delete_many_node)
+                    utils::filter_children(delete_many_node, child_ids)
                 }),
             ),
         )?;
@@ -82,30 +75,43 @@ pub fn nested_delete(
         let should_delete = if let PrismaValue::Boolean(b) = val { b } else { false };
 
         if should_delete {
-            let find_child_records_node =
-                utils::insert_find_children_by_parent_node(graph, parent_node, parent_relation_field, Filter::empty())?;
-
-            let delete_record_node = graph.create_node(Query::Write(WriteQuery::DeleteRecord(DeleteRecord {
-                model: Arc::clone(&child_model),
-                record_filter: None,
-            })));
+            let find_child_records_node = utils::insert_find_children_by_parent_node(
+                graph,
+                parent_node,
+                parent_relation_field,
+                exclude_soft_deleted(Filter::empty(), child_model),
+            )?;
+
+            let delete_record_query = if child_model.soft_delete_field().is_some() {
+                utils::delete_many_records_query(Arc::clone(&child_model), RecordFilter::empty())
+            } else {
+                WriteQuery::DeleteRecord(DeleteRecord {
+                    model: Arc::clone(&child_model),
+                    record_filter: None,
+                })
+            };
+
+            let delete_record_node = graph.create_node(Query::Write(delete_record_query));
 
             utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_record_node)?;
 
             graph.create_edge(
                  &find_child_records_node,
                  &delete_record_node,
-                 QueryGraphDependency::ParentProjection(child_model_identifier, Box::new(move |mut delete_record_node, mut child_ids| {
+                 QueryGraphDependency::ParentProjection(child_model_identifier, Box::new(move |delete_record_node, mut child_ids| {
                      let child_id = match child_ids.pop() {
                          Some(pid) => Ok(pid),
                          None => Err(QueryGraphBuilderError::AssertionError("[Query Graph] Expected a valid parent ID to be present for a nested delete on a one-to-many relation.".to_string())),
                      }?;
 
-                     if let Node::Query(Query::Write(WriteQuery::DeleteRecord(ref mut dq))) = delete_record_node {
-                         dq.record_filter = Some(child_id.into());
+                     match delete_record_node {
+                         Node::Query(Query::Write(WriteQuery::DeleteRecord(mut dq))) => {
+                             dq.record_filter = Some(child_id.into());
+                             Ok(Node::Query(Query::Write(WriteQuery::DeleteRecord(dq))))
+                         }
+                         // Soft deletes are updates of the child.
+                         node => utils::filter_children(node, vec![child_id]),
                      }
-
-                     Ok(delete_record_node)
                  })),
              )?;
         }
@@ -125,15 +131,12 @@ pub fn nested_delete_many(
 
     for value in utils::coerce_vec(value) {
         let as_map: ParsedInputMap = value.try_into()?;
-        let filter = extract_filter(as_map, child_model)?;
+        let filter = exclude_soft_deleted(extract_filter(as_map, child_model)?, child_model);
 
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter.clone())?;
 
-        let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
-            model: Arc::clone(&child_model),
-            record_filter: RecordFilter::empty(),
-        });
+        let delete_many = utils::delete_many_records_query(Arc::clone(&child_model), RecordFilter::empty());
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
         utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_many_node)?;
@@ -141,16 +144,7 @@ pub fn nested_delete_many(
         graph.create_edge(
             &find_child_records_node,
             &delete_many_node,
-            QueryGraphDependency::ParentProjection(
-                child_model_identifier.clone(),
-                Box::new(move |mut delete_many_node, child_ids| {
-                    if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) = delete_many_node {
-                        dmr.record_filter = child_ids.into();
-                    }
-
-                    Ok(delete_many_node)
-                }),
-            ),
+            QueryGraphDependency::ParentProjection(child_model_identifier.clone(), Box::new(utils::filter_children)),
         )?;
     }
 
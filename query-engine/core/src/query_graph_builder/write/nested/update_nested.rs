@@ -78,6 +78,8 @@ pub fn nested_update(
                 }),
             ),
         )?;
+
+        utils::insert_emulated_on_update(graph, &find_child_records_node, &update_node, &update_node)?;
     }
 
     Ok(())
@@ -127,6 +129,8 @@ pub fn nested_update_many(
                 }),
             ),
         )?;
+
+        utils::insert_emulated_on_update(graph, &find_child_records_node, &update_many_node, &update_many_node)?;
     }
 
     Ok(())
@@ -153,6 +153,8 @@ pub fn nested_upsert(
             })),
         )?;
 
+        utils::insert_emulated_on_update(graph, &read_children_node, &update_node, &if_node)?;
+
         graph.create_edge(&if_node, &update_node, QueryGraphDependency::Then)?;
         graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
 
@@ -6,23 +6,44 @@ use crate::{
     ArgumentListLookup, ParsedField, ParsedInputMap,
 };
 use connector::{Filter, IdFilter};
-use prisma_models::ModelRef;
+use prisma_models::{ModelRef, PrismaValue};
 use std::{convert::TryInto, sync::Arc};
 
 /// Creates an update record query and adds it to the query graph, together with it's nested queries and companion read query.
 pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
     // "where"
     let where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
-    let filter = extract_unique_filter(where_arg, &model)?;
+    let expected_version = expected_version(&where_arg, &model)?;
+    let filter = exclude_soft_deleted(extract_unique_filter(where_arg.clone(), &model)?, &model);
 
     // "data"
     let data_argument = field.arguments.lookup("data").unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
 
-    let update_node = update_record_node(graph, filter, Arc::clone(&model), data_map)?;
+    let update_node = update_record_node(graph, filter.clone(), Arc::clone(&model), data_map)?;
+
+    insert_existence_check(
+        graph,
+        &model,
+        where_arg,
+        &expected_version,
+        &update_node,
+        UPDATE_NOT_FOUND,
+    )?;
+
+    if utils::needs_emulated_on_update(graph, &update_node) {
+        let read_parent_node = graph.create_node(utils::read_ids_infallible(
+            Arc::clone(&model),
+            model.primary_identifier(),
+            filter,
+        ));
+
+        utils::insert_emulated_on_update(graph, &read_parent_node, &update_node, &update_node)?;
+    }
 
     let read_query = read::find_one(field, model.clone())?;
     let read_node = graph.create_node(Query::Read(read_query));
+    let model_name = model.name.clone();
 
     graph.add_result_node(&read_node);
     graph.create_edge(
@@ -31,12 +52,9 @@ pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
         QueryGraphDependency::ParentProjection(
             model.primary_identifier(),
             Box::new(move |mut read_node, mut parent_ids| {
-                let parent_id = match parent_ids.pop() {
-                    Some(pid) => Ok(pid),
-                    None => Err(QueryGraphBuilderError::RecordNotFound(
-                        "Record to update not found.".to_string(),
-                    )),
-                }?;
+                let parent_id = parent_ids
+                    .pop()
+                    .ok_or_else(|| missing_record_error(model_name, expected_version, UPDATE_NOT_FOUND))?;
 
                 if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = read_node {
                     rq.add_filter(parent_id.filter());
@@ -62,6 +80,7 @@ pub fn update_many_records(
         Some(where_arg) => extract_filter(where_arg.value.try_into()?, &model)?,
         None => Filter::empty(),
     };
+    let filter = exclude_soft_deleted(filter, &model);
 
     let data_argument = field.arguments.lookup("data").unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
@@ -69,14 +88,25 @@ pub fn update_many_records(
 
     let mut args = update_args.args;
     args.update_datetimes(Arc::clone(&model));
+    args.increment_version(Arc::clone(&model));
 
-    let record_filter = filter.into();
+    let record_filter = filter.clone().into();
     let update_many = WriteQuery::UpdateManyRecords(UpdateManyRecords {
-        model,
+        model: Arc::clone(&model),
         record_filter,
         args,
     });
-    graph.create_node(Query::Write(update_many));
+    let update_many_node = graph.create_node(Query::Write(update_many));
+
+    if utils::needs_emulated_on_update(graph, &update_many_node) {
+        let read_parent_node = graph.create_node(utils::read_ids_infallible(
+            Arc::clone(&model),
+            model.primary_identifier(),
+            filter,
+        ));
+
+        utils::insert_emulated_on_update(graph, &read_parent_node, &update_many_node, &update_many_node)?;
+    }
 
     Ok(())
 }
@@ -97,6 +127,7 @@ where
     let mut args = update_args.args;
 
     args.update_datetimes(Arc::clone(&model));
+    args.increment_version(Arc::clone(&model));
 
     let filter = filter.into();
     let record_filter = filter.into();
@@ -113,3 +144,89 @@ where
 
     Ok(node)
 }
+
+const UPDATE_NOT_FOUND: &str = "Record to update not found.";
+
+/// Inserts a check that the record of a versioned write exists before `node` runs, if `expected_version` is set.
+///
+/// A stale version must be told apart from a missing record: the version-guarded write finds no record in both
+/// cases, so the existence of the record is checked with the unique criterion alone beforehand. A versioned write
+/// that passes the check and still finds no record failed because of the version, see `missing_record_error`.
+pub(crate) fn insert_existence_check(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    where_arg: ParsedInputMap,
+    expected_version: &Option<PrismaValue>,
+    node: &NodeRef,
+    not_found_message: &'static str,
+) -> QueryGraphBuilderResult<()> {
+    if expected_version.is_none() {
+        return Ok(());
+    }
+
+    let unique_filter = exclude_soft_deleted(unversioned_unique_filter(where_arg, model)?, model);
+    let check_node = graph.create_node(utils::read_ids_infallible(
+        Arc::clone(model),
+        model.primary_identifier(),
+        unique_filter,
+    ));
+
+    graph.create_edge(
+        &check_node,
+        node,
+        QueryGraphDependency::ParentProjection(
+            model.primary_identifier(),
+            Box::new(move |node, parent_ids| {
+                if parent_ids.is_empty() {
+                    return Err(QueryGraphBuilderError::RecordNotFound(not_found_message.to_owned()));
+                }
+
+                Ok(node)
+            }),
+        ),
+    )?;
+
+    Ok(())
+}
+
+/// The error of a write that found no record: a version conflict if the write was versioned, see
+/// `insert_existence_check`.
+pub(crate) fn missing_record_error(
+    model_name: String,
+    expected_version: Option<PrismaValue>,
+    not_found_message: &str,
+) -> QueryGraphBuilderError {
+    match expected_version {
+        Some(version) => QueryGraphBuilderError::VersionConflict {
+            model_name,
+            expected_version: version.to_string(),
+        },
+        None => QueryGraphBuilderError::RecordNotFound(not_found_message.to_owned()),
+    }
+}
+
+/// Returns the version the record is expected to have if the unique selector of an update carries the version field.
+pub(crate) fn expected_version(
+    where_arg: &ParsedInputMap,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<Option<PrismaValue>> {
+    match model.fields().version() {
+        Some(field) => match where_arg.get(&field.name) {
+            Some(value) => Ok(Some(value.clone().try_into()?)),
+            None => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
+/// The filter of a unique selector without its version field, which selects the record whatever its version.
+pub(crate) fn unversioned_unique_filter(
+    mut where_arg: ParsedInputMap,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<Filter> {
+    if let Some(field) = model.fields().version() {
+        where_arg.remove(&field.name);
+    }
+
+    extract_unique_filter(where_arg, model)
+}
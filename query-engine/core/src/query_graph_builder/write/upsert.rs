@@ -4,8 +4,8 @@ use crate::{
     query_graph::{Flow, Node, QueryGraph, QueryGraphDependency},
     ArgumentListLookup, ParsedField, ParsedInputMap,
 };
-use connector::IdFilter;
-use prisma_models::ModelRef;
+use connector::{Filter, IdFilter, ScalarCompare};
+use prisma_models::{ModelRef, PrismaValue};
 use std::{convert::TryInto, sync::Arc};
 
 pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
@@ -13,17 +13,56 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 
     let where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
 
-    let filter = extract_unique_filter(where_arg, &model)?;
+    let expected_version = update::expected_version(&where_arg, &model)?;
+
+    let filter = exclude_soft_deleted(extract_unique_filter(where_arg.clone(), &model)?, &model);
     let model_id = model.primary_identifier();
 
+    // A record with a stale version must not be taken for a missing one, so its
+    // existence is checked with the unique criterion alone. The version-guarded
+    // update then fails instead of a new record being created.
+    let unique_filter = update::unversioned_unique_filter(where_arg, &model)?;
+    let read_filter = exclude_soft_deleted(unique_filter.clone(), &model);
+
     let create_argument = field.arguments.lookup("create").unwrap();
     let update_argument = field.arguments.lookup("update").unwrap();
 
-    let read_parent_records = utils::read_ids_infallible(model.clone(), model_id.clone(), filter.clone());
+    let read_parent_records = utils::read_ids_infallible(model.clone(), model_id.clone(), read_filter);
     let read_parent_records_node = graph.create_node(read_parent_records);
 
+    // Soft deleted records still hold their unique values, so a new record can't be created in their
+    // place. Restoring them with the update would keep the fields of the deleted record, the upsert fails instead.
+    if let Some(field) = model.soft_delete_field() {
+        let deleted_filter = Filter::and(vec![unique_filter, field.not_equals(PrismaValue::Null)]);
+        let read_deleted_node = graph.create_node(utils::read_ids_infallible(
+            model.clone(),
+            model_id.clone(),
+            deleted_filter,
+        ));
+        let model_name = model.name.clone();
+
+        graph.create_edge(
+            &read_deleted_node,
+            &read_parent_records_node,
+            QueryGraphDependency::ParentProjection(
+                model_id.clone(),
+                Box::new(move |read_parent_records_node, deleted_ids| {
+                    if !deleted_ids.is_empty() {
+                        return Err(QueryGraphBuilderError::InputError(format!(
+                            "The record to upsert is a soft deleted `{}` record, which can neither be updated nor created.",
+                            model_name
+                        )));
+                    }
+
+                    Ok(read_parent_records_node)
+                }),
+            ),
+        )?;
+    }
+
     let create_node = create::create_record_node(graph, Arc::clone(&model), create_argument.value.try_into()?)?;
-    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_argument.value.try_into()?)?;
+    let update_map: ParsedInputMap = update_argument.value.try_into()?;
+    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_map)?;
 
     let read_query = read::find_one(field, Arc::clone(&model))?;
     let read_node_create = graph.create_node(Query::Read(read_query.clone()));
@@ -50,17 +89,26 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
         ),
     )?;
 
+    utils::insert_emulated_on_update(graph, &read_parent_records_node, &update_node, &if_node)?;
+
     graph.create_edge(&if_node, &update_node, QueryGraphDependency::Then)?;
     graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+
+    let model_name = model.name.clone();
+
     graph.create_edge(
         &update_node,
         &read_node_update,
         QueryGraphDependency::ParentProjection(
             model_id.clone(),
             Box::new(move |mut read_node_update, mut parent_ids| {
-                let parent_id = match parent_ids.pop() {
-                    Some(pid) => Ok(pid),
-                    None => Err(QueryGraphBuilderError::AssertionError(
+                let parent_id = match (parent_ids.pop(), expected_version) {
+                    (Some(pid), _) => Ok(pid),
+                    (None, Some(version)) => Err(QueryGraphBuilderError::VersionConflict {
+                        model_name,
+                        expected_version: version.to_string(),
+                    }),
+                    (None, None) => Err(QueryGraphBuilderError::AssertionError(
                         "Expected a valid parent ID to be present for create follow-up for upsert query.".to_string(),
                     )),
                 }?;
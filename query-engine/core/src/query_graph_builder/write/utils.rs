@@ -1,11 +1,15 @@
 use crate::{
     query_ast::*,
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
+    query_graph_builder::exclude_soft_deleted,
     ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{Filter, WriteArgs};
+use connector::{Filter, RecordFilter, WriteArgs, WriteExpression};
 use itertools::Itertools;
-use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
+use prisma_models::{
+    dml::{OnDeleteStrategy, OnUpdateStrategy},
+    ModelProjection, ModelRef, PrismaValue, RecordProjection, RelationFieldRef,
+};
 use std::sync::Arc;
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
@@ -145,6 +149,7 @@ where
 {
     let mut args = WriteArgs::new();
     args.update_datetimes(Arc::clone(&model));
+    args.increment_version(Arc::clone(&model));
 
     let filter = filter.into();
     let record_filter = filter.into();
@@ -265,6 +270,10 @@ pub fn insert_existing_1to1_related_model_checks(
 /// the given `model`. Those checks fail at runtime (edges to the `Empty` node) if one or more
 /// records are found. Checks are inserted between `parent_node` and `child_node`.
 ///
+/// If the referential actions of relations are emulated (see `InternalDataModel::emulates_referential_actions`),
+/// relations declaring an `onDelete` action are not checked. Their actions are inserted after the checks instead,
+/// see `insert_emulated_on_delete`. The actions are always emulated for models in soft delete mode.
+///
 /// This function is usually part of a delete (`deleteOne` or `deleteMany`).
 /// Expects `parent_node` to return one or more IDs (for records of `model`) to be checked.
 ///
@@ -306,10 +315,31 @@ pub fn insert_deletion_checks(
     model: &ModelRef,
     parent_node: &NodeRef,
     child_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    let mut deleted_models = vec![model.name.clone()];
+    insert_deletion_checks_and_actions(graph, model, parent_node, child_node, &mut deleted_models)
+}
+
+/// `deleted_models` are the models whose records are deleted by the cascade `model` is part of.
+fn insert_deletion_checks_and_actions(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    parent_node: &NodeRef,
+    child_node: &NodeRef,
+    deleted_models: &mut Vec<String>,
 ) -> QueryGraphBuilderResult<()> {
     let internal_model = model.internal_data_model();
-    let relation_fields = internal_model.fields_requiring_model(model);
+
+    // Soft deletes are updates, which don't trigger the actions of the foreign keys in the database.
+    let emulates_actions = internal_model.emulates_referential_actions() || model.soft_delete_field().is_some();
+    let relation_fields: Vec<_> = internal_model
+        .fields_requiring_model(model)
+        .into_iter()
+        .filter(|rf| !emulates_actions || rf.relation_info.on_delete == OnDeleteStrategy::None)
+        .collect();
+
     let mut check_nodes = vec![];
+    let mut last_node = None;
 
     if !relation_fields.is_empty() {
         let noop_node = graph.create_node(Node::Empty);
@@ -318,8 +348,16 @@ pub fn insert_deletion_checks(
         // For all requiring models (RM), we use the field on `model` to query for existing RM records and error out if at least one exists.
         for rf in relation_fields {
             let relation_field = rf.related_field();
-            let child_model_identifier = relation_field.related_model().primary_identifier();
-            let read_node = insert_find_children_by_parent_node(graph, parent_node, &relation_field, Filter::empty())?;
+            let child_model = relation_field.related_model();
+            let child_model_identifier = child_model.primary_identifier();
+
+            // Soft deleted parents stay in the database, so soft deleted children don't hold them back.
+            let filter = match model.soft_delete_field() {
+                Some(_) => exclude_soft_deleted(Filter::empty(), &child_model),
+                None => Filter::empty(),
+            };
+
+            let read_node = insert_find_children_by_parent_node(graph, parent_node, &relation_field, filter)?;
 
             graph.create_edge(
                 &read_node,
@@ -348,9 +386,315 @@ pub fn insert_deletion_checks(
             next
         });
 
-        // Edge from empty node to the child (delete).
-        graph.create_edge(&noop_node, child_node, QueryGraphDependency::ExecutionOrder)?;
+        last_node = Some(noop_node);
+    }
+
+    if emulates_actions {
+        let action_fields: Vec<_> = internal_model
+            .relation_fields()
+            .iter()
+            .filter(|rf| &rf.related_model() == model && rf.relation_info.on_delete != OnDeleteStrategy::None)
+            .map(Arc::clone)
+            .collect();
+
+        for rf in action_fields {
+            let (read_node, action_node) = insert_emulated_on_delete(graph, &rf, parent_node, deleted_models)?;
+
+            if let Some(prev) = last_node {
+                graph.create_edge(&prev, &read_node, QueryGraphDependency::ExecutionOrder)?;
+            }
+
+            last_node = Some(action_node);
+        }
+    }
+
+    // Edge from the empty node or the last action to the child (delete).
+    if let Some(last_node) = last_node {
+        graph.create_edge(&last_node, child_node, QueryGraphDependency::ExecutionOrder)?;
+    }
+
+    Ok(())
+}
+
+/// Emulates the `onDelete` action of `relation_field` for the records returned by `parent_node`.
+/// `relation_field` is the field on the children referencing the deleted model.
+///
+/// A `Cascade` deletes the children, including their own checks and actions. A `SetNull` nulls out the
+/// fields of the children referencing the deleted records.
+///
+/// ```text
+/// ┌ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─
+///           Parent         │
+/// └ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─
+///              :
+///              ▼
+/// ┌────────────────────────┐
+/// │     Read children      │
+/// └────────────────────────┘
+///              │
+///              ▼
+/// ┌────────────────────────┐
+/// │ Delete / update child. │
+/// └────────────────────────┘
+/// ```
+///
+/// Returns `NodeRef`s to the "Read children" node and the action node in the graph illustrated above.
+fn insert_emulated_on_delete(
+    graph: &mut QueryGraph,
+    relation_field: &RelationFieldRef,
+    parent_node: &NodeRef,
+    deleted_models: &mut Vec<String>,
+) -> QueryGraphBuilderResult<(NodeRef, NodeRef)> {
+    let child_model = relation_field.model();
+    let child_model_identifier = child_model.primary_identifier();
+
+    // Children that are soft deleted already are not deleted again.
+    let filter = match relation_field.relation_info.on_delete {
+        OnDeleteStrategy::Cascade => exclude_soft_deleted(Filter::empty(), &child_model),
+        _ => Filter::empty(),
+    };
+
+    let read_children_node =
+        insert_find_children_by_parent_node(graph, parent_node, &relation_field.related_field(), filter)?;
+
+    let action_node = match relation_field.relation_info.on_delete {
+        OnDeleteStrategy::Cascade => {
+            // The query graph is static: cycles would require an unbounded number of nodes.
+            if deleted_models.contains(&child_model.name) {
+                return Err(QueryGraphBuilderError::InputError(format!(
+                    "The cascading delete of relation `{}` can't be emulated: cascading deletes of `{}` are cyclic.",
+                    relation_field.relation_name, child_model.name
+                )));
+            }
+
+            let delete_children_node = graph.create_node(Query::Write(delete_many_records_query(
+                Arc::clone(&child_model),
+                RecordFilter::empty(),
+            )));
+
+            deleted_models.push(child_model.name.clone());
+            insert_deletion_checks_and_actions(
+                graph,
+                &child_model,
+                &read_children_node,
+                &delete_children_node,
+                deleted_models,
+            )?;
+            deleted_models.pop();
+
+            delete_children_node
+        }
+        OnDeleteStrategy::SetNull => {
+            let mut args = WriteArgs::new();
+
+            for field in relation_field.scalar_fields() {
+                args.insert(&field, PrismaValue::Null);
+            }
+
+            update_children_node(graph, Arc::clone(&child_model), args)
+        }
+        OnDeleteStrategy::None => unreachable!("Relations without `onDelete` action are not emulated."),
+    };
+
+    graph.create_edge(
+        &read_children_node,
+        &action_node,
+        QueryGraphDependency::ParentProjection(child_model_identifier, Box::new(filter_children)),
+    )?;
+
+    Ok((read_children_node, action_node))
+}
+
+/// Returns true if the update of `update_node` writes fields referenced by relations with an
+/// emulated `onUpdate` action. See `insert_emulated_on_update`.
+pub fn needs_emulated_on_update(graph: &QueryGraph, update_node: &NodeRef) -> bool {
+    match updated_model_and_args(graph, update_node) {
+        Some((model, args)) => !fields_with_emulated_on_update(&model, &args).is_empty(),
+        None => false,
+    }
+}
+
+/// Inserts the emulated `onUpdate` actions of the relations referencing fields written by `update_node`
+/// into the graph, between `parent_node` and `child_node`. Nothing is inserted if the referential actions
+/// are not emulated (see `InternalDataModel::emulates_referential_actions`).
+///
+/// Expects `parent_node` to return the IDs of the records to be updated, and `child_node` to be `update_node`
+/// or one of its ancestors. The children are read with the old values of the referenced fields, so the actions
+/// happen before the update:
+/// - A `Cascade` writes the new values of the referenced fields to the children. Only plain values can be cascaded.
+/// - A `SetNull` nulls out the fields of the children referencing the updated records.
+///
+/// ```text
+///    ┌ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─
+/// ┌──          Parent         │
+/// │  └ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─
+/// │               │
+/// │               ▼
+/// │  ┌────────────────────────┐
+/// ├─▶│    Read children A     │
+/// │  └────────────────────────┘
+/// │               │
+/// │               ▼
+/// │  ┌────────────────────────┐
+/// │  │   Update children A    │
+/// │  └────────────────────────┘
+/// │               │
+/// │               ▼
+/// │  ┌────────────────────────┐
+/// └─▶│    Read children B     │
+///    └────────────────────────┘
+///                 │
+///                 ▼
+///    ┌────────────────────────┐
+///    │   Update children B    │
+///    └────────────────────────┘
+///                 │
+///                 ▼
+///    ┌────────────────────────┐
+///    │         Child          │
+///    └────────────────────────┘
+/// ```
+pub fn insert_emulated_on_update(
+    graph: &mut QueryGraph,
+    parent_node: &NodeRef,
+    update_node: &NodeRef,
+    child_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    let (model, args) = match updated_model_and_args(graph, update_node) {
+        Some(model_and_args) => model_and_args,
+        None => return Ok(()),
+    };
+
+    let mut last_node: Option<NodeRef> = None;
+
+    for rf in fields_with_emulated_on_update(&model, &args) {
+        let child_model = rf.model();
+        let child_model_identifier = child_model.primary_identifier();
+        let read_children_node =
+            insert_find_children_by_parent_node(graph, parent_node, &rf.related_field(), Filter::empty())?;
+
+        let update_children_node = update_children_node(graph, child_model, on_update_args(&rf, &args)?);
+
+        graph.create_edge(
+            &read_children_node,
+            &update_children_node,
+            QueryGraphDependency::ParentProjection(child_model_identifier, Box::new(filter_children)),
+        )?;
+
+        if let Some(prev) = last_node {
+            graph.create_edge(&prev, &read_children_node, QueryGraphDependency::ExecutionOrder)?;
+        }
+
+        last_node = Some(update_children_node);
+    }
+
+    if let Some(last_node) = last_node {
+        graph.create_edge(&last_node, child_node, QueryGraphDependency::ExecutionOrder)?;
     }
 
     Ok(())
 }
+
+fn updated_model_and_args(graph: &QueryGraph, update_node: &NodeRef) -> Option<(ModelRef, WriteArgs)> {
+    match graph.node_content(update_node) {
+        Some(Node::Query(Query::Write(WriteQuery::UpdateRecord(ur)))) => Some((Arc::clone(&ur.model), ur.args.clone())),
+        Some(Node::Query(Query::Write(WriteQuery::UpdateManyRecords(ur)))) => {
+            Some((Arc::clone(&ur.model), ur.args.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// The relation fields referencing fields of `model` written by `args`, with an emulated `onUpdate` action.
+fn fields_with_emulated_on_update(model: &ModelRef, args: &WriteArgs) -> Vec<RelationFieldRef> {
+    let internal_model = model.internal_data_model();
+
+    if !internal_model.emulates_referential_actions() {
+        return vec![];
+    }
+
+    internal_model
+        .relation_fields()
+        .iter()
+        .filter(|rf| &rf.related_model() == model && rf.relation_info.on_update != OnUpdateStrategy::None)
+        .filter(|rf| {
+            rf.related_field()
+                .linking_fields()
+                .scalar_fields()
+                .any(|field| args.has_arg_for(field.db_name()))
+        })
+        .map(Arc::clone)
+        .collect()
+}
+
+/// The arguments of the update of the children of `relation_field`, for an update of the referenced records with `args`.
+fn on_update_args(relation_field: &RelationFieldRef, args: &WriteArgs) -> QueryGraphBuilderResult<WriteArgs> {
+    let referenced_fields = relation_field.related_field().linking_fields();
+    let mut child_args = WriteArgs::new();
+
+    for (field, referenced_field) in relation_field
+        .scalar_fields()
+        .iter()
+        .zip(referenced_fields.scalar_fields())
+    {
+        match relation_field.relation_info.on_update {
+            OnUpdateStrategy::SetNull => child_args.insert(field, PrismaValue::Null),
+            OnUpdateStrategy::Cascade => match args.get_field_value(referenced_field.db_name()) {
+                Some(WriteExpression::Value(value)) => child_args.insert(field, value.clone()),
+                Some(_) => {
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "The update of `{}` can't be cascaded over relation `{}`: only setting a new value can be emulated.",
+                        referenced_field.name, relation_field.relation_name
+                    )))
+                }
+                None => (),
+            },
+            OnUpdateStrategy::None => (),
+        }
+    }
+
+    Ok(child_args)
+}
+
+/// Creates an update many records node writing `args` to the children of a relation.
+/// The children are set with `filter_children`.
+fn update_children_node(graph: &mut QueryGraph, model: ModelRef, mut args: WriteArgs) -> NodeRef {
+    args.update_datetimes(Arc::clone(&model));
+    args.increment_version(Arc::clone(&model));
+
+    graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(UpdateManyRecords {
+        model,
+        record_filter: RecordFilter::empty(),
+        args,
+    })))
+}
+
+/// Creates a delete many records query for the records matching `record_filter`.
+/// Records of models in soft delete mode are marked as deleted with an update instead.
+pub fn delete_many_records_query(model: ModelRef, record_filter: RecordFilter) -> WriteQuery {
+    if model.soft_delete_field().is_none() {
+        return WriteQuery::DeleteManyRecords(DeleteManyRecords { model, record_filter });
+    }
+
+    let mut args = WriteArgs::new();
+    args.mark_soft_deleted(Arc::clone(&model));
+    args.update_datetimes(Arc::clone(&model));
+    args.increment_version(Arc::clone(&model));
+
+    WriteQuery::UpdateManyRecords(UpdateManyRecords {
+        model,
+        record_filter,
+        args,
+    })
+}
+
+/// Restricts a delete or update many records node to the children found by the parent.
+pub fn filter_children(mut node: Node, child_ids: Vec<RecordProjection>) -> QueryGraphBuilderResult<Node> {
+    match node {
+        Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) => dmr.record_filter = child_ids.into(),
+        Node::Query(Query::Write(WriteQuery::UpdateManyRecords(ref mut umr))) => umr.record_filter = child_ids.into(),
+        _ => (),
+    }
+
+    Ok(node)
+}
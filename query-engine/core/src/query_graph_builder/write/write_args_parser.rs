@@ -1,7 +1,7 @@
 use super::*;
 use crate::query_document::{ParsedInputMap, ParsedInputValue};
 use connector::{WriteArgs, WriteExpression};
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef};
+use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
 use std::{convert::TryInto, sync::Arc};
 
 #[derive(Default, Debug)]
@@ -21,13 +21,47 @@ impl WriteArgsParser {
 
                 match field {
                     Field::Scalar(sf) if sf.is_list => {
-                        let set_value: PrismaValue = match v {
-                            ParsedInputValue::List(_) => v.try_into()?,
-                            ParsedInputValue::Map(mut map) => map.remove("set").unwrap().try_into()?,
-                            _ => unreachable!(),
+                        let expr: WriteExpression = match v {
+                            ParsedInputValue::List(_) => WriteExpression::Value(v.try_into()?),
+                            ParsedInputValue::Map(map) => {
+                                let (operation, value) = single_list_operation(&sf, map)?;
+
+                                match operation.as_str() {
+                                    "set" => WriteExpression::Value(value.try_into()?),
+                                    "push" => WriteExpression::Push(list_value(value)?),
+                                    "unshift" => WriteExpression::Unshift(list_value(value)?),
+                                    "removeAll" => WriteExpression::RemoveAll(value.try_into()?),
+                                    "setAt" => set_at_expression(value)?,
+                                    operation => {
+                                        return Err(QueryGraphBuilderError::InputError(format!(
+                                            "Invalid operation `{}` on the list field `{}`.",
+                                            operation, sf.name
+                                        )))
+                                    }
+                                }
+                            }
+                            _ => {
+                                return Err(QueryGraphBuilderError::InputError(format!(
+                                    "The list field `{}` must be set to a list or updated with a list operation.",
+                                    sf.name
+                                )))
+                            }
+                        };
+
+                        args.args.insert(sf, expr)
+                    }
+
+                    // Composite values are always written as a whole Json document.
+                    Field::Scalar(sf) if sf.is_composite() => {
+                        let value = match v {
+                            ParsedInputValue::Single(PrismaValue::Null) => PrismaValue::Null,
+                            v => {
+                                let document: serde_json::Value = v.try_into()?;
+                                PrismaValue::Json(document.to_string())
+                            }
                         };
 
-                        args.args.insert(sf, set_value)
+                        args.args.insert(sf, WriteExpression::Value(value))
                     }
 
                     Field::Scalar(sf) => {
@@ -63,3 +97,49 @@ impl WriteArgsParser {
         )
     }
 }
+
+/// Coerces a single value or a list of values into a list.
+fn list_value(value: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
+    let value: PrismaValue = value.try_into()?;
+
+    Ok(match value {
+        PrismaValue::List(_) => value,
+        value => PrismaValue::List(vec![value]),
+    })
+}
+
+/// The operation of a list update, e.g. `{ push: 1 }`. Exactly one operation is allowed.
+fn single_list_operation(
+    field: &ScalarFieldRef,
+    map: ParsedInputMap,
+) -> QueryGraphBuilderResult<(String, ParsedInputValue)> {
+    let mut operations = map.into_iter();
+
+    match (operations.next(), operations.next()) {
+        (Some(operation), None) => Ok(operation),
+        _ => Err(QueryGraphBuilderError::InputError(format!(
+            "Exactly one operation must be given for the list field `{}`.",
+            field.name
+        ))),
+    }
+}
+
+fn set_at_expression(value: ParsedInputValue) -> QueryGraphBuilderResult<WriteExpression> {
+    let mut map: ParsedInputMap = value.try_into()?;
+    let mut take = |key: &str| {
+        map.remove(key).ok_or_else(|| {
+            QueryGraphBuilderError::InputError(format!("The `setAt` operation requires the `{}` field.", key))
+        })
+    };
+
+    let index: PrismaValue = take("index")?.try_into()?;
+    let value: PrismaValue = take("value")?.try_into()?;
+
+    match index {
+        PrismaValue::Int(index) if index >= 0 => Ok(WriteExpression::SetAt(index, value)),
+        index => Err(QueryGraphBuilderError::InputError(format!(
+            "The `setAt` index must be a non-negative integer, but received {}.",
+            index
+        ))),
+    }
+}
@@ -7,7 +7,7 @@ use bigdecimal::ToPrimitive;
 use connector::AggregationResult;
 use indexmap::IndexMap;
 use prisma_models::{PrismaValue, RecordProjection};
-use std::{borrow::Borrow, collections::HashMap};
+use std::{borrow::Borrow, collections::HashMap, convert::TryFrom};
 
 /// A grouping of items to their parent record.
 /// The item implicitly holds the information of the type of item contained.
@@ -293,9 +293,14 @@ fn serialize_objects(
         for (val, scalar_field_name) in values.into_iter().zip(field_names.iter()) {
             let field = typ.find_field(scalar_field_name).unwrap();
 
-            if !field.field_type.is_object() {
-                object.insert(scalar_field_name.to_owned(), serialize_scalar(&field, val)?);
-            }
+            // Scalar values typed as objects are composite values.
+            match field.field_type.as_object_type() {
+                Some(composite_type) => object.insert(
+                    scalar_field_name.to_owned(),
+                    serialize_composite(&field, &composite_type, val)?,
+                ),
+                None => object.insert(scalar_field_name.to_owned(), serialize_scalar(&field, val)?),
+            };
         }
 
         // Write nested results
@@ -421,6 +426,120 @@ fn serialize_scalar(field: &OutputFieldRef, value: PrismaValue) -> crate::Result
     }
 }
 
+/// Composite values are stored as Json documents. They are always serialized as a whole,
+/// following the output object type of the composite type.
+fn serialize_composite(
+    field: &OutputFieldRef,
+    composite_type: &ObjectTypeStrongRef,
+    value: PrismaValue,
+) -> crate::Result<Item> {
+    match value {
+        PrismaValue::Null if !field.is_required => Ok(Item::Value(PrismaValue::Null)),
+        PrismaValue::Json(s) | PrismaValue::String(s) => {
+            let document: serde_json::Value = serde_json::from_str(&s).map_err(|err| {
+                CoreError::SerializationError(format!("Unable to parse composite value '{}': {}", s, err))
+            })?;
+
+            serialize_composite_document(composite_type, document)
+        }
+        val => Err(CoreError::SerializationError(format!(
+            "Attempted to serialize non-composite-compatible value '{}' with composite type '{}'",
+            val,
+            composite_type.identifier.name()
+        ))),
+    }
+}
+
+fn serialize_composite_document(
+    composite_type: &ObjectTypeStrongRef,
+    document: serde_json::Value,
+) -> crate::Result<Item> {
+    let mut document = match document {
+        serde_json::Value::Object(document) => document,
+        other => {
+            return Err(CoreError::SerializationError(format!(
+                "Attempted to serialize composite value '{}' which is not an object",
+                other
+            )))
+        }
+    };
+
+    let mut map = Map::with_capacity(composite_type.get_fields().len());
+
+    for field in composite_type.get_fields() {
+        let value = document.remove(&field.name).unwrap_or(serde_json::Value::Null);
+        let item = match value {
+            serde_json::Value::Null if !field.is_required => Item::Value(PrismaValue::Null),
+            serde_json::Value::Null => {
+                return Err(CoreError::SerializationError(format!(
+                    "Composite value of type '{}' is missing the required field '{}'",
+                    composite_type.identifier.name(),
+                    field.name
+                )))
+            }
+            value => serialize_composite_field_value(field.field_type.as_ref(), value)?,
+        };
+
+        map.insert(field.name.clone(), item);
+    }
+
+    Ok(Item::Map(map))
+}
+
+fn serialize_composite_field_value(typ: &OutputType, value: serde_json::Value) -> crate::Result<Item> {
+    match (typ, value) {
+        (OutputType::List(inner), serde_json::Value::Array(values)) => {
+            let items = values
+                .into_iter()
+                .map(|value| serialize_composite_field_value(inner.as_ref(), value))
+                .collect::<crate::Result<Vec<Item>>>()?;
+
+            Ok(Item::list(items))
+        }
+        (OutputType::Object(obj), value) => serialize_composite_document(&obj.into_arc(), value),
+        (OutputType::Enum(et), value) => match et.borrow() {
+            EnumType::Database(ref dbt) => convert_enum(json_to_prisma_value(value, &ScalarType::String)?, dbt),
+            _ => unreachable!(),
+        },
+        (OutputType::Scalar(st), value) => Ok(Item::Value(convert_prisma_value(json_to_prisma_value(value, st)?, st)?)),
+        (ot, value) => Err(CoreError::SerializationError(format!(
+            "Attempted to serialize composite field value '{}' with incompatible type '{:?}'",
+            value, ot
+        ))),
+    }
+}
+
+/// Reverses the Json encoding of scalar values inside of composite values.
+fn json_to_prisma_value(value: serde_json::Value, st: &ScalarType) -> crate::Result<PrismaValue> {
+    let conversion_error = |value: &serde_json::Value| {
+        CoreError::SerializationError(format!(
+            "Attempted to serialize composite field value '{}' with incompatible type '{:?}'",
+            value, st
+        ))
+    };
+
+    let pv = match (st, value) {
+        (ScalarType::Json, value) => PrismaValue::Json(value.to_string()),
+        (ScalarType::DateTime, serde_json::Value::String(s)) => chrono::DateTime::parse_from_rfc3339(&s)
+            .map(PrismaValue::DateTime)
+            .map_err(|_| conversion_error(&serde_json::Value::String(s)))?,
+        (ScalarType::BigInt, serde_json::Value::String(s)) => s
+            .parse::<i64>()
+            .map(PrismaValue::BigInt)
+            .map_err(|_| conversion_error(&serde_json::Value::String(s)))?,
+        (ScalarType::Bytes, serde_json::Value::String(s)) => prisma_models::decode_bytes(&s)
+            .map(PrismaValue::Bytes)
+            .map_err(|_| conversion_error(&serde_json::Value::String(s)))?,
+        (ScalarType::UUID, serde_json::Value::String(s)) => uuid::Uuid::parse_str(&s)
+            .map(PrismaValue::Uuid)
+            .map_err(|_| conversion_error(&serde_json::Value::String(s)))?,
+        (ScalarType::Xml, serde_json::Value::String(s)) => PrismaValue::Xml(s),
+        (_, value) => PrismaValue::try_from(value.clone()).map_err(|_| conversion_error(&value))?,
+    };
+
+    Ok(pv)
+}
+
 fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaValue, CoreError> {
     let item_value = match (st, value) {
         (ScalarType::String, PrismaValue::String(s)) => PrismaValue::String(s),
@@ -121,6 +121,14 @@ impl Item {
         }
     }
 
+    pub fn as_list(&self) -> Option<&List> {
+        match self {
+            Self::List(l) => Some(l),
+            Self::Ref(r) => r.as_list(),
+            _ => None,
+        }
+    }
+
     pub fn into_map(self) -> Option<Map> {
         match self {
             Self::Map(m) => Some(m),
@@ -1,7 +1,7 @@
 use super::*;
 use fmt::Debug;
 use prisma_models::{InternalDataModelRef, ModelRef};
-use std::{borrow::Borrow, fmt};
+use std::{borrow::Borrow, collections::HashMap, fmt};
 
 /// The query schema.
 /// Defines which operations (query/mutations) are possible on a database, based on the (internal) data model.
@@ -27,6 +27,12 @@ pub struct QuerySchema {
     /// Stores all strong refs to the output object types.
     output_object_types: Vec<ObjectTypeStrongRef>,
 
+    /// The positions of the input object types, by name.
+    input_object_type_indexes: HashMap<String, usize>,
+
+    /// The enum types accepted as input anywhere in the schema, by name.
+    input_enum_types: HashMap<String, EnumTypeRef>,
+
     pub internal_data_model: InternalDataModelRef,
 }
 
@@ -38,13 +44,24 @@ impl QuerySchema {
         output_object_types: Vec<ObjectTypeStrongRef>,
         internal_data_model: InternalDataModelRef,
     ) -> Self {
-        QuerySchema {
+        let input_object_type_indexes = input_object_types
+            .iter()
+            .enumerate()
+            .map(|(idx, typ)| (typ.identifier.name().to_owned(), idx))
+            .collect();
+
+        let mut schema = QuerySchema {
             query,
             mutation,
             input_object_types,
             output_object_types,
+            input_object_type_indexes,
+            input_enum_types: HashMap::new(),
             internal_data_model,
-        }
+        };
+
+        schema.input_enum_types = schema.collect_input_enum_types();
+        schema
     }
 
     pub fn find_mutation_field<T>(&self, name: T) -> Option<OutputFieldRef>
@@ -63,6 +80,45 @@ impl QuerySchema {
         self.query().get_fields().iter().find(|f| f.name == name).cloned()
     }
 
+    /// Finds an input object type by name.
+    pub fn find_input_object_type(&self, name: &str) -> Option<InputObjectTypeStrongRef> {
+        self.input_object_type_indexes
+            .get(name)
+            .map(|idx| self.input_object_types[*idx].clone())
+    }
+
+    /// Finds an enum type accepted as input anywhere in the schema by name.
+    pub fn find_input_enum_type(&self, name: &str) -> Option<EnumTypeRef> {
+        self.input_enum_types.get(name).cloned()
+    }
+
+    fn collect_input_enum_types(&self) -> HashMap<String, EnumTypeRef> {
+        let root_types = [self.query(), self.mutation()];
+
+        let input_fields = self.input_object_types.iter().flat_map(|typ| typ.get_fields().iter());
+        let arguments = self
+            .output_object_types
+            .iter()
+            .chain(root_types.iter())
+            .flat_map(|typ| typ.get_fields().iter())
+            .flat_map(|field| field.arguments.iter());
+
+        input_fields
+            .chain(arguments)
+            .flat_map(|field| field.field_types.iter())
+            .filter_map(Self::enum_in_input_type)
+            .map(|e| (e.name().to_owned(), e))
+            .collect()
+    }
+
+    fn enum_in_input_type(typ: &InputType) -> Option<EnumTypeRef> {
+        match typ {
+            InputType::Enum(e) | InputType::Scalar(ScalarType::Enum(e)) => Some(e.clone()),
+            InputType::List(inner) => Self::enum_in_input_type(inner),
+            _ => None,
+        }
+    }
+
     pub fn mutation(&self) -> ObjectTypeStrongRef {
         match self.mutation.borrow() {
             OutputType::Object(ref o) => o.into_arc(),
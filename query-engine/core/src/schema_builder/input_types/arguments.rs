@@ -18,6 +18,14 @@ pub(crate) fn where_unique_argument(ctx: &mut BuilderContext, model: &ModelRef)
     }
 }
 
+/// Builds "withDeleted" argument, including soft deleted records in a read.
+/// The argument is only present for models in soft delete mode.
+pub(crate) fn with_deleted_argument(model: &ModelRef) -> Option<InputField> {
+    model
+        .soft_delete_field()
+        .map(|_| input_field("withDeleted", InputType::boolean(), None).optional())
+}
+
 /// Builds "data" argument intended for the create field.
 /// The data argument is not present if no data can be created.
 pub(crate) fn create_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Vec<InputField>> {
@@ -119,6 +127,7 @@ pub(crate) fn many_records_arguments(
         );
     }
 
+    append_opt(&mut args, with_deleted_argument(model));
     args
 }
 
@@ -137,7 +146,7 @@ pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef) -> I
 pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
     let field_enum_type = InputType::Enum(model_field_enum(model));
 
-    vec![
+    let mut args = vec![
         where_argument(ctx, &model),
         order_by_argument(ctx, &model),
         input_field(
@@ -147,5 +156,8 @@ pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) ->
         ),
         input_field("take", InputType::int(), None).optional(),
         input_field("skip", InputType::int(), None).optional(),
-    ]
+    ];
+
+    append_opt(&mut args, with_deleted_argument(model));
+    args
 }
@@ -10,6 +10,7 @@ pub(crate) fn get_field_filter_types(ctx: &mut BuilderContext, field: &ModelFiel
             types.extend(mto1_relation_filter_shorthand_types(ctx, rf));
             types
         }
+        ModelField::Scalar(sf) if sf.is_composite() => composite_filter_types(ctx, sf),
         ModelField::Scalar(sf) if field.is_list() => vec![InputType::object(scalar_list_filter_type(ctx, sf))],
         ModelField::Scalar(sf) => {
             let mut types = vec![InputType::object(full_scalar_filter_type(ctx, sf, false))];
@@ -27,6 +28,30 @@ pub(crate) fn get_field_filter_types(ctx: &mut BuilderContext, field: &ModelFiel
     }
 }
 
+/// Builds the composite filter and, if partial matching is supported and unambiguous, the `is`
+/// shorthand: `where: { composite_field: { ... } }`. Null is added if the field is not required.
+fn composite_filter_types(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Vec<InputType> {
+    let mut types = vec![InputType::object(composite_objects::composite_filter_type(ctx, sf))];
+
+    let shorthand_is_unambiguous = sf
+        .composite_type()
+        .map(|composite_type| composite_filter_shorthand_is_unambiguous(&composite_type))
+        .unwrap_or(false);
+
+    if ctx.capabilities.contains(ConnectorCapability::CompositeTypeFilters) && shorthand_is_unambiguous {
+        let name = sf.composite_type.as_ref().unwrap();
+        types.push(InputType::object(composite_objects::composite_where_input_type(
+            ctx, name,
+        )));
+    }
+
+    if !sf.is_required {
+        types.push(InputType::null());
+    }
+
+    types
+}
+
 /// Builds shorthand relation equality (`is`) filter for to-one: `where: { relation_field: { ... } }` (no `is` in between).
 /// If the field is also not required, null is also added as possible type.
 fn mto1_relation_filter_shorthand_types(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Vec<InputType> {
@@ -86,12 +111,25 @@ fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Inp
     let object = Arc::new(init_input_object_type(ident.clone()));
     ctx.cache_input_type(ident, object.clone());
 
-    let fields = equality_filters(sf).collect();
+    let fields = equality_filters(sf).chain(scalar_list_filters(sf)).collect();
     object.set_fields(fields);
 
     Arc::downgrade(&object)
 }
 
+fn scalar_list_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
+    let element_type = map_scalar_element_input_type(sf);
+    let list_type = map_scalar_input_type(sf);
+
+    vec![
+        input_field("has", element_type, None).optional(),
+        input_field("hasEvery", list_type.clone(), None).optional(),
+        input_field("hasSome", list_type, None).optional(),
+        input_field("isEmpty", InputType::boolean(), None).optional(),
+    ]
+    .into_iter()
+}
+
 fn full_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested: bool) -> InputObjectTypeWeakRef {
     let ident = Identifier::new(scalar_filter_name(sf, nested), PRISMA_NAMESPACE);
     return_cached_input!(ctx, &ident);
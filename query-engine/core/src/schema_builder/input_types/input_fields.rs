@@ -126,6 +126,7 @@ pub(crate) fn nested_update_input_field(ctx: &mut BuilderContext, field: &Relati
 
 /// Builds scalar input fields using the mapper and the given, prefiltered, scalar fields.
 /// The mapper is responsible for mapping the fields to input types.
+/// List fields additionally get the atomic list operations if `with_list_operations` is set.
 pub(crate) fn scalar_input_fields<T, F>(
     ctx: &mut BuilderContext,
     model_name: String,
@@ -133,6 +134,7 @@ pub(crate) fn scalar_input_fields<T, F>(
     prefiltered_fields: Vec<ScalarFieldRef>,
     non_list_field_mapper: F,
     with_defaults: bool,
+    with_list_operations: bool,
 ) -> Vec<InputField>
 where
     T: Into<String>,
@@ -161,6 +163,18 @@ where
 
             let input_object = match ctx.get_input_type(&set_object_ident) {
                 Some(t) => t,
+                None if with_list_operations => {
+                    let mut input_object = init_input_object_type(set_object_ident.clone());
+                    input_object.require_exactly_one_field();
+
+                    let input_object = Arc::new(input_object);
+                    ctx.cache_input_type(set_object_ident, input_object.clone());
+
+                    let fields = list_operation_fields(ctx, &model_name, &input_object_name, &f);
+                    input_object.set_fields(fields);
+
+                    Arc::downgrade(&input_object)
+                }
                 None => {
                     let set_fields = vec![input_field("set", list_input_type.clone(), None)];
                     let input_object = Arc::new(input_object_type(set_object_ident.clone(), set_fields));
@@ -179,6 +193,46 @@ where
     non_list_fields
 }
 
+/// Builds the `set`, `push`, `unshift`, `removeAll` and `setAt` operations for a scalar list field.
+fn list_operation_fields(
+    ctx: &mut BuilderContext,
+    model_name: &str,
+    input_object_name: &str,
+    field: &ScalarFieldRef,
+) -> Vec<InputField> {
+    let list_input_type = map_scalar_input_type(field);
+    let element_input_type = map_scalar_element_input_type(field);
+    let values_input_types = vec![element_input_type.clone(), list_input_type.clone()];
+
+    let set_at_ident = Identifier::new(
+        format!("{}{}{}SetAtInput", model_name, input_object_name, field.name),
+        PRISMA_NAMESPACE,
+    );
+
+    let set_at_object = match ctx.get_input_type(&set_at_ident) {
+        Some(t) => t,
+        None => {
+            let set_at_fields = vec![
+                input_field("index", InputType::int(), None),
+                input_field("value", element_input_type.clone(), None),
+            ];
+
+            let input_object = Arc::new(input_object_type(set_at_ident.clone(), set_at_fields));
+            ctx.cache_input_type(set_at_ident, input_object.clone());
+
+            Arc::downgrade(&input_object)
+        }
+    };
+
+    vec![
+        input_field("set", list_input_type, None).optional(),
+        input_field("push", values_input_types.clone(), None).optional(),
+        input_field("unshift", values_input_types, None).optional(),
+        input_field("removeAll", element_input_type, None).optional(),
+        input_field("setAt", InputType::object(set_at_object), None).optional(),
+    ]
+}
+
 fn where_input_field<T>(ctx: &mut BuilderContext, name: T, field: &RelationFieldRef) -> InputField
 where
     T: Into<String>,
@@ -7,7 +7,7 @@ mod objects;
 use super::*;
 use crate::schema::*;
 use objects::*;
-use prisma_models::{RelationFieldRef, ScalarFieldRef};
+use prisma_models::{InternalEnum, RelationFieldRef, ScalarFieldRef};
 
 /// Builds "<Model>OrderByInput" object types.
 pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
@@ -34,7 +34,30 @@ pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -
 }
 
 fn map_scalar_input_type(field: &ScalarFieldRef) -> InputType {
-    let typ = match field.type_identifier {
+    let typ = map_scalar_element_input_type(field);
+
+    if field.is_list {
+        InputType::list(typ)
+    } else {
+        typ
+    }
+}
+
+/// Composite fields take a whole composite value as input, even though they are stored as Json.
+fn map_scalar_field_input_type(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputType {
+    match &field.composite_type {
+        Some(name) => InputType::object(composite_objects::composite_create_input_type(ctx, name)),
+        None => map_scalar_input_type(field),
+    }
+}
+
+/// The input type of a single value of the field, regardless of whether the field is a list.
+fn map_scalar_element_input_type(field: &ScalarFieldRef) -> InputType {
+    map_type_identifier_input_type(&field.type_identifier, field.internal_enum.as_ref())
+}
+
+fn map_type_identifier_input_type(type_identifier: &TypeIdentifier, internal_enum: Option<&InternalEnum>) -> InputType {
+    match type_identifier {
         TypeIdentifier::String => InputType::string(),
         TypeIdentifier::Int => InputType::int(),
         TypeIdentifier::Float => InputType::float(),
@@ -43,24 +66,16 @@ fn map_scalar_input_type(field: &ScalarFieldRef) -> InputType {
         TypeIdentifier::UUID => InputType::uuid(),
         TypeIdentifier::DateTime => InputType::date_time(),
         TypeIdentifier::Json => InputType::json(),
-        TypeIdentifier::Enum(_) => map_enum_input_type(&field),
+        TypeIdentifier::Enum(_) => map_enum_input_type(internal_enum),
         TypeIdentifier::Xml => InputType::xml(),
         TypeIdentifier::Bytes => InputType::bytes(),
         TypeIdentifier::BigInt => InputType::bigint(),
-    };
-
-    if field.is_list {
-        InputType::list(typ)
-    } else {
-        typ
     }
 }
 
-fn map_enum_input_type(field: &ScalarFieldRef) -> InputType {
-    let internal_enum = field
-        .internal_enum
-        .as_ref()
-        .expect("A field with TypeIdentifier Enum must always have an associated internal enum.");
+fn map_enum_input_type(internal_enum: Option<&InternalEnum>) -> InputType {
+    let internal_enum =
+        internal_enum.expect("A field with TypeIdentifier Enum must always have an associated internal enum.");
 
     let et: EnumType = internal_enum.clone().into();
     et.into()
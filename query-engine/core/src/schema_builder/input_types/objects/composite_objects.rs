@@ -0,0 +1,111 @@
+use super::*;
+use datamodel_connector::ConnectorCapability;
+use prisma_models::InternalCompositeTypeField;
+
+/// Builds the "<CompositeType>CreateInput" object type, used to write whole composite values.
+pub(crate) fn composite_create_input_type(ctx: &mut BuilderContext, name: &str) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}CreateInput", name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let composite_type = find_composite_type(ctx, name);
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|f| {
+            let typ = map_composite_field_input_type(ctx, f);
+
+            input_field(f.name.clone(), typ, None)
+                .optional_if(!f.is_required)
+                .nullable_if(!f.is_required)
+        })
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds the "<CompositeType>WhereInput" object type, matching composite values
+/// that contain all the given field values.
+pub(crate) fn composite_where_input_type(ctx: &mut BuilderContext, name: &str) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}WhereInput", name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let composite_type = find_composite_type(ctx, name);
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|f| {
+            let typ = match &f.composite_type {
+                Some(name) if !f.is_list => InputType::object(composite_where_input_type(ctx, name)),
+                _ => map_composite_field_input_type(ctx, f),
+            };
+
+            input_field(f.name.clone(), typ, None)
+                .optional()
+                .nullable_if(!f.is_required)
+        })
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds the "<CompositeType>[Nullable]CompositeFilter" object type for composite fields.
+/// Partial matching with `is` / `isNot` requires connector support for document containment.
+pub(crate) fn composite_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> InputObjectTypeWeakRef {
+    let name = sf.composite_type.as_ref().unwrap();
+    let nullable = if sf.is_required { "" } else { "Nullable" };
+    let ident = Identifier::new(format!("{}{}CompositeFilter", name, nullable), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, object.clone());
+
+    let create_input = composite_create_input_type(ctx, name);
+    let mut fields = vec![input_field("equals", InputType::object(create_input), None)
+        .optional()
+        .nullable_if(!sf.is_required)];
+
+    if ctx.capabilities.contains(ConnectorCapability::CompositeTypeFilters) {
+        let where_input = composite_where_input_type(ctx, name);
+
+        fields.push(
+            input_field("is", InputType::object(where_input.clone()), None)
+                .optional()
+                .nullable_if(!sf.is_required),
+        );
+        fields.push(
+            input_field("isNot", InputType::object(where_input), None)
+                .optional()
+                .nullable_if(!sf.is_required),
+        );
+    }
+
+    object.set_fields(fields);
+    Arc::downgrade(&object)
+}
+
+fn map_composite_field_input_type(ctx: &mut BuilderContext, field: &InternalCompositeTypeField) -> InputType {
+    let typ = match &field.composite_type {
+        Some(name) => InputType::object(composite_create_input_type(ctx, name)),
+        None => map_type_identifier_input_type(&field.type_identifier, field.internal_enum.as_ref()),
+    };
+
+    if field.is_list {
+        InputType::list(typ)
+    } else {
+        typ
+    }
+}
+
+fn find_composite_type(ctx: &BuilderContext, name: &str) -> prisma_models::InternalCompositeType {
+    ctx.internal_data_model
+        .find_composite_type(name)
+        .expect("Invariant violation: Composite fields refer to existing composite types.")
+}
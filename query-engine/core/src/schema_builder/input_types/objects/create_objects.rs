@@ -58,14 +58,21 @@ fn checked_create_input_type(
         model.name.clone(),
         "Create",
         scalar_fields,
-        |_, f: ScalarFieldRef, default: Option<DefaultValue>| {
-            let typ = map_scalar_input_type(&f);
+        |ctx, f: ScalarFieldRef, default: Option<DefaultValue>| {
+            let typ = map_scalar_field_input_type(ctx, &f);
 
             input_field(f.name.clone(), typ, default)
-                .optional_if(!f.is_required || f.default_value.is_some() || f.is_created_at() || f.is_updated_at())
+                .optional_if(
+                    !f.is_required
+                        || f.default_value.is_some()
+                        || f.is_created_at()
+                        || f.is_updated_at()
+                        || f.is_version(),
+                )
                 .nullable_if(!f.is_required)
         },
         true,
+        false,
     );
 
     // Compute input fields for relational fields.
@@ -185,7 +192,7 @@ fn unchecked_create_input_type(
         .fields()
         .scalar()
         .into_iter()
-        .filter(|sf| !linking_fields.contains(sf))
+        .filter(|sf| !linking_fields.contains(sf) && !sf.is_generated())
         .collect();
 
     let mut fields = input_fields::scalar_input_fields(
@@ -193,14 +200,21 @@ fn unchecked_create_input_type(
         model.name.clone(),
         "Create",
         scalar_fields,
-        |_, f: ScalarFieldRef, default: Option<DefaultValue>| {
-            let typ = map_scalar_input_type(&f);
+        |ctx, f: ScalarFieldRef, default: Option<DefaultValue>| {
+            let typ = map_scalar_field_input_type(ctx, &f);
 
             input_field(f.name.clone(), typ, default)
-                .optional_if(!f.is_required || f.default_value.is_some() || f.is_created_at() || f.is_updated_at())
+                .optional_if(
+                    !f.is_required
+                        || f.default_value.is_some()
+                        || f.is_created_at()
+                        || f.is_updated_at()
+                        || f.is_version(),
+                )
                 .nullable_if(!f.is_required)
         },
         true,
+        false,
     );
 
     // Compute input fields for relational fields.
@@ -78,8 +78,16 @@ pub(crate) fn where_unique_object_type(ctx: &mut BuilderContext, model: &ModelRe
     let ident = Identifier::new(format!("{}WhereUniqueInput", model.name), PRISMA_NAMESPACE);
     return_cached_input!(ctx, &ident);
 
+    let version_field = model.fields().version().clone();
     let mut x = init_input_object_type(ident.clone());
-    x.require_exactly_one_field();
+
+    // The version field can be given in addition to exactly one of the unique criteria.
+    if version_field.is_some() {
+        x.set_min_fields(1);
+        x.set_max_fields(2);
+    } else {
+        x.require_exactly_one_field();
+    }
 
     let input_object = Arc::new(x);
     ctx.cache_input_type(ident, input_object.clone());
@@ -124,6 +132,7 @@ pub(crate) fn where_unique_object_type(ctx: &mut BuilderContext, model: &ModelRe
 
     fields.extend(compound_unique_fields);
     fields.extend(compound_id_field);
+    fields.extend(version_field.map(|sf| input_field(sf.name.clone(), map_scalar_input_type(&sf), None).optional()));
 
     input_object.set_fields(fields);
 
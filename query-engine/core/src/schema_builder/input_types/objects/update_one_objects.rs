@@ -94,6 +94,7 @@ pub(super) fn scalar_input_fields_for_checked_update(ctx: &mut BuilderContext, m
             .collect(),
         |ctx, f: ScalarFieldRef, default| non_list_scalar_update_field_mapper(ctx, &f, default),
         false,
+        true,
     )
 }
 
@@ -117,7 +118,7 @@ pub(super) fn scalar_input_fields_for_unchecked_update(
         .fields()
         .scalar()
         .into_iter()
-        .filter(|sf| !linking_fields.contains(sf))
+        .filter(|sf| !linking_fields.contains(sf) && !sf.is_generated())
         .collect();
 
     input_fields::scalar_input_fields(
@@ -127,6 +128,7 @@ pub(super) fn scalar_input_fields_for_unchecked_update(
         scalar_fields,
         |ctx, f: ScalarFieldRef, default| non_list_scalar_update_field_mapper(ctx, &f, default),
         false,
+        true,
     )
 }
 
@@ -135,6 +137,14 @@ fn non_list_scalar_update_field_mapper(
     field: &ScalarFieldRef,
     default: Option<DefaultValue>,
 ) -> InputField {
+    // Composite values are always replaced as a whole.
+    if field.is_composite() {
+        let typ = map_scalar_field_input_type(ctx, field);
+        return input_field(field.name.clone(), typ, default)
+            .optional()
+            .nullable_if(!field.is_required);
+    }
+
     let base_update_type = match &field.type_identifier {
         TypeIdentifier::Float => InputType::object(operations_object_type(ctx, "Float", field, true)),
         TypeIdentifier::Decimal => InputType::object(operations_object_type(ctx, "Decimal", field, true)),
@@ -1,5 +1,5 @@
 use super::*;
-use prisma_models::ScalarFieldRef;
+use prisma_models::{InternalCompositeTypeField, InternalEnum, ScalarFieldRef};
 
 /// Initializes model output object type cache on the context.
 /// This is a critical first step to ensure that all model output object types are present
@@ -61,18 +61,32 @@ pub(crate) fn map_field(ctx: &mut BuilderContext, model_field: &ModelField) -> O
 
 pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField) -> OutputType {
     match model_field {
+        ModelField::Scalar(sf) if sf.is_composite() => map_composite_output_type(ctx, sf),
         ModelField::Scalar(sf) => map_scalar_output_type(sf),
         ModelField::Relation(rf) => map_relation_output_type(ctx, rf),
     }
 }
 
 pub(crate) fn map_scalar_output_type(field: &ScalarFieldRef) -> OutputType {
-    let output_type = match field.type_identifier {
+    let output_type = map_type_identifier_output_type(&field.type_identifier, field.internal_enum.as_ref());
+
+    if field.is_list {
+        OutputType::list(output_type)
+    } else {
+        output_type
+    }
+}
+
+fn map_type_identifier_output_type(
+    type_identifier: &TypeIdentifier,
+    internal_enum: Option<&InternalEnum>,
+) -> OutputType {
+    match type_identifier {
         TypeIdentifier::String => OutputType::string(),
         TypeIdentifier::Float => OutputType::float(),
         TypeIdentifier::Decimal => OutputType::decimal(),
         TypeIdentifier::Boolean => OutputType::boolean(),
-        TypeIdentifier::Enum(_) => map_enum_field(field).into(),
+        TypeIdentifier::Enum(_) => map_enum_type(internal_enum).into(),
         TypeIdentifier::Json => OutputType::json(),
         TypeIdentifier::DateTime => OutputType::date_time(),
         TypeIdentifier::UUID => OutputType::uuid(),
@@ -80,12 +94,6 @@ pub(crate) fn map_scalar_output_type(field: &ScalarFieldRef) -> OutputType {
         TypeIdentifier::Xml => OutputType::xml(),
         TypeIdentifier::Bytes => OutputType::bytes(),
         TypeIdentifier::BigInt => OutputType::bigint(),
-    };
-
-    if field.is_list {
-        OutputType::list(output_type)
-    } else {
-        output_type
     }
 }
 
@@ -99,17 +107,56 @@ pub(crate) fn map_relation_output_type(ctx: &mut BuilderContext, field: &Relatio
     }
 }
 
-pub(crate) fn map_enum_field(scalar_field: &ScalarFieldRef) -> EnumType {
-    match scalar_field.type_identifier {
-        TypeIdentifier::Enum(_) => {
-            let internal_enum = scalar_field
-                .internal_enum
-                .as_ref()
-                .expect("Invariant violation: Enum fields are expected to have an internal_enum associated with them.");
-
-            internal_enum.clone().into()
-        }
-        _ => panic!("Invariant violation: map_enum_field can only be called on scalar enum fields."),
+fn map_enum_type(internal_enum: Option<&InternalEnum>) -> EnumType {
+    internal_enum
+        .expect("Invariant violation: Enum fields are expected to have an internal_enum associated with them.")
+        .clone()
+        .into()
+}
+
+/// Composite fields are typed with the object type of their composite type,
+/// even though their values are stored as Json.
+fn map_composite_output_type(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> OutputType {
+    let composite_type = field.composite_type.as_ref().unwrap();
+
+    OutputType::object(composite_object_type(ctx, composite_type))
+}
+
+/// Builds the output object type of a composite type (e.g. `Address`).
+pub(crate) fn composite_object_type(ctx: &mut BuilderContext, name: &str) -> ObjectTypeWeakRef {
+    let ident = Identifier::new(name.to_owned(), MODEL_NAMESPACE);
+    return_cached_output!(ctx, &ident);
+
+    let composite_type = ctx
+        .internal_data_model
+        .find_composite_type(name)
+        .expect("Invariant violation: Composite fields refer to existing composite types.");
+
+    let object = ObjectTypeStrongRef::new(ObjectType::new(ident.clone(), None));
+    ctx.cache_output_type(ident, ObjectTypeStrongRef::clone(&object));
+
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|f| {
+            field(f.name.clone(), vec![], map_composite_field_output_type(ctx, f), None).optional_if(!f.is_required)
+        })
+        .collect();
+
+    object.set_fields(fields);
+    ObjectTypeStrongRef::downgrade(&object)
+}
+
+fn map_composite_field_output_type(ctx: &mut BuilderContext, field: &InternalCompositeTypeField) -> OutputType {
+    let output_type = match &field.composite_type {
+        Some(name) => OutputType::object(composite_object_type(ctx, name)),
+        None => map_type_identifier_output_type(&field.type_identifier, field.internal_enum.as_ref()),
+    };
+
+    if field.is_list {
+        OutputType::list(output_type)
+    } else {
+        output_type
     }
 }
 
@@ -33,10 +33,13 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
 fn find_one_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
     arguments::where_unique_argument(ctx, model).map(|arg| {
         let field_name = ctx.pluralize_internal(camel_case(&model.name), format!("findOne{}", model.name));
+        let mut args = vec![arg];
+
+        append_opt(&mut args, arguments::with_deleted_argument(model));
 
         field(
             field_name,
-            vec![arg],
+            args,
             OutputType::object(output_objects::map_model_object_type(ctx, &model)),
             Some(QueryInfo {
                 model: Some(Arc::clone(&model)),
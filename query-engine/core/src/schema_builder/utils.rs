@@ -2,7 +2,7 @@ use super::*;
 use crate::EnumType;
 use itertools::Itertools;
 use once_cell::sync::OnceCell;
-use prisma_models::{dml, ModelRef};
+use prisma_models::{dml, InternalCompositeType, ModelRef};
 use std::sync::Arc;
 
 /// Object type convenience wrapper function.
@@ -133,6 +133,19 @@ pub fn compound_index_field_name(index: &Index) -> String {
     })
 }
 
+/// The operations of the full composite filter object.
+pub const COMPOSITE_FILTER_OPERATIONS: &[&str] = &["equals", "is", "isNot"];
+
+/// Whether the `is` shorthand `where: { composite_field: { ... } }` can be told apart from the
+/// full composite filter object. It can't if a field of the composite type is named like a
+/// filter operation, the shorthand is not offered then.
+pub fn composite_filter_shorthand_is_unambiguous(composite_type: &InternalCompositeType) -> bool {
+    composite_type
+        .fields
+        .iter()
+        .all(|field| !COMPOSITE_FILTER_OPERATIONS.contains(&field.name.as_str()))
+}
+
 /// Computes a compound field name based on a multi-field id.
 pub fn compound_id_field_name<T>(field_names: &[T]) -> String
 where
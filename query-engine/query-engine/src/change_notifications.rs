@@ -0,0 +1,213 @@
+//! Delivery of change events between engine instances with Postgres
+//! `LISTEN`/`NOTIFY`.
+//!
+//! The executor sends the events of committed writes on a notification
+//! channel, see `ChangeEvents::with_notify_channel`. Every instance listens on
+//! the channel on a dedicated connection, and publishes the events it receives
+//! to its subscribers, including the events of its own writes.
+
+use crate::{PrismaError, PrismaResult};
+use futures::{
+    future::{self, Either},
+    stream, StreamExt,
+};
+use native_tls::{Certificate, Identity, TlsConnector};
+use percent_encoding::percent_decode_str;
+use postgres_native_tls::MakeTlsConnector;
+use query_core::{ChangeEvent, ChangeEvents};
+use std::{collections::HashMap, fs, time::Duration};
+use tokio_postgres::{config::SslMode as PostgresSslMode, AsyncMessage, Config};
+use url::Url;
+
+/// The connection string parameter naming the notification channel.
+pub(crate) const NOTIFY_CHANNEL_PARAM: &str = "notify_channel";
+
+/// The delay before the first reconnection attempt of the listener. It doubles
+/// with every failed attempt, up to `MAX_RECONNECT_BACKOFF`.
+const MIN_RECONNECT_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Connect to the database and start listening on the notification channel of
+/// `change_events`. The listener runs in the background, and reconnects with an
+/// exponential backoff when the connection is lost. Events sent while the
+/// listener is disconnected are not delivered.
+///
+/// The listener connection uses the same TLS settings as the connector: the
+/// `sslmode`, `sslcert`, `sslidentity`, `sslpassword` and `sslaccept`
+/// parameters of the URL.
+pub(crate) async fn listen(url: &Url, change_events: ChangeEvents) -> PrismaResult<()> {
+    let channel = change_events
+        .notify_channel()
+        .expect("Listening for change notifications without a channel.")
+        .to_owned();
+
+    let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+    let tls = tls_connector(&params)?;
+    let config = config(url, &params);
+
+    // The first connection is made upfront, so a misconfigured listener fails
+    // the startup of the engine.
+    let first_connection = config.connect(tls.clone()).await.map_err(|err| {
+        PrismaError::ConfigurationError(format!("Failed to connect the change notification listener: {}", err))
+    })?;
+
+    async_std::task::spawn(async move {
+        let mut connection = Some(first_connection);
+        let mut backoff = MIN_RECONNECT_BACKOFF;
+
+        loop {
+            let (client, connection) = match connection.take() {
+                Some(connection) => connection,
+                None => match config.connect(tls.clone()).await {
+                    Ok(connection) => connection,
+                    Err(err) => {
+                        warn!(
+                            "Failed to reconnect the change notification listener, retrying in {:?}: {}",
+                            backoff, err
+                        );
+
+                        async_std::task::sleep(backoff).await;
+                        backoff = std::cmp::min(backoff * 2, MAX_RECONNECT_BACKOFF);
+
+                        continue;
+                    }
+                },
+            };
+
+            let listen = format!("LISTEN \"{}\"", channel.replace('"', "\"\""));
+            let register = Box::pin(client.batch_execute(&listen));
+
+            // The connection has to be polled for the `LISTEN` to complete.
+            let forward = Box::pin(async {
+                let mut connection = connection;
+                let mut messages = stream::poll_fn(|cx| connection.poll_message(cx));
+
+                while let Some(message) = messages.next().await {
+                    match message {
+                        Ok(AsyncMessage::Notification(notification)) => {
+                            match serde_json::from_str::<ChangeEvent>(notification.payload()) {
+                                Ok(event) => change_events.publish(vec![event]),
+                                Err(err) => warn!("Ignoring an invalid change notification: {}", err),
+                            }
+                        }
+                        Ok(_) => (),
+                        Err(err) => {
+                            error!("The change notification listener failed: {}", err);
+                            break;
+                        }
+                    }
+                }
+            });
+
+            match future::select(register, forward).await {
+                Either::Left((Ok(()), forward)) => {
+                    backoff = MIN_RECONNECT_BACKOFF;
+                    forward.await;
+                }
+                Either::Left((Err(err), _)) => {
+                    error!(
+                        "Failed to listen on the change notification channel `{}`: {}",
+                        channel, err
+                    );
+                }
+                Either::Right(((), _)) => (),
+            }
+
+            warn!(
+                "The change notification listener lost its connection, reconnecting in {:?}.",
+                backoff
+            );
+
+            async_std::task::sleep(backoff).await;
+            backoff = std::cmp::min(backoff * 2, MAX_RECONNECT_BACKOFF);
+        }
+    });
+
+    Ok(())
+}
+
+/// The listener connection only needs the connection parameters: the
+/// Prisma-specific parameters of the connection string would be rejected.
+fn config(url: &Url, params: &HashMap<String, String>) -> Config {
+    let decode = |s: &str| percent_decode_str(s).decode_utf8_lossy().into_owned();
+    let mut config = Config::new();
+
+    config
+        .host(url.host_str().unwrap_or("localhost"))
+        .port(url.port().unwrap_or(5432))
+        .user(&decode(url.username()))
+        .dbname(&decode(url.path().trim_start_matches('/')));
+
+    if let Some(password) = url.password() {
+        config.password(decode(password));
+    }
+
+    // Unknown modes fall back to `prefer`, like in the connector.
+    let ssl_mode = match params.get("sslmode").map(String::as_str) {
+        Some("disable") => PostgresSslMode::Disable,
+        Some("require") => PostgresSslMode::Require,
+        _ => PostgresSslMode::Prefer,
+    };
+
+    config.ssl_mode(ssl_mode);
+
+    config
+}
+
+/// The TLS connector of the listener. Invalid certificates are accepted unless
+/// `sslaccept=strict` is set.
+fn tls_connector(params: &HashMap<String, String>) -> PrismaResult<MakeTlsConnector> {
+    let tls_error =
+        |err: &dyn std::fmt::Display| PrismaError::ConfigurationError(format!("Invalid TLS configuration: {}", err));
+    let mut builder = TlsConnector::builder();
+
+    if let Some(certificate_file) = params.get("sslcert") {
+        let certificate = fs::read(certificate_file).map_err(|err| tls_error(&err))?;
+        builder.add_root_certificate(Certificate::from_pem(&certificate).map_err(|err| tls_error(&err))?);
+    }
+
+    if let Some(identity_file) = params.get("sslidentity") {
+        let identity = fs::read(identity_file).map_err(|err| tls_error(&err))?;
+        let password = params.get("sslpassword").map(String::as_str).unwrap_or("");
+
+        builder.identity(Identity::from_pkcs12(&identity, password).map_err(|err| tls_error(&err))?);
+    }
+
+    let strict = params.get("sslaccept").map(String::as_str) == Some("strict");
+    builder.danger_accept_invalid_certs(!strict);
+
+    let connector = builder.build().map_err(|err| tls_error(&err))?;
+
+    Ok(MakeTlsConnector::new(connector))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ssl_mode_of(url: &str) -> PostgresSslMode {
+        let url = Url::parse(url).unwrap();
+        let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+        config(&url, &params).get_ssl_mode()
+    }
+
+    #[test]
+    fn the_ssl_mode_is_read_from_the_url() {
+        assert_eq!(
+            ssl_mode_of("postgresql://localhost/db?sslmode=disable"),
+            PostgresSslMode::Disable
+        );
+        assert_eq!(
+            ssl_mode_of("postgresql://localhost/db?sslmode=require"),
+            PostgresSslMode::Require
+        );
+    }
+
+    #[test]
+    fn the_ssl_mode_defaults_to_prefer() {
+        let url = "postgresql://localhost/db?notify_channel=changes";
+
+        assert_eq!(ssl_mode_of(url), PostgresSslMode::Prefer);
+    }
+}
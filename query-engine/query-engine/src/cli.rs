@@ -4,7 +4,7 @@ use crate::{
     context::PrismaContext,
     dmmf,
     opt::{CliOpt, PrismaOpt, Subcommand},
-    PrismaResult,
+    PrismaError, PrismaResult,
 };
 
 use datamodel::diagnostics::ValidatedConfiguration;
@@ -27,6 +27,7 @@ pub struct DmmfRequest {
     build_mode: BuildMode,
     enable_raw_queries: bool,
     config: Configuration,
+    version: u32,
 }
 
 pub struct GetConfigRequest {
@@ -35,6 +36,7 @@ pub struct GetConfigRequest {
 
 pub enum CliCommand {
     Dmmf(DmmfRequest),
+    DmmfJsonSchema,
     GetConfig(GetConfigRequest),
     ExecuteRequest(ExecuteRequest),
 }
@@ -50,7 +52,32 @@ impl CliCommand {
 
         match subcommand {
             Subcommand::Cli(ref cliopts) => match cliopts {
-                CliOpt::Dmmf => {
+                CliOpt::Dmmf(input) => {
+                    let version = input.version.unwrap_or(dmmf::DMMF_VERSION);
+
+                    let supported = dmmf::supported_dmmf_versions();
+
+                    if !supported.contains(&version) {
+                        return Err(PrismaError::InvocationError(format!(
+                            "The DMMF version {} is not supported by this engine. The supported versions are {} to {}.",
+                            version,
+                            supported.start(),
+                            supported.end()
+                        )));
+                    }
+
+                    if input.json_schema {
+                        // The JSON Schema is generated from the types of the current version.
+                        if version != dmmf::DMMF_VERSION {
+                            return Err(PrismaError::InvocationError(format!(
+                                "The JSON Schema of the DMMF is only available for the version {}.",
+                                dmmf::DMMF_VERSION
+                            )));
+                        }
+
+                        return Ok(Some(CliCommand::DmmfJsonSchema));
+                    }
+
                     let build_mode = if opts.legacy {
                         BuildMode::Legacy
                     } else {
@@ -62,6 +89,7 @@ impl CliCommand {
                         build_mode,
                         enable_raw_queries: opts.enable_raw_queries,
                         config: opts.configuration(true)?.subject,
+                        version,
                     })))
                 }
                 CliOpt::GetConfig(input) => Ok(Some(CliCommand::GetConfig(GetConfigRequest {
@@ -81,6 +109,7 @@ impl CliCommand {
     pub async fn execute(self) -> PrismaResult<()> {
         match self {
             CliCommand::Dmmf(request) => Self::dmmf(request).await,
+            CliCommand::DmmfJsonSchema => Self::dmmf_json_schema(),
             CliCommand::GetConfig(input) => Self::get_config(input.config),
             CliCommand::ExecuteRequest(request) => Self::execute_request(request).await,
         }
@@ -103,7 +132,7 @@ impl CliCommand {
             capabilities,
         ));
 
-        let dmmf = dmmf::render_dmmf(&request.datamodel, query_schema);
+        let dmmf = dmmf::render_dmmf_version(&request.datamodel, query_schema, request.version)?;
         let serialized = serde_json::to_string_pretty(&dmmf)?;
 
         println!("{}", serialized);
@@ -111,6 +140,15 @@ impl CliCommand {
         Ok(())
     }
 
+    fn dmmf_json_schema() -> PrismaResult<()> {
+        let schema = dmmf::dmmf_json_schema();
+        let serialized = serde_json::to_string_pretty(&schema)?;
+
+        println!("{}", serialized);
+
+        Ok(())
+    }
+
     fn get_config(config: ValidatedConfiguration) -> PrismaResult<()> {
         let json = datamodel::json::mcf::config_to_mcf_json_value(&config);
         let serialized = serde_json::to_string(&json)?;
@@ -1,8 +1,8 @@
-use crate::{exec_loader, PrismaError, PrismaResult};
+use crate::{exec_loader, request_handlers::PersistedOperations, PrismaError, PrismaResult};
 use datamodel::{Configuration, Datamodel};
 use prisma_models::DatamodelConverter;
 use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor};
-use std::{fmt, sync::Arc};
+use std::{fmt, sync::Arc, time::Duration};
 
 /// Prisma request context containing all immutable state of the process.
 /// There is usually only one context initialized per process.
@@ -13,6 +13,10 @@ pub struct PrismaContext {
     dm: Datamodel,
     /// Central query executor.
     pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+    /// GraphQL documents registered by clients.
+    persisted_operations: PersistedOperations,
+    /// Timeout of requests that don't set one.
+    query_timeout: Option<Duration>,
 }
 
 impl fmt::Debug for PrismaContext {
@@ -24,6 +28,7 @@ impl fmt::Debug for PrismaContext {
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    query_timeout: Option<Duration>,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -39,22 +44,31 @@ impl ContextBuilder {
         self
     }
 
+    pub fn query_timeout(mut self, val: Option<Duration>) -> Self {
+        self.query_timeout = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        let mut context = PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await?;
+        context.query_timeout = self.query_timeout;
+
+        Ok(context)
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
     async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
-        let template = DatamodelConverter::convert(&dm);
-
         // We only support one data source at the moment, so take the first one (default not exposed yet).
         let data_source = config
             .datasources
             .first()
             .ok_or_else(|| PrismaError::ConfigurationError("No valid data source found".into()))?;
 
+        let mut template = DatamodelConverter::convert(&dm);
+        template.referential_integrity = data_source.referential_integrity;
+
         // Load executor
         let (db_name, executor) = exec_loader::load(&data_source).await?;
 
@@ -74,6 +88,8 @@ impl PrismaContext {
             query_schema,
             dm,
             executor,
+            persisted_operations: PersistedOperations::default(),
+            query_timeout: None,
         };
 
         context.verify_connection().await?;
@@ -90,6 +106,7 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            query_timeout: None,
             datamodel,
             config,
         }
@@ -99,6 +116,14 @@ impl PrismaContext {
         &self.query_schema
     }
 
+    pub fn persisted_operations(&self) -> &PersistedOperations {
+        &self.persisted_operations
+    }
+
+    pub fn query_timeout(&self) -> Option<Duration> {
+        self.query_timeout
+    }
+
     pub fn datamodel(&self) -> &Datamodel {
         &self.dm
     }
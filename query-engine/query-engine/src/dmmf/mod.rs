@@ -2,15 +2,28 @@ mod schema;
 
 use query_core::schema::{QuerySchemaRef, QuerySchemaRenderer};
 use schema::*;
+use schemars::{gen::SchemaGenerator, schema::RootSchema, schema::Schema, JsonSchema};
 use serde::{ser::SerializeMap, Serialize, Serializer};
 use std::cmp::Ordering;
+use std::ops::RangeInclusive;
 use std::{cell::RefCell, collections::HashMap};
 
-#[derive(Debug, Serialize)]
+/// The version of the DMMF rendered by this engine. Any change to the shape
+/// of the DMMF must come with a version bump, the snapshots of the published
+/// versions are checked in `tests/dmmf.rs`.
+pub const DMMF_VERSION: u32 = 1;
+
+/// The oldest version of the DMMF this engine still renders, for the generators
+/// that did not move to the current version yet.
+pub const MIN_DMMF_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DataModelMetaFormat {
+    /// The version of the DMMF contract, see `DMMF_VERSION`.
+    pub version: u32,
     #[serde(rename = "datamodel")]
-    pub data_model: serde_json::Value,
+    pub data_model: datamodel::json::dmmf::Datamodel,
     pub schema: DmmfSchema,
     pub mappings: DmmfOperationMappings,
 }
@@ -18,14 +31,14 @@ pub struct DataModelMetaFormat {
 /// Model operations are serialized as an array of objects, each one
 /// corresponding to a model. All other operations are simply an object
 /// of read and write operation names that can be called on the engine.
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Default, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfOperationMappings {
     pub model_operations: Vec<DmmfModelOperations>,
     pub other_operations: DmmfNonModelOperations,
 }
 
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Default, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfNonModelOperations {
     pub read: Vec<String>,
@@ -88,13 +101,62 @@ impl Serialize for DmmfModelOperations {
     }
 }
 
+/// Model operations are serialized as a single map, see the `Serialize` implementation.
+impl JsonSchema for DmmfModelOperations {
+    fn schema_name() -> String {
+        "DmmfModelOperations".to_owned()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        <HashMap<String, String>>::json_schema(gen)
+    }
+}
+
 pub fn render_dmmf(dml: &datamodel::Datamodel, query_schema: QuerySchemaRef) -> DataModelMetaFormat {
     let (schema, mappings) = DmmfQuerySchemaRenderer::render(query_schema);
-    let datamodel_json = datamodel::json::dmmf::render_to_dmmf_value(&dml);
+    let data_model = datamodel::json::dmmf::schema_to_dmmf(&dml);
 
     DataModelMetaFormat {
-        data_model: datamodel_json,
+        version: DMMF_VERSION,
+        data_model,
         schema,
         mappings,
     }
 }
+
+/// The versions of the DMMF this engine can render.
+pub fn supported_dmmf_versions() -> RangeInclusive<u32> {
+    MIN_DMMF_VERSION..=DMMF_VERSION
+}
+
+/// Render the DMMF in one of the supported versions. The DMMF of the current
+/// version is converted down one version at a time, see `downgrade`.
+pub fn render_dmmf_version(
+    dml: &datamodel::Datamodel,
+    query_schema: QuerySchemaRef,
+    version: u32,
+) -> serde_json::Result<serde_json::Value> {
+    debug_assert!(supported_dmmf_versions().contains(&version));
+
+    let mut dmmf = serde_json::to_value(render_dmmf(dml, query_schema))?;
+
+    for to_version in (version..DMMF_VERSION).rev() {
+        downgrade(&mut dmmf, to_version);
+    }
+
+    dmmf["version"] = version.into();
+
+    Ok(dmmf)
+}
+
+/// Convert a DMMF of version `to_version + 1` to `to_version`. A bump of
+/// `DMMF_VERSION` that keeps the previous version supported adds the conversion
+/// of its changes here.
+fn downgrade(_dmmf: &mut serde_json::Value, to_version: u32) {
+    unreachable!("There is no conversion of the DMMF to version {}.", to_version)
+}
+
+/// The JSON Schema of the DMMF rendered by this engine, generated from the types above.
+pub fn dmmf_json_schema() -> RootSchema {
+    schemars::schema_for!(DataModelMetaFormat)
+}
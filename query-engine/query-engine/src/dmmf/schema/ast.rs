@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfSchema {
     pub input_object_types: HashMap<String, Vec<DmmfInputType>>,
@@ -10,7 +11,7 @@ pub struct DmmfSchema {
     pub enum_types: HashMap<String, Vec<DmmfEnum>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfOutputField {
     pub name: String,
@@ -20,7 +21,7 @@ pub struct DmmfOutputField {
     pub output_type: DmmfTypeReference,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfInputType {
     pub name: String,
@@ -28,21 +29,21 @@ pub struct DmmfInputType {
     pub fields: Vec<DmmfInputField>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfInputTypeConstraints {
     pub max_num_fields: Option<usize>,
     pub min_num_fields: Option<usize>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfOutputType {
     pub name: String,
     pub fields: Vec<DmmfOutputField>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfInputField {
     pub name: String,
@@ -51,7 +52,7 @@ pub struct DmmfInputField {
     pub input_types: Vec<DmmfTypeReference>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfTypeReference {
     #[serde(rename = "type")]
@@ -63,7 +64,7 @@ pub struct DmmfTypeReference {
     pub is_list: bool,
 }
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum TypeLocation {
     Scalar,
@@ -72,7 +73,7 @@ pub enum TypeLocation {
     EnumTypes,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DmmfEnum {
     pub name: String,
@@ -44,6 +44,9 @@ pub enum PrismaError {
 
     #[error("{}", _0)]
     FeatureError(String),
+
+    #[error("The persisted operation `{}` was not found.", _0)]
+    PersistedOperationNotFound(String),
 }
 
 impl From<PrismaError> for user_facing_errors::Error {
@@ -63,6 +66,11 @@ impl From<PrismaError> for user_facing_errors::Error {
                     user_facing_errors::common::SchemaParserError { full_error },
                 ))
             }
+            PrismaError::PersistedOperationNotFound(id) => {
+                user_facing_errors::Error::from(user_facing_errors::KnownError::new(
+                    user_facing_errors::query_engine::PersistedOperationNotFound { id },
+                ))
+            }
             other => user_facing_errors::Error::new_non_panic_with_current_backtrace(other.to_string()),
         }
     }
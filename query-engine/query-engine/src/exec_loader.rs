@@ -7,10 +7,15 @@ use datamodel::{
     common::provider_names::{MSSQL_SOURCE_NAME, MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Datasource,
 };
-use query_core::executor::{InterpretingExecutor, QueryExecutor};
+use query_core::{
+    executor::{InterpretingExecutor, QueryExecutor},
+    ChangeEvents,
+};
 use std::{collections::HashMap, path::PathBuf};
 use url::Url;
 
+#[cfg(feature = "sql")]
+use crate::change_notifications;
 #[cfg(feature = "sql")]
 use sql_connector::*;
 
@@ -61,7 +66,8 @@ async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecu
 async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading Postgres connector...");
 
-    let database_str = &source.url().value;
+    // The connection properties of the datasource reach the change listener as URL parameters.
+    let database_str = &source.connection_url();
     let psql = PostgreSql::from_source(source).await?;
 
     let url = Url::parse(database_str)?;
@@ -77,8 +83,23 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .and_then(|flag| flag.parse().ok())
         .unwrap_or(false);
 
+    // Change events are exchanged with other engine instances if a
+    // notification channel is configured.
+    let change_events = match params.get(change_notifications::NOTIFY_CHANNEL_PARAM) {
+        Some(channel) => {
+            let change_events = ChangeEvents::with_notify_channel(channel.clone());
+            change_notifications::listen(&url, change_events.clone()).await?;
+
+            change_events
+        }
+        None => ChangeEvents::new(),
+    };
+
     trace!("Loaded Postgres connector.");
-    Ok((db_name, sql_executor(psql, force_transactions)))
+    Ok((
+        db_name,
+        Box::new(InterpretingExecutor::new(psql, force_transactions).with_change_events(change_events)),
+    ))
 }
 
 #[cfg(feature = "sql")]
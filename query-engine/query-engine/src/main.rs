@@ -13,6 +13,8 @@ use tracing_subscriber::{EnvFilter, FmtSubscriber};
 use tide_server_timing::TimingLayer;
 use tracing_subscriber::layer::SubscriberExt;
 
+#[cfg(feature = "sql")]
+mod change_notifications;
 mod cli;
 mod context;
 mod dmmf;
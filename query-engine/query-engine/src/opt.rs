@@ -26,6 +26,17 @@ pub struct ExecuteRequestInput {
     pub legacy: bool,
 }
 
+#[derive(Debug, Clone, StructOpt)]
+#[structopt(rename_all = "camelCase")]
+pub struct DmmfInput {
+    /// The version of the DMMF to render. Defaults to the version of this engine.
+    #[structopt(long)]
+    pub version: Option<u32>,
+    /// Output the JSON Schema of the DMMF instead of the DMMF itself.
+    #[structopt(long)]
+    pub json_schema: bool,
+}
+
 #[derive(Debug, Clone, StructOpt)]
 #[structopt(rename_all = "camelCase")]
 pub struct GetConfigInput {
@@ -36,7 +47,7 @@ pub struct GetConfigInput {
 #[derive(Debug, StructOpt, Clone)]
 pub enum CliOpt {
     /// Output the DMMF from the loaded data model.
-    Dmmf,
+    Dmmf(DmmfInput),
     /// Get the configuration from the given data model.
     GetConfig(GetConfigInput),
     /// Executes one request and then terminates.
@@ -80,6 +91,10 @@ pub struct PrismaOpt {
     #[structopt(long, short = "r")]
     pub enable_raw_queries: bool,
 
+    /// Timeout of queries in milliseconds, for requests that don't set one.
+    #[structopt(long)]
+    pub query_timeout: Option<u64>,
+
     /// Enables the GraphQL playground
     #[structopt(long, short = "g")]
     pub enable_playground: bool,
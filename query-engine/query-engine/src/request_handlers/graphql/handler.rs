@@ -1,25 +1,41 @@
-use super::{protocol_adapter::GraphQLProtocolAdapter, GQLResponse};
-use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
+use super::{protocol_adapter::GraphQLProtocolAdapter, GQLResponse, PersistedOperation, PersistedOperations};
+use crate::{context::PrismaContext, error::PrismaError, PrismaResponse, PrismaResult};
 use futures::FutureExt;
-use graphql_parser as gql;
+use graphql_parser::{self as gql, query::Document};
 use indexmap::IndexMap;
-use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
+use query_core::{
+    BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, RequestContext, ResponseData,
+};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
+use std::{panic::AssertUnwindSafe, sync::Arc, time::Duration};
 
+// Multi comes first: a batch would otherwise deserialize as a single query
+// without a query text.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", untagged)]
 pub enum GraphQlBody {
-    Single(SingleQuery),
     Multi(MultiQuery),
+    Single(SingleQuery),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SingleQuery {
+    /// The GraphQL text of the query. It can be omitted when `id` refers to a
+    /// persisted operation.
+    #[serde(default)]
     query: String,
+    /// The id of a persisted operation, see `PersistedOperations`.
+    id: Option<String>,
     operation_name: Option<String>,
-    variables: HashMap<String, String>,
+    #[serde(default)]
+    variables: serde_json::Value,
+    /// Settings for the database session of the query, see `RequestContext`.
+    #[serde(default, skip_serializing_if = "RequestContext::is_empty")]
+    context: RequestContext,
+    /// Milliseconds after which the query is cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<u64>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -27,14 +43,23 @@ pub struct SingleQuery {
 pub struct MultiQuery {
     batch: Vec<SingleQuery>,
     transaction: bool,
+    /// Settings for the database sessions of all queries of the batch.
+    #[serde(default, skip_serializing_if = "RequestContext::is_empty")]
+    context: RequestContext,
+    /// Milliseconds after which the whole batch is cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<u64>,
 }
 
 impl From<String> for SingleQuery {
     fn from(query: String) -> Self {
         SingleQuery {
             query,
+            id: None,
             operation_name: None,
-            variables: HashMap::new(),
+            variables: serde_json::Value::Null,
+            context: RequestContext::new(),
+            timeout: None,
         }
     }
 }
@@ -45,25 +70,137 @@ impl From<&str> for SingleQuery {
     }
 }
 
+impl SingleQuery {
+    /// Create a query referring to a persisted operation by id, registering
+    /// the operation if `query` is given.
+    pub fn persisted(id: impl Into<String>, query: Option<String>, variables: serde_json::Value) -> Self {
+        SingleQuery {
+            query: query.unwrap_or_default(),
+            id: Some(id.into()),
+            operation_name: None,
+            variables,
+            context: RequestContext::new(),
+            timeout: None,
+        }
+    }
+
+    /// Set the request context of the query.
+    pub fn with_context(mut self, context: RequestContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Set the timeout of the query.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout.as_millis() as u64);
+        self
+    }
+
+    pub(crate) fn context(&self) -> &RequestContext {
+        &self.context
+    }
+
+    /// The persisted operation the query refers to, if any.
+    fn persisted_operation(
+        &self,
+        persisted_operations: &PersistedOperations,
+    ) -> PrismaResult<Option<Arc<PersistedOperation>>> {
+        match &self.id {
+            Some(id) if self.query.is_empty() => persisted_operations.get(id).map(Some),
+            Some(id) => persisted_operations.register(id, &self.query).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// The parsed GraphQL document of the query. Documents of persisted
+    /// operations are only parsed when they are registered.
+    fn document(&self, persisted_operations: &PersistedOperations) -> PrismaResult<Arc<Document<String>>> {
+        match self.persisted_operation(persisted_operations)? {
+            Some(persisted) => Ok(persisted.document().clone()),
+            None => Ok(Arc::new(gql::parse_query(&self.query)?)),
+        }
+    }
+
+    /// Persisted operations are only prepared once per operation name, and
+    /// bound to the variables of each request, see `PersistedOperation`.
+    fn into_operation(self, cx: &PrismaContext) -> PrismaResult<Operation> {
+        if let Some(persisted) = self.persisted_operation(cx.persisted_operations())? {
+            return persisted.operation(self.operation_name, &self.variables, cx.query_schema());
+        }
+
+        let gql_doc = gql::parse_query(&self.query)?;
+
+        GraphQLProtocolAdapter::convert(&gql_doc, self.operation_name, &self.variables, cx.query_schema())
+    }
+
+    pub(crate) fn into_subscription_operation(self, cx: &PrismaContext) -> PrismaResult<Operation> {
+        let gql_doc = self.document(cx.persisted_operations())?;
+
+        GraphQLProtocolAdapter::convert_subscription(&gql_doc, self.operation_name, &self.variables, cx.query_schema())
+    }
+}
+
 impl GraphQlBody {
-    /// Convert a `GraphQlBody` into a `QueryDocument`.
-    pub(crate) fn into_doc(self) -> PrismaResult<QueryDocument> {
+    /// Adds the settings of `defaults`, e.g. from the request headers, that are
+    /// not set in the request context of the body.
+    pub(crate) fn merge_context_defaults(&mut self, defaults: RequestContext) {
         match self {
-            GraphQlBody::Single(body) => {
-                let gql_doc = gql::parse_query(&body.query)?;
-                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)?;
+            GraphQlBody::Single(body) => body.context.merge_defaults(defaults),
+            GraphQlBody::Multi(bodies) => bodies.context.merge_defaults(defaults),
+        }
+    }
+
+    /// Sets the timeout of the body, e.g. from the request headers, if the body
+    /// doesn't set one.
+    pub(crate) fn merge_timeout_default(&mut self, default: Option<Duration>) {
+        let timeout = match self {
+            GraphQlBody::Single(body) => &mut body.timeout,
+            GraphQlBody::Multi(bodies) => &mut bodies.timeout,
+        };
+
+        if timeout.is_none() {
+            *timeout = default.map(|default| default.as_millis() as u64);
+        }
+    }
+
+    /// The timeout of the body. Like the request context, it is set on the
+    /// batch for all of its queries.
+    fn timeout(&self) -> PrismaResult<Option<Duration>> {
+        let timeout = match self {
+            GraphQlBody::Single(body) => body.timeout,
+            GraphQlBody::Multi(bodies) if bodies.batch.iter().any(|body| body.timeout.is_some()) => {
+                return Err(PrismaError::QueryConversionError(
+                    "The timeout of a batch must be set on the batch, not on its queries.".to_owned(),
+                ))
+            }
+            GraphQlBody::Multi(bodies) => bodies.timeout,
+        };
 
-                Ok(QueryDocument::Single(operation))
+        Ok(timeout.map(Duration::from_millis))
+    }
+
+    /// The request context of the body. The queries of a batch share the
+    /// context of the batch, as transactional batches run in one transaction.
+    fn context(&self) -> PrismaResult<RequestContext> {
+        match self {
+            GraphQlBody::Single(body) => Ok(body.context.clone()),
+            GraphQlBody::Multi(bodies) if bodies.batch.iter().any(|body| !body.context.is_empty()) => {
+                Err(PrismaError::QueryConversionError(
+                    "The request context of a batch must be set on the batch, not on its queries.".to_owned(),
+                ))
             }
+            GraphQlBody::Multi(bodies) => Ok(bodies.context.clone()),
+        }
+    }
+
+    /// Convert a `GraphQlBody` into a `QueryDocument`. Variables are checked
+    /// against the input types of the query schema.
+    pub(crate) fn into_doc(self, cx: &PrismaContext) -> PrismaResult<QueryDocument> {
+        match self {
+            GraphQlBody::Single(body) => Ok(QueryDocument::Single(body.into_operation(cx)?)),
             GraphQlBody::Multi(bodies) => {
-                let operations: PrismaResult<Vec<Operation>> = bodies
-                    .batch
-                    .into_iter()
-                    .map(|body| {
-                        let gql_doc = gql::parse_query(&body.query)?;
-                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)
-                    })
-                    .collect();
+                let operations: PrismaResult<Vec<Operation>> =
+                    bodies.batch.into_iter().map(|body| body.into_operation(cx)).collect();
 
                 Ok(QueryDocument::Multi(BatchDocument::new(
                     operations?,
@@ -78,20 +215,37 @@ impl GraphQlBody {
 pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaResponse {
     debug!("Incoming GraphQL query: {:?}", body);
 
-    match body.into_doc() {
-        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone()).await,
+    let context = match body.context() {
+        Ok(context) => context,
+        Err(err) => return PrismaResponse::Single(err.into()),
+    };
+
+    let timeout = match body.timeout() {
+        Ok(timeout) => timeout.or_else(|| cx.query_timeout()),
+        Err(err) => return PrismaResponse::Single(err.into()),
+    };
+
+    match body.into_doc(&cx) {
+        Ok(QueryDocument::Single(query)) => handle_single_query(query, &context, timeout, cx.clone()).await,
         Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx).await,
-            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx).await,
+            BatchDocument::Multi(batch, transactional) => {
+                handle_batch(batch, transactional, &context, timeout, &cx).await
+            }
+            BatchDocument::Compact(compacted) => handle_compacted(compacted, &context, timeout, &cx).await,
         },
         Err(err) => PrismaResponse::Single(err.into()),
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_single_query(
+    query: Operation,
+    context: &RequestContext,
+    timeout: Option<Duration>,
+    ctx: Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, context, timeout, &*ctx))
         .catch_unwind()
         .await
     {
@@ -107,13 +261,22 @@ async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> Prism
     PrismaResponse::Single(gql_response)
 }
 
-async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch(
+    queries: Vec<Operation>,
+    transactional: bool,
+    context: &RequestContext,
+    timeout: Option<Duration>,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    match AssertUnwindSafe(
-        ctx.executor
-            .execute_batch(queries, transactional, ctx.query_schema().clone()),
-    )
+    match AssertUnwindSafe(ctx.executor.execute_batch(
+        queries,
+        transactional,
+        ctx.query_schema().clone(),
+        context,
+        timeout,
+    ))
     .catch_unwind()
     .await
     {
@@ -139,7 +302,12 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
     }
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_compacted(
+    document: CompactedDocument,
+    context: &RequestContext,
+    timeout: Option<Duration>,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
     let plural_name = document.plural_name();
@@ -148,7 +316,7 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+    match AssertUnwindSafe(handle_graphql_query(document.operation, context, timeout, ctx))
         .catch_unwind()
         .await
     {
@@ -206,6 +374,14 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<ResponseData> {
-    Ok(ctx.executor.execute(query_doc, Arc::clone(ctx.query_schema())).await?)
+async fn handle_graphql_query(
+    query_doc: Operation,
+    context: &RequestContext,
+    timeout: Option<Duration>,
+    ctx: &PrismaContext,
+) -> PrismaResult<ResponseData> {
+    Ok(ctx
+        .executor
+        .execute(query_doc, Arc::clone(ctx.query_schema()), context, timeout)
+        .await?)
 }
@@ -1,9 +1,14 @@
 mod handler;
+mod persisted_operations;
 mod protocol_adapter;
 mod response;
 mod schema_renderer;
+mod subscriptions;
+mod variables;
 
 pub use handler::*;
+pub use persisted_operations::*;
 pub use protocol_adapter::*;
 pub use response::*;
 pub use schema_renderer::*;
+pub use subscriptions::*;
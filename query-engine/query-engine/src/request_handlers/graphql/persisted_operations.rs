@@ -0,0 +1,191 @@
+use super::protocol_adapter::{GraphQLProtocolAdapter, PreparedOperation};
+use crate::{error::PrismaError, PrismaResult};
+use graphql_parser::{self as gql, query::Document};
+use query_core::{Operation, QuerySchema};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+
+/// The number of operations kept before the least recently used ones are evicted.
+const DEFAULT_CAPACITY: usize = 1000;
+
+/// Persisted operations are GraphQL documents registered by clients under
+/// the hex-encoded SHA-256 hash of their text. Once registered, a document is
+/// only parsed once, and clients can send `{ id, variables }` instead of the
+/// full query.
+///
+/// Registration happens on the first request that carries both the `id` and
+/// the `query` of an operation. Requests with an unknown `id` and no `query`
+/// fail with a `PersistedOperationNotFound` error, telling the client to send
+/// the query again.
+///
+/// When the cache is full, the least recently used operation is evicted.
+pub struct PersistedOperations {
+    operations: Mutex<Lru<Arc<PersistedOperation>>>,
+}
+
+impl Default for PersistedOperations {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+}
+
+impl PersistedOperations {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            operations: Mutex::new(Lru::with_capacity(capacity)),
+        }
+    }
+
+    /// Fetch the operation registered under `id`.
+    pub fn get(&self, id: &str) -> PrismaResult<Arc<PersistedOperation>> {
+        self.operations
+            .lock()
+            .unwrap()
+            .get(id)
+            .ok_or_else(|| PrismaError::PersistedOperationNotFound(id.to_owned()))
+    }
+
+    /// Parse `query` and register it under `id`, or return the operation
+    /// already registered. The id must be the hash of the query.
+    pub fn register(&self, id: &str, query: &str) -> PrismaResult<Arc<PersistedOperation>> {
+        let hash = operation_id(query);
+
+        if hash != id {
+            return Err(PrismaError::QueryConversionError(format!(
+                "The id `{}` of the persisted operation does not match the hash of its query, `{}`.",
+                id, hash
+            )));
+        }
+
+        if let Ok(operation) = self.get(id) {
+            return Ok(operation);
+        }
+
+        let operation = Arc::new(PersistedOperation::new(gql::parse_query(query)?));
+        self.operations.lock().unwrap().insert(hash, operation.clone());
+
+        Ok(operation)
+    }
+}
+
+/// A registered document, along with its operations prepared for conversion.
+///
+/// Preparing an operation selects it by name and validates its fragments
+/// against the query schema, so it is done once per operation name, and
+/// requests only bind their variables. Operations converted from the same
+/// document also build the same SQL on every execution, which lets the
+/// statement cache of the connection (`statement_cache_size`) reuse their
+/// prepared statements.
+pub struct PersistedOperation {
+    document: Arc<Document<String>>,
+    /// Bounded by the operation definitions of the document.
+    prepared: Mutex<HashMap<Option<String>, Arc<PreparedOperation>>>,
+}
+
+impl PersistedOperation {
+    fn new(document: Document<String>) -> Self {
+        Self {
+            document: Arc::new(document),
+            prepared: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The parsed document of the operation.
+    pub fn document(&self) -> &Arc<Document<String>> {
+        &self.document
+    }
+
+    /// The operation named `operation_name`, converted with `variables`.
+    pub fn operation(
+        &self,
+        operation_name: Option<String>,
+        variables: &serde_json::Value,
+        query_schema: &QuerySchema,
+    ) -> PrismaResult<Operation> {
+        let cached = self.prepared.lock().unwrap().get(&operation_name).cloned();
+
+        let prepared = match cached {
+            Some(prepared) => prepared,
+            None => {
+                let prepared = Arc::new(GraphQLProtocolAdapter::prepare(
+                    &self.document,
+                    operation_name.clone(),
+                    query_schema,
+                    false,
+                )?);
+
+                self.prepared.lock().unwrap().insert(operation_name, prepared.clone());
+
+                prepared
+            }
+        };
+
+        GraphQLProtocolAdapter::bind(&self.document, &prepared, variables, query_schema)
+    }
+}
+
+/// A map from ids to values evicting its least recently used entry when it
+/// is full. Every access takes a new tick, and the ticks order the entries
+/// from the least to the most recently used, so accesses are logarithmic.
+struct Lru<V> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<String, (V, u64)>,
+    recency: BTreeMap<u64, String>,
+}
+
+impl<V: Clone> Lru<V> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::with_capacity(capacity),
+            recency: BTreeMap::new(),
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn get(&mut self, key: &str) -> Option<V> {
+        let tick = self.next_tick();
+        let (value, last_used) = self.entries.get_mut(key)?;
+        let key = self.recency.remove(last_used)?;
+
+        *last_used = tick;
+        let value = value.clone();
+        self.recency.insert(tick, key);
+
+        Some(value)
+    }
+
+    fn insert(&mut self, key: String, value: V) {
+        let tick = self.next_tick();
+
+        if let Some((_, last_used)) = self.entries.get(&key) {
+            self.recency.remove(last_used);
+        } else if self.entries.len() >= self.capacity {
+            let least_recently_used = self.recency.keys().next().copied();
+
+            if let Some(key) = least_recently_used.and_then(|tick| self.recency.remove(&tick)) {
+                self.entries.remove(&key);
+            }
+        }
+
+        self.recency.insert(tick, key.clone());
+        self.entries.insert(key, (value, tick));
+    }
+}
+
+/// The id of a persisted operation: the hex-encoded SHA-256 hash of its text.
+pub fn operation_id(query: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(query.as_bytes());
+
+    format!("{:x}", hasher.finalize())
+}
@@ -1,10 +1,16 @@
+use super::variables;
 use crate::{error::PrismaError, PrismaResult};
 use bigdecimal::{BigDecimal, FromPrimitive};
-use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+use graphql_parser::{
+    query::{
+        Definition, Document, FragmentDefinition, OperationDefinition, Selection as GqlSelection, SelectionSet,
+        TypeCondition, Value, VariableDefinition,
+    },
+    Pos,
 };
 use indexmap::IndexMap;
-use query_core::query_document::*;
+use query_core::{query_document::*, ObjectTypeStrongRef, QuerySchema};
+use std::collections::HashMap;
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -14,33 +20,140 @@ use query_core::query_document::*;
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
 /// - Fields on the queries are mapped to `Field`s, including arguments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Variables are coerced to the types of their definitions, and substituted with their values. Arguments and
+///   input object fields referring to an optional variable the request leaves out are omitted.
+/// - Fragment spreads and inline fragments are expanded into the selections they contain. Their type conditions
+///   must name the type they are spread on.
+///
+/// - Every field of a `subscription { ... }` is mapped to an `Operation::Read`, when converted with
+///   `convert_subscription`. Subscriptions are rejected by `convert`.
 ///
 /// Currently unsupported features:
-/// - Fragments in any form.
-/// - Variables.
-/// - Subscription queries.
+/// - Directives.
 /// - Query names are ignored
 pub struct GraphQLProtocolAdapter;
 
+/// An operation of a document, selected by name and validated: its fragments
+/// exist, do not spread themselves and apply to the types they are spread on.
+/// Only the variables are left to bind, on every request, so documents sent
+/// repeatedly, like persisted operations, are only validated once.
+#[derive(Debug)]
+pub struct PreparedOperation {
+    /// The positions of the operation definitions to convert in the document.
+    definitions: Vec<usize>,
+    /// The positions of the fragment definitions in the document, by name.
+    fragments: HashMap<String, usize>,
+}
+
+/// The state shared by the conversion of the operations of a document.
+struct ConversionContext<'a> {
+    document: &'a Document<String>,
+    fragments: &'a HashMap<String, usize>,
+    query_schema: &'a QuerySchema,
+}
+
+impl ConversionContext<'_> {
+    fn fragment(&self, name: &str) -> Option<&FragmentDefinition<String>> {
+        match self.fragments.get(name).map(|idx| &self.document.definitions[*idx]) {
+            Some(Definition::Fragment(fragment)) => Some(fragment),
+            _ => None,
+        }
+    }
+}
+
 impl GraphQLProtocolAdapter {
-    pub fn convert(gql_doc: Document<String>, operation: Option<String>) -> PrismaResult<Operation> {
-        let mut operations: Vec<Operation> = match operation {
-            Some(ref op) => gql_doc
-                .definitions
-                .into_iter()
-                .find(|def| Self::matches_operation(def, op))
+    pub fn convert(
+        gql_doc: &Document<String>,
+        operation: Option<String>,
+        variables: &serde_json::Value,
+        query_schema: &QuerySchema,
+    ) -> PrismaResult<Operation> {
+        let prepared = Self::prepare(gql_doc, operation, query_schema, false)?;
+
+        Self::bind(gql_doc, &prepared, variables, query_schema)
+    }
+
+    /// Converts a document containing a subscription.
+    pub fn convert_subscription(
+        gql_doc: &Document<String>,
+        operation: Option<String>,
+        variables: &serde_json::Value,
+        query_schema: &QuerySchema,
+    ) -> PrismaResult<Operation> {
+        let prepared = Self::prepare(gql_doc, operation, query_schema, true)?;
+
+        Self::bind(gql_doc, &prepared, variables, query_schema)
+    }
+
+    /// Selects and validates the operation of a document, see
+    /// `PreparedOperation`. `subscriptions` selects subscriptions, instead of
+    /// queries and mutations.
+    pub fn prepare(
+        gql_doc: &Document<String>,
+        operation: Option<String>,
+        query_schema: &QuerySchema,
+        subscriptions: bool,
+    ) -> PrismaResult<PreparedOperation> {
+        let fragments: HashMap<String, usize> = gql_doc
+            .definitions
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, def)| match def {
+                Definition::Fragment(f) => Some((f.name.clone(), idx)),
+                _ => None,
+            })
+            .collect();
+
+        let mut operation_definitions = gql_doc
+            .definitions
+            .iter()
+            .enumerate()
+            .filter(|(_, def)| !matches!(def, Definition::Fragment(_)));
+
+        let definitions: Vec<usize> = match operation {
+            Some(ref op) => operation_definitions
+                .find(|(_, def)| Self::matches_operation(def, op))
+                .map(|(idx, _)| vec![idx])
                 .ok_or_else(|| {
                     PrismaError::QueryConversionError(format!("Operation '{}' does not match any query.", op))
-                })
-                .and_then(Self::convert_definition),
+                })?,
+            None => operation_definitions.map(|(idx, _)| idx).collect(),
+        };
+
+        let ctx = ConversionContext {
+            document: gql_doc,
+            fragments: &fragments,
+            query_schema,
+        };
 
-            None => gql_doc
-                .definitions
-                .into_iter()
-                .map(Self::convert_definition)
-                .collect::<PrismaResult<Vec<Vec<Operation>>>>()
-                .map(|r| r.into_iter().flatten().collect::<Vec<Operation>>()),
-        }?;
+        for idx in &definitions {
+            let (selection_set, root_type) = Self::root_selection_set(&gql_doc.definitions[*idx], &ctx, subscriptions)?;
+
+            Self::validate_selection_set(selection_set, Some(&root_type), &ctx, &mut Vec::new())?;
+        }
+
+        Ok(PreparedOperation { definitions, fragments })
+    }
+
+    /// Converts a prepared operation of `gql_doc`, with the variables of a
+    /// request.
+    pub fn bind(
+        gql_doc: &Document<String>,
+        prepared: &PreparedOperation,
+        variables: &serde_json::Value,
+        query_schema: &QuerySchema,
+    ) -> PrismaResult<Operation> {
+        let ctx = ConversionContext {
+            document: gql_doc,
+            fragments: &prepared.fragments,
+            query_schema,
+        };
+
+        let mut operations = Vec::new();
+
+        for idx in &prepared.definitions {
+            operations.extend(Self::convert_definition(&gql_doc.definitions[*idx], variables, &ctx)?);
+        }
 
         let operation = operations
             .pop()
@@ -50,66 +163,194 @@ impl GraphQLProtocolAdapter {
         Ok(operation)
     }
 
-    fn convert_definition(def: Definition<String>) -> PrismaResult<Vec<Operation>> {
+    /// The selection set of an operation definition, with the type it selects
+    /// from. Subscriptions select from the query type.
+    fn root_selection_set<'a>(
+        def: &'a Definition<String>,
+        ctx: &ConversionContext<'_>,
+        subscriptions: bool,
+    ) -> PrismaResult<(&'a SelectionSet<String>, ObjectTypeStrongRef)> {
         match def {
-            Definition::Fragment(f) => Err(PrismaError::UnsupportedFeatureError(
-                "Fragment definition",
-                format!("Fragment '{}', at position {}.", f.name, f.position),
+            Definition::Fragment(_) => unreachable!("fragments are not operations"),
+            Definition::Operation(OperationDefinition::Subscription(s)) if subscriptions => {
+                Ok((&s.selection_set, ctx.query_schema.query()))
+            }
+            Definition::Operation(_) if subscriptions => Err(PrismaError::QueryConversionError(
+                "Only subscriptions can be sent to the subscriptions endpoint.".into(),
             )),
             Definition::Operation(op) => match op {
                 OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
                     "Subscription query",
-                    format!("At position {}.", s.position),
+                    format!(
+                        "Subscriptions are served on the `/subscriptions` endpoint. At position {}.",
+                        s.position
+                    ),
                 )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(s),
-                OperationDefinition::Query(q) => Self::convert_query(q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set),
+                OperationDefinition::SelectionSet(s) => Ok((s, ctx.query_schema.query())),
+                OperationDefinition::Query(q) => Ok((&q.selection_set, ctx.query_schema.query())),
+                OperationDefinition::Mutation(m) => Ok((&m.selection_set, ctx.query_schema.mutation())),
             },
         }
     }
 
-    fn convert_query(selection_set: SelectionSet<String>) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| fields.into_iter().map(Operation::Read).collect())
+    /// Definitions are converted by reference, so documents shared between
+    /// requests, like persisted operations, are never copied. The definition
+    /// must have been validated by `prepare`.
+    fn convert_definition(
+        def: &Definition<String>,
+        variables: &serde_json::Value,
+        ctx: &ConversionContext<'_>,
+    ) -> PrismaResult<Vec<Operation>> {
+        let (selection_set, variable_definitions, is_mutation): (_, &[VariableDefinition<String>], _) = match def {
+            Definition::Fragment(_) => return Ok(Vec::new()),
+            Definition::Operation(OperationDefinition::SelectionSet(s)) => (s, &[], false),
+            Definition::Operation(OperationDefinition::Query(q)) => (&q.selection_set, &q.variable_definitions, false),
+            Definition::Operation(OperationDefinition::Subscription(s)) => {
+                (&s.selection_set, &s.variable_definitions, false)
+            }
+            Definition::Operation(OperationDefinition::Mutation(m)) => {
+                (&m.selection_set, &m.variable_definitions, true)
+            }
+        };
+
+        let variables = variables::coerce_variables(variable_definitions, variables, ctx.query_schema)?;
+        let selections = Self::convert_selection_set(selection_set, &variables, ctx)?;
+
+        if is_mutation {
+            Ok(selections.into_iter().map(Operation::Write).collect())
+        } else {
+            Ok(selections.into_iter().map(Operation::Read).collect())
+        }
     }
 
-    fn convert_mutation(selection_set: SelectionSet<String>) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| fields.into_iter().map(Operation::Write).collect())
+    /// Checks the fragments of a selection set. `parent_type` is the type the
+    /// selections are made on, when it is known. `fragment_path` holds the
+    /// names of the fragments being expanded, to detect cycles.
+    fn validate_selection_set(
+        selection_set: &SelectionSet<String>,
+        parent_type: Option<&ObjectTypeStrongRef>,
+        ctx: &ConversionContext<'_>,
+        fragment_path: &mut Vec<String>,
+    ) -> PrismaResult<()> {
+        for item in &selection_set.items {
+            match item {
+                GqlSelection::Field(f) => {
+                    // Unknown fields are left to the query document parser to report.
+                    let field_type = parent_type
+                        .and_then(|parent_type| parent_type.find_field(&f.name))
+                        .and_then(|field| field.field_type.as_object_type());
+
+                    Self::validate_selection_set(&f.selection_set, field_type.as_ref(), ctx, fragment_path)?;
+                }
+
+                GqlSelection::FragmentSpread(fs) => {
+                    let fragment = ctx.fragment(&fs.fragment_name).ok_or_else(|| {
+                        PrismaError::QueryConversionError(format!(
+                            "Unknown fragment '{}', at position {}.",
+                            fs.fragment_name, fs.position
+                        ))
+                    })?;
+
+                    if fragment_path.contains(&fs.fragment_name) {
+                        return Err(PrismaError::QueryConversionError(format!(
+                            "Fragment '{}' spreads itself, at position {}.",
+                            fs.fragment_name, fs.position
+                        )));
+                    }
+
+                    Self::check_type_condition(&fragment.type_condition, parent_type, fs.position)?;
+
+                    fragment_path.push(fs.fragment_name.clone());
+                    Self::validate_selection_set(&fragment.selection_set, parent_type, ctx, fragment_path)?;
+                    fragment_path.pop();
+                }
+
+                GqlSelection::InlineFragment(i) => {
+                    if let Some(type_condition) = &i.type_condition {
+                        Self::check_type_condition(type_condition, parent_type, i.position)?;
+                    }
+
+                    Self::validate_selection_set(&i.selection_set, parent_type, ctx, fragment_path)?;
+                }
+            }
+        }
+
+        Ok(())
     }
 
-    fn convert_selection_set(selection_set: SelectionSet<String>) -> PrismaResult<Vec<Selection>> {
-        selection_set
-            .items
-            .into_iter()
-            .map(|item| match item {
+    /// Converts a selection set validated by `validate_selection_set`,
+    /// expanding the fragments it contains.
+    fn convert_selection_set(
+        selection_set: &SelectionSet<String>,
+        variables: &HashMap<String, Option<QueryValue>>,
+        ctx: &ConversionContext<'_>,
+    ) -> PrismaResult<Vec<Selection>> {
+        let mut selections = Vec::with_capacity(selection_set.items.len());
+
+        for item in &selection_set.items {
+            match item {
                 GqlSelection::Field(f) => {
                     let arguments: Vec<(String, QueryValue)> = f
                         .arguments
-                        .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
+                        .iter()
+                        .filter_map(|(k, v)| {
+                            Self::convert_value(v, variables)
+                                .map(|v| v.map(|v| (k.clone(), v)))
+                                .transpose()
+                        })
                         .collect::<PrismaResult<Vec<_>>>()?;
 
-                    let mut builder = Selection::builder(f.name);
+                    let mut builder = Selection::builder(f.name.clone());
                     builder.set_arguments(arguments);
-                    builder.nested_selections(Self::convert_selection_set(f.selection_set)?);
+                    builder.nested_selections(Self::convert_selection_set(&f.selection_set, variables, ctx)?);
 
-                    if let Some(alias) = f.alias {
-                        builder.alias(alias);
+                    if let Some(alias) = &f.alias {
+                        builder.alias(alias.clone());
                     };
 
-                    Ok(builder.build())
+                    selections.push(builder.build());
                 }
 
-                GqlSelection::FragmentSpread(fs) => Err(PrismaError::UnsupportedFeatureError(
-                    "Fragment spread",
-                    format!("Fragment '{}', at position {}.", fs.fragment_name, fs.position),
-                )),
+                GqlSelection::FragmentSpread(fs) => {
+                    let fragment = ctx.fragment(&fs.fragment_name).ok_or_else(|| {
+                        PrismaError::QueryConversionError(format!(
+                            "Unknown fragment '{}', at position {}.",
+                            fs.fragment_name, fs.position
+                        ))
+                    })?;
 
-                GqlSelection::InlineFragment(i) => Err(PrismaError::UnsupportedFeatureError(
-                    "Inline fragment",
-                    format!("At position {}.", i.position),
-                )),
-            })
-            .collect()
+                    selections.extend(Self::convert_selection_set(&fragment.selection_set, variables, ctx)?);
+                }
+
+                GqlSelection::InlineFragment(i) => {
+                    selections.extend(Self::convert_selection_set(&i.selection_set, variables, ctx)?);
+                }
+            }
+        }
+
+        Ok(selections)
+    }
+
+    /// The schema has no interfaces or unions, so a fragment can only be
+    /// spread on the type its condition names.
+    fn check_type_condition(
+        type_condition: &TypeCondition<String>,
+        parent_type: Option<&ObjectTypeStrongRef>,
+        position: Pos,
+    ) -> PrismaResult<()> {
+        let TypeCondition::On(type_name) = type_condition;
+
+        match parent_type {
+            Some(parent_type) if parent_type.identifier.name() != type_name => {
+                Err(PrismaError::QueryConversionError(format!(
+                    "Fragment on '{}' cannot be spread on '{}', at position {}.",
+                    type_name,
+                    parent_type.identifier.name(),
+                    position
+                )))
+            }
+            _ => Ok(()),
+        }
     }
 
     /// Checks if the given GraphQL definition matches the operation name that should be executed.
@@ -126,46 +367,63 @@ impl GraphQLProtocolAdapter {
         }
     }
 
-    fn convert_value(value: Value<String>) -> PrismaResult<QueryValue> {
-        match value {
-            Value::Variable(name) => Err(PrismaError::UnsupportedFeatureError(
-                "Variable usage",
-                format!("Variable '{}'.", name),
-            )),
+    /// Converts a GraphQL value. `None` stands for a reference to an optional
+    /// variable the request leaves out: the argument or input object field
+    /// holding it is omitted, and list items are null.
+    pub(crate) fn convert_value(
+        value: &Value<String>,
+        variables: &HashMap<String, Option<QueryValue>>,
+    ) -> PrismaResult<Option<QueryValue>> {
+        let value = match value {
+            Value::Variable(name) => {
+                return variables.get(name).cloned().ok_or_else(|| {
+                    PrismaError::QueryConversionError(format!("Variable '${}' is not defined by the operation.", name))
+                })
+            }
             Value::Int(i) => match i.as_i64() {
-                Some(i) => Ok(QueryValue::Int(i)),
-                None => Err(PrismaError::QueryConversionError(format!(
-                    "Invalid 64 bit integer: {:?}",
-                    i
-                ))),
+                Some(i) => QueryValue::Int(i),
+                None => {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "Invalid 64 bit integer: {:?}",
+                        i
+                    )))
+                }
             },
-            Value::Float(f) => match BigDecimal::from_f64(f) {
-                Some(dec) => Ok(QueryValue::Float(dec)),
-                None => Err(PrismaError::QueryConversionError(format!(
-                    "invalid 64-bit float: {:?}",
-                    f
-                ))),
+            Value::Float(f) => match BigDecimal::from_f64(*f) {
+                Some(dec) => QueryValue::Float(dec),
+                None => {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "invalid 64-bit float: {:?}",
+                        f
+                    )))
+                }
             },
-            Value::String(s) => Ok(QueryValue::String(s)),
-            Value::Boolean(b) => Ok(QueryValue::Boolean(b)),
-            Value::Null => Ok(QueryValue::Null),
-            Value::Enum(e) => Ok(QueryValue::Enum(e)),
+            Value::String(s) => QueryValue::String(s.clone()),
+            Value::Boolean(b) => QueryValue::Boolean(*b),
+            Value::Null => QueryValue::Null,
+            Value::Enum(e) => QueryValue::Enum(e.clone()),
             Value::List(values) => {
                 let values: Vec<QueryValue> = values
-                    .into_iter()
-                    .map(Self::convert_value)
+                    .iter()
+                    .map(|value| Self::convert_value(value, variables).map(|value| value.unwrap_or(QueryValue::Null)))
                     .collect::<PrismaResult<Vec<QueryValue>>>()?;
 
-                Ok(QueryValue::List(values))
+                QueryValue::List(values)
             }
             Value::Object(map) => {
                 let values = map
-                    .into_iter()
-                    .map(|(k, v)| Self::convert_value(v).map(|v| (k, v)))
+                    .iter()
+                    .filter_map(|(k, v)| {
+                        Self::convert_value(v, variables)
+                            .map(|v| v.map(|v| (k.clone(), v)))
+                            .transpose()
+                    })
                     .collect::<PrismaResult<IndexMap<String, QueryValue>>>()?;
 
-                Ok(QueryValue::Object(values))
+                QueryValue::Object(values)
             }
-        }
+        };
+
+        Ok(Some(value))
     }
 }
@@ -41,6 +41,10 @@ impl GQLResponse {
     pub fn take_data(&mut self, key: impl AsRef<str>) -> Option<Item> {
         self.data.remove(key.as_ref())
     }
+
+    pub fn get_data(&self, key: impl AsRef<str>) -> Option<&Item> {
+        self.data.get(key.as_ref())
+    }
 }
 
 impl From<PrismaError> for GQLResponse {
@@ -0,0 +1,177 @@
+use super::{GQLResponse, SingleQuery};
+use crate::{context::PrismaContext, error::PrismaError, PrismaResult};
+use indexmap::IndexMap;
+use prisma_models::PrismaValue;
+use query_core::{
+    response_ir::Item, ChangeEvent, ChangeKind, In, Operation, QueryTag, QueryValue, RequestContext, Selection,
+    SelectionSet,
+};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// A GraphQL subscription on the changes of a model.
+///
+/// Subscriptions are `findMany` queries in a `subscription` operation, e.g.
+/// `subscription { findManyPost(where: { published: true }) { id title } }`.
+/// For created or updated records of the model, the query is re-run,
+/// restricted to the changed records, and its result is emitted if the records
+/// still match. Deleted records can't be queried anymore: deletions are
+/// emitted with the identifiers of the records, regardless of the filters.
+#[derive(Debug)]
+pub struct Subscription {
+    selection: Selection,
+    model: String,
+    /// The request context the query is re-run with.
+    context: RequestContext,
+}
+
+/// An event emitted on a subscription.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionEvent {
+    pub kind: ChangeKind,
+    /// The identifiers of deleted records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<serde_json::Value>,
+    /// The result of the subscription query for created and updated records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<GQLResponse>,
+}
+
+impl Subscription {
+    pub fn new(body: SingleQuery, cx: &PrismaContext) -> PrismaResult<Self> {
+        let context = body.context().clone();
+        let selection = body
+            .into_subscription_operation(cx)?
+            .into_read()
+            .expect("Subscriptions are converted to read operations.");
+
+        let model = cx
+            .query_schema()
+            .find_query_field(selection.name())
+            .and_then(|field| field.query_info.clone())
+            .filter(|info| info.tag == QueryTag::FindMany)
+            .and_then(|info| info.model)
+            .ok_or_else(|| {
+                PrismaError::QueryConversionError(format!(
+                    "`{}` can't be subscribed to. Subscriptions must be `findMany` queries.",
+                    selection.name()
+                ))
+            })?;
+
+        Ok(Self {
+            selection,
+            model: model.name.clone(),
+            context,
+        })
+    }
+
+    /// Turn change events into subscription events, keeping the events
+    /// relevant to the subscription. Consecutive events of the same kind are
+    /// merged, so a batch of events only runs one query per run of created or
+    /// updated records.
+    pub async fn handle(&self, events: &[Arc<ChangeEvent>], cx: &Arc<PrismaContext>) -> Vec<SubscriptionEvent> {
+        let mut runs: Vec<(ChangeKind, Vec<&IndexMap<String, PrismaValue>>)> = Vec::new();
+
+        for event in events.iter().filter(|event| event.model == self.model) {
+            match runs.last_mut() {
+                Some((kind, ids)) if *kind == event.kind => ids.extend(event.ids.iter()),
+                _ => runs.push((event.kind, event.ids.iter().collect())),
+            }
+        }
+
+        let mut emitted = Vec::with_capacity(runs.len());
+
+        for (kind, ids) in runs.into_iter().filter(|(_, ids)| !ids.is_empty()) {
+            if kind == ChangeKind::Deleted {
+                emitted.push(SubscriptionEvent {
+                    kind,
+                    ids: Some(serde_json::to_value(&ids).expect("Failed to serialize record identifiers")),
+                    response: None,
+                });
+
+                continue;
+            }
+
+            let response: GQLResponse = match cx
+                .executor
+                .execute(
+                    self.query_for(&ids),
+                    Arc::clone(cx.query_schema()),
+                    &self.context,
+                    cx.query_timeout(),
+                )
+                .await
+            {
+                Ok(response) => response.into(),
+                Err(err) => err.into(),
+            };
+
+            // Records not matching the filters of the subscription are not emitted.
+            let is_empty = response
+                .get_data(self.response_key())
+                .and_then(Item::as_list)
+                .map(|list| list.is_empty())
+                .unwrap_or(false);
+
+            if !is_empty {
+                emitted.push(SubscriptionEvent {
+                    kind,
+                    ids: None,
+                    response: Some(response),
+                });
+            }
+        }
+
+        emitted
+    }
+
+    fn response_key(&self) -> &str {
+        self.selection
+            .alias()
+            .as_deref()
+            .unwrap_or_else(|| self.selection.name())
+    }
+
+    /// The subscription query, with its `where` argument restricted to the
+    /// given records.
+    fn query_for(&self, ids: &[&IndexMap<String, PrismaValue>]) -> Operation {
+        let ids = ids.iter().fold(SelectionSet::new(), |set, id| {
+            id.iter()
+                .fold(set, |set, (field, value)| set.push(field.clone(), value.clone().into()))
+        });
+
+        let ids_filter = QueryValue::from(In::new(ids));
+        let mut arguments: Vec<(String, QueryValue)> = Vec::with_capacity(self.selection.arguments().len() + 1);
+        let mut has_where = false;
+
+        for (name, value) in self.selection.arguments() {
+            if name == "where" && *value != QueryValue::Null {
+                let mut and = IndexMap::new();
+                and.insert(
+                    "AND".to_owned(),
+                    QueryValue::List(vec![value.clone(), ids_filter.clone()]),
+                );
+
+                arguments.push((name.clone(), QueryValue::Object(and)));
+                has_where = true;
+            } else if name != "where" {
+                arguments.push((name.clone(), value.clone()));
+            }
+        }
+
+        if !has_where {
+            arguments.push(("where".to_owned(), ids_filter));
+        }
+
+        let mut builder = Selection::builder(self.selection.name());
+        builder.set_arguments(arguments);
+        builder.nested_selections(self.selection.nested_selections().to_vec());
+
+        if let Some(alias) = self.selection.alias() {
+            builder.alias(alias.clone());
+        }
+
+        Operation::Read(builder.build())
+    }
+}
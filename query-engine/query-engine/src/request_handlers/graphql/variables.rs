@@ -0,0 +1,213 @@
+use super::GraphQLProtocolAdapter;
+use crate::{error::PrismaError, PrismaResult};
+use bigdecimal::BigDecimal;
+use graphql_parser::query::{Type, VariableDefinition};
+use indexmap::IndexMap;
+use query_core::{InputFieldRef, InputType, IntoArc, QuerySchema, QueryValue, ScalarType};
+use std::{collections::HashMap, sync::Arc};
+
+/// Coerces the JSON variables of a request to `QueryValue`s, following the
+/// variable definitions of the operation. Named types are resolved against the
+/// input types of the query schema, so malformed variables are rejected before
+/// they reach the query document parser. Optional variables the request leaves
+/// out, without a default value, are `None`: they are omitted where they are
+/// used, unlike variables explicitly set to null.
+pub(crate) fn coerce_variables(
+    definitions: &[VariableDefinition<String>],
+    variables: &serde_json::Value,
+    query_schema: &QuerySchema,
+) -> PrismaResult<HashMap<String, Option<QueryValue>>> {
+    let variables = match variables {
+        serde_json::Value::Object(variables) => Some(variables),
+        serde_json::Value::Null => None,
+        _ => {
+            return Err(PrismaError::QueryConversionError(
+                "The variables of a request must be a JSON object.".into(),
+            ))
+        }
+    };
+
+    definitions
+        .iter()
+        .map(|definition| {
+            let value = match (
+                variables.and_then(|variables| variables.get(&definition.name)),
+                &definition.default_value,
+            ) {
+                (Some(value), _) => coerce_value(value, &definition.var_type, query_schema).map(Some),
+                // Default values are literals, they can't refer to variables.
+                (None, Some(default_value)) => {
+                    let value = GraphQLProtocolAdapter::convert_value(default_value, &HashMap::new())?;
+
+                    return Ok((definition.name.clone(), value));
+                }
+                (None, None) if matches!(definition.var_type, Type::NonNullType(_)) => {
+                    Err("the variable is required.".to_owned())
+                }
+                (None, None) => Ok(None),
+            };
+
+            value.map(|value| (definition.name.clone(), value)).map_err(|err| {
+                PrismaError::QueryConversionError(format!(
+                    "Invalid value for variable `${}` of type `{}`: {}",
+                    definition.name, definition.var_type, err
+                ))
+            })
+        })
+        .collect()
+}
+
+fn coerce_value(
+    value: &serde_json::Value,
+    typ: &Type<String>,
+    query_schema: &QuerySchema,
+) -> Result<QueryValue, String> {
+    match (typ, value) {
+        (Type::NonNullType(_), serde_json::Value::Null) => Err("the variable is required.".into()),
+        (Type::NonNullType(inner), _) => coerce_value(value, inner, query_schema),
+        (_, serde_json::Value::Null) => Ok(QueryValue::Null),
+        (Type::ListType(inner), serde_json::Value::Array(values)) => values
+            .iter()
+            .map(|value| coerce_value(value, inner, query_schema))
+            .collect::<Result<Vec<_>, _>>()
+            .map(QueryValue::List),
+        // Single values are coerced to lists of one element, as per the GraphQL spec.
+        (Type::ListType(inner), _) => Ok(QueryValue::List(vec![coerce_value(value, inner, query_schema)?])),
+        (Type::NamedType(name), _) => {
+            let input_type = resolve_named_type(name, query_schema)?;
+
+            coerce_input_value(value, &input_type)
+        }
+    }
+}
+
+fn resolve_named_type(name: &str, query_schema: &QuerySchema) -> Result<InputType, String> {
+    let scalar = match name {
+        "String" | "ID" => Some(ScalarType::String),
+        "Int" => Some(ScalarType::Int),
+        "BigInt" => Some(ScalarType::BigInt),
+        "Float" => Some(ScalarType::Float),
+        "Decimal" => Some(ScalarType::Decimal),
+        "Boolean" => Some(ScalarType::Boolean),
+        "DateTime" => Some(ScalarType::DateTime),
+        "Json" => Some(ScalarType::Json),
+        "UUID" => Some(ScalarType::UUID),
+        "Xml" => Some(ScalarType::Xml),
+        "Bytes" => Some(ScalarType::Bytes),
+        _ => None,
+    };
+
+    if let Some(scalar) = scalar {
+        return Ok(InputType::Scalar(scalar));
+    }
+
+    if let Some(object) = query_schema.find_input_object_type(name) {
+        return Ok(InputType::object(Arc::downgrade(&object)));
+    }
+
+    if let Some(enum_type) = query_schema.find_input_enum_type(name) {
+        return Ok(InputType::enum_type(enum_type));
+    }
+
+    Err(format!("`{}` is not an input type of the schema.", name))
+}
+
+fn coerce_input_value(value: &serde_json::Value, input_type: &InputType) -> Result<QueryValue, String> {
+    use serde_json::Value as Json;
+
+    match (input_type, value) {
+        (InputType::Scalar(ScalarType::Null), Json::Null) => Ok(QueryValue::Null),
+        (InputType::Scalar(ScalarType::Int), Json::Number(n)) if n.is_i64() => Ok(QueryValue::Int(n.as_i64().unwrap())),
+        (InputType::Scalar(ScalarType::BigInt), Json::Number(n)) if n.is_i64() => {
+            Ok(QueryValue::Int(n.as_i64().unwrap()))
+        }
+        (InputType::Scalar(ScalarType::Float), Json::Number(n))
+        | (InputType::Scalar(ScalarType::Decimal), Json::Number(n)) => n
+            .to_string()
+            .parse::<BigDecimal>()
+            .map(QueryValue::Float)
+            .map_err(|err| format!("invalid number {}: {}", n, err)),
+        (InputType::Scalar(ScalarType::Boolean), Json::Bool(b)) => Ok(QueryValue::Boolean(*b)),
+        (InputType::Scalar(ScalarType::String), Json::String(s))
+        | (InputType::Scalar(ScalarType::BigInt), Json::String(s))
+        | (InputType::Scalar(ScalarType::Decimal), Json::String(s))
+        | (InputType::Scalar(ScalarType::DateTime), Json::String(s))
+        | (InputType::Scalar(ScalarType::UUID), Json::String(s))
+        | (InputType::Scalar(ScalarType::Xml), Json::String(s))
+        | (InputType::Scalar(ScalarType::Bytes), Json::String(s))
+        | (InputType::Scalar(ScalarType::Json), Json::String(s))
+        | (InputType::Scalar(ScalarType::JsonList), Json::String(s)) => Ok(QueryValue::String(s.clone())),
+        // Json inputs are strings in the query document.
+        (InputType::Scalar(ScalarType::Json), _) | (InputType::Scalar(ScalarType::JsonList), Json::Array(_))
+            if !value.is_null() =>
+        {
+            Ok(QueryValue::String(value.to_string()))
+        }
+        (InputType::Scalar(ScalarType::Enum(_)), Json::String(s)) | (InputType::Enum(_), Json::String(s)) => {
+            Ok(QueryValue::Enum(s.clone()))
+        }
+        (InputType::List(inner), Json::Array(values)) => values
+            .iter()
+            .map(|value| coerce_input_value(value, inner))
+            .collect::<Result<Vec<_>, _>>()
+            .map(QueryValue::List),
+        (InputType::Object(object), Json::Object(fields)) => {
+            let object = object.into_arc();
+            let object_fields: HashMap<&str, &InputFieldRef> = object
+                .get_fields()
+                .iter()
+                .map(|field| (field.name.as_str(), field))
+                .collect();
+
+            fields
+                .iter()
+                .map(|(name, value)| {
+                    let field = object_fields
+                        .get(name.as_str())
+                        .ok_or_else(|| format!("`{}` is not a field of `{}`.", name, object.identifier.name()))?;
+
+                    let value = coerce_union_value(value, &field.field_types).map_err(|err| match err {
+                        Some(err) => format!("invalid value for `{}.{}`: {}", object.identifier.name(), name, err),
+                        None => format!(
+                            "`{}` is not a valid value for `{}.{}`.",
+                            value,
+                            object.identifier.name(),
+                            name
+                        ),
+                    })?;
+
+                    Ok((name.clone(), value))
+                })
+                .collect::<Result<IndexMap<_, _>, String>>()
+                .map(QueryValue::Object)
+        }
+        (typ, value) => Err(format!("`{}` is not a valid {:?}.", value, typ)),
+    }
+}
+
+/// Coerces a value to the first of the possible input types of a field it is valid for. If there
+/// is none, the most specific error is returned: the one of the only possible type, or else the
+/// one of the object or list type matching the shape of the value. An error about a nested field
+/// tells more than the mismatch with the other types.
+fn coerce_union_value(value: &serde_json::Value, input_types: &[InputType]) -> Result<QueryValue, Option<String>> {
+    let mut most_specific = None;
+
+    for input_type in input_types {
+        match coerce_input_value(value, input_type) {
+            Ok(value) => return Ok(value),
+            Err(err) if input_types.len() == 1 || matches_shape(value, input_type) => {
+                most_specific.get_or_insert(err);
+            }
+            Err(_) => (),
+        }
+    }
+
+    Err(most_specific)
+}
+
+fn matches_shape(value: &serde_json::Value, input_type: &InputType) -> bool {
+    matches!(
+        (input_type, value),
+        (InputType::Object(_), serde_json::Value::Object(_)) | (InputType::List(_), serde_json::Value::Array(_))
+    )
+}
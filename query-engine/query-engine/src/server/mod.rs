@@ -3,17 +3,20 @@
 use crate::context::PrismaContext;
 use crate::dmmf;
 use crate::opt::PrismaOpt;
-use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
+use crate::request_handlers::graphql::{
+    self, GQLResponse, GraphQLSchemaRenderer, GraphQlBody, SingleQuery, Subscription,
+};
 use crate::PrismaResult;
 use elapsed_middleware::ElapsedMiddleware;
 
-use query_core::schema::QuerySchemaRenderer;
+use futures::StreamExt;
+use query_core::{schema::QuerySchemaRenderer, RequestContext};
 use serde_json::json;
 use tide::http::{mime, StatusCode};
-use tide::{prelude::*, Body, Request, Response};
+use tide::{prelude::*, sse, Body, Request, Response};
 use tide_server_timing::TimingMiddleware;
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 mod elapsed_middleware;
 
@@ -26,9 +29,9 @@ pub(crate) struct State {
 
 impl State {
     /// Create a new instance of `State`.
-    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool) -> Self {
+    fn new(cx: Arc<PrismaContext>, enable_playground: bool, enable_debug_mode: bool) -> Self {
         Self {
-            cx: Arc::new(cx),
+            cx,
             enable_playground,
             enable_debug_mode,
         }
@@ -56,32 +59,41 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     let cx = PrismaContext::builder(config, datamodel)
         .legacy(opts.legacy)
         .enable_raw_queries(opts.enable_raw_queries)
+        .query_timeout(opts.query_timeout.map(Duration::from_millis))
         .build()
         .await?;
 
-    let mut app = tide::with_state(State::new(cx, opts.enable_playground, opts.enable_debug_mode));
+    let app = server(Arc::new(cx), opts.enable_playground, opts.enable_debug_mode);
+
+    // Start the Tide server and log the server details.
+    // NOTE: The `info!` statement is essential for the correct working of the client.
+    let mut listener = match opts.unix_path() {
+        Some(path) => app.bind(format!("http+unix://{}", path)).await?,
+        None => app.bind(format!("{}:{}", opts.host.as_str(), opts.port)).await?,
+    };
+    info!("Started http server on {}", listener);
+    listener.accept().await?;
+    Ok(())
+}
+
+/// Create the server with its routes, without binding it to an address.
+pub(crate) fn server(cx: Arc<PrismaContext>, enable_playground: bool, enable_debug_mode: bool) -> tide::Server<State> {
+    let mut app = tide::with_state(State::new(cx, enable_playground, enable_debug_mode));
     app.with(ElapsedMiddleware::new());
 
-    if opts.enable_playground {
+    if enable_playground {
         app.with(TimingMiddleware::new());
     }
 
     app.at("/").post(graphql_handler);
+    app.at("/subscriptions").get(sse::endpoint(subscription_handler));
     app.at("/").get(playground_handler);
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
     app.at("/status").get(|_| async move { Ok(json!({"status": "ok"})) });
 
-    // Start the Tide server and log the server details.
-    // NOTE: The `info!` statement is essential for the correct working of the client.
-    let mut listener = match opts.unix_path() {
-        Some(path) => app.bind(format!("http+unix://{}", path)).await?,
-        None => app.bind(format!("{}:{}", opts.host.as_str(), opts.port)).await?,
-    };
-    info!("Started http server on {}", listener);
-    listener.accept().await?;
-    Ok(())
+    app
 }
 
 /// The main query handler. This handles incoming GraphQL queries and passes it
@@ -94,7 +106,12 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         }
     }
 
-    let body: GraphQlBody = req.body_json().await?;
+    let header_context = request_context_header(&req)?;
+    let header_timeout = request_timeout_header(&req)?;
+    let mut body: GraphQlBody = req.body_json().await?;
+    body.merge_context_defaults(header_context);
+    body.merge_timeout_default(header_timeout);
+
     let cx = req.state().cx.clone();
     let result = graphql::handle(body, cx).await;
     let mut res = Response::new(StatusCode::Ok);
@@ -102,6 +119,115 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
     Ok(res)
 }
 
+/// The request context passed in the `X-Prisma-Context` header, a JSON object
+/// of settings. Settings in the request body take precedence.
+fn request_context_header(req: &Request<State>) -> tide::Result<RequestContext> {
+    match req.header("X-Prisma-Context") {
+        Some(values) => serde_json::from_str(values.last().as_str()).map_err(|err| {
+            tide::Error::from_str(
+                StatusCode::BadRequest,
+                format!("Invalid X-Prisma-Context header: {}", err),
+            )
+        }),
+        None => Ok(RequestContext::new()),
+    }
+}
+
+/// The timeout of the request in milliseconds, passed in the `X-Prisma-Timeout`
+/// header. A timeout in the request body takes precedence.
+fn request_timeout_header(req: &Request<State>) -> tide::Result<Option<Duration>> {
+    match req.header("X-Prisma-Timeout") {
+        Some(values) => values
+            .last()
+            .as_str()
+            .parse()
+            .map(|millis| Some(Duration::from_millis(millis)))
+            .map_err(|err| {
+                tide::Error::from_str(
+                    StatusCode::BadRequest,
+                    format!("Invalid X-Prisma-Timeout header: {}", err),
+                )
+            }),
+        None => Ok(None),
+    }
+}
+
+/// The query string parameters of a subscription request. `variables` and
+/// `context` are JSON-encoded objects.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SubscriptionParams {
+    query: Option<String>,
+    id: Option<String>,
+    operation_name: Option<String>,
+    variables: Option<String>,
+    context: Option<String>,
+}
+
+impl SubscriptionParams {
+    fn into_single_query(self) -> PrismaResult<SingleQuery> {
+        let variables: serde_json::Value = match self.variables {
+            Some(variables) => serde_json::from_str(&variables)?,
+            None => serde_json::Value::Null,
+        };
+
+        let context: RequestContext = match self.context {
+            Some(context) => serde_json::from_str(&context)?,
+            None => RequestContext::new(),
+        };
+
+        let query: SingleQuery = serde_json::from_value(json!({
+            "query": self.query.unwrap_or_default(),
+            "id": self.id,
+            "operationName": self.operation_name,
+            "variables": variables,
+        }))?;
+
+        Ok(query.with_context(context))
+    }
+}
+
+/// Streams the events of a GraphQL subscription as server-sent events. The
+/// subscription is passed in the query string, as `EventSource` can only send
+/// GET requests. Events are named `change`, or `error` if the subscription is
+/// invalid.
+async fn subscription_handler(req: Request<State>, sender: sse::Sender) -> tide::Result<()> {
+    let cx = req.state().cx.clone();
+    let subscription = req
+        .query::<SubscriptionParams>()
+        .map_err(|err| crate::PrismaError::QueryConversionError(err.to_string()))
+        .and_then(SubscriptionParams::into_single_query)
+        .and_then(|body| Subscription::new(body, &cx));
+
+    let subscription = match subscription {
+        Ok(subscription) => subscription,
+        Err(err) => {
+            let response = GQLResponse::from(err);
+            sender.send("error", serde_json::to_string(&response)?, None).await?;
+
+            return Ok(());
+        }
+    };
+
+    let mut events = cx.executor.change_events().subscribe();
+
+    // The loop ends when the client disconnects, as sending fails. The events
+    // received in the meantime are handled together.
+    while let Some(event) = events.next().await {
+        let mut batch = vec![event];
+
+        while let Ok(Some(event)) = events.try_next() {
+            batch.push(event);
+        }
+
+        for event in subscription.handle(&batch, &cx).await {
+            sender.send("change", serde_json::to_string(&event)?, None).await?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
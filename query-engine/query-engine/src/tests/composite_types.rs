@@ -0,0 +1,183 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static USER: &str = indoc! {r#"
+    model User {
+        id      Int      @id
+        address Address
+        work    Address?
+    }
+
+    type Address {
+        street String
+        zip    String?
+        geo    Geo?
+    }
+
+    type Geo {
+        lat Float
+        lng Float
+    }
+"#};
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn composite_values_can_be_written_and_read(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER).await?;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                createOneUser(data: { id: 1, address: { street: "Main St", geo: { lat: 1.5, lng: 2.5 } } }) {
+                    id
+                    address { street zip geo { lat lng } }
+                    work { street }
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        response,
+        json!({ "data": { "createOneUser": {
+            "id": 1,
+            "address": { "street": "Main St", "zip": null, "geo": { "lat": 1.5, "lng": 2.5 } },
+            "work": null,
+        } } })
+    );
+
+    let response = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 1 }, data: { work: { street: "Side St", zip: "1234" } }) { work { street zip geo { lat } } } }"#)
+        .await;
+
+    assert_eq!(
+        response,
+        json!({ "data": { "updateOneUser": { "work": { "street": "Side St", "zip": "1234", "geo": null } } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn composite_values_can_be_filtered(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, address: { street: "Main St", zip: "1234", geo: { lat: 1.5, lng: 2.5 } } }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 2, address: { street: "Side St" }, work: { street: "Main St" } }) { id } }"#)
+        .await;
+
+    let filters = vec![
+        (r#"{ address: { is: { street: "Main St" } } }"#, json!([{ "id": 1 }])),
+        (r#"{ address: { street: "Side St" } }"#, json!([{ "id": 2 }])),
+        (r#"{ address: { isNot: { zip: "1234" } } }"#, json!([{ "id": 2 }])),
+        (
+            r#"{ address: { equals: { street: "Side St" } } }"#,
+            json!([{ "id": 2 }]),
+        ),
+        (
+            r#"{ address: { equals: { street: "Main St", zip: "1234" } } }"#,
+            json!([]),
+        ),
+        (r#"{ address: { is: { geo: { lat: 1.5 } } } }"#, json!([{ "id": 1 }])),
+        (r#"{ address: { geo: { lng: 2.5 } } }"#, json!([{ "id": 1 }])),
+        (r#"{ address: { isNot: { geo: { lat: 1.5 } } } }"#, json!([{ "id": 2 }])),
+        (r#"{ work: null }"#, json!([{ "id": 1 }])),
+        (r#"{ work: { isNot: null } }"#, json!([{ "id": 2 }])),
+    ];
+
+    for (filter, expected) in filters {
+        let response = query_engine
+            .request(format!(
+                "query {{ findManyUser(where: {}, orderBy: {{ id: asc }}) {{ id }} }}",
+                filter
+            ))
+            .await;
+
+        assert_eq!(response, json!({ "data": { "findManyUser": expected } }), "{}", filter);
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn composite_fields_named_like_filter_operations_need_the_filter_object(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model Item {
+            id    Int   @id
+            label Label
+        }
+
+        type Label {
+            is     String
+            equals String?
+        }
+    "#};
+
+    let query_engine = api.create_engine(dm).await?;
+
+    query_engine
+        .request(r#"mutation { createOneItem(data: { id: 1, label: { is: "new" } }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneItem(data: { id: 2, label: { is: "old", equals: "new" } }) { id } }"#)
+        .await;
+
+    let filters = vec![
+        (r#"{ label: { is: { is: "new" } } }"#, json!([{ "id": 1 }])),
+        (r#"{ label: { is: { equals: "new" } } }"#, json!([{ "id": 2 }])),
+        (r#"{ label: { equals: { is: "new" } } }"#, json!([{ "id": 1 }])),
+        (r#"{ label: { isNot: { is: "new" } } }"#, json!([{ "id": 2 }])),
+    ];
+
+    for (filter, expected) in filters {
+        let response = query_engine
+            .request(format!(
+                "query {{ findManyItem(where: {}, orderBy: {{ id: asc }}) {{ id }} }}",
+                filter
+            ))
+            .await;
+
+        assert_eq!(response, json!({ "data": { "findManyItem": expected } }), "{}", filter);
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn mysql_compares_composite_values_as_json_documents(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, address: { street: "Main St", zip: "1234", geo: { lat: 1.5, lng: 2.5 } } }) { id } }"#)
+        .await;
+
+    let filters = vec![
+        (
+            r#"{ address: { equals: { street: "Main St", zip: "1234", geo: { lat: 1.5, lng: 2.5 } } } }"#,
+            json!([{ "id": 1 }]),
+        ),
+        (
+            r#"{ address: { equals: { geo: { lng: 2.5, lat: 1.5 }, zip: "1234", street: "Main St" } } }"#,
+            json!([{ "id": 1 }]),
+        ),
+        (
+            r#"{ address: { equals: { street: "Main St", zip: "1234" } } }"#,
+            json!([]),
+        ),
+        (r#"{ work: { equals: null } }"#, json!([{ "id": 1 }])),
+    ];
+
+    for (filter, expected) in filters {
+        let response = query_engine
+            .request(format!("query {{ findManyUser(where: {}) {{ id }} }}", filter))
+            .await;
+
+        assert_eq!(response, json!({ "data": { "findManyUser": expected } }), "{}", filter);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use super::test_api::*;
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static DATAMODEL: &str = indoc! {r#"
+    model Item {
+        id         String @id @default(uuid(7))
+        shortId    String @default(nanoid(8))
+        sortableId String @default(ulid())
+    }
+"#};
+
+#[test_each_connector]
+async fn id_generators_fill_in_missing_values(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DATAMODEL).await?;
+
+    let first = query_engine
+        .request("mutation { createOneItem(data: {}) { id shortId sortableId } }")
+        .await;
+    let second = query_engine
+        .request("mutation { createOneItem(data: {}) { id shortId sortableId } }")
+        .await;
+
+    let first = &first["data"]["createOneItem"];
+    let second = &second["data"]["createOneItem"];
+
+    let id = first["id"].as_str().unwrap();
+    assert_eq!(id.len(), 36);
+    assert_eq!(&id[14..15], "7");
+    assert_eq!(first["shortId"].as_str().unwrap().len(), 8);
+    assert_eq!(first["sortableId"].as_str().unwrap().len(), 26);
+
+    assert_ne!(first["shortId"], second["shortId"]);
+
+    Ok(())
+}
@@ -1,13 +1,16 @@
 use crate::{
     cli::CliCommand,
-    opt::{CliOpt, PrismaOpt, Subcommand},
+    dmmf::{dmmf_json_schema, render_dmmf_version, supported_dmmf_versions, DMMF_VERSION, MIN_DMMF_VERSION},
+    opt::{CliOpt, DmmfInput, PrismaOpt, Subcommand},
     PrismaResult,
 };
 use datamodel_connector::ConnectorCapabilities;
+use jsonschema::JSONSchema;
 use prisma_models::DatamodelConverter;
 use query_core::{schema_builder, BuildMode, QuerySchema};
+use serde_json::Value;
 use serial_test::serial;
-use std::sync::Arc;
+use std::{fmt::Write as _, path::PathBuf, sync::Arc};
 
 // Tests in this file run serially because the function `get_query_schema` depends on setting an env var.
 
@@ -73,7 +76,171 @@ fn must_fail_if_the_schema_is_invalid() {
     assert!(test_dmmf_cli_command(schema).is_err());
 }
 
+#[test]
+#[serial]
+fn must_fail_if_an_unsupported_dmmf_version_is_requested() {
+    let schema = r#"
+        model Blog {
+            blogId String @id
+        }
+    "#;
+
+    let previous_version = DmmfInput {
+        version: Some(MIN_DMMF_VERSION - 1),
+        json_schema: false,
+    };
+
+    let next_version = DmmfInput {
+        version: Some(DMMF_VERSION + 1),
+        json_schema: false,
+    };
+
+    assert!(test_dmmf_cli_command_with_input(schema, previous_version).is_err());
+    assert!(test_dmmf_cli_command_with_input(schema, next_version).is_err());
+}
+
+#[test]
+#[serial]
+fn all_supported_dmmf_versions_can_be_requested() {
+    let schema = r#"
+        model Blog {
+            blogId String @id
+        }
+    "#;
+
+    for version in supported_dmmf_versions() {
+        let input = DmmfInput {
+            version: Some(version),
+            json_schema: false,
+        };
+
+        test_dmmf_cli_command_with_input(schema, input).unwrap();
+
+        let (query_schema, datamodel) = get_query_schema(schema);
+        let dmmf = render_dmmf_version(&datamodel, Arc::new(query_schema), version).unwrap();
+
+        assert_eq!(dmmf["version"], version);
+    }
+}
+
+#[test]
+#[serial]
+fn the_json_schema_can_be_rendered_from_the_cli() {
+    let input = DmmfInput {
+        version: None,
+        json_schema: true,
+    };
+
+    test_dmmf_cli_command_with_input("", input).unwrap();
+}
+
+/// The shape of the DMMF is part of the contract with the generators. This
+/// test fails on any change to the shape that does not come with a bump of
+/// `DMMF_VERSION`, and when the snapshot of a supported version is missing.
+/// The snapshot of a new version is recorded by running the test with
+/// `UPDATE_DMMF_SNAPSHOTS=1`.
+#[test]
+fn the_dmmf_shape_matches_the_snapshot_of_its_version() {
+    let shape = describe_dmmf_shape(&serde_json::to_value(dmmf_json_schema()).unwrap());
+    let snapshot_path = |version: u32| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/tests/snapshots")
+            .join(format!("dmmf_v{}.txt", version))
+    };
+
+    if std::env::var("UPDATE_DMMF_SNAPSHOTS").is_ok() && !snapshot_path(DMMF_VERSION).exists() {
+        std::fs::write(snapshot_path(DMMF_VERSION), &shape).unwrap();
+    }
+
+    for version in supported_dmmf_versions() {
+        assert!(
+            snapshot_path(version).exists(),
+            "The snapshot of the DMMF version {} is missing. Run the test with `UPDATE_DMMF_SNAPSHOTS=1` to record the snapshot of a new version.",
+            version
+        );
+    }
+
+    let snapshot = std::fs::read_to_string(snapshot_path(DMMF_VERSION)).unwrap();
+
+    assert!(
+        snapshot == shape,
+        "The shape of the DMMF changed without a version bump. Bump `DMMF_VERSION` to record a new snapshot.\n\nExpected:\n{}\nFound:\n{}",
+        snapshot,
+        shape
+    );
+}
+
+#[test]
+#[serial]
+fn the_rendered_dmmf_is_valid_against_its_json_schema() {
+    let dm = r#"
+        /// Blog posts.
+        model Post {
+            id        Int      @id @default(autoincrement())
+            title     String   @unique
+            status    Status   @default(DRAFT)
+            tags      String[]
+            authorId  Int
+            author    User     @relation(fields: [authorId], references: [id])
+            updatedAt DateTime @updatedAt
+
+            @@unique([title, authorId])
+        }
+
+        model User {
+            id    Int    @id
+            email String @map("email_address")
+            posts Post[]
+        }
+
+        enum Status {
+            DRAFT
+            PUBLISHED @map("published")
+        }
+    "#;
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+    let dmmf = serde_json::to_value(&dmmf).unwrap();
+    let schema = serde_json::to_value(dmmf_json_schema()).unwrap();
+
+    assert_eq!(dmmf["version"], DMMF_VERSION);
+    assert_eq!(validate(&schema, &dmmf), Vec::<String>::new());
+}
+
+#[test]
+#[serial]
+fn the_json_schema_rejects_unversioned_shape_changes() {
+    let dm = r#"
+        model Blog {
+            blogId String @id
+        }
+    "#;
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+    let mut dmmf = serde_json::to_value(&dmmf).unwrap();
+    let schema = serde_json::to_value(dmmf_json_schema()).unwrap();
+
+    dmmf["datamodel"]["models"][0].as_object_mut().unwrap().remove("name");
+    dmmf["datamodel"]["models"][0]["fields"][0]["isList"] = Value::String("false".into());
+
+    assert_eq!(
+        validate(&schema, &dmmf),
+        &["'\"false\"' is not of type 'boolean'", "'name' is a required property",]
+    );
+}
+
 fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
+    let input = DmmfInput {
+        version: None,
+        json_schema: false,
+    };
+
+    test_dmmf_cli_command_with_input(schema, input)
+}
+
+fn test_dmmf_cli_command_with_input(schema: &str, input: DmmfInput) -> PrismaResult<()> {
     feature_flags::initialize(&[String::from("all")]).unwrap();
 
     let prisma_opt = PrismaOpt {
@@ -89,7 +256,7 @@ fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
         port: 123,
         raw_feature_flags: vec![],
         unix_path: None,
-        subcommand: Some(Subcommand::Cli(CliOpt::Dmmf)),
+        subcommand: Some(Subcommand::Cli(CliOpt::Dmmf(input))),
     };
 
     let cli_cmd = CliCommand::from_opt(&prisma_opt)?.unwrap();
@@ -119,3 +286,112 @@ fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Dat
         dm,
     )
 }
+
+/// Render the shape of the DMMF described by a JSON Schema: one block per
+/// definition, listing the properties with their types. Optional properties
+/// are marked with a `?`.
+fn describe_dmmf_shape(schema: &Value) -> String {
+    let mut out = String::new();
+
+    describe_definition(schema["title"].as_str().unwrap(), schema, &mut out);
+
+    if let Some(definitions) = schema["definitions"].as_object() {
+        let mut names: Vec<&String> = definitions.keys().collect();
+        names.sort();
+
+        for name in names {
+            describe_definition(name, &definitions[name.as_str()], &mut out);
+        }
+    }
+
+    out
+}
+
+fn describe_definition(name: &str, definition: &Value, out: &mut String) {
+    let properties = match definition["properties"].as_object() {
+        Some(properties) => properties,
+        None => {
+            writeln!(out, "{} = {}\n", name, describe_type(definition)).unwrap();
+            return;
+        }
+    };
+
+    let required = definition["required"].as_array().cloned().unwrap_or_default();
+    let mut property_names: Vec<&String> = properties.keys().collect();
+    property_names.sort();
+
+    writeln!(out, "{} {{", name).unwrap();
+
+    for property_name in property_names {
+        let optional = if required.iter().any(|r| r == property_name.as_str()) {
+            ""
+        } else {
+            "?"
+        };
+
+        writeln!(
+            out,
+            "  {}{}: {}",
+            property_name,
+            optional,
+            describe_type(&properties[property_name.as_str()])
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "}}\n").unwrap();
+}
+
+fn describe_type(schema: &Value) -> String {
+    if let Value::Bool(allowed) = schema {
+        return if *allowed { "any" } else { "never" }.to_owned();
+    }
+
+    if let Some(reference) = schema["$ref"].as_str() {
+        return reference.trim_start_matches("#/definitions/").to_owned();
+    }
+
+    if let Some(alternatives) = schema["anyOf"].as_array().or_else(|| schema["oneOf"].as_array()) {
+        return alternatives.iter().map(describe_type).collect::<Vec<_>>().join(" | ");
+    }
+
+    if let Some(values) = schema["enum"].as_array() {
+        return values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(" | ");
+    }
+
+    let types = match &schema["type"] {
+        Value::String(tpe) => vec![tpe.as_str()],
+        Value::Array(types) => types.iter().filter_map(|tpe| tpe.as_str()).collect(),
+        _ => return "any".to_owned(),
+    };
+
+    types
+        .into_iter()
+        .map(|tpe| match tpe {
+            "array" => format!("[{}]", describe_type(&schema["items"])),
+            "object" if schema["additionalProperties"].is_object() => {
+                format!("{{string: {}}}", describe_type(&schema["additionalProperties"]))
+            }
+            other => other.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// Validate a JSON document against a JSON Schema, returning the errors in a
+/// stable order.
+fn validate(schema: &Value, document: &Value) -> Vec<String> {
+    let schema = JSONSchema::compile(schema).unwrap();
+
+    let mut errors: Vec<String> = match schema.validate(document) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors.map(|error| error.to_string()).collect(),
+    };
+
+    errors.sort();
+    errors
+}
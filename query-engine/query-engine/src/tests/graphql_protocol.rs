@@ -0,0 +1,388 @@
+use super::test_api::*;
+use crate::request_handlers::{operation_id, PersistedOperations, SingleQuery};
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id Int @id
+        title String
+        done Boolean @default(false)
+    }
+"};
+
+fn query_with_variables(query: &str, variables: serde_json::Value) -> SingleQuery {
+    serde_json::from_value(json!({ "query": query, "variables": variables })).unwrap()
+}
+
+#[test_each_connector]
+async fn variables_are_substituted(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        mutation createTodo($data: TodoCreateInput!) {
+            createOneTodo(data: $data) { id title done }
+        }
+    "#};
+
+    let response = query_engine
+        .request(query_with_variables(
+            query,
+            json!({ "data": { "id": 1, "title": "write tests" } }),
+        ))
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "createOneTodo": { "id": 1, "title": "write tests", "done": false } } }),
+        response
+    );
+
+    let query = indoc! {r#"
+        query findTodos($title: String!, $take: Int = 10) {
+            findManyTodo(where: { title: { equals: $title } }, take: $take) { id }
+        }
+    "#};
+
+    let response = query_engine
+        .request(query_with_variables(query, json!({ "title": "write tests" })))
+        .await;
+
+    assert_eq!(json!({ "data": { "findManyTodo": [{ "id": 1 }] } }), response);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn missing_optional_variables_are_omitted(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "write tests" }) { id } }"#)
+        .await;
+
+    let query = indoc! {r#"
+        query findTodos($where: TodoWhereInput, $take: Int, $done: Boolean) {
+            findManyTodo(where: $where, take: $take) { id }
+            findFirstTodo(where: { id: 1, done: $done }) { id }
+        }
+    "#};
+
+    let response = query_engine.request(query_with_variables(query, json!({}))).await;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1 }], "findFirstTodo": { "id": 1 } } }),
+        response
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn invalid_variables_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        query findTodos($take: Int!) {
+            findManyTodo(take: $take) { id }
+        }
+    "#};
+
+    let response = query_engine
+        .request(query_with_variables(query, json!({ "take": "ten" })))
+        .await;
+
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Invalid value for variable `$take` of type `Int!`"));
+
+    let response = query_engine.request(query_with_variables(query, json!({}))).await;
+
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("the variable is required."));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn invalid_nested_variables_are_rejected_with_the_most_specific_error(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        query findTodos($where: TodoWhereInput) {
+            findManyTodo(where: $where) { id }
+        }
+    "#};
+
+    let response = query_engine
+        .request(query_with_variables(
+            query,
+            json!({ "where": { "title": { "equals": 1 } } }),
+        ))
+        .await;
+
+    let error = response["errors"][0]["error"].as_str().unwrap();
+
+    assert!(error.contains("invalid value for `TodoWhereInput.title`"), "{}", error);
+    assert!(
+        error.contains("invalid value for `StringFilter.equals`: `1` is not a valid"),
+        "{}",
+        error
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn fragments_are_expanded(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "write tests" }) { id } }"#)
+        .await;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo {
+                ...TodoFields
+                ... on Todo { done }
+            }
+        }
+
+        fragment TodoFields on Todo {
+            id
+            ...TodoTitle
+        }
+
+        fragment TodoTitle on Todo {
+            title
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1, "title": "write tests", "done": false }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn fragments_on_other_types_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo { id ... on Query { title } }
+        }
+    "#};
+
+    let response = query_engine.request(query).await;
+
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Fragment on 'Query' cannot be spread on 'Todo'"));
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo { ...TodoId }
+        }
+
+        fragment TodoId on Mutation { id }
+    "#};
+
+    let response = query_engine.request(query).await;
+
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Fragment on 'Mutation' cannot be spread on 'Todo'"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cyclic_fragments_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo { ...A }
+        }
+
+        fragment A on Todo { id ...B }
+        fragment B on Todo { title ...A }
+    "#};
+
+    let response = query_engine.request(query).await;
+
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Fragment 'A' spreads itself"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn persisted_operations_are_registered_and_reused(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        mutation createTodo($id: Int!, $title: String!) {
+            createOneTodo(data: { id: $id, title: $title }) { id title }
+        }
+    "#};
+    let id = operation_id(query);
+
+    let response = query_engine
+        .request(SingleQuery::persisted(&id, None, json!({ "id": 1, "title": "a" })))
+        .await;
+
+    assert_eq!(
+        Some("P2023"),
+        response["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    let response = query_engine
+        .request(SingleQuery::persisted(
+            &id,
+            Some(query.to_owned()),
+            json!({ "id": 1, "title": "a" }),
+        ))
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "createOneTodo": { "id": 1, "title": "a" } } }),
+        response
+    );
+
+    let response = query_engine
+        .request(SingleQuery::persisted(&id, None, json!({ "id": 2, "title": "b" })))
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "createOneTodo": { "id": 2, "title": "b" } } }),
+        response
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn persisted_operations_must_match_their_id(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let response = query_engine
+        .request(SingleQuery::persisted(
+            operation_id("query { findManyTodo { id } }"),
+            Some("query { findManyTodo { title } }".to_owned()),
+            serde_json::Value::Null,
+        ))
+        .await;
+
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("does not match the hash of its query"));
+
+    // A registered id does not make the query it is sent with trusted.
+    let id = operation_id("query { findManyTodo { id } }");
+
+    query_engine
+        .request(SingleQuery::persisted(
+            &id,
+            Some("query { findManyTodo { id } }".to_owned()),
+            serde_json::Value::Null,
+        ))
+        .await;
+
+    let response = query_engine
+        .request(SingleQuery::persisted(
+            &id,
+            Some("query { findManyTodo { title } }".to_owned()),
+            serde_json::Value::Null,
+        ))
+        .await;
+
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("does not match the hash of its query"));
+
+    Ok(())
+}
+
+#[test]
+fn persisted_operations_evict_the_least_recently_used_operation() {
+    let operations = PersistedOperations::with_capacity(2);
+    let queries = [
+        "query { findManyTodo { id } }",
+        "query { findManyTodo { title } }",
+        "query { findManyTodo { done } }",
+    ];
+    let ids: Vec<String> = queries.iter().map(|query| operation_id(query)).collect();
+
+    operations.register(&ids[0], queries[0]).unwrap();
+    operations.register(&ids[1], queries[1]).unwrap();
+
+    // Using the first operation makes the second one the least recently used.
+    operations.get(&ids[0]).unwrap();
+    operations.register(&ids[2], queries[2]).unwrap();
+
+    assert!(operations.get(&ids[0]).is_ok());
+    assert!(operations.get(&ids[1]).is_err());
+    assert!(operations.get(&ids[2]).is_ok());
+}
+
+#[test_each_connector]
+async fn persisted_operations_reuse_their_converted_operations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }"#)
+        .await;
+
+    let query = indoc! {r#"
+        query findTodo($id: Int!) {
+            findOneTodo(where: { id: $id }) { id title }
+        }
+    "#};
+    let id = operation_id(query);
+
+    for (query, variables) in vec![
+        (Some(query.to_owned()), json!({ "id": 1 })),
+        (None, json!({ "id": 1 })),
+        (None, json!({ "id": 1 })),
+    ] {
+        let response = query_engine
+            .request(SingleQuery::persisted(&id, query, variables))
+            .await;
+
+        assert_eq!(
+            json!({ "data": { "findOneTodo": { "id": 1, "title": "a" } } }),
+            response
+        );
+    }
+
+    let response = query_engine
+        .request(SingleQuery::persisted(&id, None, json!({ "id": 2 })))
+        .await;
+
+    assert_eq!(json!({ "data": { "findOneTodo": null } }), response);
+
+    let response = query_engine
+        .request(SingleQuery::persisted(&id, None, json!({ "id": "one" })))
+        .await;
+
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Invalid value for variable `$id` of type `Int!`"));
+
+    Ok(())
+}
@@ -1,4 +1,14 @@
+mod composite_types;
+mod default_values;
 mod dmmf;
 mod errors;
 mod execute_raw;
+mod graphql_protocol;
+mod optimistic_concurrency;
+mod query_timeouts;
+mod referential_actions;
+mod request_context;
+mod scalar_lists;
+mod soft_delete;
+mod subscriptions;
 mod test_api;
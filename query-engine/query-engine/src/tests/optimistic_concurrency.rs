@@ -0,0 +1,164 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static DOCUMENTS: &str = indoc! {r#"
+    model Document {
+        id      Int    @id
+        title   String
+        version Int    @version
+    }
+"#};
+
+#[test_each_connector]
+async fn versions_start_at_one_and_are_incremented_on_updates(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+
+    let response = query_engine
+        .request(r#"mutation { createOneDocument(data: { id: 1, title: "Draft" }) { version } }"#)
+        .await;
+    assert_eq!(response, json!({ "data": { "createOneDocument": { "version": 1 } } }));
+
+    let response = query_engine
+        .request(r#"mutation { updateOneDocument(where: { id: 1 }, data: { title: "Final" }) { version } }"#)
+        .await;
+    assert_eq!(response, json!({ "data": { "updateOneDocument": { "version": 2 } } }));
+
+    let response = query_engine
+        .request(r#"mutation { updateManyDocument(data: { title: "Published" }) { count } }"#)
+        .await;
+    assert_eq!(response, json!({ "data": { "updateManyDocument": { "count": 1 } } }));
+
+    let response = query_engine
+        .request("query { findOneDocument(where: { id: 1 }) { title version } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findOneDocument": { "title": "Published", "version": 3 } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn updates_against_a_stale_version_fail(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+    query_engine
+        .request(r#"mutation { createOneDocument(data: { id: 1, title: "Draft" }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { updateOneDocument(where: { id: 1, version: 1 }, data: { title: "Final" }) { title version } }"#)
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "updateOneDocument": { "title": "Final", "version": 2 } } })
+    );
+
+    let response = query_engine
+        .request(r#"mutation { updateOneDocument(where: { id: 1, version: 1 }, data: { title: "Lost" }) { id } }"#)
+        .await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2024");
+
+    let response = query_engine
+        .request("query { findOneDocument(where: { id: 1 }) { title version } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findOneDocument": { "title": "Final", "version": 2 } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn version_guarded_updates_of_missing_records_fail_with_not_found(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+
+    let response = query_engine
+        .request(r#"mutation { updateOneDocument(where: { id: 1, version: 1 }, data: { title: "Final" }) { id } }"#)
+        .await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2018");
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn the_version_alone_is_not_a_unique_criterion(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+
+    let response = query_engine
+        .request(r#"mutation { updateOneDocument(where: { version: 1 }, data: { title: "Final" }) { id } }"#)
+        .await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2019");
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn upserts_against_a_stale_version_fail(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+    query_engine
+        .request(r#"mutation { createOneDocument(data: { id: 1, title: "Draft" }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { upsertOneDocument(where: { id: 1, version: 1 }, create: { id: 1, title: "New" }, update: { title: "Final" }) { title version } }"#)
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "upsertOneDocument": { "title": "Final", "version": 2 } } })
+    );
+
+    let response = query_engine
+        .request(r#"mutation { upsertOneDocument(where: { id: 1, version: 1 }, create: { id: 1, title: "New" }, update: { title: "Lost" }) { id } }"#)
+        .await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2024");
+
+    let response = query_engine
+        .request("query { findOneDocument(where: { id: 1 }) { title version } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findOneDocument": { "title": "Final", "version": 2 } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn deletes_against_a_stale_version_fail(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENTS).await?;
+    query_engine
+        .request(r#"mutation { createOneDocument(data: { id: 1, title: "Draft" }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { updateOneDocument(where: { id: 1 }, data: { title: "Final" }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request("mutation { deleteOneDocument(where: { id: 1, version: 1 }) { id } }")
+        .await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2024");
+
+    let response = query_engine
+        .request("query { findOneDocument(where: { id: 1 }) { title version } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findOneDocument": { "title": "Final", "version": 2 } } })
+    );
+
+    let response = query_engine
+        .request("mutation { deleteOneDocument(where: { id: 1, version: 2 }) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "deleteOneDocument": { "id": 1 } } }));
+
+    let response = query_engine
+        .request("query { findOneDocument(where: { id: 1 }) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "findOneDocument": null } }));
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use super::test_api::*;
+use crate::{request_handlers::SingleQuery, server};
+use indoc::indoc;
+use serde_json::json;
+use std::time::{Duration, Instant};
+use test_macros::test_each_connector;
+use tide::http::{Method, Request, Response, StatusCode, Url};
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_queries_running_past_the_timeout_are_cancelled(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let started = Instant::now();
+    let query = SingleQuery::from(r#"mutation { queryRaw(query: "SELECT pg_sleep(10)") }"#)
+        .with_timeout(Duration::from_millis(200));
+    let response = query_engine.request(query).await;
+
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2025");
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    // The cancelled statement doesn't hold on to its connection.
+    let response = query_engine
+        .request(r#"mutation { queryRaw(query: "SELECT count(*)::int AS sleeping FROM pg_stat_activity WHERE query = 'SELECT pg_sleep(10)' AND state = 'active'") }"#)
+        .await;
+    assert_eq!(response, json!({ "data": { "queryRaw": [{ "sleeping": 0 }] } }));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn mysql_queries_running_past_the_timeout_are_cancelled(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let started = Instant::now();
+    let query = SingleQuery::from(r#"mutation { queryRaw(query: "SELECT SLEEP(10)") }"#)
+        .with_timeout(Duration::from_millis(200));
+    let response = query_engine.request(query).await;
+
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2025");
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_batches_running_past_the_timeout_are_cancelled(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let server = server::server(query_engine.context().clone(), false, false);
+
+    let sleep = json!({ "query": r#"mutation { queryRaw(query: "SELECT pg_sleep(10)") }"#, "variables": {} });
+    let mut request = Request::new(Method::Post, Url::parse("http://localhost/").unwrap());
+    request.insert_header("X-Prisma-Timeout", "200");
+    request.set_body(json!({ "batch": [sleep.clone(), sleep], "transaction": false }));
+
+    let started = Instant::now();
+    let mut response: Response = server.respond(request).await.unwrap();
+    let body: serde_json::Value = response.body_json().await.unwrap();
+
+    assert_eq!(body[0]["errors"][0]["user_facing_error"]["error_code"], "P2025");
+    assert_eq!(body[1]["errors"][0]["user_facing_error"]["error_code"], "P2025");
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    // Each operation cancels its statement before its connection goes back to the pool.
+    let response = query_engine
+        .request(r#"mutation { queryRaw(query: "SELECT count(*)::int AS sleeping FROM pg_stat_activity WHERE query = 'SELECT pg_sleep(10)' AND state = 'active'") }"#)
+        .await;
+    assert_eq!(response, json!({ "data": { "queryRaw": [{ "sleeping": 0 }] } }));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn queries_finishing_within_the_timeout_succeed(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = SingleQuery::from(r#"mutation { createOneTodo(data: { id: 1, title: "Write tests" }) { id } }"#)
+        .with_timeout(Duration::from_secs(10));
+    let response = query_engine.request(query).await;
+
+    assert_eq!(response, json!({ "data": { "createOneTodo": { "id": 1 } } }));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn waiting_for_a_connection_past_the_pool_timeout_fails(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_connection_params(&TODO, &[("connection_limit", "1"), ("pool_timeout", "1")])
+        .await?;
+
+    let sleep = query_engine.request(r#"mutation { queryRaw(query: "SELECT pg_sleep(3)") }"#);
+    let waiting = async {
+        tokio::time::delay_for(Duration::from_millis(200)).await;
+        query_engine.request("query { findManyTodo { id } }").await
+    };
+
+    let (_, response) = futures::join!(sleep, waiting);
+
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2026");
+
+    Ok(())
+}
+
+fn graphql_request(query: &str, timeout_header: &str) -> Request {
+    let mut request = Request::new(Method::Post, Url::parse("http://localhost/").unwrap());
+    request.insert_header("X-Prisma-Timeout", timeout_header);
+    request.set_body(json!({ "query": query, "variables": {} }));
+
+    request
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn the_timeout_header_applies_to_requests_without_a_timeout(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let server = server::server(query_engine.context().clone(), false, false);
+
+    let started = Instant::now();
+    let request = graphql_request(r#"mutation { queryRaw(query: "SELECT pg_sleep(10)") }"#, "200");
+    let mut response: Response = server.respond(request).await.unwrap();
+    let body: serde_json::Value = response.body_json().await.unwrap();
+
+    assert_eq!(body["errors"][0]["user_facing_error"]["error_code"], "P2025");
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    let request = graphql_request(
+        r#"mutation { createOneTodo(data: { id: 1, title: "Write tests" }) { id } }"#,
+        "10000",
+    );
+    let mut response: Response = server.respond(request).await.unwrap();
+    let body: serde_json::Value = response.body_json().await.unwrap();
+
+    assert_eq!(body, json!({ "data": { "createOneTodo": { "id": 1 } } }));
+
+    let request = graphql_request("query { findManyTodo { id } }", "soon");
+    let response: Response = server.respond(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BadRequest);
+
+    Ok(())
+}
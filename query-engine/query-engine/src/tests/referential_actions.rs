@@ -0,0 +1,209 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {r#"
+    model User {
+        id    Int    @id
+        email String @unique
+        posts Post[]
+        likes Like[]
+    }
+
+    model Post {
+        id          Int       @id
+        authorEmail String?
+        author      User?     @relation(fields: [authorEmail], references: [email], onDelete: CASCADE, onUpdate: SET_NULL)
+        comments    Comment[]
+    }
+
+    model Comment {
+        id     Int  @id
+        postId Int
+        post   Post @relation(fields: [postId], references: [id], onDelete: CASCADE)
+    }
+
+    model Like {
+        id     Int   @id
+        userId Int?
+        user   User? @relation(fields: [userId], references: [id], onDelete: SET_NULL)
+    }
+"#};
+
+static SEED: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: {
+            id: 1,
+            email: "a@prisma.io",
+            posts: { create: [{ id: 1, comments: { create: [{ id: 1 }, { id: 2 }] } }, { id: 2 }] },
+            likes: { create: [{ id: 1 }] }
+        }) { id }
+    }
+"#};
+
+#[test_each_connector]
+async fn deletes_cascade_to_children_and_grandchildren(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_emulating_referential_actions(&BLOG).await?;
+    query_engine.request(SEED).await;
+
+    let response = query_engine
+        .request("mutation { deleteOneUser(where: { id: 1 }) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "deleteOneUser": { "id": 1 } } }));
+
+    let response = query_engine
+        .request("query { findManyPost { id } findManyComment { id } findManyLike { id userId } }")
+        .await;
+
+    assert_eq!(
+        response,
+        json!({ "data": { "findManyPost": [], "findManyComment": [], "findManyLike": [{ "id": 1, "userId": null }] } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn delete_many_cascades_to_children(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_emulating_referential_actions(&BLOG).await?;
+    query_engine.request(SEED).await;
+
+    let response = query_engine
+        .request("mutation { deleteManyPost(where: { id: 1 }) { count } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "deleteManyPost": { "count": 1 } } }));
+
+    let response = query_engine
+        .request("query { findManyPost { id } findManyComment { id } }")
+        .await;
+
+    assert_eq!(
+        response,
+        json!({ "data": { "findManyPost": [{ "id": 2 }], "findManyComment": [] } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn updating_referenced_fields_sets_null_on_children(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_emulating_referential_actions(&BLOG).await?;
+    query_engine.request(SEED).await;
+
+    let response = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 1 }, data: { email: "b@prisma.io" }) { email } }"#)
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "updateOneUser": { "email": "b@prisma.io" } } })
+    );
+
+    let response = query_engine.request("query { findManyPost { id authorEmail } }").await;
+
+    assert_eq!(
+        response,
+        json!({ "data": { "findManyPost": [{ "id": 1, "authorEmail": null }, { "id": 2, "authorEmail": null }] } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cyclic_cascades_cannot_be_emulated(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model Todo {
+            id       Int    @id
+            parentId Int?
+            parent   Todo?  @relation("TodoTree", fields: [parentId], references: [id], onDelete: CASCADE)
+            children Todo[] @relation("TodoTree")
+        }
+    "#};
+
+    let query_engine = api.create_engine_emulating_referential_actions(dm).await?;
+    query_engine
+        .request("mutation { createOneTodo(data: { id: 1 }) { id } }")
+        .await;
+
+    let response = query_engine
+        .request("mutation { deleteOneTodo(where: { id: 1 }) { id } }")
+        .await;
+
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2019");
+    assert!(response["errors"][0]["user_facing_error"]["meta"]["details"]
+        .as_str()
+        .unwrap()
+        .contains("cascading deletes of `Todo` are cyclic"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn indirectly_cyclic_cascades_cannot_be_emulated(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model User {
+            id          Int   @id
+            favoriteId  Int?
+            favorite    Post? @relation("Favorite", fields: [favoriteId], references: [id], onDelete: CASCADE)
+            posts       Post[] @relation("Author")
+        }
+
+        model Post {
+            id         Int    @id
+            authorId   Int
+            author     User   @relation("Author", fields: [authorId], references: [id], onDelete: CASCADE)
+            favoriteOf User[] @relation("Favorite")
+        }
+    "#};
+
+    let query_engine = api.create_engine_emulating_referential_actions(dm).await?;
+    query_engine
+        .request("mutation { createOneUser(data: { id: 1 }) { id } }")
+        .await;
+
+    let response = query_engine
+        .request("mutation { deleteOneUser(where: { id: 1 }) { id } }")
+        .await;
+
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2019");
+    assert!(response["errors"][0]["user_facing_error"]["meta"]["details"]
+        .as_str()
+        .unwrap()
+        .contains("cascading deletes of `User` are cyclic"));
+
+    // Nothing was deleted.
+    let response = query_engine.request("query { findManyUser { id } }").await;
+    assert_eq!(response, json!({ "data": { "findManyUser": [{ "id": 1 }] } }));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cycles_broken_by_set_null_can_be_emulated(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model Todo {
+            id       Int    @id
+            parentId Int?
+            parent   Todo?  @relation("TodoTree", fields: [parentId], references: [id], onDelete: SET_NULL)
+            children Todo[] @relation("TodoTree")
+        }
+    "#};
+
+    let query_engine = api.create_engine_emulating_referential_actions(dm).await?;
+    query_engine
+        .request("mutation { createOneTodo(data: { id: 1, children: { create: [{ id: 2 }] } }) { id } }")
+        .await;
+
+    let response = query_engine
+        .request("mutation { deleteOneTodo(where: { id: 1 }) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "deleteOneTodo": { "id": 1 } } }));
+
+    let response = query_engine.request("query { findManyTodo { id parentId } }").await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findManyTodo": [{ "id": 2, "parentId": null }] } })
+    );
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use super::test_api::*;
+use crate::request_handlers::SingleQuery;
+use indoc::indoc;
+use query_core::RequestContext;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+fn current_user_query() -> SingleQuery {
+    SingleQuery::from(r#"mutation { queryRaw(query: "SELECT current_setting('app.user_id', true) AS user_id") }"#)
+}
+
+fn user_context(user_id: &str) -> RequestContext {
+    let mut context = RequestContext::new();
+    context.insert("app.user_id", user_id);
+    context
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn the_request_context_is_set_for_the_transaction(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let response = query_engine
+        .request(current_user_query().with_context(user_context("42")))
+        .await;
+    assert_eq!(response, json!({ "data": { "queryRaw": [{ "user_id": "42" }] } }));
+
+    // The setting is local to the transaction of the request.
+    let response = query_engine.request(current_user_query()).await;
+    assert_ne!(response, json!({ "data": { "queryRaw": [{ "user_id": "42" }] } }));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn reads_run_with_the_request_context(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "Write tests" }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(SingleQuery::from("query { findManyTodo { title } }").with_context(user_context("42")))
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findManyTodo": [{ "title": "Write tests" }] } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("postgres"))]
+async fn a_request_context_is_rejected_without_postgres(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let response = query_engine
+        .request(SingleQuery::from("query { findManyTodo { id } }").with_context(user_context("42")))
+        .await;
+    let message = response["errors"][0]["error"].as_str().unwrap();
+    assert!(message.contains("A request context is not supported"), "{}", message);
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static POST: &str = indoc! {r#"
+    model Post {
+        id   Int      @id
+        tags String[]
+    }
+"#};
+
+#[test_each_connector(tags("postgres"))]
+async fn list_operations_update_the_list_in_place(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POST).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, tags: { set: ["b", "x", "b"] } }) { id } }"#)
+        .await;
+
+    let operations = vec![
+        (r#"{ push: "c" }"#, json!(["b", "x", "b", "c"])),
+        (r#"{ push: ["d", "e"] }"#, json!(["b", "x", "b", "c", "d", "e"])),
+        (r#"{ unshift: "a" }"#, json!(["a", "b", "x", "b", "c", "d", "e"])),
+        (r#"{ removeAll: "b" }"#, json!(["a", "x", "c", "d", "e"])),
+        (
+            r#"{ setAt: { index: 1, value: "y" } }"#,
+            json!(["a", "y", "c", "d", "e"]),
+        ),
+    ];
+
+    for (operation, expected) in operations {
+        let response = query_engine
+            .request(format!(
+                "mutation {{ updateOnePost(where: {{ id: 1 }}, data: {{ tags: {} }}) {{ tags }} }}",
+                operation
+            ))
+            .await;
+
+        assert_eq!(response, json!({ "data": { "updateOnePost": { "tags": expected } } }));
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn list_operations_work_in_update_many(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POST).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, tags: { set: ["a"] } }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 2, tags: { set: [] } }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { updateManyPost(data: { tags: { push: "new" } }) { count } }"#)
+        .await;
+    assert_eq!(response, json!({ "data": { "updateManyPost": { "count": 2 } } }));
+
+    let response = query_engine
+        .request("query { findManyPost(orderBy: { id: asc }) { id tags } }")
+        .await;
+
+    assert_eq!(
+        response,
+        json!({ "data": { "findManyPost": [{ "id": 1, "tags": ["a", "new"] }, { "id": 2, "tags": ["new"] }] } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn scalar_list_filters_work(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POST).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, tags: { set: ["a", "b"] } }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 2, tags: { set: ["b", "c"] } }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 3, tags: { set: [] } }) { id } }"#)
+        .await;
+    // A `NULL` list.
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 4 }) { id } }"#)
+        .await;
+
+    let filters = vec![
+        (r#"{ has: "b" }"#, json!([{ "id": 1 }, { "id": 2 }])),
+        (r#"{ hasEvery: ["a", "b"] }"#, json!([{ "id": 1 }])),
+        (r#"{ hasSome: ["a", "c"] }"#, json!([{ "id": 1 }, { "id": 2 }])),
+        (r#"{ isEmpty: true }"#, json!([{ "id": 3 }, { "id": 4 }])),
+        (r#"{ isEmpty: false }"#, json!([{ "id": 1 }, { "id": 2 }])),
+    ];
+
+    for (filter, expected) in filters {
+        let response = query_engine
+            .request(format!(
+                "query {{ findManyPost(where: {{ tags: {} }}, orderBy: {{ id: asc }}) {{ id }} }}",
+                filter
+            ))
+            .await;
+
+        assert_eq!(response, json!({ "data": { "findManyPost": expected } }));
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn set_at_with_an_index_out_of_range_fails(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POST).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, tags: { set: ["a", "b"] } }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { setAt: { index: 2, value: "c" } } }) { tags } }"#)
+        .await;
+
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2020");
+    assert_eq!(
+        response["errors"][0]["user_facing_error"]["meta"]["details"],
+        "The index 2 is out of range for the list `tags` of length 2."
+    );
+
+    let response = query_engine
+        .request("query { findOnePost(where: { id: 1 }) { tags } }")
+        .await;
+
+    assert_eq!(response, json!({ "data": { "findOnePost": { "tags": ["a", "b"] } } }));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn list_operations_apply_to_records_whose_id_is_updated(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POST).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, tags: { set: ["a"] } }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { id: 2, tags: { push: "b" } }) { id tags } }"#)
+        .await;
+
+    assert_eq!(
+        response,
+        json!({ "data": { "updateOnePost": { "id": 2, "tags": ["a", "b"] } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn list_operations_on_null_lists_and_invalid_operations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POST).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1 }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { push: "a" } }) { tags } }"#)
+        .await;
+
+    assert_eq!(response, json!({ "data": { "updateOnePost": { "tags": ["a"] } } }));
+
+    let response = query_engine
+        .request(
+            r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { push: "b", unshift: "c" } }) { tags } }"#,
+        )
+        .await;
+
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Exactly one operation must be given for the list field `tags`."));
+
+    Ok(())
+}
@@ -0,0 +1,261 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id        Int       @id
+        authorId  Int
+        author    User      @relation(fields: [authorId], references: [id])
+        title     String?
+        comments  Comment[]
+        deletedAt DateTime?
+
+        @@softDelete(field: deletedAt)
+    }
+
+    model Comment {
+        id     Int  @id
+        postId Int
+        post   Post @relation(fields: [postId], references: [id])
+    }
+"#};
+
+async fn create_user_with_posts(query_engine: &QueryEngine) {
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }, { id: 2 }] } }) { id } }"#)
+        .await;
+}
+
+#[test_each_connector]
+async fn deleted_records_are_hidden_from_reads(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_user_with_posts(&query_engine).await;
+
+    let response = query_engine
+        .request("mutation { deleteOnePost(where: { id: 1 }) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "deleteOnePost": { "id": 1 } } }));
+
+    let response = query_engine.request("query { findManyPost { id } }").await;
+    assert_eq!(response, json!({ "data": { "findManyPost": [{ "id": 2 }] } }));
+
+    let response = query_engine
+        .request("query { findOnePost(where: { id: 1 }) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "findOnePost": null } }));
+
+    let response = query_engine.request("query { aggregatePost { count { _all } } }").await;
+    assert_eq!(
+        response,
+        json!({ "data": { "aggregatePost": { "count": { "_all": 1 } } } })
+    );
+
+    let response = query_engine
+        .request("query { findOneUser(where: { id: 1 }) { posts { id } } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findOneUser": { "posts": [{ "id": 2 }] } } })
+    );
+
+    let response = query_engine
+        .request("query { findManyUser(where: { posts: { some: { id: 1 } } }) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "findManyUser": [] } }));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn deleted_records_can_be_read_explicitly(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_user_with_posts(&query_engine).await;
+
+    query_engine
+        .request("mutation { deleteOnePost(where: { id: 1 }) { id } }")
+        .await;
+
+    let response = query_engine
+        .request("query { findManyPost(withDeleted: true, where: { deletedAt: { not: null } }) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "findManyPost": [{ "id": 1 }] } }));
+
+    let response = query_engine
+        .request("query { findOnePost(where: { id: 1 }, withDeleted: true) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "findOnePost": { "id": 1 } } }));
+
+    let response = query_engine
+        .request("query { findOneUser(where: { id: 1 }) { posts(withDeleted: true, orderBy: { id: asc }) { id } } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findOneUser": { "posts": [{ "id": 1 }, { "id": 2 }] } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn delete_many_only_marks_records_not_deleted_yet(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_user_with_posts(&query_engine).await;
+
+    query_engine
+        .request("mutation { deleteOnePost(where: { id: 1 }) { id } }")
+        .await;
+
+    let response = query_engine.request("mutation { deleteManyPost { count } }").await;
+    assert_eq!(response, json!({ "data": { "deleteManyPost": { "count": 1 } } }));
+
+    let response = query_engine
+        .request("query { aggregatePost(withDeleted: true) { count { _all } } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "aggregatePost": { "count": { "_all": 2 } } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn deleted_records_can_not_be_deleted_or_updated_again(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_user_with_posts(&query_engine).await;
+
+    query_engine
+        .request("mutation { deleteOnePost(where: { id: 1 }) { id } }")
+        .await;
+
+    let response = query_engine
+        .request("mutation { deleteOnePost(where: { id: 1 }) { id } }")
+        .await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2018");
+
+    let response = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { title: "Restored" }) { id } }"#)
+        .await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2018");
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn records_with_required_children_can_not_be_deleted(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_user_with_posts(&query_engine).await;
+
+    query_engine
+        .request("mutation { createOneComment(data: { id: 1, post: { connect: { id: 1 } } }) { id } }")
+        .await;
+
+    let response = query_engine
+        .request("mutation { deleteOnePost(where: { id: 1 }) { id } }")
+        .await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2014");
+
+    let response = query_engine.request("mutation { deleteManyPost { count } }").await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2014");
+
+    let response = query_engine
+        .request("query { findOneComment(where: { id: 1 }) { post { id } } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findOneComment": { "post": { "id": 1 } } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn deletes_of_parents_cascade_to_their_children(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model Post {
+            id        Int       @id
+            comments  Comment[]
+            deletedAt DateTime?
+
+            @@softDelete(field: deletedAt)
+        }
+
+        model Comment {
+            id        Int       @id
+            postId    Int
+            post      Post      @relation(fields: [postId], references: [id], onDelete: CASCADE)
+            deletedAt DateTime?
+
+            @@softDelete(field: deletedAt)
+        }
+    "#};
+
+    let query_engine = api.create_engine(dm).await?;
+
+    query_engine
+        .request("mutation { createOnePost(data: { id: 1, comments: { create: [{ id: 1 }, { id: 2 }] } }) { id } }")
+        .await;
+
+    let response = query_engine
+        .request("mutation { deleteOnePost(where: { id: 1 }) { id } }")
+        .await;
+    assert_eq!(response, json!({ "data": { "deleteOnePost": { "id": 1 } } }));
+
+    let response = query_engine.request("query { findManyComment { id } }").await;
+    assert_eq!(response, json!({ "data": { "findManyComment": [] } }));
+
+    let response = query_engine
+        .request("query { findOneComment(where: { id: 1 }, withDeleted: true) { id post { id } } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findOneComment": { "id": 1, "post": { "id": 1 } } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn upserting_a_deleted_record_fails(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    create_user_with_posts(&query_engine).await;
+
+    query_engine
+        .request("mutation { deleteOnePost(where: { id: 1 }) { id } }")
+        .await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                upsertOnePost(
+                    where: { id: 1 },
+                    create: { id: 1, title: "Created", author: { connect: { id: 1 } } },
+                    update: { title: "Updated" }
+                ) { id }
+            }
+        "#})
+        .await;
+
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2016");
+    assert!(response["errors"][0]["user_facing_error"]["meta"]["details"]
+        .as_str()
+        .unwrap()
+        .contains("soft deleted `Post` record"));
+
+    let response = query_engine
+        .request("query { findManyPost(withDeleted: true, orderBy: { id: asc }) { id title } }")
+        .await;
+    assert_eq!(
+        response,
+        json!({ "data": { "findManyPost": [{ "id": 1, "title": null }, { "id": 2, "title": null }] } })
+    );
+
+    Ok(())
+}
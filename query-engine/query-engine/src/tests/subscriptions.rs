@@ -0,0 +1,104 @@
+use super::test_api::*;
+use crate::request_handlers::Subscription;
+use futures::StreamExt;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id Int @id
+        title String
+        done Boolean @default(false)
+    }
+"};
+
+#[test_each_connector]
+async fn subscriptions_emit_matching_changes(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let cx = query_engine.context();
+
+    let subscription = Subscription::new(
+        "subscription { findManyTodo(where: { done: false }) { id title } }".into(),
+        cx,
+    )?;
+    let mut events = cx.executor.change_events().subscribe();
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 2, title: "b", done: true }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { deleteOneTodo(where: { id: 1 }) { id } }"#)
+        .await;
+
+    let mut batch = Vec::new();
+
+    for _ in 0..3 {
+        batch.push(events.next().await.unwrap());
+    }
+
+    // Both creations are merged into one query.
+    let emitted = subscription
+        .handle(&batch, cx)
+        .await
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(
+        emitted,
+        &[
+            json!({ "kind": "CREATED", "response": { "data": { "findManyTodo": [{ "id": 1, "title": "a" }] } } }),
+            json!({ "kind": "DELETED", "ids": [{ "id": 1 }] }),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn deleting_nothing_emits_no_event(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let cx = query_engine.context();
+    let mut events = cx.executor.change_events().subscribe();
+
+    query_engine
+        .request(r#"mutation { deleteManyTodo(where: { id: 1 }) { count } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { deleteOneTodo(where: { id: 1 }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }"#)
+        .await;
+
+    let event = events.next().await.unwrap();
+
+    assert_eq!(serde_json::to_value(&*event)?["kind"], json!("CREATED"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn only_find_many_queries_can_be_subscribed_to(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let err = Subscription::new(
+        "subscription { findOneTodo(where: { id: 1 }) { id } }".into(),
+        query_engine.context(),
+    )
+    .unwrap_err();
+
+    assert!(err.to_string().contains("Subscriptions must be `findMany` queries."));
+
+    let err = Subscription::new("query { findManyTodo { id } }".into(), query_engine.context()).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("Only subscriptions can be sent to the subscriptions endpoint."));
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use crate::{
     request_handlers::{graphql, GraphQlBody, SingleQuery},
     PrismaResponse,
 };
+use datamodel::{Datamodel, ReferentialIntegrity};
 use migration_core::{
     api::{GenericApi, MigrationApi},
     commands::SchemaPushInput,
@@ -15,6 +16,7 @@ use quaint::{
 use sql_migration_connector::{SqlMigration, SqlMigrationConnector};
 use std::sync::Arc;
 use test_setup::*;
+use url::Url;
 
 pub struct QueryEngine {
     context: Arc<PrismaContext>,
@@ -26,6 +28,10 @@ impl QueryEngine {
         QueryEngine { context: Arc::new(ctx) }
     }
 
+    pub fn context(&self) -> &Arc<PrismaContext> {
+        &self.context
+    }
+
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
         let body = GraphQlBody::Single(body.into());
         let cx = self.context.clone();
@@ -44,9 +50,43 @@ pub struct TestApi {
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with_config(&self.config, datamodel).await
+    }
+
+    /// Create an engine with the referential actions of the relations emulated
+    /// by the query engine, as with a database without foreign keys.
+    pub async fn create_engine_emulating_referential_actions(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        let mut config = datamodel::parse_configuration(&self.config).unwrap().subject;
+        config.datasources[0].referential_integrity = ReferentialIntegrity::Prisma;
+
+        let config = datamodel::render_datamodel_and_config_to_string(&Datamodel::new(), &config);
+
+        self.create_engine_with_config(&config, datamodel).await
+    }
+
+    /// Create an engine with extra parameters in the connection string, like
+    /// `connection_limit=1`.
+    pub async fn create_engine_with_connection_params(
+        &self,
+        datamodel: &str,
+        params: &[(&str, &str)],
+    ) -> anyhow::Result<QueryEngine> {
+        let mut config = datamodel::parse_configuration(&self.config).unwrap().subject;
+        let url = &mut config.datasources[0].url;
+
+        let mut parsed_url = Url::parse(&url.value)?;
+        parsed_url.query_pairs_mut().extend_pairs(params);
+        url.value = parsed_url.to_string();
+
+        let config = datamodel::render_datamodel_and_config_to_string(&Datamodel::new(), &config);
+
+        self.create_engine_with_config(&config, datamodel).await
+    }
+
+    async fn create_engine_with_config(&self, config: &str, datamodel: &str) -> anyhow::Result<QueryEngine> {
         feature_flags::initialize(&[String::from("all")]).unwrap();
 
-        let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
+        let datamodel_string = format!("{}\n\n{}", config, datamodel);
         let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
         let config = datamodel::parse_configuration(&datamodel_string).unwrap();
 